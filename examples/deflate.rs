@@ -0,0 +1,392 @@
+/*! DEFLATE-Style Tokenizer
+
+This example encodes text into a bit stream shaped like DEFLATE, and decodes it
+again with `BitReader`. It exercises the parts of the crate that real decoders
+need:
+
+- fields packed least significant bit first, as in DEFLATE block headers;
+- prefix codes written most significant bit first, decoded by looking ahead by
+  the longest code length and then consuming only the length of the code found;
+- a bit-level variable-length integer, the Elias gamma code, read with a unary
+  prefix;
+- stored blocks, which skip to the next byte boundary and then borrow whole
+  bytes from the stream without copying them;
+- truncated input, detected by reading past the end with zero padding and then
+  checking how far the reader overran.
+
+Run with
+
+```sh
+$ cargo run --example deflate -- [text]
+```
+
+where text is an optional message to encode in place of the built-in sample.
+
+# Format
+
+The stream is a sequence of blocks. Each block begins with a bit that is set on
+the last block, and a two-bit type field. A stored block (type 0) is padded to a
+byte boundary, and holds a 16-bit length, its complement, and that many bytes. A
+coded block (type 1) holds prefix codes from a fixed table, in which symbols 0
+through 255 are literal bytes, 256 ends the block, and 257 begins a back
+reference. A back reference is followed by two gamma codes: the match length
+less two, and the distance back into the output.
+!*/
+
+#[cfg(feature = "alloc")]
+extern crate bitvec;
+
+#[cfg(feature = "alloc")]
+use bitvec::{
+	prelude::*,
+	reader::Tail,
+};
+
+#[cfg(feature = "alloc")]
+use std::{
+	cmp,
+	env,
+};
+
+/// The symbol that ends a coded block.
+#[cfg(feature = "alloc")]
+const END: usize = 256;
+
+/// The symbol that begins a back reference.
+#[cfg(feature = "alloc")]
+const MATCH: usize = 257;
+
+/// The number of symbols in the prefix code.
+#[cfg(feature = "alloc")]
+const SYMBOLS: usize = 258;
+
+/// The length of the longest prefix code.
+#[cfg(feature = "alloc")]
+const MAX_CODE: usize = 9;
+
+/// The shortest back reference worth writing.
+#[cfg(feature = "alloc")]
+const MIN_MATCH: usize = 3;
+
+/// The farthest that a back reference may reach.
+#[cfg(feature = "alloc")]
+const WINDOW: usize = 4096;
+
+/// A unit of the tokenized text.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Token {
+	/// A single byte.
+	Literal(u8),
+	/// A copy of earlier output.
+	Match {
+		length: usize,
+		distance: usize,
+	},
+}
+
+/// The ways a stream can fail to decode.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Error {
+	/// The stream ended inside a block.
+	Truncated,
+	/// A block header named an unknown block type.
+	BlockType(u64),
+	/// The length of a stored block did not match its complement.
+	StoredLength,
+	/// The bits at this position are not a code in the table.
+	Code(usize),
+	/// A gamma code at this position is too long for any length or distance.
+	Gamma(usize),
+	/// A back reference reached before the start of the output.
+	Distance(usize),
+}
+
+/// The code length of each symbol. This has the shape of the fixed table of
+/// DEFLATE: common bytes receive the shorter codes.
+#[cfg(feature = "alloc")]
+fn code_length(symbol: usize) -> usize {
+	match symbol {
+		0 ..= 143 => 8,
+		144 ..= 255 => 9,
+		_ => 7,
+	}
+}
+
+/// Assigns canonical prefix codes to every symbol, from the code lengths.
+///
+/// Codes of one length are consecutive, in symbol order, and each length
+/// begins where the codes of the previous length end, doubled.
+#[cfg(feature = "alloc")]
+fn canonical_codes() -> Vec<u64> {
+	let mut count = [0u64; MAX_CODE + 1];
+	for symbol in 0 .. SYMBOLS {
+		count[code_length(symbol)] += 1;
+	}
+	let mut next = [0u64; MAX_CODE + 1];
+	let mut code = 0;
+	for len in 1 ..= MAX_CODE {
+		code = (code + count[len - 1]) << 1;
+		next[len] = code;
+	}
+	(0 .. SYMBOLS)
+		.map(|symbol| {
+			let len = code_length(symbol);
+			next[len] += 1;
+			next[len] - 1
+		})
+		.collect()
+}
+
+/// Builds a table that maps every `MAX_CODE`-bit window to the symbol whose
+/// code begins it, and the length of that code.
+#[cfg(feature = "alloc")]
+fn decode_table(codes: &[u64]) -> Vec<Option<(usize, usize)>> {
+	let mut table = vec![None; 1 << MAX_CODE];
+	for (symbol, &code) in codes.iter().enumerate() {
+		let pad = MAX_CODE - code_length(symbol);
+		let first = (code << pad) as usize;
+		for slot in &mut table[first .. first + (1 << pad)] {
+			*slot = Some((symbol, code_length(symbol)));
+		}
+	}
+	table
+}
+
+/// Splits text into literals and greedy back references.
+#[cfg(feature = "alloc")]
+fn tokenize(data: &[u8]) -> Vec<Token> {
+	let mut out = Vec::new();
+	let mut at = 0;
+	while at < data.len() {
+		let mut best = (0, 0);
+		for from in at.saturating_sub(WINDOW) .. at {
+			let len = data[at ..]
+				.iter()
+				.zip(&data[from ..])
+				.take_while(|(a, b)| a == b)
+				.count();
+			if len > best.0 {
+				best = (len, at - from);
+			}
+		}
+		if best.0 >= MIN_MATCH {
+			out.push(Token::Match { length: best.0, distance: best.1 });
+			at += best.0;
+		}
+		else {
+			out.push(Token::Literal(data[at]));
+			at += 1;
+		}
+	}
+	out
+}
+
+/// Appends an integer, least significant bit first.
+#[cfg(feature = "alloc")]
+fn put_le(out: &mut BitVec<LittleEndian, u8>, value: u64, count: usize) {
+	for n in 0 .. count {
+		out.push(value >> n & 1 == 1);
+	}
+}
+
+/// Appends an integer, most significant bit first.
+#[cfg(feature = "alloc")]
+fn put_be(out: &mut BitVec<LittleEndian, u8>, value: u64, count: usize) {
+	for n in (0 .. count).rev() {
+		out.push(value >> n & 1 == 1);
+	}
+}
+
+/// Appends the Elias gamma code of a positive integer: one cleared bit for
+/// each bit after the highest set bit, and then the integer itself.
+#[cfg(feature = "alloc")]
+fn put_gamma(out: &mut BitVec<LittleEndian, u8>, value: u64) {
+	let width = 64 - value.leading_zeros() as usize;
+	put_be(out, 0, width - 1);
+	put_be(out, value, width);
+}
+
+/// Appends a stored block.
+#[cfg(feature = "alloc")]
+fn put_stored(out: &mut BitVec<LittleEndian, u8>, data: &[u8], last: bool) {
+	out.push(last);
+	put_le(out, 0, 2);
+	while out.len() % 8 != 0 {
+		out.push(false);
+	}
+	put_le(out, data.len() as u64, 16);
+	put_le(out, !data.len() as u64 & 0xFFFF, 16);
+	for &byte in data {
+		put_le(out, byte as u64, 8);
+	}
+}
+
+/// Appends a coded block.
+#[cfg(feature = "alloc")]
+fn put_coded(
+	out: &mut BitVec<LittleEndian, u8>,
+	tokens: &[Token],
+	codes: &[u64],
+	last: bool,
+) {
+	let symbol = |out: &mut BitVec<LittleEndian, u8>, sym: usize| {
+		put_be(out, codes[sym], code_length(sym));
+	};
+	out.push(last);
+	put_le(out, 1, 2);
+	for &token in tokens {
+		match token {
+			Token::Literal(byte) => symbol(out, byte as usize),
+			Token::Match { length, distance } => {
+				symbol(out, MATCH);
+				put_gamma(out, (length - MIN_MATCH + 1) as u64);
+				put_gamma(out, distance as u64);
+			},
+		}
+	}
+	symbol(out, END);
+}
+
+/// Reads an Elias gamma code.
+#[cfg(feature = "alloc")]
+fn read_gamma(r: &mut BitReader<LittleEndian, u8>) -> Result<usize, Error> {
+	let at = r.position();
+	let zeros = r.read_unary().ok_or(Error::Truncated)?;
+	if zeros >= 32 {
+		return Err(Error::Gamma(at));
+	}
+	let low = r.read_be(zeros).ok_or(Error::Truncated)?;
+	Ok((1 << zeros | low) as usize)
+}
+
+/// Fails if the reader has consumed padding past the end of the stream.
+#[cfg(feature = "alloc")]
+fn check(r: &BitReader<LittleEndian, u8>) -> Result<(), Error> {
+	if r.overrun() == 0 {
+		Ok(())
+	}
+	else {
+		Err(Error::Truncated)
+	}
+}
+
+/// Decodes a stream of blocks.
+#[cfg(feature = "alloc")]
+fn decode(bits: &BitSlice<LittleEndian, u8>) -> Result<Vec<u8>, Error> {
+	let table = decode_table(&canonical_codes());
+	//  Look-ahead past the end reads zeros; `check` catches any code that was
+	//  completed by them.
+	let mut r = BitReader::new(bits).with_tail(Tail::Zeros);
+	let mut out = Vec::new();
+	loop {
+		let last = r.read_bit() == Some(true);
+		let kind = r.read_le(2).unwrap_or(0);
+		check(&r)?;
+		match kind {
+			0 => {
+				r.align(8);
+				let len = r.read_le(16).unwrap_or(0);
+				let nlen = r.read_le(16).unwrap_or(0);
+				check(&r)?;
+				if len != !nlen & 0xFFFF {
+					return Err(Error::StoredLength);
+				}
+				let data = r.read_bits(len as usize * 8)
+					.ok_or(Error::Truncated)?;
+				out.extend(data.iter_fields::<u8>(8));
+			},
+			1 => loop {
+				let at = r.position();
+				let window = r.peek_be(MAX_CODE).unwrap_or(0) as usize;
+				let (symbol, len) = table[window].ok_or(Error::Code(at))?;
+				r.skip(len);
+				check(&r)?;
+				match symbol {
+					END => break,
+					MATCH => {
+						let length = read_gamma(&mut r)? + MIN_MATCH - 1;
+						let distance = read_gamma(&mut r)?;
+						if distance > out.len() {
+							return Err(Error::Distance(at));
+						}
+						//  The copy may overlap the bytes it produces.
+						for _ in 0 .. length {
+							let byte = out[out.len() - distance];
+							out.push(byte);
+						}
+					},
+					byte => out.push(byte as u8),
+				}
+			},
+			other => return Err(Error::BlockType(other)),
+		}
+		if last {
+			return Ok(out);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+fn main() {
+	let args = env::args().skip(1).collect::<Vec<_>>();
+	let text = if args.is_empty() {
+		"Header: plain. \
+		 bits and bytes, bytes and bits; \
+		 bits and bytes, bytes and bits; \
+		 the bits go in and the bytes come out."
+			.to_owned()
+	}
+	else {
+		args.join(" ")
+	};
+	let data = text.as_bytes();
+	let codes = canonical_codes();
+
+	//  The first few bytes go out in a stored block, and the rest are coded.
+	let split = cmp::min(data.len(), 16);
+	let tokens = tokenize(&data[split ..]);
+	let mut stream = BitVec::<LittleEndian, u8>::new();
+	put_stored(&mut stream, &data[.. split], false);
+	put_coded(&mut stream, &tokens, &codes, true);
+
+	let matches = tokens
+		.iter()
+		.filter(|t| match t {
+			Token::Match { .. } => true,
+			_ => false,
+		})
+		.count();
+	println!("Input:   {} bytes", data.len());
+	println!(
+		"Tokens:  {} literals and {} back references after a {}-byte stored \
+		 block",
+		tokens.len() - matches,
+		matches,
+		split,
+	);
+	println!(
+		"Encoded: {} bits, in {} bytes",
+		stream.len(),
+		stream.as_slice().len(),
+	);
+
+	//  Decode from the bytes, as a real decoder would receive them. The final
+	//  byte is padded with zeros, which the decoder never reaches.
+	let bytes = stream.as_slice();
+	let decoded = decode(bytes.as_bitslice::<LittleEndian>())
+		.expect("the stream is well formed");
+	assert_eq!(decoded, data);
+	println!("Decoded: {:?}", String::from_utf8_lossy(&decoded));
+
+	//  Dropping the last few bits cuts the final end-of-block code short.
+	let cut = &stream[.. stream.len() - 3];
+	assert_eq!(decode(cut), Err(Error::Truncated));
+	println!("Truncated by 3 bits: {:?}", decode(cut).unwrap_err());
+}
+
+#[cfg(not(feature = "alloc"))]
+fn main() {
+	println!("This example only runs when an allocator is present");
+}
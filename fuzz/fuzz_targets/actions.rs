@@ -0,0 +1,34 @@
+/*! Drives arbitrary sequences of mutating operations against the `Vec<bool>`
+model.
+
+The first input byte selects the cursor and storage types; the remainder is
+decoded into actions by the interpreter in `tests/model`.
+!*/
+
+#![no_main]
+
+#[path = "../../tests/model/mod.rs"]
+mod model;
+
+use bitvec::prelude::*;
+
+use libfuzzer_sys::fuzz_target;
+
+use model::{
+	Machine,
+	Source,
+};
+
+fuzz_target!(|data: &[u8]| {
+	let mut src = Source::new(data);
+	match src.byte() % 8 {
+		0 => Machine::<BigEndian, u8>::new().run(&mut src),
+		1 => Machine::<LittleEndian, u8>::new().run(&mut src),
+		2 => Machine::<BigEndian, u16>::new().run(&mut src),
+		3 => Machine::<LittleEndian, u16>::new().run(&mut src),
+		4 => Machine::<BigEndian, u32>::new().run(&mut src),
+		5 => Machine::<LittleEndian, u32>::new().run(&mut src),
+		6 => Machine::<BigEndian, u64>::new().run(&mut src),
+		_ => Machine::<LittleEndian, u64>::new().run(&mut src),
+	}
+});
@@ -0,0 +1,59 @@
+/*! Builds a bit sequence from arbitrary actions, then checks that every
+subslice of it survives a trip through `serde`.
+
+Subslices carry their head offset into the serialized form, so this exercises
+the deserializers on partial edge elements as well as on whole ones.
+!*/
+
+#![no_main]
+
+#[path = "../../tests/model/mod.rs"]
+mod model;
+
+use bitvec::prelude::*;
+
+use libfuzzer_sys::fuzz_target;
+
+use model::{
+	Machine,
+	Source,
+};
+
+fn roundtrip<C, T>(src: &mut Source)
+where
+	C: Cursor,
+	T: 'static + BitStore + serde::Serialize + serde::de::DeserializeOwned,
+{
+	let mut machine = Machine::<C, T>::new();
+	//  Spend a bounded prefix of the input building the sequence, and the
+	//  remainder choosing subslices of it.
+	for _ in 0 .. src.below(64) {
+		machine.step(src);
+	}
+	machine.check();
+	let bits = machine.bits.as_bitslice();
+	while !src.is_empty() {
+		let (from, upto) = src.range(bits.len());
+		let part = &bits[from .. upto];
+		let json = serde_json::to_string(part).expect("serialization failed");
+
+		let bb: BitBox<C, T> = serde_json::from_str(&json)
+			.expect("deserialization to BitBox failed");
+		assert_eq!(bb.as_bitslice(), part, "BitBox from {}", json);
+
+		let bv: BitVec<C, T> = serde_json::from_str(&json)
+			.expect("deserialization to BitVec failed");
+		assert_eq!(bv.as_bitslice(), part, "BitVec from {}", json);
+		assert!(bv.iter().eq(machine.model[from .. upto].iter().cloned()));
+	}
+}
+
+fuzz_target!(|data: &[u8]| {
+	let mut src = Source::new(data);
+	match src.byte() % 4 {
+		0 => roundtrip::<BigEndian, u8>(&mut src),
+		1 => roundtrip::<LittleEndian, u16>(&mut src),
+		2 => roundtrip::<BigEndian, u32>(&mut src),
+		_ => roundtrip::<LittleEndian, u64>(&mut src),
+	}
+});
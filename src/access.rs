@@ -96,6 +96,45 @@ where T: BitStore + BitOps {
 		self.fetch_xor(*C::mask(place), Ordering::Relaxed);
 	}
 
+	/// Set every bit in an element which is set in a mask to some value.
+	///
+	/// Like [`invert_bits`], this is a single read-modify-write of the element,
+	/// and does not disturb the bits outside the mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `mask`: An element whose high bits mark the bits to write. This is
+	///   already in electrical order, and is not translated by a `Cursor`.
+	/// - `value`: The value to which the masked bits shall be set.
+	///
+	/// [`invert_bits`]: #method.invert_bits
+	#[inline(always)]
+	fn set_bits(&self, mask: T, value: bool) {
+		if value {
+			self.fetch_or(mask, Ordering::Relaxed);
+		}
+		else {
+			self.fetch_and(!mask, Ordering::Relaxed);
+		}
+	}
+
+	/// Invert every bit in an element which is set in a mask.
+	///
+	/// This is a single read-modify-write of the element, so it can commit
+	/// changes to many bits at once without disturbing the bits outside the
+	/// mask, which may belong to other handles.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `mask`: An element whose high bits mark the bits to invert. This is
+	///   already in electrical order, and is not translated by a `Cursor`.
+	#[inline(always)]
+	fn invert_bits(&self, mask: T) {
+		self.fetch_xor(mask, Ordering::Relaxed);
+	}
+
 	/// Retrieve a single bit from an element.
 	///
 	/// # Type Parameters
@@ -0,0 +1,405 @@
+/*! `BitArena` structure
+
+This module holds a bump allocator for bit slices. Programs that build very
+many short bit sequences pay for one heap allocation, and one handle of at least
+three words, per `BitVec`; an arena instead carves every sequence out of a few
+large buffers, packs them end to end with no padding between them, and hands
+them out as plain `BitSlice` references that live as long as the arena.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	indices::Indexable,
+	pointer::BitPtr,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use alloc::{
+	boxed::Box,
+	vec::Vec,
+};
+
+use core::{
+	cell::RefCell,
+	cmp,
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+	mem,
+	slice,
+};
+
+/// The fewest elements that the arena requests in one buffer.
+const MIN_ELEMENTS: usize = 64;
+
+/** A region that allocates many bit slices out of a few large buffers.
+
+Slices are handed out through a shared reference to the arena, so any number of
+them can be held at once, and they all remain valid until the arena is
+[`reset`] or dropped. Each slice starts at the bit immediately after the one
+before it, so slices may share storage elements at their edges; `BitSlice`
+already governs such shared elements safely, as it does after `split_at_mut`.
+
+When the current buffer cannot hold a request, the arena allocates a new buffer
+at least twice as large, and keeps the old one alive for the slices it holds.
+No buffer ever moves, and no slice is ever copied after it is handed out.
+
+A slice can be requested mutably, with [`alloc_mut`], filled in, and then
+frozen by reborrowing it as `&BitSlice`.
+
+# Type Parameters
+
+- `C`: The cursor type of the produced slices.
+- `T`: The storage type of the arena buffers.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let arena = BitArena::<BigEndian, u8>::new();
+
+let a = arena.alloc(&bitvec![1, 0, 1]);
+let b = {
+  let bits = arena.alloc_mut(5);
+  bits.set(4, true);
+  &*bits
+};
+assert_eq!(a, &bitvec![1, 0, 1]);
+assert_eq!(b, &bitvec![0, 0, 0, 0, 1]);
+assert_eq!(arena.allocated(), 8);
+```
+
+[`alloc_mut`]: #method.alloc_mut
+[`reset`]: #method.reset
+**/
+pub struct BitArena<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The arena buffers. This is borrowed only for the duration of one
+	/// allocation, never while a produced slice is in use.
+	chunks: RefCell<Chunks<T>>,
+	/// The arena only produces slices with this cursor.
+	_cursor: PhantomData<C>,
+}
+
+/// The buffers of an arena.
+struct Chunks<T>
+where T: BitStore {
+	/// The buffer from which new slices are carved. It is never resized.
+	current: Buffer<T>,
+	/// The number of bits in `current` already handed out.
+	used: usize,
+	/// Full buffers, kept alive for the slices that point into them.
+	retired: Vec<Buffer<T>>,
+	/// The number of bits handed out from `retired`.
+	retired_bits: usize,
+}
+
+/** One arena buffer of cleared elements.
+
+The buffer is held by a raw pointer, taken once when it is allocated, rather
+than by a `Vec` or `Box`. Every slice is carved from that pointer, so handing
+out a new slice never reborrows the whole buffer, and never invalidates the
+slices already handed out from it.
+**/
+struct Buffer<T>
+where T: BitStore {
+	/// The first element of the buffer, from `Box::into_raw`.
+	data: *mut T,
+	/// The number of elements in the buffer.
+	len: usize,
+}
+
+impl<T> Buffer<T>
+where T: BitStore {
+	/// Allocates a buffer of cleared elements.
+	///
+	/// # Parameters
+	///
+	/// - `elts`: The number of elements in the buffer.
+	///
+	/// # Returns
+	///
+	/// A buffer of `elts` elements, all zero.
+	fn zeroed(elts: usize) -> Self {
+		let mut out = Vec::with_capacity(elts);
+		out.resize(elts, T::from(0));
+		let data = Box::into_raw(out.into_boxed_slice()) as *mut T;
+		Self { data, len: elts }
+	}
+
+	/// Views the whole buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: An exclusive borrow of the arena proves that none of the
+	///   slices carved from the buffer are still alive.
+	///
+	/// # Returns
+	///
+	/// The elements of the buffer.
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe { slice::from_raw_parts_mut(self.data, self.len) }
+	}
+}
+
+impl<T> Drop for Buffer<T>
+where T: BitStore {
+	fn drop(&mut self) {
+		unsafe { drop(Box::from_raw(self.as_mut_slice() as *mut [T])); }
+	}
+}
+
+/// The buffer owns its elements, as the `Box<[T]>` it came from did.
+unsafe impl<T> Send for Buffer<T>
+where T: BitStore {}
+
+impl<C, T> BitArena<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs an empty arena, which does not allocate until it is first
+	/// used.
+	///
+	/// # Returns
+	///
+	/// An empty arena.
+	pub fn new() -> Self {
+		Self::with_capacity(0)
+	}
+
+	/// Constructs an arena which can hand out `bits` bits before it needs to
+	/// allocate again.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The number of bits to allocate at once.
+	///
+	/// # Returns
+	///
+	/// An empty arena, whose first buffer holds at least `bits` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let arena = BitArena::<BigEndian, u16>::with_capacity(100);
+	/// assert_eq!(arena.capacity(), 112);
+	/// assert_eq!(arena.allocated(), 0);
+	/// ```
+	pub fn with_capacity(bits: usize) -> Self {
+		Self {
+			chunks: RefCell::new(Chunks {
+				current: Buffer::zeroed(elements::<T>(bits)),
+				used: 0,
+				retired: Vec::new(),
+				retired_bits: 0,
+			}),
+			_cursor: PhantomData,
+		}
+	}
+
+	/// Counts the bits handed out since the arena was built or last reset.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The total length of all slices produced by the arena.
+	pub fn allocated(&self) -> usize {
+		let chunks = self.chunks.borrow();
+		chunks.retired_bits + chunks.used
+	}
+
+	/// Counts the bits in all of the arena’s buffers.
+	///
+	/// This includes bits already handed out, bits left unused at the end of
+	/// full buffers, and bits still available in the current buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits of memory owned by the arena.
+	pub fn capacity(&self) -> usize {
+		let chunks = self.chunks.borrow();
+		let elts = chunks.retired
+			.iter()
+			.fold(chunks.current.len, |sum, buf| sum + buf.len);
+		elts * T::BITS as usize
+	}
+
+	/// Copies a bit slice into the arena.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `src`: The bits to copy.
+	///
+	/// # Returns
+	///
+	/// A slice in the arena, equal to `src`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let arena = BitArena::<LittleEndian, u8>::new();
+	/// let words: Vec<&BitSlice<LittleEndian, u8>> = (0u8 .. 100)
+	///   .map(|n| arena.alloc(&n.as_bitslice::<LittleEndian>()[.. 7]))
+	///   .collect();
+	/// assert_eq!(words[5], &5u8.as_bitslice::<LittleEndian>()[.. 7]);
+	/// assert_eq!(arena.allocated(), 700);
+	/// ```
+	pub fn alloc(&self, src: &BitSlice<C, T>) -> &BitSlice<C, T> {
+		let out = self.alloc_mut(src.len());
+		out.copy_bits_from(src);
+		out
+	}
+
+	/// Allocates a cleared bit slice in the arena, for the caller to fill.
+	///
+	/// The slice can be frozen into a shared reference, by reborrowing it,
+	/// once it is filled.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `len`: The number of bits to allocate.
+	///
+	/// # Returns
+	///
+	/// A slice of `len` bits in the arena, all cleared.
+	///
+	/// # Panics
+	///
+	/// This panics if `len` is larger than a `BitSlice` can address.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let arena = BitArena::<BigEndian, u8>::new();
+	/// let bits = arena.alloc_mut(12);
+	/// assert!(bits.not_any());
+	/// bits[.. 4].set_all(true);
+	/// let frozen: &BitSlice = bits;
+	/// assert_eq!(frozen.count_ones(), 4);
+	/// ```
+	//  Every call carves out a region that no earlier call has handed out, so
+	//  the produced references are all unique.
+	#[allow(clippy::mut_from_ref)]
+	pub fn alloc_mut(&self, len: usize) -> &mut BitSlice<C, T> {
+		let bits = T::BITS as usize;
+		let mut chunks = self.chunks.borrow_mut();
+		let chunks = &mut *chunks;
+		if chunks.current.len * bits - chunks.used < len {
+			let elts = cmp::max(
+				cmp::max(elements::<T>(len), MIN_ELEMENTS),
+				chunks.current.len * 2,
+			);
+			let full = mem::replace(&mut chunks.current, Buffer::zeroed(elts));
+			chunks.retired_bits += mem::replace(&mut chunks.used, 0);
+			if full.len != 0 {
+				chunks.retired.push(full);
+			}
+		}
+		let start = chunks.used;
+		chunks.used += len;
+		//  Offset the buffer pointer directly, without borrowing the buffer,
+		//  so that the slices already carved from it stay valid.
+		let data = chunks.current.data.wrapping_add(start / bits);
+		BitPtr::new(data, ((start % bits) as u8).idx(), len)
+			.into_bitslice_mut()
+	}
+
+	/// Releases every slice handed out by the arena, so that its memory can be
+	/// used again.
+	///
+	/// The arena keeps one buffer as large as all of its buffers were together,
+	/// so that repeating the same work after a reset needs no allocation.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: An exclusive borrow proves that no slice from the arena
+	///   is still alive.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut arena = BitArena::<BigEndian, u8>::new();
+	/// for _ in 0 .. 1000 {
+	///   arena.alloc(&bitvec![1; 3]);
+	/// }
+	/// let capacity = arena.capacity();
+	///
+	/// arena.reset();
+	/// assert_eq!(arena.allocated(), 0);
+	/// assert_eq!(arena.capacity(), capacity);
+	/// assert!(arena.alloc_mut(3000).not_any());
+	/// assert_eq!(arena.capacity(), capacity);
+	/// ```
+	pub fn reset(&mut self) {
+		let chunks = self.chunks.get_mut();
+		if chunks.retired.is_empty() {
+			let used = elements::<T>(chunks.used);
+			for elt in &mut chunks.current.as_mut_slice()[.. used] {
+				*elt = T::from(0);
+			}
+		}
+		else {
+			let elts = chunks.retired
+				.drain(..)
+				.fold(chunks.current.len, |sum, buf| sum + buf.len);
+			chunks.current = Buffer::zeroed(0);
+			chunks.current = Buffer::zeroed(elts);
+		}
+		chunks.used = 0;
+		chunks.retired_bits = 0;
+	}
+}
+
+impl<C, T> Debug for BitArena<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"BitArena<{}, {}> {{ allocated: {}, capacity: {} }}",
+			C::TYPENAME,
+			T::TYPENAME,
+			self.allocated(),
+			self.capacity(),
+		)
+	}
+}
+
+impl<C, T> Default for BitArena<C, T>
+where C: Cursor, T: BitStore {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Counts the elements needed to hold some number of bits.
+fn elements<T>(bits: usize) -> usize
+where T: BitStore {
+	let width = T::BITS as usize;
+	bits / width + (bits % width != 0) as usize
+}
@@ -0,0 +1,493 @@
+/*! `BitArray` structure
+
+This module holds the type for an owned, fixed-size bit sequence that lives
+wherever its storage array lives, including on the stack, without any use of
+an allocator. `BitArray` is available in `#![no_std]` builds without the `alloc`
+feature.
+!*/
+
+use crate::{
+	bits::BitsMut,
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::{
+		BitMut,
+		BitSlice,
+	},
+	store::BitStore,
+};
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	clone::Clone,
+	cmp::{
+		Eq,
+		Ord,
+		Ordering,
+		PartialEq,
+		PartialOrd,
+	},
+	convert::{
+		AsMut,
+		AsRef,
+		From,
+	},
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::IntoIterator,
+	marker::{
+		Copy,
+		PhantomData,
+	},
+	ops::{
+		BitAnd,
+		BitAndAssign,
+		BitOr,
+		BitOrAssign,
+		BitXor,
+		BitXorAssign,
+		Deref,
+		DerefMut,
+		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
+	},
+};
+
+/** A fixed-size bit sequence, stored in an array of elements.
+
+`BitArray` owns its storage array directly, so it is exactly the size of that
+array, needs no allocator, and is `Copy` whenever the array is. It provides the
+full `BitSlice` API through `Deref` and `DerefMut`, and always spans every bit
+of every element in the array.
+
+The storage array type is a type parameter rather than a length, as this crate
+does not require const generics. Any array `[T; N]` of a `BitStore` type `T`,
+for `N` in `0 ..= 32`, can be used; these are the arrays that implement
+[`BitsMut`].
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `A: BitsMut`: The storage array, such as `[u8; 4]` or `[u64; 2]`. The number
+  of bits in the `BitArray` is the number of bits in this array.
+
+# Trait Implementations
+
+`BitArray<C, A>` implements the value traits (`Clone`, `Copy`, `Default`,
+comparison, hashing, and formatting) by deferring to the `BitSlice`
+implementations, and the bitwise and shift operators by value, for convenient
+use as a `Copy` type. Indexing, and every other `BitSlice` method, is available
+through `Deref`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut flags: BitArray<LittleEndian, [u16; 2]> = BitArray::new([0; 2]);
+assert_eq!(flags.len(), 32);
+flags.set(3, true);
+flags.set(17, true);
+let copy = flags;
+assert_eq!(copy.into_inner(), [0b1000, 0b10]);
+assert_eq!(flags.count_ones(), 2);
+assert!(flags[17]);
+```
+
+[`BitsMut`]: ../bits/trait.BitsMut.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+**/
+#[repr(transparent)]
+pub struct BitArray<C = BigEndian, A = [u8; 1]>
+where C: Cursor, A: BitsMut {
+	/// Phantom `Cursor` member to satisfy the constraint checker.
+	///
+	/// The fields are public only so that `bitarr!` can build values in
+	/// `const` and `static` items, which cannot call trait-bounded functions.
+	/// They are not part of the API.
+	#[doc(hidden)]
+	pub _cursor: PhantomData<C>,
+	/// The storage array.
+	#[doc(hidden)]
+	pub data: A,
+}
+
+impl<C, A> BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	/// Wraps a storage array as a `BitArray`.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The storage array. Every bit of it becomes part of the
+	///   `BitArray`, in the order that `C` reads it.
+	///
+	/// # Returns
+	///
+	/// A `BitArray` owning `data`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let arr = BitArray::<BigEndian, _>::new([0x80u8, 0x01]);
+	/// assert!(arr[0]);
+	/// assert!(arr[15]);
+	/// assert_eq!(arr.count_ones(), 2);
+	/// ```
+	pub fn new(data: A) -> Self {
+		Self {
+			_cursor: PhantomData,
+			data,
+		}
+	}
+
+	/// Removes the `BitArray` wrapper from its storage array.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The storage array underneath `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut arr: BitArray<BigEndian, [u8; 1]> = BitArray::default();
+	/// arr.set(7, true);
+	/// assert_eq!(arr.into_inner(), [1]);
+	/// ```
+	pub fn into_inner(self) -> A {
+		self.data
+	}
+
+	/// Accesses the `BitArray` as a `BitSlice`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `BitSlice` over every bit of the storage array.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let arr = BitArray::<LittleEndian, _>::new([1u32]);
+	/// let bits: &BitSlice<LittleEndian, u32> = arr.as_bitslice();
+	/// assert_eq!(bits.len(), 32);
+	/// ```
+	pub fn as_bitslice(&self) -> &BitSlice<C, A::Store> {
+		self.data.as_bitslice::<C>()
+	}
+
+	/// Accesses the `BitArray` as a mutable `BitSlice`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable `BitSlice` over every bit of the storage array.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut arr = BitArray::<LittleEndian, _>::new([0u32]);
+	/// arr.as_mut_bitslice()[.. 4].set_all(true);
+	/// assert_eq!(arr.into_inner(), [0xF]);
+	/// ```
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<C, A::Store> {
+		self.data.as_mut_bitslice::<C>()
+	}
+}
+
+impl<C, A> Borrow<BitSlice<C, A::Store>> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn borrow(&self) -> &BitSlice<C, A::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> BorrowMut<BitSlice<C, A::Store>> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn borrow_mut(&mut self) -> &mut BitSlice<C, A::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, A> Clone for BitArray<C, A>
+where C: Cursor, A: BitsMut + Clone {
+	fn clone(&self) -> Self {
+		Self::new(self.data.clone())
+	}
+}
+
+impl<C, A> Copy for BitArray<C, A>
+where C: Cursor, A: BitsMut + Copy {}
+
+impl<C, A> Eq for BitArray<C, A>
+where C: Cursor, A: BitsMut {}
+
+impl<C, A> Ord for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.as_bitslice().cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, B> PartialEq<BitArray<D, B>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, B: BitsMut {
+	fn eq(&self, rhs: &BitArray<D, B>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, T> PartialEq<BitSlice<D, T>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, T: BitStore {
+	fn eq(&self, rhs: &BitSlice<D, T>) -> bool {
+		self.as_bitslice().eq(rhs)
+	}
+}
+
+impl<C, T, D, B> PartialEq<BitArray<D, B>> for BitSlice<C, T>
+where C: Cursor, T: BitStore, D: Cursor, B: BitsMut {
+	fn eq(&self, rhs: &BitArray<D, B>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, B> PartialOrd<BitArray<D, B>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, B: BitsMut {
+	fn partial_cmp(&self, rhs: &BitArray<D, B>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, T> PartialOrd<BitSlice<D, T>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, T: BitStore {
+	fn partial_cmp(&self, rhs: &BitSlice<D, T>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs)
+	}
+}
+
+impl<C, T, D, B> PartialOrd<BitArray<D, B>> for BitSlice<C, T>
+where C: Cursor, T: BitStore, D: Cursor, B: BitsMut {
+	fn partial_cmp(&self, rhs: &BitArray<D, B>) -> Option<Ordering> {
+		self.partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A> AsMut<BitSlice<C, A::Store>> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn as_mut(&mut self) -> &mut BitSlice<C, A::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, A> AsRef<BitSlice<C, A::Store>> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn as_ref(&self) -> &BitSlice<C, A::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> From<A> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn from(data: A) -> Self {
+		Self::new(data)
+	}
+}
+
+impl<C, A> Default for BitArray<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn default() -> Self {
+		Self::new(A::default())
+	}
+}
+
+impl<C, A> Debug for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitArray<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(A::Store::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Display for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Hash for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<'a, C, A> IntoIterator for &'a BitArray<C, A>
+where C: Cursor, A: 'a + BitsMut {
+	type Item = bool;
+	type IntoIter = <&'a BitSlice<C, A::Store> as IntoIterator>::IntoIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_bitslice().into_iter()
+	}
+}
+
+impl<'a, C, A> IntoIterator for &'a mut BitArray<C, A>
+where C: Cursor, A: 'a + BitsMut {
+	type Item = BitMut<'a, C, A::Store>;
+	type IntoIter = <&'a mut BitSlice<C, A::Store> as IntoIterator>::IntoIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_bitslice().into_iter()
+	}
+}
+
+impl<C, A, I> BitAnd<I> for BitArray<C, A>
+where C: Cursor, A: BitsMut, I: IntoIterator<Item=bool> {
+	type Output = Self;
+
+	fn bitand(mut self, rhs: I) -> Self::Output {
+		self &= rhs;
+		self
+	}
+}
+
+impl<C, A, I> BitAndAssign<I> for BitArray<C, A>
+where C: Cursor, A: BitsMut, I: IntoIterator<Item=bool> {
+	fn bitand_assign(&mut self, rhs: I) {
+		self.as_mut_bitslice().bitand_assign(rhs);
+	}
+}
+
+impl<C, A, I> BitOr<I> for BitArray<C, A>
+where C: Cursor, A: BitsMut, I: IntoIterator<Item=bool> {
+	type Output = Self;
+
+	fn bitor(mut self, rhs: I) -> Self::Output {
+		self |= rhs;
+		self
+	}
+}
+
+impl<C, A, I> BitOrAssign<I> for BitArray<C, A>
+where C: Cursor, A: BitsMut, I: IntoIterator<Item=bool> {
+	fn bitor_assign(&mut self, rhs: I) {
+		self.as_mut_bitslice().bitor_assign(rhs);
+	}
+}
+
+impl<C, A, I> BitXor<I> for BitArray<C, A>
+where C: Cursor, A: BitsMut, I: IntoIterator<Item=bool> {
+	type Output = Self;
+
+	fn bitxor(mut self, rhs: I) -> Self::Output {
+		self ^= rhs;
+		self
+	}
+}
+
+impl<C, A, I> BitXorAssign<I> for BitArray<C, A>
+where C: Cursor, A: BitsMut, I: IntoIterator<Item=bool> {
+	fn bitxor_assign(&mut self, rhs: I) {
+		self.as_mut_bitslice().bitxor_assign(rhs);
+	}
+}
+
+impl<C, A> Deref for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	type Target = BitSlice<C, A::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> DerefMut for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, A> Not for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	type Output = Self;
+
+	fn not(mut self) -> Self::Output {
+		let _ = self.as_mut_bitslice().not();
+		self
+	}
+}
+
+impl<C, A> Shl<usize> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	type Output = Self;
+
+	fn shl(mut self, shamt: usize) -> Self::Output {
+		self <<= shamt;
+		self
+	}
+}
+
+impl<C, A> ShlAssign<usize> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn shl_assign(&mut self, shamt: usize) {
+		self.as_mut_bitslice().shl_assign(shamt);
+	}
+}
+
+impl<C, A> Shr<usize> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	type Output = Self;
+
+	fn shr(mut self, shamt: usize) -> Self::Output {
+		self >>= shamt;
+		self
+	}
+}
+
+impl<C, A> ShrAssign<usize> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn shr_assign(&mut self, shamt: usize) {
+		self.as_mut_bitslice().shr_assign(shamt);
+	}
+}
@@ -0,0 +1,494 @@
+/*! `BitArrayVec` structure
+
+This module holds a growable bit vector whose storage is a fixed-size array
+inside the value, for targets that have no allocator. It grows and shrinks like
+a `BitVec`, up to the number of bits in its array, and reports an attempt to
+grow past that as an error rather than reallocating. `BitArrayVec` is available
+in `#![no_std]` builds without the `alloc` feature.
+!*/
+
+use crate::{
+	array::BitArray,
+	bits::BitsMut,
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	clone::Clone,
+	cmp::{
+		Eq,
+		PartialEq,
+	},
+	convert::TryFrom,
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::{
+		Extend,
+		IntoIterator,
+	},
+	marker::Copy,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+/** A bit vector with a fixed capacity, stored in an array of elements.
+
+`BitArrayVec` owns a storage array directly, as [`BitArray`] does, and tracks
+how many of its bits are live. It never allocates: [`try_push`] and
+[`try_extend_from_bitslice`] report a [`CapacityError`] when the array is full,
+and [`push`] panics. The live bits are available as a `BitSlice` through
+`Deref` and `DerefMut`.
+
+Bits past the live length are always cleared, so [`into_inner`] returns an
+array holding only the live bits.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `A: BitsMut`: The storage array, such as `[u8; 4]` or `[u64; 2]`. The capacity
+  of the `BitArrayVec` is the number of bits in this array.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bits: BitArrayVec<BigEndian, [u8; 1]> = BitArrayVec::new();
+for n in 0 .. 8 {
+  bits.try_push(n % 3 == 0).unwrap();
+}
+assert!(bits.is_full());
+assert!(bits.try_push(true).is_err());
+assert_eq!(bits.as_bitslice(), &bitvec![1, 0, 0, 1, 0, 0, 1, 0]);
+
+bits.truncate(4);
+assert_eq!(bits.into_inner(), [0b1001_0000]);
+```
+
+[`BitArray`]: ../array/struct.BitArray.html
+[`CapacityError`]: struct.CapacityError.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`into_inner`]: #method.into_inner
+[`push`]: #method.push
+[`try_extend_from_bitslice`]: #method.try_extend_from_bitslice
+[`try_push`]: #method.try_push
+**/
+pub struct BitArrayVec<C = BigEndian, A = [u8; 1]>
+where C: Cursor, A: BitsMut {
+	/// The storage array. Bits at and above `len` are cleared.
+	bits: BitArray<C, A>,
+	/// The number of live bits.
+	len: usize,
+}
+
+impl<C, A> BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	/// Constructs a new, empty, `BitArrayVec<C, A>`.
+	///
+	/// # Returns
+	///
+	/// An empty vector, over a zeroed storage array.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits: BitArrayVec<LittleEndian, [u16; 2]> = BitArrayVec::new();
+	/// assert!(bits.is_empty());
+	/// assert_eq!(bits.capacity(), 32);
+	/// ```
+	pub fn new() -> Self
+	where A: Default {
+		Self { bits: BitArray::default(), len: 0 }
+	}
+
+	/// Counts the bits that the vector can hold.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits in the storage array.
+	pub fn capacity(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Counts the live bits in the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of live bits.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the vector is empty.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the vector has no live bits.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Tests if the vector is full.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether every bit of the storage array is live.
+	pub fn is_full(&self) -> bool {
+		self.len == self.capacity()
+	}
+
+	/// Appends a bit to the back of the vector, if there is room.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to append.
+	///
+	/// # Returns
+	///
+	/// `Ok` if the bit was appended, or an error describing the capacity if
+	/// the vector was full. A full vector is not changed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::array_vec::CapacityError;
+	///
+	/// let mut bits: BitArrayVec<BigEndian, [u8; 0]> = BitArrayVec::new();
+	/// assert_eq!(
+	///   bits.try_push(true),
+	///   Err(CapacityError { capacity: 0, required: 1 }),
+	/// );
+	/// ```
+	pub fn try_push(&mut self, value: bool) -> Result<(), CapacityError> {
+		if self.is_full() {
+			return Err(self.error(1));
+		}
+		let len = self.len;
+		self.bits.set(len, value);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Appends a bit to the back of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to append.
+	///
+	/// # Panics
+	///
+	/// This panics if the vector is full.
+	pub fn push(&mut self, value: bool) {
+		if let Err(err) = self.try_push(value) {
+			panic!("{}", err);
+		}
+	}
+
+	/// Removes the last bit from the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last bit, or `None` if the vector is empty.
+	pub fn pop(&mut self) -> Option<bool> {
+		let last = self.last()?;
+		let len = self.len - 1;
+		self.truncate(len);
+		Some(last)
+	}
+
+	/// Shortens the vector to `len` bits, if it is longer, and clears the
+	/// removed bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The number of bits to keep.
+	pub fn truncate(&mut self, len: usize) {
+		if len < self.len {
+			self.bits[len .. self.len].set_all(false);
+			self.len = len;
+		}
+	}
+
+	/// Removes every bit from the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Appends a bit slice to the back of the vector, if all of it fits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The bits to append.
+	///
+	/// # Returns
+	///
+	/// `Ok` if the bits were appended, or an error describing the capacity if
+	/// they would not all fit. In that case, the vector is not changed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits: BitArrayVec<BigEndian, [u8; 1]> = BitArrayVec::new();
+	/// assert!(bits.try_extend_from_bitslice(&bitvec![1; 5]).is_ok());
+	/// let err = bits.try_extend_from_bitslice(&bitvec![0; 5]).unwrap_err();
+	/// assert_eq!((err.capacity, err.required), (8, 10));
+	/// assert_eq!(bits.len(), 5);
+	/// ```
+	pub fn try_extend_from_bitslice(
+		&mut self,
+		other: &BitSlice<C, A::Store>,
+	) -> Result<(), CapacityError> {
+		if other.len() > self.capacity() - self.len {
+			return Err(self.error(other.len()));
+		}
+		let len = self.len;
+		self.bits[len .. len + other.len()].copy_bits_from(other);
+		self.len += other.len();
+		Ok(())
+	}
+
+	/// Views the live bits of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `BitSlice` over the live bits.
+	pub fn as_bitslice(&self) -> &BitSlice<C, A::Store> {
+		&self.bits[.. self.len]
+	}
+
+	/// Views the live bits of the vector, mutably.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable `BitSlice` over the live bits.
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<C, A::Store> {
+		let len = self.len;
+		&mut self.bits[.. len]
+	}
+
+	/// Removes the `BitArrayVec` wrapper from its storage array.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The storage array, in which only the live bits may be set.
+	pub fn into_inner(self) -> A {
+		self.bits.into_inner()
+	}
+
+	/// Describes a failure to add `additional` bits.
+	fn error(&self, additional: usize) -> CapacityError {
+		CapacityError {
+			capacity: self.capacity(),
+			required: self.len.saturating_add(additional),
+		}
+	}
+}
+
+/** The error produced when a `BitArrayVec` has no room for more bits.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CapacityError {
+	/// The number of bits in the vector’s storage array.
+	pub capacity: usize,
+	/// The number of bits that the rejected operation would have needed.
+	pub required: usize,
+}
+
+impl Display for CapacityError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"Capacity of {} bits exceeded: {} bits required",
+			self.capacity,
+			self.required,
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+impl<C, A> Clone for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut + Clone {
+	fn clone(&self) -> Self {
+		Self { bits: self.bits.clone(), len: self.len }
+	}
+}
+
+impl<C, A> Copy for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut + Copy {}
+
+impl<C, A> Eq for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {}
+
+impl<C, A> PartialEq for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	fn eq(&self, rhs: &Self) -> bool {
+		self.as_bitslice() == rhs.as_bitslice()
+	}
+}
+
+impl<C, A> Default for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C, A> Debug for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	/// Renders the `BitArrayVec` type header and its live bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits: BitArrayVec<LittleEndian, [u16; 1]> = BitArrayVec::new();
+	/// bits.push(true);
+	/// bits.push(false);
+	/// assert_eq!(
+	///   "BitArrayVec<LittleEndian, u16> [10]",
+	///   &format!("{:?}", bits),
+	/// );
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitArrayVec<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(A::Store::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Display for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Hash for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<C, A> Deref for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	type Target = BitSlice<C, A::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> DerefMut for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, A> Extend<bool> for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut {
+	/// Appends each bit of an iterator.
+	///
+	/// # Panics
+	///
+	/// This panics if the vector fills before the iterator is exhausted.
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item=bool> {
+		for bit in src {
+			self.push(bit);
+		}
+	}
+}
+
+impl<'a, C, A> TryFrom<&'a BitSlice<C, A::Store>> for BitArrayVec<C, A>
+where C: Cursor, A: BitsMut + Default {
+	type Error = CapacityError;
+
+	/// Copies a bit slice into a new vector, if it fits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use std::convert::TryFrom;
+	///
+	/// let src = bitvec![LittleEndian, u8; 1, 0, 1];
+	/// let bits = BitArrayVec::<LittleEndian, [u8; 1]>::try_from(&src[..]);
+	/// assert_eq!(bits.unwrap().into_inner(), [0b101]);
+	/// ```
+	fn try_from(src: &'a BitSlice<C, A::Store>) -> Result<Self, Self::Error> {
+		let mut out = Self::new();
+		out.try_extend_from_bitslice(src)?;
+		Ok(out)
+	}
+}
@@ -23,6 +23,40 @@ impl<C: Cursor, T: BitsMut> AsMut<BitSlice<C, T::Store>> for T {
 but it is correct in theory, and so all types which implement `Bits` should
 implement `AsRef<BitSlice>` and all types which implement `BitsMut` should
 implement `AsMut<BitSlice>`.
+
+# Fixed-Size Arrays
+
+References to arrays of up to 32 elements also convert into `BitSlice`
+references through `From`, and arrays accept `&BitSlice` on the right of the
+`&=`, `|=`, and `^=` operators. This allows fixed-size state, such as the words
+of a cipher or hash state, to be combined with bit slices in place and without
+allocation. The array is viewed with the `Cursor` of the right-hand slice, and
+only its first `rhs.len()` bits are modified. When the right-hand slice begins
+at the front of an element, whole elements are combined at once.
+
+```rust
+use bitvec::prelude::*;
+
+let mut state = [0u64; 4];
+let key = [!0u64, 0, !0, 0];
+let key_bits: &BitSlice<BigEndian, u64> = (&key).into();
+state ^= key_bits;
+state ^= &key_bits[.. 64];
+assert_eq!(state, [0, 0, !0, 0]);
+```
+
+The `BitSlice` operators already accept any stream of bits on their right, so
+the reverse direction combines an array into a slice by viewing it as bits:
+
+```rust
+use bitvec::prelude::*;
+
+let mut bv = bitvec![BigEndian, u64; 0; 80];
+let mask = [0x8000_0000_0000_0001u64];
+bv[8 ..] |= mask.as_bitslice::<BigEndian>();
+assert!(bv[8] && bv[71]);
+assert_eq!(bv.count_ones(), 2);
+```
 !*/
 
 use crate::{
@@ -31,9 +65,16 @@ use crate::{
 	store::BitStore,
 };
 
-use core::convert::{
-	AsMut,
-	AsRef,
+use core::{
+	convert::{
+		AsMut,
+		AsRef,
+	},
+	ops::{
+		BitAndAssign,
+		BitOrAssign,
+		BitXorAssign,
+	},
 };
 
 /** Allows a type to be used as a sequence of immutable bits.
@@ -243,6 +284,41 @@ impl BitsMut for [$t; $n] {
 		BitSlice::from_slice_mut(&mut self[..])
 	}
 }
+
+impl<'a, C> From<&'a [$t; $n]> for &'a BitSlice<C, $t>
+where C: Cursor {
+	fn from(src: &'a [$t; $n]) -> Self {
+		src.as_bitslice::<C>()
+	}
+}
+
+impl<'a, C> From<&'a mut [$t; $n]> for &'a mut BitSlice<C, $t>
+where C: Cursor {
+	fn from(src: &'a mut [$t; $n]) -> Self {
+		src.as_mut_bitslice::<C>()
+	}
+}
+
+impl<'a, C> BitAndAssign<&'a BitSlice<C, $t>> for [$t; $n]
+where C: Cursor {
+	fn bitand_assign(&mut self, rhs: &'a BitSlice<C, $t>) {
+		self.as_mut_bitslice::<C>().zip_assign(rhs, |l, r| l & r);
+	}
+}
+
+impl<'a, C> BitOrAssign<&'a BitSlice<C, $t>> for [$t; $n]
+where C: Cursor {
+	fn bitor_assign(&mut self, rhs: &'a BitSlice<C, $t>) {
+		self.as_mut_bitslice::<C>().zip_assign(rhs, |l, r| l | r);
+	}
+}
+
+impl<'a, C> BitXorAssign<&'a BitSlice<C, $t>> for [$t; $n]
+where C: Cursor {
+	fn bitxor_assign(&mut self, rhs: &'a BitSlice<C, $t>) {
+		self.as_mut_bitslice::<C>().zip_assign(rhs, |l, r| l ^ r);
+	}
+}
 	)* };
 }
 
@@ -12,7 +12,11 @@ use crate::{
 		Cursor,
 	},
 	pointer::BitPtr,
-	slice::BitSlice,
+	slice::{
+		BitMut,
+		BitSlice,
+	},
+	span::BitSpan,
 	store::BitStore,
 	vec::BitVec,
 };
@@ -276,6 +280,9 @@ where C: Cursor, T: BitStore {
 
 	/// Removes the `BitBox` wrapper from a `Box<[T]>`.
 	///
+	/// This does not copy or reallocate. The box holds every element that the
+	/// live bits touch, including any dead bits at either edge.
+	///
 	/// # Parameters
 	///
 	/// - `self`
@@ -290,10 +297,12 @@ where C: Cursor, T: BitStore {
 	/// use bitvec::prelude::*;
 	///
 	/// let slice: Box<[u16]> = vec![0, !0].into_boxed_slice();
+	/// let addr = slice.as_ptr();
 	/// let bb = BitBox::<LittleEndian, _>::from_boxed_slice(slice);
 	/// assert_eq!(bb.len(), 32);
 	/// let slice = bb.into_boxed_slice();
 	/// assert_eq!(slice.len(), 2);
+	/// assert_eq!(slice.as_ptr(), addr);
 	/// ```
 	pub fn into_boxed_slice(self) -> Box<[T]> {
 		let slice = self.pointer.as_mut_slice();
@@ -304,16 +313,51 @@ where C: Cursor, T: BitStore {
 		out
 	}
 
-	/// Constructs a `BitBox` from a raw `BitPtr`.
+	/// Removes the `BitBox` wrapper from a `Box<[T]>`, if the box has no
+	/// partial elements.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// If the box begins at the front of its first element and ends at the
+	/// back of its last, so that its elements contain no dead bits, this
+	/// returns the `Box<[T]>` underneath it, without copying.
+	///
+	/// Otherwise, this returns the box unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = bitbox![BigEndian, u8; 0, 1, 0, 1, 0, 1, 0, 1];
+	/// assert_eq!(&*bb.try_into_boxed_slice().unwrap(), &[0x55]);
+	///
+	/// let bb = BitBox::from_bitslice(&0x55u8.as_bitslice::<BigEndian>()[1 ..]);
+	/// assert!(bb.try_into_boxed_slice().is_err());
+	/// ```
+	pub fn try_into_boxed_slice(self) -> Result<Box<[T]>, Self> {
+		if self.pointer.is_whole() {
+			Ok(self.into_boxed_slice())
+		}
+		else {
+			Err(self)
+		}
+	}
+
+	/// Constructs a `BitBox` from a raw `BitSlice` pointer.
 	///
 	/// After calling this function, the raw pointer is owned by the resulting
 	/// `BitBox`. The `BitBox` will deallocate the memory region it describes.
 	///
 	/// # Parameters
 	///
-	/// - `pointer`: A `BitPtr<T>` describing a region of owned memory. This
-	///   must have previously produced by `BitBox` constructors; it is unsound
-	///   to even pass in `BitPtr<T>` values taken from `BitVec<C, T>` handles.
+	/// - `raw`: A pointer to a `BitSlice` region of owned memory. This must
+	///   have previously been produced by [`BitBox::into_raw`]; it is unsound to
+	///   pass in pointers to borrowed slices, or to the buffer of a `BitVec`.
 	///
 	/// # Returns
 	///
@@ -321,34 +365,92 @@ where C: Cursor, T: BitStore {
 	///
 	/// # Safety
 	///
-	/// Because Rust does not specify the allocation scheme used, the only
-	/// valid pointer to pass into this function is one that had previously been
-	/// produced by `BitBox` constructors and extracted by [`BitBox::into_raw`].
+	/// The memory behind a `BitBox` is allocated by the global allocator as a
+	/// `Box<[T]>`, and is released through the same allocator. The only valid
+	/// pointer to pass into this function is one that was extracted by
+	/// [`BitBox::into_raw`] in a program that uses the same global allocator
+	/// as the caller. When the pointer crosses a dynamic-library boundary, both
+	/// sides must be built with the same toolchain, against the same version of
+	/// this crate, and must share one global allocator: neither library may
+	/// install its own `#[global_allocator]` unless the other links to it.
+	///
+	/// The `C` and `T` type parameters must also match those of the `BitBox`
+	/// that produced the pointer.
 	///
 	/// This function is unsafe because improper use can lead to double-free
-	/// errors (constructing multiple `BitBox`es from the same `BitPtr`) or
+	/// errors (constructing multiple `BitBox`es from the same pointer) or
 	/// allocator inconsistencies (arbitrary pointers).
 	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = bitbox![0, 1, 1, 0];
+	/// let raw = BitBox::into_raw(bb);
+	/// let bb = unsafe { BitBox::from_raw(raw) };
+	/// assert_eq!(bb, bitbox![0, 1, 1, 0]);
+	/// ```
+	///
 	/// [`BitBox::into_raw`]: #method.into_raw
-	pub unsafe fn from_raw(pointer: BitPtr<T>) -> Self {
-		Self {
-			_cursor: PhantomData,
-			pointer,
-		}
+	pub unsafe fn from_raw(raw: *mut BitSlice<C, T>) -> Self {
+		Self::from_bitptr(BitPtr::from_bitslice(&*raw))
 	}
 
-	/// Consumes the `BitBox`, returning the wrapped `BitPtr` directly.
+	/// Consumes the `BitBox`, returning a raw pointer to its `BitSlice`.
 	///
 	/// After calling this function, the caller is responsible for the memory
 	/// previously managed by the `BitBox`. In particular, the caller must
-	/// properly release the memory region to which the `BitPtr` refers.
-	/// The proper way to do so is to convert the `BitPtr` back into a `BitBox`
-	/// with the [`BitBox::from_raw`] function.
+	/// properly release the memory region to which the pointer refers. The
+	/// proper way to do so is to convert the pointer back into a `BitBox` with
+	/// the [`BitBox::from_raw`] function, which states the requirements for
+	/// moving the pointer between programs or libraries.
+	///
+	/// This is an associated function, rather than a method, so that it does
+	/// not shadow methods on the `BitSlice` to which `BitBox` dereferences.
+	///
+	/// # Parameters
+	///
+	/// - `b`: The `BitBox` to deconstruct.
+	///
+	/// # Returns
+	///
+	/// A pointer to the slice formerly governed by the `BitBox`. It is a wide
+	/// pointer, and cannot be passed through an FFI boundary that expects a
+	/// thin pointer. `Arc<BitBox<C, T>>` is `Sized`, so a shared handle can be
+	/// passed as a thin pointer with `Arc::into_raw` and `Arc::from_raw`
+	/// instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let raw = BitBox::into_raw(bitbox![1; 10]);
+	/// unsafe { (*raw).set(0, false); }
+	/// let bb = unsafe { BitBox::from_raw(raw) };
+	/// assert!(!bb[0]);
+	/// ```
+	///
+	/// Sharing a box through a thin pointer:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use std::sync::Arc;
+	///
+	/// let shared = Arc::new(bitbox![0, 1, 0, 1]);
+	/// let thin: *const BitBox = Arc::into_raw(shared.clone());
+	/// let restored = unsafe { Arc::from_raw(thin) };
+	/// assert_eq!(restored.count_ones(), 2);
+	/// drop(restored);
+	/// assert_eq!(Arc::strong_count(&shared), 1);
+	/// ```
 	///
 	/// [`BitBox::from_raw`]: #method.from_raw
-	pub unsafe fn into_raw(self) -> BitPtr<T> {
-		let out = self.bitptr();
-		mem::forget(self);
+	#[allow(clippy::wrong_self_convention)]
+	pub fn into_raw(b: Self) -> *mut BitSlice<C, T> {
+		let out = b.pointer.into_bitslice_mut::<C>() as *mut BitSlice<C, T>;
+		mem::forget(b);
 		out
 	}
 
@@ -411,7 +513,7 @@ where C: Cursor, T: BitStore {
 	where D: Cursor {
 		let bp = self.bitptr();
 		mem::forget(self);
-		unsafe { BitBox::from_raw(bp) }
+		unsafe { BitBox::from_bitptr(bp) }
 	}
 
 	/// Accesses the `BitSlice<C, T>` to which the `BitBox` refers.
@@ -453,6 +555,29 @@ where C: Cursor, T: BitStore {
 		self.pointer
 	}
 
+	/// Constructs a `BitBox` from a raw `BitPtr`.
+	///
+	/// # Parameters
+	///
+	/// - `pointer`: A `BitPtr<T>` describing a region of owned memory,
+	///   allocated as a `Box<[T]>`.
+	///
+	/// # Returns
+	///
+	/// An owned `BitBox` over the given pointer.
+	///
+	/// # Safety
+	///
+	/// The pointer must own its memory, as for [`BitBox::from_raw`].
+	///
+	/// [`BitBox::from_raw`]: #method.from_raw
+	pub(crate) unsafe fn from_bitptr(pointer: BitPtr<T>) -> Self {
+		Self {
+			_cursor: PhantomData,
+			pointer,
+		}
+	}
+
 	/// Allows a function to access the `Box<[T]>` that the `BitBox` is using
 	/// under the hood.
 	///
@@ -685,6 +810,16 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
+impl<'a, C, T> IntoIterator for &'a mut BitBox<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = BitMut<'a, C, T>;
+	type IntoIter = <&'a mut BitSlice<C, T> as IntoIterator>::IntoIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_bitslice().into_iter()
+	}
+}
+
 /// `BitBox` is safe to move across thread boundaries, as is `&mut BitBox`.
 unsafe impl<C, T> Send for BitBox<C, T>
 where C: Cursor, T: BitStore {}
@@ -787,6 +922,8 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+__bitslice_int_cmp!(for [C, T] BitBox<C, T> => u8, u16, u32, u64, u128);
+
 impl<C, T> Index<usize> for BitBox<C, T>
 where C: Cursor, T: BitStore {
 	type Output = bool;
@@ -895,6 +1032,22 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+impl<C, T> Index<BitSpan> for BitBox<C, T>
+where C: Cursor, T: BitStore {
+	type Output = BitSlice<C, T>;
+
+	fn index(&self, span: BitSpan) -> &Self::Output {
+		&self.as_bitslice()[span]
+	}
+}
+
+impl<C, T> IndexMut<BitSpan> for BitBox<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, span: BitSpan) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[span]
+	}
+}
+
 impl<C, T> Neg for BitBox<C, T>
 where C: Cursor, T: BitStore {
 	type Output = Self;
@@ -0,0 +1,260 @@
+/*! Shared-mutable bit views
+
+`BitSlice` routes every access to its memory through the `BitStore::Access`
+type: `Cell` in ordinary builds, and the atomic types when the `atomic` feature
+is enabled. The `&mut` requirement on its write methods is therefore a choice of
+API, not of memory safety. This module provides [`BitCells`], a view of a
+`BitSlice` which can be written through shared references, in the manner of
+`Cell`.
+
+A `&BitCells` can only be made from a `&mut BitSlice`, so the cell view holds
+the exclusive borrow for as long as it lives. It is `Sync`, and so can be shared
+between threads, only when the `atomic` feature is enabled.
+
+[`BitCells`]: struct.BitCells.html
+!*/
+
+use crate::{
+	access::BitAccess,
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Debug,
+	Display,
+	Formatter,
+};
+
+/** A `BitSlice` which is mutable through shared references.
+
+This is the bit-slice counterpart of `[Cell<T>]`: any number of `&BitCells`
+handles may coexist, and each may read or write any bit in the view. It is
+produced by [`BitSlice::as_cells`] or [`BitCells::from_mut`].
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `BitSlice`.
+- `T: BitStore`: The storage type of the underlying `BitSlice`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::cells::BitCells;
+
+fn mark(seen: &BitCells, a: usize, b: usize) {
+  seen.set(a, true);
+  seen.set(b, true);
+}
+
+let mut src = 0u8;
+let cells = src.as_mut_bitslice::<BigEndian>().as_cells();
+let (alias_a, alias_b) = (cells, cells);
+mark(alias_a, 0, 7);
+assert!(alias_b.get(7));
+assert_eq!(src, 0x81);
+```
+
+[`BitCells::from_mut`]: #method.from_mut
+[`BitSlice::as_cells`]: ../slice/struct.BitSlice.html#method.as_cells
+**/
+#[repr(transparent)]
+pub struct BitCells<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The slice being viewed.
+	bits: BitSlice<C, T>,
+}
+
+impl<C, T> BitCells<C, T>
+where C: Cursor, T: BitStore {
+	/// Views a mutable `BitSlice` as a shared-mutable `BitCells`.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice to view. It remains borrowed for the lifetime of
+	///   the returned view.
+	///
+	/// # Returns
+	///
+	/// A cell view of `bits`.
+	pub fn from_mut(bits: &mut BitSlice<C, T>) -> &Self {
+		unsafe { &*(bits as *mut BitSlice<C, T> as *const Self) }
+	}
+
+	/// Returns the number of bits in the view.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits in the view.
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests if the view is empty.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the view has no bits.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Reads a bit from the view.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to read.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0x40u8;
+	/// let cells = src.as_mut_bitslice::<BigEndian>().as_cells();
+	/// assert!(cells.get(1));
+	/// ```
+	pub fn get(&self, index: usize) -> bool {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bits.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { (&*bitptr.pointer().a().offset(elt)).get::<C>(bit) }
+	}
+
+	/// Writes a bit in the view.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to write.
+	/// - `value`: The value to write into the bit.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let cells = src.as_mut_bitslice::<LittleEndian>().as_cells();
+	/// cells.set(1, true);
+	/// assert_eq!(src, 2);
+	/// ```
+	pub fn set(&self, index: usize, value: bool) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bits.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { (&*bitptr.pointer().a().offset(elt)).set::<C>(bit, value) }
+	}
+
+	/// Writes a bit in the view, returning its previous value.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to write.
+	/// - `value`: The value to write into the bit.
+	///
+	/// # Returns
+	///
+	/// The value of the bit before the write.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let cells = src.as_mut_bitslice::<BigEndian>().as_cells();
+	/// assert!(!cells.replace(3, true));
+	/// assert!(cells.replace(3, false));
+	/// ```
+	pub fn replace(&self, index: usize, value: bool) -> bool {
+		let out = self.get(index);
+		self.set(index, value);
+		out
+	}
+
+	/// Divides the view into two at an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mid`: The index at which to split.
+	///
+	/// # Returns
+	///
+	/// - The bits up to but not including `mid`.
+	/// - The bits from `mid` onwards.
+	///
+	/// # Panics
+	///
+	/// This panics if `mid > self.len()`.
+	pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+		let (head, tail) = self.bits.split_at(mid);
+		unsafe {
+			(
+				&*(head as *const BitSlice<C, T> as *const Self),
+				&*(tail as *const BitSlice<C, T> as *const Self),
+			)
+		}
+	}
+
+	/// Returns the viewed `BitSlice`, which requires exclusive access.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The `BitSlice` underneath the view.
+	pub fn get_mut(&mut self) -> &mut BitSlice<C, T> {
+		&mut self.bits
+	}
+}
+
+/// Prints the current contents of the view.
+impl<C, T> Debug for BitCells<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitCells<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(&self.bits, f)
+	}
+}
@@ -19,6 +19,7 @@ use crate::{
 		BitIdx,
 		BitMask,
 		BitPos,
+		BitTail,
 		Indexable,
 	},
 	store::BitStore,
@@ -32,6 +33,41 @@ pub struct BigEndian;
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct LittleEndian;
 
+/// An alias for `BigEndian`, in the numbering that calls the most significant
+/// bit of an element bit 0.
+pub type Msb0 = BigEndian;
+
+/// An alias for `LittleEndian`, in the numbering that calls the least
+/// significant bit of an element bit 0.
+pub type Lsb0 = LittleEndian;
+
+/** The bit ordering of network protocol diagrams.
+
+Protocol specifications, such as the RFCs for IP and TCP, number the bits of a
+packet from the most significant bit of its first byte. This is the `BigEndian`
+cursor over `u8` storage, which is the default storage type; `NetworkOrder` is
+an alias for `BigEndian` that names this use. A `BitSlice<NetworkOrder>` over a
+received buffer is indexed by the bit offsets printed in the diagrams.
+
+As an alias, it is the same type as `BigEndian`, and displays under that name.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+//  The first two bytes of an IPv4 header.
+let header = [0x45u8, 0xB8];
+let bits = header.as_bitslice::<NetworkOrder>();
+let mut r = BitReader::new(bits);
+assert_eq!(r.read_be(4), Some(4)); // version
+assert_eq!(r.read_be(4), Some(5)); // header length, in words
+assert_eq!(r.read_be(6), Some(46)); // DSCP: expedited forwarding
+assert!(!bits[14] && !bits[15]); // ECN: not capable
+```
+**/
+pub type NetworkOrder = BigEndian;
+
 /** A cursor over an element.
 
 # Usage
@@ -44,6 +80,28 @@ pub trait Cursor {
 	/// Name of the cursor type, for use in text display.
 	const TYPENAME: &'static str;
 
+	/// Marks that `at` is the identity function: semantic index `n` is
+	/// electrical position `n` in every storage type.
+	///
+	/// Generic algorithms test this constant to select kernels that operate on
+	/// whole elements with shifts and masks, rather than one bit at a time.
+	/// The test is resolved at compile time, so the unused path is removed.
+	///
+	/// This defaults to `false`, which is always correct. An implementor must
+	/// only set it to `true` if `at` is the identity for every `T: BitStore`;
+	/// setting it falsely causes the library to compute incorrect results.
+	const IS_IDENTITY: bool = false;
+
+	/// Marks that `at` reverses an element: semantic index `n` is electrical
+	/// position `T::MASK - n` in every storage type.
+	///
+	/// This is the counterpart of [`IS_IDENTITY`] for orderings that begin at
+	/// the most significant bit, and carries the same obligations. A cursor
+	/// must not set both constants.
+	///
+	/// [`IS_IDENTITY`]: #associatedconstant.IS_IDENTITY
+	const IS_REVERSED: bool = false;
+
 	/// Translate a semantic bit index into an electrical bit position.
 	///
 	/// # Parameters
@@ -149,10 +207,53 @@ pub trait Cursor {
 		);
 		unsafe { BitMask::new_unchecked(T::from(1) << *place) }
 	}
+
+	/// Translate a range of semantic bit indices into an electrical bit mask.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The library uses this mask for every operation which touches several
+	/// bits of one element at once, such as the partial edge elements of
+	/// `set_all` and `count_ones`, and the single read/modify/write by which
+	/// the atomic access path commits them. The default implementation
+	/// combines `Self::mask` for each index in the range, one at a time.
+	/// `Cursor` implementations which keep a range of indices contiguous in
+	/// the element should provide a faster mask production here, with shifts.
+	///
+	/// # Parameters
+	///
+	/// - `from`: The first semantic index in the range.
+	/// - `upto`: The first semantic index after the range. This may be
+	///   `T::BITS`.
+	///
+	/// # Returns
+	///
+	/// An element with the electrical position of each index in
+	/// `from .. upto` set, and every other position cleared. If `from` is not
+	/// before `upto`, the range is empty, and so is the mask.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type for which the mask will be calculated.
+	///
+	/// # Invariants
+	///
+	/// The produced value must be equivalent to the bitwise OR of
+	/// `Self::mask(n)` for each `n` in `from .. upto`.
+	fn mask_range<T>(from: BitIdx<T>, upto: BitTail<T>) -> T
+	where T: BitStore {
+		let mut mask = T::bits(false);
+		for n in *from .. *upto {
+			mask |= *Self::mask::<T>(n.idx());
+		}
+		mask
+	}
 }
 
 impl Cursor for BigEndian {
 	const TYPENAME: &'static str = "BigEndian";
+	const IS_REVERSED: bool = true;
 
 	/// Maps a semantic count to a concrete position.
 	///
@@ -170,10 +271,80 @@ impl Cursor for BigEndian {
 		//  only a single right-shift.
 		unsafe { BitMask::new_unchecked((T::from(1) << T::MASK) >> *cursor) }
 	}
+
+	fn mask_range<T>(from: BitIdx<T>, upto: BitTail<T>) -> T
+	where T: BitStore {
+		//  The range is a run of ones, counted down from the MSbit.
+		match ones::<T>(*from, *upto) {
+			Some(run) => run << (T::BITS - *upto),
+			None => T::bits(false),
+		}
+	}
+}
+
+/** Constant forms of the `BigEndian` cursor functions.
+
+Trait methods cannot be called in constant expressions, so these compute the
+same values as `Cursor::at`, `Cursor::mask`, and `Cursor::mask_range` with
+constant arithmetic alone. They can build masks and lookup tables in `const`
+and `static` items, and `bitarr!` uses them to place its bits.
+
+Constant functions cannot be generic over `BitStore`, so each takes the width of
+the storage element, `T::BITS`, as its first argument, and masks are produced
+as `u64` for the caller to narrow.
+
+# Panics
+
+Each function panics, or when evaluated in a constant fails to compile, if the
+width is not the width of a `BitStore` type, or if an index is out of range for
+it.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::store::BitStore;
+
+const W: u8 = <u16 as BitStore>::BITS;
+static FLAGS: [u16; 3] = [
+  BigEndian::const_mask(W, 0) as u16,
+  BigEndian::const_mask(W, 15) as u16,
+  BigEndian::const_mask_range(W, 4, 8) as u16,
+];
+assert_eq!(FLAGS, [0x8000, 0x0001, 0x0F00]);
+assert_eq!(BigEndian::const_at(W, 1), 14);
+```
+
+An index past the end of the element is rejected at compile time:
+
+```rust,compile_fail
+use bitvec::prelude::*;
+
+const BAD: u64 = BigEndian::const_mask(8, 8);
+assert_eq!(BAD, 0);
+```
+**/
+impl BigEndian {
+	/// Maps a semantic index to an electrical position, as `Cursor::at`.
+	pub const fn const_at(width: u8, index: u8) -> u8 {
+		width - 1 - checked(width, index, index + 1)
+	}
+
+	/// Produces the mask of one semantic index, as `Cursor::mask`.
+	pub const fn const_mask(width: u8, index: u8) -> u64 {
+		1 << Self::const_at(width, index)
+	}
+
+	/// Produces the mask of the semantic indices `from .. upto`, as
+	/// `Cursor::mask_range`.
+	pub const fn const_mask_range(width: u8, from: u8, upto: u8) -> u64 {
+		const_run(checked(width, from, upto), upto, width - upto)
+	}
 }
 
 impl Cursor for LittleEndian {
 	const TYPENAME: &'static str = "LittleEndian";
+	const IS_IDENTITY: bool = true;
 
 	/// Maps a semantic count to a concrete position.
 	///
@@ -188,6 +359,627 @@ impl Cursor for LittleEndian {
 		//  Set the LSbit, then shift it up.
 		unsafe { BitMask::new_unchecked(T::from(1) << *cursor) }
 	}
+
+	fn mask_range<T>(from: BitIdx<T>, upto: BitTail<T>) -> T
+	where T: BitStore {
+		//  The range is a run of ones, counted up from the LSbit.
+		match ones::<T>(*from, *upto) {
+			Some(run) => run << *from,
+			None => T::bits(false),
+		}
+	}
+}
+
+/** Constant forms of the `LittleEndian` cursor functions.
+
+These mirror the constant forms of the [`BigEndian`] functions, and have the
+same arguments and panics.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::store::BitStore;
+
+const W: u8 = <u8 as BitStore>::BITS;
+const LOW_NIBBLE: u8 = LittleEndian::const_mask_range(W, 0, 4) as u8;
+assert_eq!(LOW_NIBBLE, 0x0F);
+assert_eq!(LittleEndian::const_mask(W, 7), 0x80);
+```
+
+[`BigEndian`]: struct.BigEndian.html#impl
+**/
+impl LittleEndian {
+	/// Maps a semantic index to an electrical position, as `Cursor::at`.
+	pub const fn const_at(width: u8, index: u8) -> u8 {
+		checked(width, index, index + 1)
+	}
+
+	/// Produces the mask of one semantic index, as `Cursor::mask`.
+	pub const fn const_mask(width: u8, index: u8) -> u64 {
+		1 << Self::const_at(width, index)
+	}
+
+	/// Produces the mask of the semantic indices `from .. upto`, as
+	/// `Cursor::mask_range`.
+	pub const fn const_mask_range(width: u8, from: u8, upto: u8) -> u64 {
+		const_run(checked(width, from, upto), upto, from)
+	}
+}
+
+/// Produces a run of `upto - from` ones at the bottom of an element, or `None`
+/// if the range is empty.
+fn ones<T>(from: u8, upto: u8) -> Option<T>
+where T: BitStore {
+	if from >= upto {
+		return None;
+	}
+	//  Shifting by the full width overflows, so the run is cut down from a
+	//  full element rather than built up from zero.
+	Some(!T::bits(false) >> (T::BITS - (upto - from)))
+}
+
+/// Validates the arguments of the constant cursor functions.
+///
+/// Constant functions cannot branch, so an invalid argument indexes past the
+/// end of a one-element array, which fails constant evaluation or panics.
+///
+/// # Parameters
+///
+/// - `width`: The width of a storage element. It must be 8, 16, 32, or 64.
+/// - `from`: The first index of a range.
+/// - `upto`: The index after the last of a range. It must be at least `from`
+///   and at most `width`.
+///
+/// # Returns
+///
+/// `from`, if the arguments are valid.
+const fn checked(width: u8, from: u8, upto: u8) -> u8 {
+	[from][(
+		(width.count_ones() != 1)
+		| (width < 8)
+		| (width > 64)
+		| (from > upto)
+		| (upto > width)
+	) as usize]
+}
+
+/// Produces a run of `upto - from` ones, shifted up by `shift` bits.
+const fn const_run(from: u8, upto: u8, shift: u8) -> u64 {
+	//  The run, or the shift, may be all 64 bits, which a `u64` cannot do.
+	(((1u128 << (upto - from)) - 1) << shift) as u64
+}
+
+/** Interleaves the two halves of an element, as in a Morton (Z-order) code.
+
+The first half of the semantic indices in an element occupy the even electrical
+positions, and the second half occupy the odd positions, each in increasing
+order. Writing the bits of one coordinate, least significant first, into the
+front half of an element, and the bits of another into the back half, produces
+their Morton code, with the first coordinate in the even bits:
+
+```text
+u8 semantic:   0 1 2 3 4 5 6 7
+u8 electrical: 0 2 4 6 1 3 5 7
+```
+
+This ordering is neither the identity nor the reversal of an element, so every
+`BitSlice` operation takes its general path, one bit at a time, for it. It
+serves both as an ordering for texture swizzling and spatial indices, and as a
+demonstration that cursors need not be monotonic.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::cursor::Morton;
+
+fn z_order(x: u8, y: u8) -> u16 {
+  let mut code = 0u16;
+  let bits = code.as_mut_bitslice::<Morton>();
+  for n in 0 .. 8 {
+    bits.set(n, x >> n & 1 == 1);
+    bits.set(n + 8, y >> n & 1 == 1);
+  }
+  code
+}
+
+assert_eq!(z_order(3, 1), 0b0111);
+assert_eq!(z_order(0, 255), 0xAAAA);
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Morton;
+
+impl Cursor for Morton {
+	const TYPENAME: &'static str = "Morton";
+
+	/// Maps a semantic count to a concrete position.
+	///
+	/// `Morton` order fills the even positions from `LSbit` upwards, then the
+	/// odd positions.
+	fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		let half = T::BITS / 2;
+		let n = *cursor;
+		if n < half {
+			(n << 1).pos()
+		}
+		else {
+			(((n - half) << 1) | 1).pos()
+		}
+	}
+}
+
+/** Traverses each byte from `LSbit` to `MSbit`, and the bytes of an element
+from the most significant to the least significant.
+
+This is the ordering of a `LittleEndian` bit stream whose elements were loaded
+with the wrong byte order: a register dump of little-endian words read as
+big-endian ones, or the reverse. Viewing such memory through `ByteSwapped`
+produces the bits that `LittleEndian` would produce after swapping the bytes of
+each element, without modifying the memory.
+
+```text
+u32 semantic:    0 …  7  8 … 15  16 … 23  24 … 31
+u32 electrical: 24 … 31 16 … 23   8 … 15   0 …  7
+```
+
+Over `u8`, this is the same ordering as `LittleEndian`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::cursor::ByteSwapped;
+
+let word = 0x1234_5678u32;
+let bits = word.as_bitslice::<ByteSwapped>();
+let swapped = word.swap_bytes().as_bitslice::<LittleEndian>().to_owned();
+assert_eq!(bits, &swapped);
+
+let mut low = 0u8;
+for n in 0 .. 8 {
+  low |= (bits[n] as u8) << n;
+}
+assert_eq!(low, 0x12);
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ByteSwapped;
+
+impl Cursor for ByteSwapped {
+	const TYPENAME: &'static str = "ByteSwapped";
+
+	/// Maps a semantic count to a concrete position.
+	///
+	/// `ByteSwapped` order moves from the `LSbit` of the most significant byte
+	/// first to the `MSbit` of the least significant byte last.
+	fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		let n = *cursor;
+		let last = T::BITS / 8 - 1;
+		((last - n / 8) * 8 + n % 8).pos()
+	}
+}
+
+/** Traverses each byte from `LSbit` to `MSbit`, beginning with its high nibble.
+
+Some devices present the two halves of each byte exchanged, as when a 4-bit bus
+transfers the high nibble first. Viewing their memory through `NibbleSwapped`
+produces the bits that `LittleEndian` would produce after swapping the nibbles
+of each byte back.
+
+```text
+u8 semantic:   0 1 2 3 4 5 6 7
+u8 electrical: 4 5 6 7 0 1 2 3
+```
+
+The bytes of a wider element are visited from the least significant to the
+most significant, as in `LittleEndian`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::cursor::NibbleSwapped;
+
+let byte = 0x1Fu8;
+let bits = byte.as_bitslice::<NibbleSwapped>();
+assert_eq!(bits, 0xF1u8.as_bitslice::<LittleEndian>());
+assert!(bits[4 ..].all());
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NibbleSwapped;
+
+impl Cursor for NibbleSwapped {
+	const TYPENAME: &'static str = "NibbleSwapped";
+
+	/// Maps a semantic count to a concrete position.
+	///
+	/// `NibbleSwapped` order exchanges the two halves of each byte of the
+	/// `LittleEndian` order.
+	fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		(*cursor ^ 4).pos()
+	}
+}
+
+/** A bit ordering chosen at run time.
+
+The `Cursor` types select an ordering at compile time, and every structure that
+uses one is compiled separately for it. Programs that learn the ordering from
+their input, such as a flag in a file header, can carry a `BitOrder` value
+instead, and view memory through the [`DynBitSlice`] types, which dispatch on
+it.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::cursor::BitOrder;
+
+assert_eq!(BitOrder::of::<BigEndian>(), Some(BitOrder::BigEndian));
+assert_eq!(BitOrder::of::<LittleEndian>(), Some(BitOrder::LittleEndian));
+
+let header = 0x01u8;
+let order = if header & 1 == 1 {
+  BitOrder::LittleEndian
+}
+else {
+  BitOrder::BigEndian
+};
+assert_eq!(order, BitOrder::LittleEndian);
+```
+
+[`DynBitSlice`]: ../dynamic/enum.DynBitSlice.html
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitOrder {
+	/// The ordering of the `BigEndian` cursor, from `MSbit` to `LSbit`.
+	BigEndian,
+	/// The ordering of the `LittleEndian` cursor, from `LSbit` to `MSbit`.
+	LittleEndian,
+}
+
+impl BitOrder {
+	/// Finds the run-time ordering that matches a cursor type.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: A cursor type.
+	///
+	/// # Returns
+	///
+	/// The ordering that `C` implements, if it is one of the two that
+	/// `BitOrder` can represent.
+	pub fn of<C>() -> Option<Self>
+	where C: Cursor {
+		fn test<C, T>(order: BitOrder) -> bool
+		where C: Cursor, T: BitStore {
+			(0 .. T::BITS)
+				.all(|n| C::at::<T>(n.idx()) == order.at::<T>(n.idx()))
+		}
+		if C::IS_REVERSED {
+			return Some(BitOrder::BigEndian);
+		}
+		if C::IS_IDENTITY {
+			return Some(BitOrder::LittleEndian);
+		}
+		[BitOrder::BigEndian, BitOrder::LittleEndian]
+			.iter()
+			.cloned()
+			.find(|&order| test::<C, u8>(order) && test::<C, u32>(order))
+	}
+
+	/// Translates a semantic bit index into an electrical bit position, as
+	/// `Cursor::at` does for the matching cursor type.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `cursor`: The semantic bit index.
+	///
+	/// # Returns
+	///
+	/// The electrical position of the bit at `cursor`.
+	pub fn at<T>(self, cursor: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		match self {
+			BitOrder::BigEndian => BigEndian::at::<T>(cursor),
+			BitOrder::LittleEndian => LittleEndian::at::<T>(cursor),
+		}
+	}
+
+	/// Translates a semantic bit index into an electrical bit mask, as
+	/// `Cursor::mask` does for the matching cursor type.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `cursor`: The semantic bit index.
+	///
+	/// # Returns
+	///
+	/// A one-hot mask of the bit at `cursor`.
+	pub fn mask<T>(self, cursor: BitIdx<T>) -> BitMask<T>
+	where T: BitStore {
+		match self {
+			BitOrder::BigEndian => BigEndian::mask::<T>(cursor),
+			BitOrder::LittleEndian => LittleEndian::mask::<T>(cursor),
+		}
+	}
+}
+
+/** Checks that a `Cursor` implementation upholds the trait’s requirements.
+
+This is exhaustive over one storage type: it tests every index against
+`Cursor::at` and `Cursor::mask`, and every range against `Cursor::mask_range`.
+It is meant to be called from the test suite of a crate that implements its own
+cursor; the [`verify_cursor!`] macro calls it for every storage type.
+
+The checks are that:
+
+- `at` produces a position inside the element for every index;
+- `at` produces the same position when asked twice;
+- no two indices produce the same position, so `at` is a bijection;
+- `mask` sets exactly the bit at the position from `at`;
+- `mask_range` is the union of `mask` over every range of indices, and is empty
+  for empty ranges;
+- `IS_IDENTITY` and `IS_REVERSED`, if set, describe `at` truthfully, and are not
+  both set.
+
+# Type Parameters
+
+- `C`: The cursor under test.
+- `T`: The storage type over which to test it.
+
+# Panics
+
+This panics, naming the cursor, the storage type, and the index at fault, on
+the first requirement that the cursor fails.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::cursor;
+use bitvec::indices::{BitIdx, BitPos};
+
+/// Counts up from the second bit, and wraps around to the first.
+struct Rotated;
+
+impl Cursor for Rotated {
+  const TYPENAME: &'static str = "Rotated";
+
+  fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+  where T: BitStore {
+    BitPos::new((*cursor + 1) & T::MASK)
+  }
+}
+
+cursor::verify::<Rotated, u8>();
+cursor::verify::<Rotated, u32>();
+```
+
+A cursor which forgets to wrap does not pass:
+
+```rust,should_panic
+use bitvec::prelude::*;
+use bitvec::cursor;
+use bitvec::indices::{BitIdx, BitPos};
+
+struct Shifted;
+
+impl Cursor for Shifted {
+  const TYPENAME: &'static str = "Shifted";
+
+  fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+  where T: BitStore {
+    unsafe { BitPos::new_unchecked(*cursor + 1) }
+  }
+}
+
+cursor::verify::<Shifted, u8>();
+```
+
+[`verify_cursor!`]: ../macro.verify_cursor.html
+**/
+pub fn verify<C, T>()
+where C: Cursor, T: BitStore {
+	let zero = T::bits(false);
+	let mut seen = zero;
+	assert!(
+		!(C::IS_IDENTITY && C::IS_REVERSED),
+		"Cursor {} sets both IS_IDENTITY and IS_REVERSED",
+		C::TYPENAME,
+	);
+	for n in 0 .. T::BITS {
+		let pos = *C::at::<T>(n.idx());
+		assert!(
+			pos < T::BITS,
+			"Cursor {} maps index {} to position {}, outside {}",
+			C::TYPENAME,
+			n,
+			pos,
+			T::TYPENAME,
+		);
+		assert_eq!(
+			*C::at::<T>(n.idx()),
+			pos,
+			"Cursor {} maps index {} in {} to different positions",
+			C::TYPENAME,
+			n,
+			T::TYPENAME,
+		);
+		let bit = T::from(1) << pos;
+		assert!(
+			seen & bit == zero,
+			"Cursor {} maps two indices in {} to position {}",
+			C::TYPENAME,
+			T::TYPENAME,
+			pos,
+		);
+		seen |= bit;
+		assert_eq!(
+			*C::mask::<T>(n.idx()),
+			bit,
+			"Cursor {} produces a mask for index {} in {} that is not bit {}",
+			C::TYPENAME,
+			n,
+			T::TYPENAME,
+			pos,
+		);
+		assert!(
+			!C::IS_IDENTITY || pos == n,
+			"Cursor {} sets IS_IDENTITY, but maps index {} in {} to {}",
+			C::TYPENAME,
+			n,
+			T::TYPENAME,
+			pos,
+		);
+		assert!(
+			!C::IS_REVERSED || pos == T::MASK - n,
+			"Cursor {} sets IS_REVERSED, but maps index {} in {} to {}",
+			C::TYPENAME,
+			n,
+			T::TYPENAME,
+			pos,
+		);
+	}
+	for from in 0 .. T::BITS {
+		let mut expected = zero;
+		for upto in 0 ..= T::BITS {
+			if upto > from {
+				expected |= *C::mask::<T>((upto - 1).idx());
+			}
+			assert_eq!(
+				C::mask_range::<T>(from.idx(), upto.tail()),
+				expected,
+				"Cursor {} produces a wrong mask for the range {} .. {} in {}",
+				C::TYPENAME,
+				from,
+				upto,
+				T::TYPENAME,
+			);
+		}
+	}
+}
+
+/// Rewrites whole elements by moving each bit to a new position.
+///
+/// This carries an element from the ordering of one cursor to that of
+/// another, or reverses the semantic order of the bits in an element.
+#[derive(Clone, Copy)]
+pub(crate) enum Reorder {
+	/// Every bit stays where it is, and elements are unchanged.
+	Same,
+	/// The bit at each electrical position `p` moves to `T::MASK - p`.
+	Reverse,
+	/// The bit at each electrical position `p` moves to position `moves[p]`.
+	Permute([u8; 64]),
+}
+
+impl Reorder {
+	/// Finds the rewrite from one cursor to another, for a storage type.
+	///
+	/// The semantic index `n` of an element, at the position `C::at(n)`, is
+	/// moved to the position `D::at(n)`, so that the element holds the same
+	/// sequence of bits under `D` as it held under `C`.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: The cursor of the source elements.
+	/// - `D`: The cursor of the rewritten elements.
+	/// - `T`: The storage type of the elements.
+	#[cfg(feature = "alloc")]
+	pub(crate) fn new<C, D, T>() -> Self
+	where C: Cursor, D: Cursor, T: BitStore {
+		let mut moves = [0; 64];
+		for n in 0 .. T::BITS {
+			moves[*C::at::<T>(n.idx()) as usize] = *D::at::<T>(n.idx());
+		}
+		Self::classify::<T>(moves)
+	}
+
+	/// Finds the rewrite that reverses the semantic order of the bits in an
+	/// element, for a cursor and storage type.
+	///
+	/// The semantic index `n`, at the position `C::at(n)`, is moved to the
+	/// position `C::at(T::MASK - n)`.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: The cursor of the elements.
+	/// - `T`: The storage type of the elements.
+	pub(crate) fn reversal<C, T>() -> Self
+	where C: Cursor, T: BitStore {
+		let mut moves = [0; 64];
+		for n in 0 .. T::BITS {
+			let to = *C::at::<T>((T::MASK - n).idx());
+			moves[*C::at::<T>(n.idx()) as usize] = to;
+		}
+		Self::classify::<T>(moves)
+	}
+
+	/// Selects the cheapest rewrite that performs a table of moves.
+	fn classify<T>(moves: [u8; 64]) -> Self
+	where T: BitStore {
+		if (0 .. T::BITS).all(|p| moves[p as usize] == p) {
+			Reorder::Same
+		}
+		else if (0 .. T::BITS).all(|p| moves[p as usize] == T::MASK - p) {
+			Reorder::Reverse
+		}
+		else {
+			Reorder::Permute(moves)
+		}
+	}
+
+	/// Rewrites one element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `elt`: An element, in the source ordering.
+	///
+	/// # Returns
+	///
+	/// The element, in the destination ordering.
+	pub(crate) fn apply<T>(&self, elt: T) -> T
+	where T: BitStore {
+		match self {
+			Reorder::Same => elt,
+			Reorder::Reverse => {
+				//  Reverse the element as the low bits of a `u64`, then move
+				//  the result back down from the top.
+				let mut x: u64 = elt.into();
+				x = x.swap_bytes();
+				x = (x & 0x0F0F_0F0F_0F0F_0F0F) << 4
+					| (x >> 4) & 0x0F0F_0F0F_0F0F_0F0F;
+				x = (x & 0x3333_3333_3333_3333) << 2
+					| (x >> 2) & 0x3333_3333_3333_3333;
+				x = (x & 0x5555_5555_5555_5555) << 1
+					| (x >> 1) & 0x5555_5555_5555_5555;
+				x >>= 64 - u32::from(T::BITS);
+				let mut out = T::bits(false);
+				for byte in 0 .. T::BITS / 8 {
+					out |= T::from((x >> (byte * 8)) as u8) << (byte * 8);
+				}
+				out
+			},
+			Reorder::Permute(moves) => {
+				let mut out = T::bits(false);
+				for pos in 0 .. T::BITS {
+					if elt & (T::from(1) << pos) != T::bits(false) {
+						out |= T::from(1) << moves[pos as usize];
+					}
+				}
+				out
+			},
+		}
+	}
 }
 
 #[cfg(test)]
@@ -331,6 +1123,250 @@ mod tests {
 		assert_eq!(BigEndian::at::<u64>(63u8.idx()), 0u8.pos());
 	}
 
+	#[test]
+	fn orientation_flags() {
+		//  The flags are constants, so they are compared as data rather than
+		//  asserted one at a time.
+		let flags = [
+			(BigEndian::IS_REVERSED, BigEndian::IS_IDENTITY),
+			(LittleEndian::IS_REVERSED, LittleEndian::IS_IDENTITY),
+		];
+		assert_eq!(flags, [(true, false), (false, true)]);
+	}
+
+	#[test]
+	fn morton_u8_range() {
+		let positions = [0, 2, 4, 6, 1, 3, 5, 7];
+		for (n, &pos) in positions.iter().enumerate() {
+			assert_eq!(Morton::at::<u8>((n as u8).idx()), pos.pos());
+		}
+		assert_eq!(Morton::at::<u32>(15u8.idx()), 30u8.pos());
+		assert_eq!(Morton::at::<u32>(16u8.idx()), 1u8.pos());
+		assert_eq!(Morton::at::<u32>(31u8.idx()), 31u8.pos());
+		assert_eq!(BitOrder::of::<Morton>(), None);
+	}
+
+	#[test]
+	fn swapped_u32_range() {
+		for n in 0 .. 32u8 {
+			let bytes = 3 - n / 8;
+			let pos = ByteSwapped::at::<u32>(n.idx());
+			assert_eq!(*pos, bytes * 8 + n % 8);
+			let pos = NibbleSwapped::at::<u32>(n.idx());
+			assert_eq!(*pos, n / 8 * 8 + (n % 8 + 4) % 8);
+		}
+		for n in 0 .. 8u8 {
+			assert_eq!(ByteSwapped::at::<u8>(n.idx()), n.pos());
+		}
+		assert_eq!(BitOrder::of::<ByteSwapped>(), None);
+		assert_eq!(BitOrder::of::<NibbleSwapped>(), None);
+	}
+
+	#[test]
+	fn mask_range() {
+		fn each<C, T>()
+		where C: Cursor, T: BitStore {
+			for from in 0 .. T::BITS {
+				for upto in 0 ..= T::BITS {
+					let mut mask = T::bits(false);
+					for n in from .. upto {
+						mask |= *C::mask::<T>(n.idx());
+					}
+					let range = C::mask_range::<T>(from.idx(), upto.tail());
+					assert_eq!(range, mask);
+				}
+			}
+		}
+		each::<BigEndian, u8>();
+		each::<BigEndian, u32>();
+		each::<LittleEndian, u16>();
+		each::<LittleEndian, u32>();
+		each::<Morton, u8>();
+		each::<ByteSwapped, u32>();
+		each::<NibbleSwapped, u16>();
+
+		assert_eq!(BigEndian::mask_range::<u8>(2u8.idx(), 5u8.tail()), 0x38);
+		assert_eq!(LittleEndian::mask_range::<u8>(2u8.idx(), 5u8.tail()), 0x1C);
+		assert_eq!(Morton::mask_range::<u8>(2u8.idx(), 5u8.tail()), 0x52);
+	}
+
+	#[test]
+	fn const_forms() {
+		fn each<T>()
+		where T: BitStore {
+			let w = T::BITS;
+			for from in 0 .. w {
+				let n = from.idx();
+				let at = *BigEndian::at::<T>(n);
+				assert_eq!(BigEndian::const_at(w, from), at);
+				assert_eq!(LittleEndian::const_at(w, from), from);
+				assert_eq!(
+					BigEndian::const_mask(w, from),
+					(*BigEndian::mask::<T>(n)).into(),
+				);
+				for upto in from ..= w {
+					let (n, t) = (from.idx(), upto.tail());
+					assert_eq!(
+						BigEndian::const_mask_range(w, from, upto),
+						BigEndian::mask_range::<T>(n, t).into(),
+					);
+					assert_eq!(
+						LittleEndian::const_mask_range(w, from, upto),
+						LittleEndian::mask_range::<T>(n, t).into(),
+					);
+				}
+			}
+		}
+		each::<u8>();
+		each::<u16>();
+		each::<u32>();
+		#[cfg(target_pointer_width = "64")]
+		each::<u64>();
+
+		assert_eq!(BigEndian::const_mask_range(64, 0, 0), 0);
+		assert_eq!(LittleEndian::const_mask_range(64, 64, 64), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn const_forms_reject_width() {
+		LittleEndian::const_mask(12, 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn const_forms_reject_index() {
+		BigEndian::const_at(16, 16);
+	}
+
+	#[test]
+	fn table_cursors() {
+		bit_cursor! {
+			struct Identity = [0, 1, 2, 3, 4, 5, 6, 7];
+		}
+		bit_cursor! {
+			struct Pairs = [1, 0];
+		}
+		bit_cursor! {
+			struct Halves = [
+				8, 9, 10, 11, 12, 13, 14, 15,
+				0, 1, 2, 3, 4, 5, 6, 7,
+			];
+		}
+		verify_cursor!(Identity, Pairs);
+		verify::<Halves, u16>();
+		verify::<Halves, u32>();
+		for n in 0 .. 32u8 {
+			assert_eq!(Identity::at::<u32>(n.idx()), n.pos());
+			assert_eq!(Pairs::at::<u32>(n.idx()), (n ^ 1).pos());
+			assert_eq!(*Halves::at::<u32>(n.idx()), n ^ 8);
+		}
+		assert_eq!(Halves::TYPENAME, "Halves");
+	}
+
+	#[test]
+	#[should_panic]
+	fn table_cursor_too_wide() {
+		bit_cursor! {
+			struct Halves = [
+				8, 9, 10, 11, 12, 13, 14, 15,
+				0, 1, 2, 3, 4, 5, 6, 7,
+			];
+		}
+		Halves::at::<u8>(0u8.idx());
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn reorder_elements() {
+		fn each<C, D, T>(elt: T)
+		where C: Cursor, D: Cursor, T: BitStore {
+			let out = Reorder::new::<C, D, T>().apply(elt);
+			for n in 0 .. T::BITS {
+				let src = elt & *C::mask::<T>(n.idx()) != T::bits(false);
+				let dst = out & *D::mask::<T>(n.idx()) != T::bits(false);
+				assert_eq!(src, dst);
+			}
+		}
+		each::<BigEndian, BigEndian, u8>(0xA5);
+		each::<BigEndian, LittleEndian, u8>(0x1E);
+		each::<LittleEndian, BigEndian, u16>(0x0F31);
+		each::<BigEndian, LittleEndian, u32>(0x8000_1234);
+		#[cfg(target_pointer_width = "64")]
+		each::<LittleEndian, BigEndian, u64>(0x0123_4567_89AB_CDEF);
+		each::<Morton, BigEndian, u16>(0xC3A5);
+		each::<ByteSwapped, LittleEndian, u32>(0xDEAD_BEEF);
+		each::<NibbleSwapped, Morton, u8>(0x6C);
+
+		match Reorder::new::<BigEndian, LittleEndian, u32>() {
+			Reorder::Reverse => {},
+			_ => panic!("opposite cursors reverse each element"),
+		}
+		match Reorder::new::<ByteSwapped, LittleEndian, u8>() {
+			Reorder::Same => {},
+			_ => panic!("ByteSwapped and LittleEndian agree on bytes"),
+		}
+	}
+
+	#[test]
+	fn reverse_elements() {
+		fn each<C, T>(elt: T)
+		where C: Cursor, T: BitStore {
+			let out = Reorder::reversal::<C, T>().apply(elt);
+			for n in 0 .. T::BITS {
+				let src = elt & *C::mask::<T>(n.idx()) != T::bits(false);
+				let idx = (T::MASK - n).idx();
+				let dst = out & *C::mask::<T>(idx) != T::bits(false);
+				assert_eq!(src, dst);
+			}
+		}
+		bit_cursor! {
+			struct Rotate = [1, 2, 3, 0];
+		}
+		each::<BigEndian, u8>(0x1E);
+		each::<LittleEndian, u16>(0x0F31);
+		each::<BigEndian, u32>(0x8000_1234);
+		#[cfg(target_pointer_width = "64")]
+		each::<LittleEndian, u64>(0x0123_4567_89AB_CDEF);
+		each::<Morton, u16>(0xC3A5);
+		each::<NibbleSwapped, u32>(0xDEAD_BEEF);
+		each::<Rotate, u8>(0x6C);
+
+		match Reorder::reversal::<Morton, u8>() {
+			Reorder::Reverse => {},
+			_ => panic!("Morton reverses with its mirror image"),
+		}
+		match Reorder::reversal::<Rotate, u8>() {
+			Reorder::Permute(_) => {},
+			_ => panic!("Rotate does not reverse electrically"),
+		}
+	}
+
+	#[test]
+	fn verify_cursors() {
+		verify_cursor!(
+			BigEndian,
+			LittleEndian,
+			Morton,
+			ByteSwapped,
+			NibbleSwapped,
+		);
+	}
+
+	#[test]
+	fn bit_order() {
+		assert_eq!(BitOrder::of::<BigEndian>(), Some(BitOrder::BigEndian));
+		assert_eq!(
+			BitOrder::of::<LittleEndian>(),
+			Some(BitOrder::LittleEndian),
+		);
+		for n in 0 .. 16u8 {
+			let (be, le) = (BitOrder::BigEndian, BitOrder::LittleEndian);
+			assert_eq!(be.at::<u16>(n.idx()), BigEndian::at(n.idx()));
+			assert_eq!(le.mask::<u16>(n.idx()), LittleEndian::mask(n.idx()));
+		}
+	}
+
 	#[test]
 	fn le_u8_range() {
 		assert_eq!(LittleEndian::at::<u8>(0u8.idx()), 0u8.pos());
@@ -0,0 +1,1015 @@
+/*! `BitDeque` structure
+
+This module holds a double-ended queue of bits, stored in a ring buffer. Bits
+can be pushed and popped at either end in constant time, which makes it a
+first-in, first-out buffer for streaming bit codecs. Removing bits from the
+front of a `BitVec` instead moves every remaining bit, and costs time in
+proportion to the length of the vector.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::{
+		self,
+		BitSlice,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	clone::Clone,
+	cmp::{
+		self,
+		Eq,
+		PartialEq,
+	},
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+		Write,
+	},
+	iter::{
+		Chain,
+		DoubleEndedIterator,
+		ExactSizeIterator,
+		Extend,
+		FromIterator,
+		FusedIterator,
+		IntoIterator,
+		Iterator,
+	},
+	ops::Index,
+};
+
+/** A double-ended queue of bits, stored in a growable ring buffer.
+
+The live bits of the queue occupy a run of the buffer that begins at a head
+index and may wrap around the back edge of the buffer to its front. Pushing and
+popping at either end moves only the edge of that run. When the buffer is full,
+it grows to at least twice its size, and the live bits are copied into the new
+buffer in order, a storage element at a time.
+
+Because the live bits may wrap, they are not always a single `BitSlice`.
+[`as_slices`] views them as two slices, and [`make_contiguous`] rotates the
+buffer so that they become one.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  queue will use to store data.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut queue: BitDeque = BitDeque::new();
+queue.push_back(true);
+queue.push_back(false);
+queue.push_front(false);
+assert_eq!(queue.len(), 3);
+assert_eq!(queue.pop_front(), Some(false));
+assert_eq!(queue.pop_front(), Some(true));
+assert_eq!(queue.pop_back(), Some(false));
+assert_eq!(queue.pop_back(), None);
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`as_slices`]: #method.as_slices
+[`make_contiguous`]: #method.make_contiguous
+**/
+pub struct BitDeque<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The ring buffer. Every bit of every element in it is live, so its length
+	/// is the capacity of the queue.
+	buf: BitVec<C, T>,
+	/// The index in `buf` of the front bit of the queue.
+	head: usize,
+	/// The number of bits in the queue.
+	len: usize,
+}
+
+impl<C, T> BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs a new, empty, `BitDeque<C, T>`.
+	///
+	/// The queue does not allocate until bits are pushed into it.
+	///
+	/// # Returns
+	///
+	/// An empty, unallocated, `BitDeque`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue: BitDeque = BitDeque::new();
+	/// assert!(queue.is_empty());
+	/// assert_eq!(queue.capacity(), 0);
+	/// ```
+	pub fn new() -> Self {
+		Self {
+			buf: BitVec::new(),
+			head: 0,
+			len: 0,
+		}
+	}
+
+	/// Constructs a new, empty, `BitDeque<C, T>` with room for at least
+	/// `capacity` bits.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: The minimum number of bits that the new queue can hold
+	///   without reallocating.
+	///
+	/// # Returns
+	///
+	/// An empty `BitDeque` whose capacity is `capacity`, rounded up to a whole
+	/// number of storage elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue: BitDeque<BigEndian, u16> = BitDeque::with_capacity(20);
+	/// assert!(queue.is_empty());
+	/// assert_eq!(queue.capacity(), 32);
+	/// ```
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			buf: BitVec::repeat(false, Self::round_up(capacity)),
+			head: 0,
+			len: 0,
+		}
+	}
+
+	/// Counts the bits in the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits in the queue.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue: BitDeque = bitvec![0, 1, 0].into();
+	/// assert_eq!(queue.len(), 3);
+	/// ```
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the queue is empty.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the queue holds no bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = BitDeque::new();
+	/// assert!(queue.is_empty());
+	/// queue.push_back(true);
+	/// assert!(!queue.is_empty());
+	/// ```
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Counts the bits the queue can hold without reallocating.
+	///
+	/// The ring buffer is made of whole storage elements, so this is always a
+	/// multiple of `T::BITS`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits that the queue can hold before it must grow.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque<BigEndian, u32> = BitDeque::new();
+	/// queue.push_back(true);
+	/// assert_eq!(queue.capacity(), 32);
+	/// ```
+	pub fn capacity(&self) -> usize {
+		self.buf.len()
+	}
+
+	/// Reserves room for at least `additional` more bits.
+	///
+	/// If the buffer must grow, it grows to at least twice its current size,
+	/// and the live bits are copied to the front of the new buffer, in order.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of bits to make room for, beyond the current
+	///   length.
+	///
+	/// # Panics
+	///
+	/// This panics if the new capacity overflows the maximum length of a
+	/// `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![1; 3].into();
+	/// queue.reserve(10);
+	/// assert!(queue.capacity() >= 13);
+	/// ```
+	pub fn reserve(&mut self, additional: usize) {
+		let needed = self.len.checked_add(additional)
+			.expect("BitDeque capacity overflow");
+		if needed <= self.capacity() {
+			return;
+		}
+		let capacity = Self::round_up(cmp::max(needed, self.capacity() * 2));
+		let mut buf = BitVec::repeat(false, capacity);
+		let (front, back) = self.as_slices();
+		let split = front.len();
+		buf[.. split].copy_bits_from(front);
+		buf[split .. self.len].copy_bits_from(back);
+		self.buf = buf;
+		self.head = 0;
+	}
+
+	/// Removes every bit from the queue, without releasing its buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![1; 30].into();
+	/// queue.clear();
+	/// assert!(queue.is_empty());
+	/// assert!(queue.capacity() >= 30);
+	/// ```
+	pub fn clear(&mut self) {
+		self.head = 0;
+		self.len = 0;
+	}
+
+	/// Appends a bit to the back of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to append.
+	///
+	/// # Panics
+	///
+	/// This panics if the queue must grow, and the new capacity overflows the
+	/// maximum length of a `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = BitDeque::new();
+	/// queue.push_back(true);
+	/// queue.push_back(false);
+	/// assert_eq!(queue.back(), Some(false));
+	/// ```
+	pub fn push_back(&mut self, value: bool) {
+		self.reserve(1);
+		let idx = self.physical(self.len);
+		self.buf.set(idx, value);
+		self.len += 1;
+	}
+
+	/// Prepends a bit to the front of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to prepend.
+	///
+	/// # Panics
+	///
+	/// This panics if the queue must grow, and the new capacity overflows the
+	/// maximum length of a `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = BitDeque::new();
+	/// queue.push_front(true);
+	/// queue.push_front(false);
+	/// assert_eq!(queue.front(), Some(false));
+	/// ```
+	pub fn push_front(&mut self, value: bool) {
+		self.reserve(1);
+		self.head = match self.head {
+			0 => self.capacity() - 1,
+			n => n - 1,
+		};
+		let idx = self.head;
+		self.buf.set(idx, value);
+		self.len += 1;
+	}
+
+	/// Removes the bit at the front of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The front bit, or `None` if the queue is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![1, 0].into();
+	/// assert_eq!(queue.pop_front(), Some(true));
+	/// assert_eq!(queue.pop_front(), Some(false));
+	/// assert_eq!(queue.pop_front(), None);
+	/// ```
+	pub fn pop_front(&mut self) -> Option<bool> {
+		if self.is_empty() {
+			return None;
+		}
+		let out = self.buf[self.head];
+		self.head = self.physical(1);
+		self.len -= 1;
+		if self.is_empty() {
+			self.head = 0;
+		}
+		Some(out)
+	}
+
+	/// Removes the bit at the back of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The back bit, or `None` if the queue is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![1, 0].into();
+	/// assert_eq!(queue.pop_back(), Some(false));
+	/// assert_eq!(queue.pop_back(), Some(true));
+	/// assert_eq!(queue.pop_back(), None);
+	/// ```
+	pub fn pop_back(&mut self) -> Option<bool> {
+		if self.is_empty() {
+			return None;
+		}
+		self.len -= 1;
+		let out = self.buf[self.physical(self.len)];
+		if self.is_empty() {
+			self.head = 0;
+		}
+		Some(out)
+	}
+
+	/// Reads the bit at the front of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The front bit, or `None` if the queue is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue: BitDeque = bitvec![1, 0].into();
+	/// assert_eq!(queue.front(), Some(true));
+	/// ```
+	pub fn front(&self) -> Option<bool> {
+		self.get(0)
+	}
+
+	/// Reads the bit at the back of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The back bit, or `None` if the queue is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue: BitDeque = bitvec![1, 0].into();
+	/// assert_eq!(queue.back(), Some(false));
+	/// ```
+	pub fn back(&self) -> Option<bool> {
+		self.len.checked_sub(1).and_then(|idx| self.get(idx))
+	}
+
+	/// Reads the bit at an index, counted from the front of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The distance of the bit from the front of the queue.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, or `None` if `index` is not less than the length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![0, 1].into();
+	/// queue.push_front(true);
+	/// assert_eq!(queue.get(0), Some(true));
+	/// assert_eq!(queue.get(2), Some(true));
+	/// assert_eq!(queue.get(3), None);
+	/// ```
+	pub fn get(&self, index: usize) -> Option<bool> {
+		if index < self.len {
+			Some(self.buf[self.physical(index)])
+		}
+		else {
+			None
+		}
+	}
+
+	/// Writes the bit at an index, counted from the front of the queue.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The distance of the bit from the front of the queue.
+	/// - `value`: The bit to write.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than the length of the queue.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![0, 0].into();
+	/// queue.set(1, true);
+	/// assert_eq!(queue.back(), Some(true));
+	/// ```
+	pub fn set(&mut self, index: usize, value: bool) {
+		let len = self.len;
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let idx = self.physical(index);
+		self.buf.set(idx, value);
+	}
+
+	/// Appends every bit of a slice to the back of the queue.
+	///
+	/// The bits are copied as runs, a storage element at a time, in at most
+	/// two pieces: one up to the back edge of the buffer, and one from its
+	/// front edge.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bits`: The bits to append, in order.
+	///
+	/// # Panics
+	///
+	/// This panics if the queue must grow, and the new capacity overflows the
+	/// maximum length of a `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![1; 6].into();
+	/// queue.pop_front();
+	/// queue.extend_from_bitslice(&bitvec![0, 1, 0, 1]);
+	/// assert_eq!(queue, bitvec![1, 1, 1, 1, 1, 0, 1, 0, 1]);
+	/// ```
+	pub fn extend_from_bitslice(&mut self, bits: &BitSlice<C, T>) {
+		self.reserve(bits.len());
+		let start = self.physical(self.len);
+		let split = cmp::min(bits.len(), self.capacity() - start);
+		self.buf[start .. start + split].copy_bits_from(&bits[.. split]);
+		self.buf[.. bits.len() - split].copy_bits_from(&bits[split ..]);
+		self.len += bits.len();
+	}
+
+	/// Removes a run of bits from the front of the queue.
+	///
+	/// This is the bulk counterpart of [`pop_front`], for readers that take
+	/// fixed-width fields from a stream.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The number of bits to remove.
+	///
+	/// # Returns
+	///
+	/// The first `count` bits of the queue, in order, or `None` if the queue
+	/// holds fewer than `count` bits. The queue is not modified if it holds too
+	/// few bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![1, 0, 1, 1, 0].into();
+	/// assert_eq!(queue.pop_front_bits(3), Some(bitvec![1, 0, 1]));
+	/// assert_eq!(queue.pop_front_bits(3), None);
+	/// assert_eq!(queue.len(), 2);
+	/// ```
+	///
+	/// [`pop_front`]: #method.pop_front
+	pub fn pop_front_bits(&mut self, count: usize) -> Option<BitVec<C, T>> {
+		if count > self.len {
+			return None;
+		}
+		let mut out = BitVec::with_capacity(count);
+		let (front, back) = self.as_slices();
+		let split = cmp::min(count, front.len());
+		out.extend_from_bitslice(&front[.. split]);
+		out.extend_from_bitslice(&back[.. count - split]);
+		self.head = self.physical(count);
+		self.len -= count;
+		if self.is_empty() {
+			self.head = 0;
+		}
+		Some(out)
+	}
+
+	/// Views the queue as two slices, which hold its bits in order when
+	/// joined.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The bits from the front of the queue to the back edge of the buffer,
+	/// and then the bits that wrapped around to the front of the buffer. The
+	/// second slice is empty when the bits do not wrap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = BitDeque::with_capacity(8);
+	/// queue.extend(bitvec![0, 0, 1, 1]);
+	/// queue.push_front(true);
+	/// let (front, back) = queue.as_slices();
+	/// assert_eq!(front, &bitvec![1]);
+	/// assert_eq!(back, &bitvec![0, 0, 1, 1]);
+	/// ```
+	pub fn as_slices(&self) -> (&BitSlice<C, T>, &BitSlice<C, T>) {
+		let split = cmp::min(self.len, self.capacity() - self.head);
+		(
+			&self.buf[self.head .. self.head + split],
+			&self.buf[.. self.len - split],
+		)
+	}
+
+	/// Views the queue as two mutable slices, which hold its bits in order
+	/// when joined.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The same two regions as [`as_slices`], mutably.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = BitDeque::with_capacity(8);
+	/// queue.extend(bitvec![0; 4]);
+	/// queue.push_front(false);
+	/// let (front, back) = queue.as_mut_slices();
+	/// front.set_all(true);
+	/// back.set(3, true);
+	/// assert_eq!(queue, bitvec![1, 0, 0, 0, 1]);
+	/// ```
+	///
+	/// [`as_slices`]: #method.as_slices
+	pub fn as_mut_slices(&mut self)
+	-> (&mut BitSlice<C, T>, &mut BitSlice<C, T>) {
+		let split = cmp::min(self.len, self.capacity() - self.head);
+		let (head, len) = (self.head, self.len);
+		let (low, high) = self.buf.split_at_mut(head);
+		(&mut high[.. split], &mut low[.. len - split])
+	}
+
+	/// Rotates the buffer so that the bits of the queue are in a single
+	/// slice.
+	///
+	/// If the bits do not wrap around the back edge of the buffer, this does
+	/// not move them. Otherwise, the whole buffer is rotated so that the front
+	/// bit of the queue is at its front edge.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable slice over the bits of the queue, in order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = BitDeque::with_capacity(8);
+	/// queue.extend(bitvec![0, 0, 1]);
+	/// queue.push_front(true);
+	/// assert!(!queue.as_slices().1.is_empty());
+	/// assert_eq!(queue.make_contiguous(), &bitvec![1, 0, 0, 1]);
+	/// assert!(queue.as_slices().1.is_empty());
+	/// ```
+	pub fn make_contiguous(&mut self) -> &mut BitSlice<C, T> {
+		if self.head + self.len > self.capacity() {
+			let head = self.head;
+			self.buf.rotate_left(head);
+			self.head = 0;
+		}
+		let (head, len) = (self.head, self.len);
+		&mut self.buf[head .. head + len]
+	}
+
+	/// Iterates over the bits of the queue, from front to back.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A double-ended iterator over the bits of the queue.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![0, 1].into();
+	/// queue.push_front(true);
+	/// let bits: Vec<bool> = queue.iter().collect();
+	/// assert_eq!(bits, [true, false, true]);
+	/// assert_eq!(queue.iter().rev().next(), Some(true));
+	/// ```
+	pub fn iter(&self) -> Iter<C, T> {
+		let (front, back) = self.as_slices();
+		Iter {
+			inner: front.iter().chain(back.iter()),
+		}
+	}
+
+	/// Maps an index counted from the front of the queue to an index in the
+	/// ring buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: A distance from the front of the queue, no greater than the
+	///   capacity.
+	///
+	/// # Returns
+	///
+	/// The index in `self.buf` of the bit `index` places after the front.
+	fn physical(&self, index: usize) -> usize {
+		let idx = self.head + index;
+		if idx >= self.capacity() {
+			idx - self.capacity()
+		}
+		else {
+			idx
+		}
+	}
+
+	/// Rounds a bit count up to a whole number of storage elements.
+	fn round_up(bits: usize) -> usize {
+		let width = T::BITS as usize;
+		bits.checked_add(width - 1)
+			.expect("BitDeque capacity overflow")
+			/ width * width
+	}
+}
+
+impl<C, T> Clone for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self {
+			buf: self.buf.clone(),
+			head: self.head,
+			len: self.len,
+		}
+	}
+}
+
+impl<C, T> Eq for BitDeque<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<A, B, C, D> PartialEq<BitDeque<C, D>> for BitDeque<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitDeque<C, D>) -> bool {
+		self.len() == rhs.len() && self.iter().eq(rhs.iter())
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitVec<C, D>> for BitDeque<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitVec<C, D>) -> bool {
+		self.len() == rhs.len() && self.iter().eq(rhs.iter())
+	}
+}
+
+impl<C, T> Default for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C, T> Debug for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitDeque` type header and contents for debug.
+	///
+	/// The bits are not grouped by storage element, as the front of the queue
+	/// need not be at the front of an element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque<LittleEndian, u16> =
+	///   bitvec![LittleEndian, u16; 0, 1, 1].into();
+	/// queue.push_front(true);
+	/// assert_eq!(
+	///   "BitDeque<LittleEndian, u16> [1011]",
+	///   &format!("{:?}", queue),
+	/// );
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitDeque<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str("> [")?;
+		for bit in self {
+			f.write_char(if bit { '1' } else { '0' })?;
+		}
+		f.write_char(']')
+	}
+}
+
+impl<C, T> Extend<bool> for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item=bool> {
+		let iter = src.into_iter();
+		self.reserve(iter.size_hint().0);
+		for bit in iter {
+			self.push_back(bit);
+		}
+	}
+}
+
+impl<C, T> FromIterator<bool> for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item=bool> {
+		BitVec::from_iter(src).into()
+	}
+}
+
+impl<C, T> From<BitVec<C, T>> for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	/// Moves the bits of a vector into a queue, reusing its buffer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue = BitDeque::from(bitvec![1, 0, 1]);
+	/// assert_eq!(queue.len(), 3);
+	/// assert_eq!(queue.capacity(), 8);
+	/// ```
+	fn from(mut buf: BitVec<C, T>) -> Self {
+		let len = buf.len();
+		//  Vectors begin at the front of an element, so this makes every bit
+		//  of every element live.
+		buf.resize(Self::round_up(len), false);
+		Self { buf, head: 0, len }
+	}
+}
+
+impl<C, T> From<BitDeque<C, T>> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Moves the bits of a queue into a vector, reusing its buffer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut queue: BitDeque = bitvec![0, 1].into();
+	/// queue.push_front(true);
+	/// assert_eq!(BitVec::from(queue), bitvec![1, 0, 1]);
+	/// ```
+	fn from(queue: BitDeque<C, T>) -> Self {
+		let BitDeque { mut buf, head, len } = queue;
+		if head > 0 {
+			buf.rotate_left(head);
+		}
+		buf.truncate(len);
+		buf
+	}
+}
+
+impl<C, T> Index<usize> for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	type Output = bool;
+
+	/// Looks up a single bit by its distance from the front of the queue.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than the length of the queue.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let queue: BitDeque = bitvec![0, 1].into();
+	/// assert!(!queue[0]);
+	/// assert!(queue[1]);
+	/// ```
+	fn index(&self, index: usize) -> &Self::Output {
+		let len = self.len;
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		&self.buf[self.physical(index)]
+	}
+}
+
+impl<C, T> IntoIterator for BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	type Item = bool;
+	type IntoIter = IntoIter<C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIter { queue: self }
+	}
+}
+
+impl<'a, C, T> IntoIterator for &'a BitDeque<C, T>
+where C: Cursor, T: BitStore {
+	type Item = bool;
+	type IntoIter = Iter<'a, C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An iterator over the bits of a `BitDeque`, from front to back.
+#[derive(Clone, Debug)]
+pub struct Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The two slices of the queue, in order.
+	inner: Chain<slice::Iter<'a, C, T>, slice::Iter<'a, C, T>>,
+}
+
+impl<'a, C, T> DoubleEndedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back()
+	}
+}
+
+impl<'a, C, T> ExactSizeIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> FusedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+/// An iterator that moves the bits out of a `BitDeque`, from front to back.
+#[derive(Clone, Debug)]
+pub struct IntoIter<C, T>
+where C: Cursor, T: BitStore {
+	/// The queue from which bits are popped.
+	queue: BitDeque<C, T>,
+}
+
+impl<C, T> DoubleEndedIterator for IntoIter<C, T>
+where C: Cursor, T: BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.queue.pop_back()
+	}
+}
+
+impl<C, T> ExactSizeIterator for IntoIter<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> FusedIterator for IntoIter<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> Iterator for IntoIter<C, T>
+where C: Cursor, T: BitStore {
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.queue.pop_front()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.queue.len();
+		(len, Some(len))
+	}
+}
@@ -4,7 +4,14 @@ The domains governed by `BitSlice` and owned-variant handles have different
 representative states depending on the span of governed elements and live bits.
 
 This module provides representations of the domain states for ease of use by
-handle operations.
+handle operations. The [`Domain`] and [`DomainMut`] types are the public face
+of these states, and are produced by [`BitSlice::domain`] and
+[`BitSlice::domain_mut`].
+
+[`BitSlice::domain`]: ../slice/struct.BitSlice.html#method.domain
+[`BitSlice::domain_mut`]: ../slice/struct.BitSlice.html#method.domain_mut
+[`Domain`]: enum.Domain.html
+[`DomainMut`]: enum.DomainMut.html
 !*/
 
 use crate::{
@@ -318,6 +325,198 @@ where T: 'a + BitStore {
 	}
 }
 
+/** Public view of the memory elements underneath a `BitSlice`.
+
+A `BitSlice` may begin and end partway through its first and last memory
+elements. Those edge elements may be shared with other handles, and so are only
+ever exposed through their `T::Access` shared-mutability wrappers; the interior
+elements are wholly owned by the slice, and are exposed as an ordinary `[T]`.
+This lets callers run element-wise (or vectorized) kernels over the interior,
+and treat the edges with masks derived from the provided indices.
+
+# Lifetimes
+
+- `'a`: Lifetime of the containing storage
+
+# Type Parameters
+
+- `T: BitStore` The type of the elements the domain inhabits.
+**/
+#[derive(Clone, Debug)]
+pub enum Domain<'a, T>
+where T: 'a + BitStore {
+	/// The slice lives entirely inside one element, and touches neither of
+	/// its edges.
+	Enclave {
+		/// Index of the first live bit in the element.
+		head: BitIdx<T>,
+		/// The element containing the slice.
+		elem: &'a T::Access,
+		/// Index of the first dead bit after the slice.
+		tail: BitTail<T>,
+	},
+	/// The slice covers zero or more whole elements, with optional partial
+	/// elements on either edge.
+	Region {
+		/// The partially-live head element, and the index of its first live
+		/// bit. This is `None` when the slice begins at an element edge.
+		head: Option<(BitIdx<T>, &'a T::Access)>,
+		/// The fully-live elements of the slice. This may be empty.
+		body: &'a [T],
+		/// The partially-live tail element, and the index of its first dead
+		/// bit. This is `None` when the slice ends at an element edge.
+		tail: Option<(&'a T::Access, BitTail<T>)>,
+	},
+}
+
+impl<'a, T> Domain<'a, T>
+where T: 'a + BitStore {
+	/// Tests if the domain is held entirely inside one element.
+	pub fn is_enclave(&self) -> bool {
+		match self {
+			Domain::Enclave { .. } => true,
+			Domain::Region { .. } => false,
+		}
+	}
+
+	/// Tests if the domain spans element edges.
+	pub fn is_region(&self) -> bool {
+		!self.is_enclave()
+	}
+}
+
+impl<'a, T> From<BitDomain<'a, T>> for Domain<'a, T>
+where T: 'a + BitStore {
+	fn from(source: BitDomain<'a, T>) -> Self {
+		match source {
+			BitDomain::Empty => Domain::Region {
+				head: None,
+				body: &[],
+				tail: None,
+			},
+			BitDomain::Minor(head, elem, tail) => {
+				Domain::Enclave { head, elem, tail }
+			},
+			BitDomain::Major(hc, h, body, t, tc) => Domain::Region {
+				head: Some((hc, h)),
+				body,
+				tail: Some((t, tc)),
+			},
+			BitDomain::PartialHead(hc, h, body) => Domain::Region {
+				head: Some((hc, h)),
+				body,
+				tail: None,
+			},
+			BitDomain::PartialTail(body, t, tc) => Domain::Region {
+				head: None,
+				body,
+				tail: Some((t, tc)),
+			},
+			BitDomain::Spanning(body) => Domain::Region {
+				head: None,
+				body,
+				tail: None,
+			},
+		}
+	}
+}
+
+/** Public mutable view of the memory elements underneath a `BitSlice`.
+
+This is the same decomposition as [`Domain`], except that the wholly-owned
+interior elements are exposed as `&mut [T]`. The edge elements remain behind
+their `T::Access` wrappers, because other handles may still be writing to the
+bits outside this slice.
+
+# Lifetimes
+
+- `'a`: Lifetime of the containing storage
+
+# Type Parameters
+
+- `T: BitStore` The type of the elements the domain inhabits.
+
+[`Domain`]: enum.Domain.html
+**/
+#[derive(Debug)]
+pub enum DomainMut<'a, T>
+where T: 'a + BitStore {
+	/// The slice lives entirely inside one element, and touches neither of
+	/// its edges.
+	Enclave {
+		/// Index of the first live bit in the element.
+		head: BitIdx<T>,
+		/// The element containing the slice.
+		elem: &'a T::Access,
+		/// Index of the first dead bit after the slice.
+		tail: BitTail<T>,
+	},
+	/// The slice covers zero or more whole elements, with optional partial
+	/// elements on either edge.
+	Region {
+		/// The partially-live head element, and the index of its first live
+		/// bit. This is `None` when the slice begins at an element edge.
+		head: Option<(BitIdx<T>, &'a T::Access)>,
+		/// The fully-live elements of the slice. This may be empty.
+		body: &'a mut [T],
+		/// The partially-live tail element, and the index of its first dead
+		/// bit. This is `None` when the slice ends at an element edge.
+		tail: Option<(&'a T::Access, BitTail<T>)>,
+	},
+}
+
+impl<'a, T> DomainMut<'a, T>
+where T: 'a + BitStore {
+	/// Tests if the domain is held entirely inside one element.
+	pub fn is_enclave(&self) -> bool {
+		match self {
+			DomainMut::Enclave { .. } => true,
+			DomainMut::Region { .. } => false,
+		}
+	}
+
+	/// Tests if the domain spans element edges.
+	pub fn is_region(&self) -> bool {
+		!self.is_enclave()
+	}
+}
+
+impl<'a, T> From<BitDomainMut<'a, T>> for DomainMut<'a, T>
+where T: 'a + BitStore {
+	fn from(source: BitDomainMut<'a, T>) -> Self {
+		match source {
+			BitDomainMut::Empty => DomainMut::Region {
+				head: None,
+				body: &mut [],
+				tail: None,
+			},
+			BitDomainMut::Minor(head, elem, tail) => {
+				DomainMut::Enclave { head, elem, tail }
+			},
+			BitDomainMut::Major(hc, h, body, t, tc) => DomainMut::Region {
+				head: Some((hc, h)),
+				body,
+				tail: Some((t, tc)),
+			},
+			BitDomainMut::PartialHead(hc, h, body) => DomainMut::Region {
+				head: Some((hc, h)),
+				body,
+				tail: None,
+			},
+			BitDomainMut::PartialTail(body, t, tc) => DomainMut::Region {
+				head: None,
+				body,
+				tail: Some((t, tc)),
+			},
+			BitDomainMut::Spanning(body) => DomainMut::Region {
+				head: None,
+				body,
+				tail: None,
+			},
+		}
+	}
+}
+
 #[cfg(all(test, feature = "testing"))]
 mod tests {
 	use super::*;
@@ -0,0 +1,644 @@
+/*! Bit slices with a run-time ordering
+
+`BitSlice` takes its bit ordering as a type parameter, so code that must handle
+both orderings is compiled once for each, and code that learns the ordering from
+its input must branch into one of two copies of itself. The types in this module
+carry a [`BitOrder`] value instead, and dispatch each operation to the matching
+`BitSlice` type internally, so that callers can write their own logic once.
+
+The variants of each type are public, so that code which needs an operation not
+provided here can match on the ordering and use the full `BitSlice` API.
+
+[`BitOrder`]: ../cursor/enum.BitOrder.html
+!*/
+
+use crate::{
+	cursor::{
+		BigEndian,
+		BitOrder,
+		Cursor,
+		LittleEndian,
+	},
+	slice::{
+		BitSlice,
+		Iter,
+	},
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+use core::{
+	cmp::{
+		Eq,
+		PartialEq,
+	},
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	iter::{
+		DoubleEndedIterator,
+		ExactSizeIterator,
+		FusedIterator,
+		Iterator,
+	},
+	ops::{
+		Bound,
+		Range,
+		RangeBounds,
+	},
+};
+
+/// Runs an expression on the `BitSlice` inside any variant of a dynamic type.
+macro_rules! dispatch {
+	( $ty:ident, $this:expr, $bits:ident => $body:expr ) => {
+		match $this {
+			$ty::BigEndian($bits) => $body,
+			$ty::LittleEndian($bits) => $body,
+		}
+	};
+}
+
+/** A shared bit slice whose ordering is chosen at run time.
+
+# Examples
+
+```rust
+use bitvec::cursor::BitOrder;
+use bitvec::dynamic::DynBitSlice;
+
+fn count_flags(order: BitOrder, data: &[u8]) -> usize {
+  let bits = DynBitSlice::new(order, data);
+  bits.slice(4 .. 12).unwrap().count_ones()
+}
+
+let data = [0x0Fu8, 0x01];
+assert_eq!(count_flags(BitOrder::BigEndian, &data), 4);
+assert_eq!(count_flags(BitOrder::LittleEndian, &data), 1);
+```
+**/
+#[derive(Clone, Copy)]
+pub enum DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	/// A slice in the ordering of the `BigEndian` cursor.
+	BigEndian(&'a BitSlice<BigEndian, T>),
+	/// A slice in the ordering of the `LittleEndian` cursor.
+	LittleEndian(&'a BitSlice<LittleEndian, T>),
+}
+
+impl<'a, T> DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	/// Views a slice of memory as bits in a run-time ordering.
+	///
+	/// # Parameters
+	///
+	/// - `order`: The ordering of bits within each element.
+	/// - `data`: The memory to view.
+	///
+	/// # Returns
+	///
+	/// A bit slice over all of `data`.
+	pub fn new(order: BitOrder, data: &'a [T]) -> Self {
+		match order {
+			BitOrder::BigEndian => {
+				DynBitSlice::BigEndian(BitSlice::from_slice(data))
+			},
+			BitOrder::LittleEndian => {
+				DynBitSlice::LittleEndian(BitSlice::from_slice(data))
+			},
+		}
+	}
+
+	/// Gets the ordering of the slice.
+	pub fn order(&self) -> BitOrder {
+		match self {
+			DynBitSlice::BigEndian(_) => BitOrder::BigEndian,
+			DynBitSlice::LittleEndian(_) => BitOrder::LittleEndian,
+		}
+	}
+
+	/// Counts the bits in the slice.
+	pub fn len(&self) -> usize {
+		dispatch!(DynBitSlice, self, bits => bits.len())
+	}
+
+	/// Tests if the slice is empty.
+	pub fn is_empty(&self) -> bool {
+		dispatch!(DynBitSlice, self, bits => bits.is_empty())
+	}
+
+	/// Looks up a bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The semantic index of the bit.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, if it is in the slice.
+	pub fn get(&self, index: usize) -> Option<bool> {
+		dispatch!(DynBitSlice, self, bits => bits.get(index))
+	}
+
+	/// Takes a subslice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `range`: Any range of semantic indices.
+	///
+	/// # Returns
+	///
+	/// The subslice over `range`, in the same ordering, if `range` lies inside
+	/// the slice.
+	pub fn slice<R>(self, range: R) -> Option<Self>
+	where R: RangeBounds<usize> {
+		let range = normalize(range, self.len())?;
+		Some(match self {
+			DynBitSlice::BigEndian(bits) => bits.get(range)?.into(),
+			DynBitSlice::LittleEndian(bits) => bits.get(range)?.into(),
+		})
+	}
+
+	/// Divides the slice in two at an index.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `mid`: The index at which to split.
+	///
+	/// # Returns
+	///
+	/// The bits in `.. mid` and the bits in `mid ..`.
+	///
+	/// # Panics
+	///
+	/// This panics if `mid` is greater than the length of the slice.
+	pub fn split_at(self, mid: usize) -> (Self, Self) {
+		match self {
+			DynBitSlice::BigEndian(bits) => {
+				let (head, rest) = bits.split_at(mid);
+				(head.into(), rest.into())
+			},
+			DynBitSlice::LittleEndian(bits) => {
+				let (head, rest) = bits.split_at(mid);
+				(head.into(), rest.into())
+			},
+		}
+	}
+
+	/// Counts the set bits in the slice.
+	pub fn count_ones(&self) -> usize {
+		dispatch!(DynBitSlice, self, bits => bits.count_ones())
+	}
+
+	/// Counts the cleared bits in the slice.
+	pub fn count_zeros(&self) -> usize {
+		dispatch!(DynBitSlice, self, bits => bits.count_zeros())
+	}
+
+	/// Tests if every bit in the slice is set.
+	pub fn all(&self) -> bool {
+		dispatch!(DynBitSlice, self, bits => bits.all())
+	}
+
+	/// Tests if any bit in the slice is set.
+	pub fn any(&self) -> bool {
+		dispatch!(DynBitSlice, self, bits => bits.any())
+	}
+
+	/// Tests if no bit in the slice is set.
+	pub fn not_any(&self) -> bool {
+		dispatch!(DynBitSlice, self, bits => bits.not_any())
+	}
+
+	/// Finds the index of the first set bit in the slice, if any.
+	pub fn first_one(&self) -> Option<usize> {
+		dispatch!(DynBitSlice, self, bits => bits.first_one())
+	}
+
+	/// Finds the index of the last set bit in the slice, if any.
+	pub fn last_one(&self) -> Option<usize> {
+		dispatch!(DynBitSlice, self, bits => bits.last_one())
+	}
+
+	/// Iterates over the bits in the slice, in semantic order.
+	pub fn iter(&self) -> DynIter<'a, T> {
+		match *self {
+			DynBitSlice::BigEndian(bits) => DynIter::BigEndian(bits.iter()),
+			DynBitSlice::LittleEndian(bits) => {
+				DynIter::LittleEndian(bits.iter())
+			},
+		}
+	}
+
+	/// Views the memory elements that the slice touches.
+	pub fn as_slice(&self) -> &'a [T] {
+		dispatch!(DynBitSlice, *self, bits => bits.as_slice())
+	}
+
+	/// Views the slice with a compile-time cursor, if it has that ordering.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: A cursor type.
+	///
+	/// # Returns
+	///
+	/// The slice, typed with `C`, if `C` implements the slice’s ordering.
+	pub fn to_static<C>(self) -> Option<&'a BitSlice<C, T>>
+	where C: Cursor {
+		if BitOrder::of::<C>() != Some(self.order()) {
+			return None;
+		}
+		//  The cursor implements this ordering, so reinterpreting the handle
+		//  does not change which bits it refers to.
+		Some(dispatch!(DynBitSlice, self, bits => unsafe {
+			&*(bits as *const BitSlice<_, T> as *const BitSlice<C, T>)
+		}))
+	}
+
+	/// Copies the bits into a vector with a compile-time cursor.
+	///
+	/// The vector holds the same sequence of bits, and so has the memory
+	/// layout of `C`, whatever the ordering of the slice.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: The cursor type of the vector.
+	///
+	/// # Returns
+	///
+	/// A vector equal to the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::cursor::BitOrder;
+	/// use bitvec::dynamic::DynBitSlice;
+	///
+	/// let data = [0x01u8];
+	/// let bits = DynBitSlice::new(BitOrder::LittleEndian, &data);
+	/// let bv = bits.to_bitvec::<BigEndian>();
+	/// assert_eq!(bv.as_slice(), &[0x80]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn to_bitvec<C>(&self) -> BitVec<C, T>
+	where C: Cursor {
+		self.iter().collect()
+	}
+}
+
+impl<'a, T> Debug for DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		dispatch!(DynBitSlice, self, bits => Debug::fmt(bits, f))
+	}
+}
+
+impl<'a, T> Display for DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		dispatch!(DynBitSlice, self, bits => Display::fmt(bits, f))
+	}
+}
+
+impl<'a, T> Eq for DynBitSlice<'a, T>
+where T: 'a + BitStore {}
+
+/// Compares the bit sequences, whatever the orderings of the two slices.
+impl<'a, 'b, T, U> PartialEq<DynBitSlice<'b, U>> for DynBitSlice<'a, T>
+where T: 'a + BitStore, U: 'b + BitStore {
+	fn eq(&self, rhs: &DynBitSlice<'b, U>) -> bool {
+		dispatch!(DynBitSlice, self, this => {
+			dispatch!(DynBitSlice, rhs, that => *this == *that)
+		})
+	}
+}
+
+impl<'a, T> From<&'a BitSlice<BigEndian, T>> for DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	fn from(bits: &'a BitSlice<BigEndian, T>) -> Self {
+		DynBitSlice::BigEndian(bits)
+	}
+}
+
+impl<'a, T> From<&'a BitSlice<LittleEndian, T>> for DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	fn from(bits: &'a BitSlice<LittleEndian, T>) -> Self {
+		DynBitSlice::LittleEndian(bits)
+	}
+}
+
+impl<'a, T> IntoIterator for DynBitSlice<'a, T>
+where T: 'a + BitStore {
+	type Item = bool;
+	type IntoIter = DynIter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** A mutable bit slice whose ordering is chosen at run time.
+
+# Examples
+
+```rust
+use bitvec::cursor::BitOrder;
+use bitvec::dynamic::DynBitSliceMut;
+
+let mut data = [0u8; 2];
+let mut bits = DynBitSliceMut::new(BitOrder::LittleEndian, &mut data);
+bits.set(0, true);
+bits.set(9, true);
+assert_eq!(bits.as_dyn().count_ones(), 2);
+assert_eq!(data, [0x01, 0x02]);
+```
+**/
+pub enum DynBitSliceMut<'a, T>
+where T: 'a + BitStore {
+	/// A slice in the ordering of the `BigEndian` cursor.
+	BigEndian(&'a mut BitSlice<BigEndian, T>),
+	/// A slice in the ordering of the `LittleEndian` cursor.
+	LittleEndian(&'a mut BitSlice<LittleEndian, T>),
+}
+
+impl<'a, T> DynBitSliceMut<'a, T>
+where T: 'a + BitStore {
+	/// Views a slice of memory as mutable bits in a run-time ordering.
+	///
+	/// # Parameters
+	///
+	/// - `order`: The ordering of bits within each element.
+	/// - `data`: The memory to view.
+	///
+	/// # Returns
+	///
+	/// A mutable bit slice over all of `data`.
+	pub fn new(order: BitOrder, data: &'a mut [T]) -> Self {
+		match order {
+			BitOrder::BigEndian => {
+				DynBitSliceMut::BigEndian(BitSlice::from_slice_mut(data))
+			},
+			BitOrder::LittleEndian => {
+				DynBitSliceMut::LittleEndian(BitSlice::from_slice_mut(data))
+			},
+		}
+	}
+
+	/// Gets the ordering of the slice.
+	pub fn order(&self) -> BitOrder {
+		self.as_dyn().order()
+	}
+
+	/// Counts the bits in the slice.
+	pub fn len(&self) -> usize {
+		self.as_dyn().len()
+	}
+
+	/// Tests if the slice is empty.
+	pub fn is_empty(&self) -> bool {
+		self.as_dyn().is_empty()
+	}
+
+	/// Looks up a bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The semantic index of the bit.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, if it is in the slice.
+	pub fn get(&self, index: usize) -> Option<bool> {
+		self.as_dyn().get(index)
+	}
+
+	/// Sets a bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The semantic index of the bit.
+	/// - `value`: The new value of the bit.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is out of bounds.
+	pub fn set(&mut self, index: usize, value: bool) {
+		dispatch!(DynBitSliceMut, self, bits => bits.set(index, value))
+	}
+
+	/// Sets every bit in the slice to a value.
+	pub fn set_all(&mut self, value: bool) {
+		dispatch!(DynBitSliceMut, self, bits => bits.set_all(value))
+	}
+
+	/// Takes a mutable subslice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `range`: Any range of semantic indices.
+	///
+	/// # Returns
+	///
+	/// The subslice over `range`, in the same ordering, if `range` lies inside
+	/// the slice.
+	pub fn slice<R>(self, range: R) -> Option<Self>
+	where R: RangeBounds<usize> {
+		let range = normalize(range, self.len())?;
+		Some(match self {
+			DynBitSliceMut::BigEndian(bits) => bits.get_mut(range)?.into(),
+			DynBitSliceMut::LittleEndian(bits) => bits.get_mut(range)?.into(),
+		})
+	}
+
+	/// Divides the slice in two at an index.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `mid`: The index at which to split.
+	///
+	/// # Returns
+	///
+	/// The bits in `.. mid` and the bits in `mid ..`.
+	///
+	/// # Panics
+	///
+	/// This panics if `mid` is greater than the length of the slice.
+	pub fn split_at(self, mid: usize) -> (Self, Self) {
+		match self {
+			DynBitSliceMut::BigEndian(bits) => {
+				let (head, rest) = bits.split_at_mut(mid);
+				(head.into(), rest.into())
+			},
+			DynBitSliceMut::LittleEndian(bits) => {
+				let (head, rest) = bits.split_at_mut(mid);
+				(head.into(), rest.into())
+			},
+		}
+	}
+
+	/// Copies the bits of another slice into this one, translating between
+	/// orderings as needed.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The bits to copy.
+	///
+	/// # Panics
+	///
+	/// This panics if `src` is not as long as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::cursor::BitOrder;
+	/// use bitvec::dynamic::{DynBitSlice, DynBitSliceMut};
+	///
+	/// let src = [0xC0u8];
+	/// let mut dst = [0u8];
+	/// DynBitSliceMut::new(BitOrder::LittleEndian, &mut dst)
+	///   .copy_from(DynBitSlice::new(BitOrder::BigEndian, &src));
+	/// assert_eq!(dst, [0x03]);
+	/// ```
+	pub fn copy_from<U>(&mut self, src: DynBitSlice<U>)
+	where U: BitStore {
+		assert_eq!(
+			self.len(),
+			src.len(),
+			"Copied slices must have equal lengths",
+		);
+		for (index, bit) in src.iter().enumerate() {
+			self.set(index, bit);
+		}
+	}
+
+	/// Reborrows the slice as a shared slice.
+	pub fn as_dyn(&self) -> DynBitSlice<T> {
+		match self {
+			DynBitSliceMut::BigEndian(bits) => DynBitSlice::BigEndian(bits),
+			DynBitSliceMut::LittleEndian(bits) => {
+				DynBitSlice::LittleEndian(bits)
+			},
+		}
+	}
+
+	/// Reborrows the slice as a shorter-lived mutable slice.
+	pub fn reborrow(&mut self) -> DynBitSliceMut<T> {
+		match self {
+			DynBitSliceMut::BigEndian(bits) => DynBitSliceMut::BigEndian(bits),
+			DynBitSliceMut::LittleEndian(bits) => {
+				DynBitSliceMut::LittleEndian(bits)
+			},
+		}
+	}
+
+	/// Freezes the slice into a shared slice with the same lifetime.
+	pub fn into_dyn(self) -> DynBitSlice<'a, T> {
+		match self {
+			DynBitSliceMut::BigEndian(bits) => DynBitSlice::BigEndian(bits),
+			DynBitSliceMut::LittleEndian(bits) => {
+				DynBitSlice::LittleEndian(bits)
+			},
+		}
+	}
+}
+
+impl<'a, T> Debug for DynBitSliceMut<'a, T>
+where T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&self.as_dyn(), f)
+	}
+}
+
+impl<'a, T> Display for DynBitSliceMut<'a, T>
+where T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(&self.as_dyn(), f)
+	}
+}
+
+impl<'a, T> From<&'a mut BitSlice<BigEndian, T>> for DynBitSliceMut<'a, T>
+where T: 'a + BitStore {
+	fn from(bits: &'a mut BitSlice<BigEndian, T>) -> Self {
+		DynBitSliceMut::BigEndian(bits)
+	}
+}
+
+impl<'a, T> From<&'a mut BitSlice<LittleEndian, T>> for DynBitSliceMut<'a, T>
+where T: 'a + BitStore {
+	fn from(bits: &'a mut BitSlice<LittleEndian, T>) -> Self {
+		DynBitSliceMut::LittleEndian(bits)
+	}
+}
+
+/// An iterator over the bits of a `DynBitSlice`.
+#[derive(Clone, Debug)]
+pub enum DynIter<'a, T>
+where T: 'a + BitStore {
+	/// Iteration in the ordering of the `BigEndian` cursor.
+	BigEndian(Iter<'a, BigEndian, T>),
+	/// Iteration in the ordering of the `LittleEndian` cursor.
+	LittleEndian(Iter<'a, LittleEndian, T>),
+}
+
+impl<'a, T> Iterator for DynIter<'a, T>
+where T: 'a + BitStore {
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		dispatch!(DynIter, self, iter => iter.next())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		dispatch!(DynIter, self, iter => iter.size_hint())
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		dispatch!(DynIter, self, iter => iter.nth(n))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for DynIter<'a, T>
+where T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		dispatch!(DynIter, self, iter => iter.next_back())
+	}
+}
+
+impl<'a, T> ExactSizeIterator for DynIter<'a, T>
+where T: 'a + BitStore {}
+
+impl<'a, T> FusedIterator for DynIter<'a, T>
+where T: 'a + BitStore {}
+
+/// Converts any range into a half-open range, if it fits in `len`.
+fn normalize<R>(range: R, len: usize) -> Option<Range<usize>>
+where R: RangeBounds<usize> {
+	let start = match range.start_bound() {
+		Bound::Included(&n) => n,
+		Bound::Excluded(&n) => n.checked_add(1)?,
+		Bound::Unbounded => 0,
+	};
+	let end = match range.end_bound() {
+		Bound::Included(&n) => n.checked_add(1)?,
+		Bound::Excluded(&n) => n,
+		Bound::Unbounded => len,
+	};
+	if start > end || end > len {
+		return None;
+	}
+	Some(start .. end)
+}
@@ -0,0 +1,481 @@
+/*! Elias–Fano encoding of monotone sequences
+
+This module holds a sorted sequence of `usize` values in close to the minimum
+space that any encoding of such sequences can use. Each value is split into a
+fixed number of low bits, which are stored verbatim, and the remaining high
+bits, which are stored in unary as the gaps between set bits of a second bit
+vector. The `i`th value is found by locating the `i`th set bit of the high
+vector, and the number of values below a bound is found by locating a cleared
+bit, so the structure keeps a sparse sample of both kinds of position to bound
+the distance that either search must scan.
+
+The structure is an auxiliary index in the sense of the `serde` support: it
+serializes with a version number, and is re-validated when deserialized.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	clone::Clone,
+	cmp::{
+		Eq,
+		PartialEq,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		ExactSizeIterator,
+		FusedIterator,
+		IntoIterator,
+		Iterator,
+	},
+	mem,
+};
+
+/// The number of set, or of cleared, bits in the high vector between each
+/// recorded position.
+const SAMPLE: usize = 64;
+
+/** A sorted sequence of `usize` values, stored in Elias–Fano form.
+
+A sequence of `n` values, the largest of which is `u`, occupies about
+`n * (2 + log₂(u / n))` bits, no matter how the values are distributed. The
+values can be read by position with [`select`], counted below a bound with
+[`rank`], and iterated in order. The sequence is immutable once built.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  encoding will use to store data.
+
+# Examples
+
+```rust
+use bitvec::elias_fano::EliasFano;
+
+let ef: EliasFano = EliasFano::from_sorted(&[3, 8, 8, 21, 1000]);
+assert_eq!(ef.len(), 5);
+assert_eq!(ef.select(3), Some(21));
+assert_eq!(ef.rank(9), 3);
+assert!(ef.contains(1000));
+assert!(!ef.contains(999));
+assert_eq!(ef.iter().collect::<Vec<_>>(), [3, 8, 8, 21, 1000]);
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`rank`]: #method.rank
+[`select`]: #method.select
+**/
+pub struct EliasFano<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The low `low_bits` bits of each value, most significant bit first.
+	low: BitVec<C, T>,
+	/// The high bits of each value, in unary. Value `i` sets the bit at its
+	/// high part plus `i`, and each cleared bit ends the run of values that
+	/// share one high part.
+	high: BitVec<C, T>,
+	/// The number of values in the sequence.
+	len: usize,
+	/// The number of low bits of each value stored in `low`.
+	low_bits: u8,
+	/// The position in `high` of every `SAMPLE`th set bit.
+	ones: Vec<usize>,
+	/// The position in `high` of every `SAMPLE`th cleared bit.
+	zeros: Vec<usize>,
+}
+
+impl<C, T> EliasFano<C, T>
+where C: Cursor, T: BitStore {
+	/// Encodes a sorted sequence of values.
+	///
+	/// The values may repeat, but must not decrease.
+	///
+	/// # Parameters
+	///
+	/// - `values`: The sequence to encode.
+	///
+	/// # Returns
+	///
+	/// An encoding of `values`.
+	///
+	/// # Panics
+	///
+	/// Panics if `values` is not sorted.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::elias_fano::EliasFano;
+	///
+	/// let ef: EliasFano = EliasFano::from_sorted(&[1, 4, 7]);
+	/// assert_eq!(ef.select(1), Some(4));
+	/// ```
+	pub fn from_sorted(values: &[usize]) -> Self {
+		assert!(
+			values.windows(2).all(|pair| pair[0] <= pair[1]),
+			"Elias–Fano values must be sorted",
+		);
+		let len = values.len();
+		let max = values.last().cloned().unwrap_or(0);
+		let low_bits = Self::low_width(max, len);
+		let shift = low_bits as usize;
+
+		let mut low = BitVec::with_capacity(len * shift);
+		for &value in values {
+			for n in (0 .. shift).rev() {
+				low.push(value >> n & 1 == 1);
+			}
+		}
+		let mut high = BitVec::repeat(false, len + (max >> shift) + 1);
+		for (n, &value) in values.iter().enumerate() {
+			high.set((value >> shift) + n, true);
+		}
+		Self::from_bits(low, high, len, low_bits)
+	}
+
+	/// Counts the values in the sequence.
+	///
+	/// # Returns
+	///
+	/// The number of values, including repeats.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the sequence is empty.
+	///
+	/// # Returns
+	///
+	/// Whether the sequence has no values.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Finds the value at a position in the sequence.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: A position in the sequence.
+	///
+	/// # Returns
+	///
+	/// The value at `index`, or `None` if `index` is not less than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::elias_fano::EliasFano;
+	///
+	/// let ef: EliasFano = EliasFano::from_sorted(&[5, 9, 9, 40]);
+	/// assert_eq!(ef.select(0), Some(5));
+	/// assert_eq!(ef.select(2), Some(9));
+	/// assert_eq!(ef.select(4), None);
+	/// ```
+	pub fn select(&self, index: usize) -> Option<usize> {
+		if index >= self.len {
+			return None;
+		}
+		let pos = self.select_bit(index, true)?;
+		Some(self.value_at(index, pos))
+	}
+
+	/// Counts the values in the sequence that are less than a bound.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bound`: The exclusive upper bound of the values to count.
+	///
+	/// # Returns
+	///
+	/// The number of values less than `bound`. This is also the position at
+	/// which `bound` would be inserted to keep the sequence sorted, before
+	/// any copies of it that are already present.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::elias_fano::EliasFano;
+	///
+	/// let ef: EliasFano = EliasFano::from_sorted(&[5, 9, 9, 40]);
+	/// assert_eq!(ef.rank(0), 0);
+	/// assert_eq!(ef.rank(9), 1);
+	/// assert_eq!(ef.rank(10), 3);
+	/// assert_eq!(ef.rank(!0), 4);
+	/// ```
+	pub fn rank(&self, bound: usize) -> usize {
+		let shift = self.low_bits as usize;
+		let bucket = bound >> shift;
+		//  Each bucket of values that share a high part ends in a cleared bit.
+		if bucket >= self.high.len() - self.len {
+			return self.len;
+		}
+		let start = match bucket {
+			0 => 0,
+			n => self.select_bit(n - 1, false)
+				.expect("Elias–Fano bucket count is inconsistent") + 1,
+		};
+		let low = bound & ((1 << shift) - 1);
+		let (mut index, mut pos) = (start - bucket, start);
+		while self.high[pos] && self.low_at(index) < low {
+			index += 1;
+			pos += 1;
+		}
+		index
+	}
+
+	/// Tests if a value is in the sequence.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The value to look for.
+	///
+	/// # Returns
+	///
+	/// Whether `value` occurs at least once in the sequence.
+	pub fn contains(&self, value: usize) -> bool {
+		self.select(self.rank(value)) == Some(value)
+	}
+
+	/// Iterates over the values in the sequence, in order.
+	///
+	/// This decodes each value in turn, without searching, and so is faster
+	/// than calling `select` on each position.
+	///
+	/// # Returns
+	///
+	/// An iterator over the values.
+	pub fn iter(&self) -> Iter<C, T> {
+		Iter {
+			ef: self,
+			index: 0,
+			pos: 0,
+		}
+	}
+
+	/// Assembles an encoding from its parts, checking that they describe a
+	/// sorted sequence exactly as `from_sorted` would have encoded it.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of values.
+	/// - `low_bits`: The number of low bits stored for each value.
+	/// - `low`: The low bits of each value.
+	/// - `high`: The unary high parts of each value.
+	///
+	/// # Returns
+	///
+	/// The encoding, or a description of the first inconsistency found.
+	#[cfg(feature = "serde")]
+	pub(crate) fn from_parts(
+		len: usize,
+		low_bits: u8,
+		low: BitVec<C, T>,
+		high: BitVec<C, T>,
+	) -> Result<Self, &'static str> {
+		if low_bits as usize >= mem::size_of::<usize>() * 8 {
+			return Err("low bit width is too large");
+		}
+		if len.checked_mul(low_bits as usize) != Some(low.len()) {
+			return Err("low bits do not match the length");
+		}
+		if high.last_zero().map(|n| n + 1) != Some(high.len()) {
+			return Err("high bits do not end a bucket");
+		}
+		if high.count_ones() != len {
+			return Err("high bits do not match the length");
+		}
+		let out = Self::from_bits(low, high, len, low_bits);
+		let values = out.iter().collect::<Vec<_>>();
+		if !values.windows(2).all(|pair| pair[0] <= pair[1]) {
+			return Err("values are not sorted");
+		}
+		if Self::from_sorted(&values) != out {
+			return Err("values are not in canonical form");
+		}
+		Ok(out)
+	}
+
+	/// Views the parts of the encoding, in the order that `from_parts` takes.
+	#[cfg(feature = "serde")]
+	pub(crate) fn parts(&self)
+	-> (usize, u8, &BitVec<C, T>, &BitVec<C, T>) {
+		(self.len, self.low_bits, &self.low, &self.high)
+	}
+
+	/// Chooses the low bit width for a sequence.
+	///
+	/// This is `⌊log₂(max / len)⌋`, or zero when the values are at least as
+	/// dense as one per integer.
+	fn low_width(max: usize, len: usize) -> u8 {
+		match max.checked_div(len) {
+			Some(ratio) if ratio > 0 => {
+				(mem::size_of::<usize>() * 8 - 1) as u8
+					- ratio.leading_zeros() as u8
+			},
+			_ => 0,
+		}
+	}
+
+	/// Builds the position samples over the encoded bits.
+	fn from_bits(
+		low: BitVec<C, T>,
+		high: BitVec<C, T>,
+		len: usize,
+		low_bits: u8,
+	) -> Self {
+		let sample = |value: bool| high.iter()
+			.enumerate()
+			.filter(|&(_, bit)| bit == value)
+			.step_by(SAMPLE)
+			.map(|(n, _)| n)
+			.collect::<Vec<_>>();
+		let (ones, zeros) = (sample(true), sample(false));
+		Self { low, high, len, low_bits, ones, zeros }
+	}
+
+	/// Finds the position in `high` of the `count`th bit equal to `value`.
+	fn select_bit(&self, count: usize, value: bool) -> Option<usize> {
+		let samples = if value { &self.ones } else { &self.zeros };
+		let mut pos = *samples.get(count / SAMPLE)?;
+		for _ in 0 .. count % SAMPLE {
+			let rest = &self.high[pos + 1 ..];
+			let step = if value { rest.first_one() } else { rest.first_zero() };
+			pos += step? + 1;
+		}
+		Some(pos)
+	}
+
+	/// Reads the low bits of a value.
+	fn low_at(&self, index: usize) -> usize {
+		let width = self.low_bits as usize;
+		self.low[index * width ..][.. width]
+			.iter()
+			.fold(0, |acc, bit| acc << 1 | bit as usize)
+	}
+
+	/// Reassembles a value from its position and the position of its high bit.
+	fn value_at(&self, index: usize, pos: usize) -> usize {
+		(pos - index) << self.low_bits | self.low_at(index)
+	}
+}
+
+impl<C, T> Clone for EliasFano<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self {
+			low: self.low.clone(),
+			high: self.high.clone(),
+			len: self.len,
+			low_bits: self.low_bits,
+			ones: self.ones.clone(),
+			zeros: self.zeros.clone(),
+		}
+	}
+}
+
+impl<C, T> Eq for EliasFano<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> PartialEq for EliasFano<C, T>
+where C: Cursor, T: BitStore {
+	/// Tests if two encodings hold the same sequence.
+	///
+	/// Encodings are canonical, so this compares the encoded bits directly.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.low_bits == rhs.low_bits
+			&& self.low == rhs.low
+			&& self.high == rhs.high
+	}
+}
+
+impl<C, T> Debug for EliasFano<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the values of the sequence, as a list.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::elias_fano::EliasFano;
+	///
+	/// let ef: EliasFano = EliasFano::from_sorted(&[2, 3, 3]);
+	/// assert_eq!(format!("{:?}", ef), "[2, 3, 3]");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<'a, C, T> IntoIterator for &'a EliasFano<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+	type IntoIter = Iter<'a, C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** An iterator over the values of an `EliasFano` sequence, in order.
+
+Each step finds the next set bit of the high vector, which is never further
+away than the next change of high part.
+**/
+#[derive(Clone, Debug)]
+pub struct Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The sequence being decoded.
+	ef: &'a EliasFano<C, T>,
+	/// The position of the next value to produce.
+	index: usize,
+	/// The position in the high vector at which to search for the next value.
+	pos: usize,
+}
+
+impl<'a, C, T> ExactSizeIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> FusedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index >= self.ef.len {
+			return None;
+		}
+		self.pos += self.ef.high[self.pos ..].first_one()?;
+		let value = self.ef.value_at(self.index, self.pos);
+		self.index += 1;
+		self.pos += 1;
+		Some(value)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.ef.len - self.index;
+		(len, Some(len))
+	}
+}
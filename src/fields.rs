@@ -0,0 +1,1890 @@
+/*! Integer fields in bit slices
+
+This module provides the `BitField` trait, which reads and writes a bit slice as
+a single integer of any width up to 128 bits, either unsigned or in two’s
+complement. The slice may begin and end anywhere, and may span any number of
+storage elements, so that the headers of packets and the operands of machine
+instructions can be decoded and encoded without assembling their fields one bit
+at a time.
+
+The significance of each bit in a field follows the significance order that the
+rest of the crate uses for integer fields: a slice whose cursor begins at the
+most significant bit of an element, such as `BigEndian`, is read most
+significant bit first, and all others are read least significant bit first.
+Under either of the cursors that order bits by significance, a field is moved a
+whole element at a time.
+
+The `_le` and `_be` forms of `load` and `store` instead split a field into the
+chunks that lie in each storage element, and join those chunks in a fixed order
+of elements, least or most significant first. A field then has the same value
+under every cursor, as long as it covers the same bits of the same elements.
+
+The `_be_bytes` and `_le_bytes` forms divide a field into bytes of eight bits
+from the front of the slice, whatever the storage type, and join the bytes in
+network order or in reverse, for wire formats whose byte order differs from the
+bit order of the stream that carries them.
+!*/
+
+use crate::{
+	access::BitAccess,
+	cursor::Cursor,
+	indices::Indexable,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	mem,
+};
+
+/** Reads and writes a bit slice as an unsigned integer.
+
+# Significance
+
+The first bit of a `BigEndian` slice is the most significant bit of its value,
+and the last bit is the least significant. The first bit of a slice with any
+other cursor is the least significant bit of its value. This is the same order
+that [`BitSlice::iter_fields`] uses.
+
+# Examples
+
+The first word of an IPv4 header, in network order:
+
+```rust
+use bitvec::prelude::*;
+
+let header = [0x45u8, 0x00, 0x05, 0xDC];
+let bits = header.as_bitslice::<BigEndian>();
+assert_eq!(bits[.. 4].load::<u8>(), 4);
+assert_eq!(bits[4 .. 8].load::<u8>(), 5);
+assert_eq!(bits[16 ..].load::<u16>(), 1500);
+```
+
+A field that crosses an element boundary:
+
+```rust
+use bitvec::prelude::*;
+
+let mut data = [0u16; 2];
+let bits = data.as_mut_bitslice::<LittleEndian>();
+bits[10 .. 22].store(0xABCu16);
+assert_eq!(bits[10 .. 22].load::<u32>(), 0xABC);
+assert_eq!(data, [0xF000, 0x002A]);
+```
+
+[`BitSlice::iter_fields`]: ../slice/struct.BitSlice.html#method.iter_fields
+**/
+pub trait BitField {
+	/// Reads the bits of the slice as an unsigned integer.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the slice, zero-extended to `U`. An empty slice has the
+	/// value zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56, 0x78];
+	/// let bits = data.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits[4 .. 28].load::<u32>(), 0x23_4567);
+	/// assert_eq!(bits.load::<u128>(), 0x1234_5678);
+	/// ```
+	fn load<U>(&self) -> U
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into the bits of the slice.
+	///
+	/// The bits of `value` above the length of the slice are discarded. Bits
+	/// outside the slice are not modified, even when they share an element
+	/// with it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0xFFu8; 3];
+	/// data.as_mut_bitslice::<BigEndian>()[4 .. 20].store(0x1234u16);
+	/// assert_eq!(data, [0xF1, 0x23, 0x4F]);
+	///
+	/// data.as_mut_bitslice::<BigEndian>()[8 .. 12].store(0xA5u8);
+	/// assert_eq!(data, [0xF1, 0x53, 0x4F]);
+	/// ```
+	fn store<U>(&mut self, value: U)
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into the bits of the slice, if it fits.
+	///
+	/// Where [`store`] discards the bits of `value` above the length of the
+	/// slice, this refuses to write a value that would lose any set bits, and
+	/// reports a field that is wider than `U` instead of panicking. The slice
+	/// is not modified when this fails.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Returns
+	///
+	/// `Ok` if `value` was written, or a [`StoreError`] that describes why it
+	/// was not.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::fields::StoreError;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = data.as_mut_bitslice::<BigEndian>();
+	/// assert_eq!(bits[4 .. 8].try_store(9u8), Ok(()));
+	/// assert_eq!(
+	///   bits[8 .. 12].try_store(0x1Fu8),
+	///   Err(StoreError::Overflow { len: 4, value: 0x1F }),
+	/// );
+	/// assert_eq!(
+	///   bits.try_store(1u8),
+	///   Err(StoreError::Width { len: 16, bits: 8 }),
+	/// );
+	/// assert_eq!(data, [0x09, 0x00]);
+	/// ```
+	///
+	/// [`StoreError`]: enum.StoreError.html
+	/// [`store`]: #tymethod.store
+	fn try_store<U>(&mut self, value: U) -> Result<(), StoreError>
+	where U: Unsigned;
+
+	/// Reads the bits of the slice as an unsigned integer, with the least
+	/// significant chunk in the first element.
+	///
+	/// The live bits of each storage element under the slice form one chunk of
+	/// the value. Within a chunk, a bit at a more significant electrical
+	/// position of its element is more significant in the value. The chunk in
+	/// the element at the lowest address is the least significant part of the
+	/// value, and each following element holds the next more significant part.
+	///
+	/// The value therefore depends only on the numeric values of the storage
+	/// elements, and on which of their bits the slice covers, and not on the
+	/// order in which the cursor visits those bits. A slice of whole bytes
+	/// reads as `from_le_bytes` would read them, under every cursor.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the slice, zero-extended to `U`. An empty slice has the
+	/// value zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x1234u16, 0xABCD];
+	/// let le = data.as_bitslice::<LittleEndian>();
+	/// let be = data.as_bitslice::<BigEndian>();
+	/// assert_eq!(le[8 .. 24].load_le::<u16>(), 0xCD12);
+	/// assert_eq!(be[8 .. 24].load_le::<u16>(), 0xAB34);
+	///
+	/// let bytes = [0x78u8, 0x56, 0x34, 0x12];
+	/// let le = bytes.as_bitslice::<LittleEndian>();
+	/// let be = bytes.as_bitslice::<BigEndian>();
+	/// assert_eq!(le.load_le::<u32>(), 0x1234_5678);
+	/// assert_eq!(be.load_le::<u32>(), 0x1234_5678);
+	/// ```
+	fn load_le<U>(&self) -> U
+	where U: Unsigned;
+
+	/// Reads the bits of the slice as an unsigned integer, with the most
+	/// significant chunk in the first element.
+	///
+	/// This is [`load_le`], with the chunks of the elements joined in the
+	/// opposite order: the element at the lowest address holds the most
+	/// significant part of the value. A slice of whole bytes reads as
+	/// `from_be_bytes` would read them, under every cursor.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the slice, zero-extended to `U`. An empty slice has the
+	/// value zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x1234u16, 0xABCD];
+	/// let le = data.as_bitslice::<LittleEndian>();
+	/// let be = data.as_bitslice::<BigEndian>();
+	/// assert_eq!(le[8 .. 24].load_be::<u16>(), 0x12CD);
+	/// assert_eq!(be[8 .. 24].load_be::<u16>(), 0x34AB);
+	///
+	/// let bytes = [0x12u8, 0x34, 0x56];
+	/// let le = bytes.as_bitslice::<LittleEndian>();
+	/// assert_eq!(le[4 ..].load_be::<u32>(), 0x1_3456);
+	/// ```
+	///
+	/// [`load_le`]: #tymethod.load_le
+	fn load_be<U>(&self) -> U
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into the bits of the slice, with the least
+	/// significant chunk in the first element.
+	///
+	/// This is the inverse of [`load_le`]. The bits of `value` above the
+	/// length of the slice are discarded, and bits outside the slice are not
+	/// modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 4];
+	/// data.as_mut_bitslice::<BigEndian>().store_le(0x1234_5678u32);
+	/// assert_eq!(data, 0x1234_5678u32.to_le_bytes());
+	/// ```
+	///
+	/// [`load_le`]: #tymethod.load_le
+	fn store_le<U>(&mut self, value: U)
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into the bits of the slice, with the most
+	/// significant chunk in the first element.
+	///
+	/// This is the inverse of [`load_be`]. The bits of `value` above the
+	/// length of the slice are discarded, and bits outside the slice are not
+	/// modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u16; 2];
+	/// data.as_mut_bitslice::<LittleEndian>()[8 .. 24].store_be(0x12CDu16);
+	/// assert_eq!(data, [0x1200, 0x00CD]);
+	/// ```
+	///
+	/// [`load_be`]: #tymethod.load_be
+	fn store_be<U>(&mut self, value: U)
+	where U: Unsigned;
+
+	/// Reads the bits of the slice as a sequence of bytes, with the most
+	/// significant byte first.
+	///
+	/// The slice is divided into bytes of eight consecutive bits, from the
+	/// front, and each byte is read as [`load`] reads it, in the significance
+	/// order of the cursor. The bytes are then joined as `from_be_bytes` would
+	/// join them. This depends only on the order of the bits in the slice, not
+	/// on the storage type, so a big-endian field in a stream of bits reads
+	/// correctly under any cursor.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the slice, zero-extended to `U`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`, or if its length is
+	/// not a multiple of eight.
+	///
+	/// # Examples
+	///
+	/// A 24-bit field, sent most significant byte first over a link that
+	/// sends each byte least significant bit first:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56, 0x78];
+	/// let bits = data.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits[.. 24].load::<u32>(), 0x56_3412);
+	/// assert_eq!(bits[.. 24].load_be_bytes::<u32>(), 0x12_3456);
+	///
+	/// let wide = [0x1234u16, 0x5678];
+	/// let bits = wide.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits[8 .. 32].load_be_bytes::<u32>(), 0x34_5678);
+	/// ```
+	///
+	/// [`load`]: #tymethod.load
+	fn load_be_bytes<U>(&self) -> U
+	where U: Unsigned;
+
+	/// Reads the bits of the slice as a sequence of bytes, with the least
+	/// significant byte first.
+	///
+	/// This is [`load_be_bytes`], with the bytes joined as `from_le_bytes`
+	/// would join them.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the slice, zero-extended to `U`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`, or if its length is
+	/// not a multiple of eight.
+	///
+	/// # Examples
+	///
+	/// A little-endian length field in a network-order header:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let header = [0xA0u8, 0x34, 0x12];
+	/// let bits = header.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits[8 ..].load::<u16>(), 0x3412);
+	/// assert_eq!(bits[8 ..].load_le_bytes::<u16>(), 0x1234);
+	/// ```
+	///
+	/// [`load_be_bytes`]: #tymethod.load_be_bytes
+	fn load_le_bytes<U>(&self) -> U
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into the bits of the slice as a sequence of
+	/// bytes, with the most significant byte first.
+	///
+	/// This is the inverse of [`load_be_bytes`]. The bits of `value` above the
+	/// length of the slice are discarded, and bits outside the slice are not
+	/// modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`, or if its length is
+	/// not a multiple of eight.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 4];
+	/// let bits = data.as_mut_bitslice::<LittleEndian>();
+	/// bits[8 ..].store_be_bytes(0xAB_CDEFu32);
+	/// assert_eq!(data, [0x00, 0xAB, 0xCD, 0xEF]);
+	/// ```
+	///
+	/// [`load_be_bytes`]: #tymethod.load_be_bytes
+	fn store_be_bytes<U>(&mut self, value: U)
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into the bits of the slice as a sequence of
+	/// bytes, with the least significant byte first.
+	///
+	/// This is the inverse of [`load_le_bytes`]. The bits of `value` above the
+	/// length of the slice are discarded, and bits outside the slice are not
+	/// modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `U`, or if its length is
+	/// not a multiple of eight.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u16; 2];
+	/// data.as_mut_bitslice::<BigEndian>()[8 .. 24].store_le_bytes(0x1234u16);
+	/// assert_eq!(data, [0x0034, 0x1200]);
+	/// ```
+	///
+	/// [`load_le_bytes`]: #tymethod.load_le_bytes
+	fn store_le_bytes<U>(&mut self, value: U)
+	where U: Unsigned;
+
+	/// Reads a field at an offset in the slice as an unsigned integer.
+	///
+	/// This is `self[offset .. offset + width].load()`, without producing the
+	/// subslice. The bounds of the field are checked once, and the elements
+	/// under it are then read directly, which matters to decoders that read
+	/// many small fields from one buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `offset`: The index in the slice at which the field begins.
+	/// - `width`: The number of bits in the field.
+	///
+	/// # Returns
+	///
+	/// The value of the field, zero-extended to `U`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the field extends past the end of the slice, or if
+	/// `width` is more than the width of `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let bits = data.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.extract::<u16>(4, 12), 0x234);
+	/// assert_eq!(bits.extract::<u16>(4, 12), bits[4 .. 16].load::<u16>());
+	/// assert_eq!(bits[8 ..].extract::<u8>(4, 4), 4);
+	/// ```
+	fn extract<U>(&self, offset: usize, width: usize) -> U
+	where U: Unsigned;
+
+	/// Writes an unsigned integer into a field at an offset in the slice.
+	///
+	/// This is `self[offset .. offset + width].store(value)`, without
+	/// producing the subslice. The bits of `value` above `width` are
+	/// discarded, and bits outside the field are not modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `offset`: The index in the slice at which the field begins.
+	/// - `width`: The number of bits in the field.
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the field extends past the end of the slice, or if
+	/// `width` is more than the width of `U`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u16; 2];
+	/// let bits = data.as_mut_bitslice::<LittleEndian>();
+	/// bits.insert_field(12, 8, 0xA5u8);
+	/// assert_eq!(bits.extract::<u8>(12, 8), 0xA5);
+	/// assert_eq!(data, [0x5000, 0x000A]);
+	/// ```
+	fn insert_field<U>(&mut self, offset: usize, width: usize, value: U)
+	where U: Unsigned;
+
+	/// Reads the bits of the slice as a two’s-complement signed integer.
+	///
+	/// The most significant bit of the slice is its sign bit, and is copied
+	/// into every higher bit of the produced integer.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The value of the slice, sign-extended to `I`. An empty slice has the
+	/// value zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `I`: The integer type to produce.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `I`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xFDu8, 0x80];
+	/// let bits = data.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits[4 .. 8].load_signed::<i8>(), -3);
+	/// assert_eq!(bits[.. 9].load_signed::<i16>(), -5);
+	/// assert_eq!(bits[1 .. 8].load_signed::<i32>(), -3);
+	/// assert_eq!(bits[8 .. 9].load_signed::<i8>(), -1);
+	/// assert_eq!(bits[9 ..].load_signed::<i64>(), 0);
+	/// ```
+	fn load_signed<I>(&self) -> I
+	where I: Signed;
+
+	/// Writes a signed integer into the bits of the slice, in two’s
+	/// complement.
+	///
+	/// The bits of `value` above the length of the slice are discarded, so a
+	/// value that fits in the field is read back unchanged by
+	/// [`load_signed`]. Bits outside the slice are not modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `I`: The integer type to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice has more bits than `I`.
+	///
+	/// # Examples
+	///
+	/// A twelve-bit temperature signal, least significant bit first, as in a
+	/// CAN frame:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut frame = [0u8; 3];
+	/// frame.as_mut_bitslice::<LittleEndian>()[6 .. 18].store_signed(-40i16);
+	/// assert_eq!(frame, [0x00, 0xF6, 0x03]);
+	///
+	/// let bits = frame.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits[6 .. 18].load_signed::<i16>(), -40);
+	/// assert_eq!(bits[6 .. 18].load::<u16>(), 0xFD8);
+	/// ```
+	///
+	/// [`load_signed`]: #tymethod.load_signed
+	fn store_signed<I>(&mut self, value: I)
+	where I: Signed;
+
+	/// Reads the bits of the slice as an IEEE 754 single-precision float.
+	///
+	/// The slice is read as a `u32`, in the significance order of its cursor,
+	/// and then reinterpreted with [`f32::from_bits`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The float whose bit pattern is the value of the slice.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is not exactly 32 bits long.
+	///
+	/// # Examples
+	///
+	/// A float packed three bits into a telemetry frame:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let frame = [0b101_01000u8, 0b0000_0100, 0, 0, 0b000_11011];
+	/// let bits = frame.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits[3 .. 35].load_f32(), 2.5);
+	/// ```
+	///
+	/// [`f32::from_bits`]: https://doc.rust-lang.org/stable/std/primitive.f32.html#method.from_bits
+	fn load_f32(&self) -> f32;
+
+	/// Reads the bits of the slice as an IEEE 754 double-precision float.
+	///
+	/// The slice is read as a `u64`, in the significance order of its cursor,
+	/// and then reinterpreted with [`f64::from_bits`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The float whose bit pattern is the value of the slice.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is not exactly 64 bits long.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut frame = [0u32; 3];
+	/// frame.as_mut_bitslice::<LittleEndian>()[20 .. 84].store_f64(-0.125);
+	/// let bits = frame.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits[20 .. 84].load_f64(), -0.125);
+	/// ```
+	///
+	/// [`f64::from_bits`]: https://doc.rust-lang.org/stable/std/primitive.f64.html#method.from_bits
+	fn load_f64(&self) -> f64;
+
+	/// Writes an IEEE 754 single-precision float into the bits of the slice.
+	///
+	/// The bit pattern of `value`, from [`f32::to_bits`], is written as a
+	/// `u32`, in the significance order of the cursor.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The float to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is not exactly 32 bits long.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut frame = [0u8; 5];
+	/// frame.as_mut_bitslice::<BigEndian>()[3 .. 35].store_f32(2.5);
+	/// assert_eq!(frame, [0b000_01000, 0b0000_0100, 0, 0, 0]);
+	/// ```
+	///
+	/// [`f32::to_bits`]: https://doc.rust-lang.org/stable/std/primitive.f32.html#method.to_bits
+	fn store_f32(&mut self, value: f32);
+
+	/// Writes an IEEE 754 double-precision float into the bits of the slice.
+	///
+	/// The bit pattern of `value`, from [`f64::to_bits`], is written as a
+	/// `u64`, in the significance order of the cursor.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The float to write.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is not exactly 64 bits long.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut frame = [0u8; 9];
+	/// frame.as_mut_bitslice::<BigEndian>()[4 .. 68].store_f64(1.0);
+	/// assert_eq!(frame[.. 2], [0x03, 0xFF]);
+	/// assert_eq!(frame[2 ..], [0; 7]);
+	/// ```
+	///
+	/// [`f64::to_bits`]: https://doc.rust-lang.org/stable/std/primitive.f64.html#method.to_bits
+	fn store_f64(&mut self, value: f64);
+}
+
+impl<C, T> BitField for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn load<U>(&self) -> U
+	where U: Unsigned {
+		check(self.len(), U::BITS);
+		U::from_u128(load_bits(self, 0, self.len()))
+	}
+
+	fn store<U>(&mut self, value: U)
+	where U: Unsigned {
+		check(self.len(), U::BITS);
+		store_bits(self, 0, self.len(), value.into_u128());
+	}
+
+	fn try_store<U>(&mut self, value: U) -> Result<(), StoreError>
+	where U: Unsigned {
+		let len = self.len();
+		if len > U::BITS as usize {
+			return Err(StoreError::Width { len, bits: U::BITS });
+		}
+		let value = value.into_u128();
+		if value.checked_shr(len as u32).unwrap_or(0) != 0 {
+			return Err(StoreError::Overflow { len, value });
+		}
+		store_bits(self, 0, len, value);
+		Ok(())
+	}
+
+	fn load_le<U>(&self) -> U
+	where U: Unsigned {
+		check(self.len(), U::BITS);
+		U::from_u128(load_elements(self, 0, self.len(), false))
+	}
+
+	fn load_be<U>(&self) -> U
+	where U: Unsigned {
+		check(self.len(), U::BITS);
+		U::from_u128(load_elements(self, 0, self.len(), true))
+	}
+
+	fn store_le<U>(&mut self, value: U)
+	where U: Unsigned {
+		check(self.len(), U::BITS);
+		store_elements(self, 0, self.len(), value.into_u128(), false);
+	}
+
+	fn store_be<U>(&mut self, value: U)
+	where U: Unsigned {
+		check(self.len(), U::BITS);
+		store_elements(self, 0, self.len(), value.into_u128(), true);
+	}
+
+	fn load_be_bytes<U>(&self) -> U
+	where U: Unsigned {
+		check_bytes(self.len(), U::BITS);
+		U::from_u128(load_bytes(self, true))
+	}
+
+	fn load_le_bytes<U>(&self) -> U
+	where U: Unsigned {
+		check_bytes(self.len(), U::BITS);
+		U::from_u128(load_bytes(self, false))
+	}
+
+	fn store_be_bytes<U>(&mut self, value: U)
+	where U: Unsigned {
+		check_bytes(self.len(), U::BITS);
+		store_bytes(self, value.into_u128(), true);
+	}
+
+	fn store_le_bytes<U>(&mut self, value: U)
+	where U: Unsigned {
+		check_bytes(self.len(), U::BITS);
+		store_bytes(self, value.into_u128(), false);
+	}
+
+	fn extract<U>(&self, offset: usize, width: usize) -> U
+	where U: Unsigned {
+		check_range(self.len(), offset, width);
+		check(width, U::BITS);
+		U::from_u128(load_bits(self, offset, width))
+	}
+
+	fn insert_field<U>(&mut self, offset: usize, width: usize, value: U)
+	where U: Unsigned {
+		check_range(self.len(), offset, width);
+		check(width, U::BITS);
+		store_bits(self, offset, width, value.into_u128());
+	}
+
+	fn load_signed<I>(&self) -> I
+	where I: Signed {
+		check(self.len(), I::BITS);
+		let len = self.len() as u32;
+		if len == 0 {
+			return I::from_i128(0);
+		}
+		//  Move the sign bit of the field to the sign bit of the register, and
+		//  then shift it back down, arithmetically.
+		let shift = 128 - len;
+		I::from_i128((load_bits(self, 0, self.len()) << shift) as i128 >> shift)
+	}
+
+	fn store_signed<I>(&mut self, value: I)
+	where I: Signed {
+		check(self.len(), I::BITS);
+		store_bits(self, 0, self.len(), value.into_i128() as u128);
+	}
+
+	fn load_f32(&self) -> f32 {
+		check_float(self.len(), 32);
+		f32::from_bits(load_bits(self, 0, self.len()) as u32)
+	}
+
+	fn load_f64(&self) -> f64 {
+		check_float(self.len(), 64);
+		f64::from_bits(load_bits(self, 0, self.len()) as u64)
+	}
+
+	fn store_f32(&mut self, value: f32) {
+		check_float(self.len(), 32);
+		store_bits(self, 0, self.len(), u128::from(value.to_bits()));
+	}
+
+	fn store_f64(&mut self, value: f64) {
+		check_float(self.len(), 64);
+		store_bits(self, 0, self.len(), u128::from(value.to_bits()));
+	}
+}
+
+/** The unsigned integer types that a `BitField` can read and write.
+
+This trait is implemented for `u8`, `u16`, `u32`, `u64`, `u128`, and `usize`. It
+is sealed, and cannot be implemented outside this crate.
+
+```rust,compile_fail
+use bitvec::prelude::*;
+
+let data = [0u8; 4];
+let value = data.as_bitslice::<BigEndian>().load::<i32>();
+```
+**/
+pub trait Unsigned: seal::Sealed + Copy + Debug + Eq + Ord {
+	/// The width of the integer, in bits.
+	const BITS: u8;
+
+	/// Narrows an integer to this type, discarding its high bits.
+	///
+	/// # Parameters
+	///
+	/// - `value`: An integer.
+	///
+	/// # Returns
+	///
+	/// The low `Self::BITS` bits of `value`.
+	fn from_u128(value: u128) -> Self;
+
+	/// Widens this integer to `u128`.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// `self`, zero-extended.
+	fn into_u128(self) -> u128;
+}
+
+macro_rules! unsigned {
+	( $( $t:ty ),* ) => { $(
+		impl seal::Sealed for $t {}
+
+		impl Unsigned for $t {
+			const BITS: u8 = (mem::size_of::<$t>() * 8) as u8;
+
+			fn from_u128(value: u128) -> Self {
+				value as $t
+			}
+
+			fn into_u128(self) -> u128 {
+				self as u128
+			}
+		}
+
+		impl FieldValue for $t {
+			fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+			where C: Cursor, T: BitStore {
+				bits.load()
+			}
+
+			fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+			where C: Cursor, T: BitStore {
+				bits.store(self);
+			}
+		}
+	)* };
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+/** The signed integer types that a `BitField` can read and write.
+
+This trait is implemented for `i8`, `i16`, `i32`, `i64`, `i128`, and `isize`. It
+is sealed, and cannot be implemented outside this crate.
+**/
+pub trait Signed: seal::Sealed + Copy + Debug + Eq + Ord {
+	/// The width of the integer, in bits.
+	const BITS: u8;
+
+	/// Narrows an integer to this type, discarding its high bits.
+	///
+	/// # Parameters
+	///
+	/// - `value`: An integer.
+	///
+	/// # Returns
+	///
+	/// The low `Self::BITS` bits of `value`, as a two’s-complement integer.
+	fn from_i128(value: i128) -> Self;
+
+	/// Widens this integer to `i128`.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// `self`, sign-extended.
+	fn into_i128(self) -> i128;
+}
+
+macro_rules! signed {
+	( $( $t:ty ),* ) => { $(
+		impl seal::Sealed for $t {}
+
+		impl Signed for $t {
+			const BITS: u8 = (mem::size_of::<$t>() * 8) as u8;
+
+			fn from_i128(value: i128) -> Self {
+				value as $t
+			}
+
+			fn into_i128(self) -> i128 {
+				self as i128
+			}
+		}
+
+		impl FieldValue for $t {
+			fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+			where C: Cursor, T: BitStore {
+				bits.load_signed()
+			}
+
+			fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+			where C: Cursor, T: BitStore {
+				bits.store_signed(self);
+			}
+		}
+	)* };
+}
+
+signed!(i8, i16, i32, i64, i128, isize);
+
+/** A type that can be read from and written into a field of bits.
+
+This is the conversion that the accessors generated by [`bitfields!`] use. It is
+implemented for the unsigned integers, through [`load`] and [`store`], for the
+signed integers, through [`load_signed`] and [`store_signed`], for `bool`,
+which reads as `true` when any bit of its field is set, and for `f32` and `f64`,
+which require fields of exactly 32 and 64 bits.
+
+The trait is not sealed, so that enumerations and other small types can be
+stored in fields by converting them to and from integers.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::fields::FieldValue;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Ecn {
+  NotCapable,
+  Capable,
+  Congested,
+}
+
+impl FieldValue for Ecn {
+  fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+  where C: Cursor, T: BitStore {
+    match bits.load::<u8>() {
+      0 => Ecn::NotCapable,
+      3 => Ecn::Congested,
+      _ => Ecn::Capable,
+    }
+  }
+
+  fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+  where C: Cursor, T: BitStore {
+    bits.store(match self {
+      Ecn::NotCapable => 0u8,
+      Ecn::Capable => 2,
+      Ecn::Congested => 3,
+    });
+  }
+}
+
+let mut tos = 0u8;
+Ecn::Congested.store_field(&mut tos.as_mut_bitslice::<BigEndian>()[6 ..]);
+assert_eq!(tos, 3);
+let ecn = Ecn::load_field(&tos.as_bitslice::<BigEndian>()[6 ..]);
+assert_eq!(ecn, Ecn::Congested);
+```
+
+[`bitfields!`]: ../macro.bitfields.html
+[`load`]: trait.BitField.html#tymethod.load
+[`load_signed`]: trait.BitField.html#tymethod.load_signed
+[`store`]: trait.BitField.html#tymethod.store
+[`store_signed`]: trait.BitField.html#tymethod.store_signed
+**/
+pub trait FieldValue: Sized {
+	/// Reads a value from a field.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The field.
+	///
+	/// # Returns
+	///
+	/// The value that `bits` holds.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: The cursor of the field.
+	/// - `T`: The storage type of the field.
+	fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+	where C: Cursor, T: BitStore;
+
+	/// Writes a value into a field.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `bits`: The field. Bits outside it are not modified.
+	///
+	/// # Type Parameters
+	///
+	/// - `C`: The cursor of the field.
+	/// - `T`: The storage type of the field.
+	fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+	where C: Cursor, T: BitStore;
+}
+
+impl FieldValue for bool {
+	fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+	where C: Cursor, T: BitStore {
+		bits.any()
+	}
+
+	fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+	where C: Cursor, T: BitStore {
+		bits.store(self as u128);
+	}
+}
+
+impl FieldValue for f32 {
+	fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+	where C: Cursor, T: BitStore {
+		bits.load_f32()
+	}
+
+	fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+	where C: Cursor, T: BitStore {
+		bits.store_f32(self);
+	}
+}
+
+impl FieldValue for f64 {
+	fn load_field<C, T>(bits: &BitSlice<C, T>) -> Self
+	where C: Cursor, T: BitStore {
+		bits.load_f64()
+	}
+
+	fn store_field<C, T>(self, bits: &mut BitSlice<C, T>)
+	where C: Cursor, T: BitStore {
+		bits.store_f64(self);
+	}
+}
+
+/** The error produced when [`BitField::try_store`] cannot write a value.
+
+[`BitField::try_store`]: trait.BitField.html#tymethod.try_store
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StoreError {
+	/// The slice has more bits than the integer type.
+	Width {
+		/// The length of the slice.
+		len: usize,
+		/// The width of the integer type.
+		bits: u8,
+	},
+	/// The value has set bits above the length of the slice.
+	Overflow {
+		/// The length of the slice.
+		len: usize,
+		/// The rejected value.
+		value: u128,
+	},
+}
+
+impl Display for StoreError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			StoreError::Width { len, bits } => write!(
+				f,
+				"A field of {} bits does not fit in a {}-bit integer",
+				len,
+				bits,
+			),
+			StoreError::Overflow { len, value } => write!(
+				f,
+				"The value {:#x} does not fit in a field of {} bits",
+				value,
+				len,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StoreError {}
+
+/// Holds the supertrait that seals `Unsigned` and `Signed`.
+mod seal {
+	/// Marker trait to seal `Unsigned` and `Signed` against downstream
+	/// implementation.
+	pub trait Sealed {}
+}
+
+/// Checks that a field fits in an integer type.
+///
+/// # Parameters
+///
+/// - `len`: The length of the field.
+/// - `width`: The width of the integer type.
+///
+/// # Panics
+///
+/// This panics if `len` is more than `width`.
+fn check(len: usize, width: u8) {
+	assert!(
+		len <= width as usize,
+		"A field of {} bits does not fit in a {}-bit integer",
+		len,
+		width,
+	);
+}
+
+/// Checks that a field lies within a slice.
+///
+/// # Parameters
+///
+/// - `len`: The length of the slice.
+/// - `offset`: The index in the slice at which the field begins.
+/// - `width`: The length of the field.
+///
+/// # Panics
+///
+/// This panics if the field extends past `len`.
+fn check_range(len: usize, offset: usize, width: usize) {
+	assert!(
+		offset <= len && width <= len - offset,
+		"A field of {} bits at {} is out of bounds for a slice of {} bits",
+		width,
+		offset,
+		len,
+	);
+}
+
+/// Checks that a field fits in an integer type, and is made of whole bytes.
+///
+/// # Parameters
+///
+/// - `len`: The length of the field.
+/// - `width`: The width of the integer type.
+///
+/// # Panics
+///
+/// This panics if `len` is more than `width`, or is not a multiple of eight.
+fn check_bytes(len: usize, width: u8) {
+	check(len, width);
+	assert!(
+		len % 8 == 0,
+		"A field of {} bits is not a whole number of bytes",
+		len,
+	);
+}
+
+/// Checks that a field is exactly as wide as a float type.
+///
+/// # Parameters
+///
+/// - `len`: The length of the field.
+/// - `width`: The width of the float type.
+///
+/// # Panics
+///
+/// This panics if `len` is not `width`.
+fn check_float(len: usize, width: usize) {
+	assert_eq!(
+		len,
+		width,
+		"A field of {} bits cannot hold a {}-bit float",
+		len,
+		width,
+	);
+}
+
+/// Reads a field of no more than 128 bits in a slice as an unsigned integer.
+///
+/// # Parameters
+///
+/// - `bits`: A slice.
+/// - `offset`: The index in `bits` at which the field begins.
+/// - `len`: The length of the field. `offset + len` must be no more than
+///   `bits.len()`.
+///
+/// # Returns
+///
+/// The value of the field, in the significance order of the cursor.
+fn load_bits<C, T>(bits: &BitSlice<C, T>, offset: usize, len: usize) -> u128
+where C: Cursor, T: BitStore {
+	match BitSlice::<C, T>::orientation() {
+		Some(big) => load_elements(bits, offset, len, big),
+		None => (0 .. len).fold(0, |out, n| {
+			let bit = unsafe { bits.get_unchecked(offset + n) };
+			out | (bit as u128) << n
+		}),
+	}
+}
+
+/// Writes the low bits of an integer into a field of no more than 128 bits in
+/// a slice.
+///
+/// # Parameters
+///
+/// - `bits`: A slice.
+/// - `offset`: The index in `bits` at which the field begins.
+/// - `len`: The length of the field. `offset + len` must be no more than
+///   `bits.len()`.
+/// - `value`: The integer to write, in the significance order of the cursor.
+fn store_bits<C, T>(
+	bits: &mut BitSlice<C, T>,
+	offset: usize,
+	len: usize,
+	value: u128,
+)
+where C: Cursor, T: BitStore {
+	match BitSlice::<C, T>::orientation() {
+		Some(big) => store_elements(bits, offset, len, value, big),
+		None => for n in 0 .. len {
+			unsafe { bits.set_unchecked(offset + n, value >> n & 1 == 1) };
+		},
+	}
+}
+
+/// Reads a field of no more than 128 bits in a slice as an unsigned integer,
+/// one element at a time.
+///
+/// # Parameters
+///
+/// - `bits`: A slice.
+/// - `offset`: The index in `bits` at which the field begins.
+/// - `len`: The length of the field. `offset + len` must be no more than
+///   `bits.len()`.
+/// - `big`: Whether the first element holds the most significant chunk of the
+///   value, rather than the least.
+///
+/// # Returns
+///
+/// The chunks of each element, joined in the order that `big` selects.
+fn load_elements<C, T>(
+	bits: &BitSlice<C, T>,
+	offset: usize,
+	len: usize,
+	big: bool,
+) -> u128
+where C: Cursor, T: BitStore {
+	let total = len as u8;
+	let mut out = 0u128;
+	let mut done = 0;
+	each_element(bits, offset, len, |elt, from, len| {
+		let mask = C::mask_range::<T>(from.idx(), (from + len).tail());
+		let at = if big { total - done - len } else { done };
+		out |= u128::from(gather(elt.load(), mask)) << at;
+		done += len;
+	});
+	out
+}
+
+/// Writes the low bits of an integer into a field of no more than 128 bits in
+/// a slice, one element at a time.
+///
+/// # Parameters
+///
+/// - `bits`: A slice.
+/// - `offset`: The index in `bits` at which the field begins.
+/// - `len`: The length of the field. `offset + len` must be no more than
+///   `bits.len()`.
+/// - `value`: The integer to write.
+/// - `big`: Whether the first element receives the most significant chunk of
+///   the value, rather than the least.
+fn store_elements<C, T>(
+	bits: &mut BitSlice<C, T>,
+	offset: usize,
+	len: usize,
+	value: u128,
+	big: bool,
+)
+where C: Cursor, T: BitStore {
+	let total = len as u8;
+	let mut done = 0;
+	each_element(bits, offset, len, |elt, from, len| {
+		let mask = C::mask_range::<T>(from.idx(), (from + len).tail());
+		let at = if big { total - done - len } else { done };
+		let chunk = (value >> at) as u64 & !0 >> (64 - len);
+		BitSlice::<C, T>::store_masked(elt, scatter(chunk, mask), mask);
+		done += len;
+	});
+}
+
+/// Reads a slice of whole bytes, no more than 128 bits long, as an unsigned
+/// integer.
+///
+/// # Parameters
+///
+/// - `bits`: A slice whose length is a multiple of eight.
+/// - `big`: Whether the first byte is the most significant byte of the value,
+///   rather than the least.
+///
+/// # Returns
+///
+/// The value of `bits`. Each byte is read in the significance order of the
+/// cursor.
+fn load_bytes<C, T>(bits: &BitSlice<C, T>, big: bool) -> u128
+where C: Cursor, T: BitStore {
+	let count = bits.len() / 8;
+	(0 .. count).fold(0, |out, n| {
+		let byte = load_bits(bits, n * 8, 8);
+		let at = if big { count - 1 - n } else { n };
+		out | byte << (at * 8)
+	})
+}
+
+/// Writes the low bytes of an integer into a slice of whole bytes, no more
+/// than 128 bits long.
+///
+/// # Parameters
+///
+/// - `bits`: A slice whose length is a multiple of eight.
+/// - `value`: The integer to write.
+/// - `big`: Whether the first byte receives the most significant byte of the
+///   value, rather than the least.
+fn store_bytes<C, T>(bits: &mut BitSlice<C, T>, value: u128, big: bool)
+where C: Cursor, T: BitStore {
+	let count = bits.len() / 8;
+	for n in 0 .. count {
+		let at = if big { count - 1 - n } else { n };
+		store_bits(bits, n * 8, 8, value >> (at * 8) & 0xFF);
+	}
+}
+
+/// Visits each element under a field in a slice, with the live bits in it.
+///
+/// The elements are found from the pointer of the slice, without producing a
+/// subslice for the field or for any element.
+///
+/// # Parameters
+///
+/// - `bits`: A slice.
+/// - `offset`: The index in `bits` at which the field begins.
+/// - `len`: The length of the field. `offset + len` must be no more than
+///   `bits.len()`.
+/// - `func`: A function which receives, for each element from front to back,
+///   a reference to the element, the semantic index of the first live bit in
+///   it, and the number of live bits.
+fn each_element<C, T, F>(
+	bits: &BitSlice<C, T>,
+	offset: usize,
+	len: usize,
+	mut func: F,
+)
+where C: Cursor, T: BitStore, F: FnMut(&T::Access, u8, u8) {
+	let bitptr = bits.bitptr();
+	let start = *bitptr.head() as usize + offset;
+	let elts = &bitptr.as_access_slice()[start / T::BITS as usize ..];
+	let mut from = (start % T::BITS as usize) as u8;
+	let mut rest = len;
+	for elt in elts {
+		if rest == 0 {
+			break;
+		}
+		let here = cmp::min((T::BITS - from) as usize, rest) as u8;
+		func(elt, from, here);
+		rest -= here as usize;
+		from = 0;
+	}
+}
+
+/// Collects the bits of an element under a mask into the low bits of an
+/// integer, keeping their order of significance.
+///
+/// # Parameters
+///
+/// - `elt`: An element.
+/// - `mask`: The electrical positions to collect. This must not be empty.
+///
+/// # Returns
+///
+/// The bits of `elt` under `mask`, packed together at the bottom.
+fn gather<T>(elt: T, mask: T) -> u64
+where T: BitStore {
+	let (elt, mask): (u64, u64) = (elt.into(), mask.into());
+	let shift = mask.trailing_zeros();
+	let run = mask >> shift;
+	if run & run.wrapping_add(1) == 0 {
+		return (elt & mask) >> shift;
+	}
+	let mut out = 0;
+	let mut done = 0;
+	for pos in 0 .. 64 {
+		if mask >> pos & 1 == 1 {
+			out |= (elt >> pos & 1) << done;
+			done += 1;
+		}
+	}
+	out
+}
+
+/// Spreads the low bits of an integer into the positions of an element under
+/// a mask, keeping their order of significance. This undoes `gather`.
+///
+/// # Parameters
+///
+/// - `chunk`: The bits to spread. Bits above the population of `mask` must
+///   be clear.
+/// - `mask`: The electrical positions to fill. This must not be empty.
+///
+/// # Returns
+///
+/// An element with the bits of `chunk` under `mask`, and clear elsewhere.
+fn scatter<T>(chunk: u64, mask: T) -> T
+where T: BitStore {
+	let mask: u64 = mask.into();
+	let shift = mask.trailing_zeros();
+	let run = mask >> shift;
+	if run & run.wrapping_add(1) == 0 {
+		return narrow(u128::from(chunk) << shift);
+	}
+	let mut out = 0u64;
+	let mut done = 0;
+	for pos in 0 .. 64 {
+		if mask >> pos & 1 == 1 {
+			out |= (chunk >> done & 1) << pos;
+			done += 1;
+		}
+	}
+	narrow(u128::from(out))
+}
+
+/// Narrows an integer to an element, discarding its high bits.
+fn narrow<T>(value: u128) -> T
+where T: BitStore {
+	let mut out = T::bits(false);
+	for byte in 0 .. T::BITS / 8 {
+		out |= T::from((value >> (byte * 8)) as u8) << (byte * 8);
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::cursor::{
+		BigEndian,
+		LittleEndian,
+		Morton,
+	};
+
+	#[test]
+	fn round_trip() {
+		fn each<C, T>()
+		where C: Cursor, T: BitStore {
+			let mut data = [T::bits(false); 5];
+			let total = data.len() * T::BITS as usize;
+			for from in 0 .. total {
+				for len in 0 ..= cmp::min(128, total - from) {
+					let value = 0x8123_4567_89AB_CDEF_0FED_CBA9_8765_4321u128
+						& (!0u128).checked_shr(128 - len as u32).unwrap_or(0);
+					let bits = BitSlice::<C, T>::from_slice_mut(&mut data);
+					bits.set_all(true);
+					bits[from .. from + len].store(value);
+					assert_eq!(bits[from .. from + len].load::<u128>(), value);
+					assert!(bits[.. from].all());
+					assert!(bits[from + len ..].all());
+					let msb = BitSlice::<C, T>::orientation() == Some(true);
+					for n in 0 .. len {
+						let bit = if msb { len - 1 - n } else { n };
+						assert_eq!(bits[from + n], value >> bit & 1 == 1);
+					}
+				}
+			}
+		}
+		each::<BigEndian, u8>();
+		each::<LittleEndian, u16>();
+		each::<BigEndian, u32>();
+		#[cfg(target_pointer_width = "64")]
+		each::<LittleEndian, u64>();
+		each::<Morton, u8>();
+	}
+
+	#[test]
+	fn offsets() {
+		fn each<C, T>()
+		where C: Cursor, T: BitStore {
+			let mut data = [T::bits(false); 5];
+			let total = data.len() * T::BITS as usize;
+			let value = 0x0F1E_2D3C_4B5A_6978u64;
+			for from in 0 .. total {
+				for len in 0 ..= cmp::min(64, total - from) {
+					let mask =
+						(!0u64).checked_shr(64 - len as u32).unwrap_or(0);
+					let bits = BitSlice::<C, T>::from_slice_mut(&mut data);
+					bits.set_all(true);
+					bits.insert_field(from, len, value);
+					assert_eq!(bits.extract::<u64>(from, len), value & mask);
+					let field = &bits[from .. from + len];
+					assert_eq!(field.load::<u64>(), value & mask);
+					bits[from .. from + len].store(!value);
+					assert_eq!(
+						bits.extract::<u64>(from, len),
+						!value & mask,
+					);
+					assert!(bits[.. from].all());
+					assert!(bits[from + len ..].all());
+				}
+			}
+		}
+		each::<BigEndian, u8>();
+		each::<LittleEndian, u16>();
+		each::<BigEndian, u32>();
+		each::<Morton, u8>();
+	}
+
+	#[test]
+	fn checked_store() {
+		let mut data = [0x5Au8; 3];
+		let bits = BitSlice::<LittleEndian, u8>::from_slice_mut(&mut data);
+		for len in 0 ..= 16 {
+			let field = &mut bits[4 .. 4 + len];
+			for &value in &[0u16, 1, 0x7F, 0x80, 0xFFFF] {
+				let before = field.load::<u16>();
+				let fits = u32::from(value) < 1 << len;
+				match field.try_store(value) {
+					Ok(()) => assert_eq!(field.load::<u16>(), value),
+					Err(err) => {
+						assert_eq!(err, StoreError::Overflow {
+							len,
+							value: u128::from(value),
+						});
+						assert_eq!(field.load::<u16>(), before);
+					},
+				}
+				assert_eq!(field.try_store(value).is_ok(), fits);
+			}
+		}
+		assert_eq!(
+			bits[.. 9].try_store(0u8),
+			Err(StoreError::Width { len: 9, bits: 8 }),
+		);
+	}
+
+	#[test]
+	fn signed() {
+		fn each<C, T>()
+		where C: Cursor, T: BitStore {
+			let mut data = [T::bits(false); 3];
+			let total = data.len() * T::BITS as usize;
+			let bits = BitSlice::<C, T>::from_slice_mut(&mut data);
+			for len in 1 ..= cmp::min(64, total) {
+				let (min, max) = (-1i64 << (len - 1), !(-1i64 << (len - 1)));
+				for &value in &[min, -1, 0, max, min / 3, max / 5] {
+					let field = &mut bits[total - len ..];
+					field.store_signed(value);
+					assert_eq!(field.load_signed::<i64>(), value);
+					assert_eq!(field.load_signed::<i128>(), i128::from(value));
+					let raw = value as u64 & !0 >> (64 - len);
+					assert_eq!(field.load::<u64>(), raw);
+				}
+			}
+		}
+		each::<BigEndian, u8>();
+		each::<LittleEndian, u32>();
+		each::<Morton, u16>();
+	}
+
+	#[test]
+	fn element_order() {
+		fn each<C, T>()
+		where C: Cursor, T: BitStore {
+			let mut data = [T::bits(false); 4];
+			let w = T::BITS as usize;
+			let total = data.len() * w;
+			let at = |i: usize| *C::at::<T>(((i % w) as u8).idx());
+			//  A bit is ranked by its electrical position among the live bits
+			//  of its element, and then offset by the live bits of the elements
+			//  before it, for `_le`, or after it, for `_be`.
+			let rank = |i: usize, from: usize, upto: usize| {
+				let (lo, hi) = (i / w * w, i / w * w + w);
+				let (lo, hi) = (cmp::max(lo, from), cmp::min(hi, upto));
+				let rank = (lo .. hi).filter(|&j| at(j) < at(i)).count();
+				(lo - from + rank, upto - hi + rank)
+			};
+			for from in 0 .. total {
+				for len in 0 ..= cmp::min(128, total - from) {
+					let value = 0xF00D_5EED_1234_5678_9ABC_DEF0_0FED_CBA9u128
+						& (!0u128).checked_shr(128 - len as u32).unwrap_or(0);
+					let upto = from + len;
+					let bits = BitSlice::<C, T>::from_slice_mut(&mut data);
+
+					bits[from .. upto].store_le(value);
+					assert_eq!(bits[from .. upto].load_le::<u128>(), value);
+					for i in from .. upto {
+						let k = rank(i, from, upto).0;
+						assert_eq!(bits[i], value >> k & 1 == 1);
+					}
+
+					bits[from .. upto].store_be(value);
+					assert_eq!(bits[from .. upto].load_be::<u128>(), value);
+					for i in from .. upto {
+						let k = rank(i, from, upto).1;
+						assert_eq!(bits[i], value >> k & 1 == 1);
+					}
+				}
+			}
+		}
+		each::<BigEndian, u8>();
+		each::<LittleEndian, u16>();
+		each::<BigEndian, u32>();
+		each::<Morton, u16>();
+		each::<crate::cursor::NibbleSwapped, u8>();
+	}
+
+	#[test]
+	fn byte_order() {
+		fn each<C, T>()
+		where C: Cursor, T: BitStore {
+			let mut data = [T::bits(false); 4];
+			let total = data.len() * T::BITS as usize;
+			let value = 0x0123_4567_89AB_CDEF_u128;
+			for from in 0 .. total {
+				for bytes in 0 ..= cmp::min(8, (total - from) / 8) {
+					let len = bytes * 8;
+					let bits = BitSlice::<C, T>::from_slice_mut(&mut data);
+					bits.set_all(true);
+					let field = &mut bits[from .. from + len];
+					let byte = |n: usize| value >> (8 * n) & 0xFF;
+					let expected = value & mask(len);
+					field.store_le_bytes(value);
+					for n in 0 .. bytes {
+						let octet = &field[n * 8 .. n * 8 + 8];
+						assert_eq!(u128::from(octet.load::<u8>()), byte(n));
+					}
+					assert_eq!(field.load_le_bytes::<u128>(), expected);
+					field.store_be_bytes(value);
+					for n in 0 .. bytes {
+						let octet = &field[n * 8 .. n * 8 + 8];
+						let at = bytes - 1 - n;
+						assert_eq!(u128::from(octet.load::<u8>()), byte(at));
+					}
+					assert_eq!(field.load_be_bytes::<u128>(), expected);
+					assert!(bits[.. from].all());
+					assert!(bits[from + len ..].all());
+				}
+			}
+		}
+		fn mask(len: usize) -> u128 {
+			(!0u128).checked_shr(128 - len as u32).unwrap_or(0)
+		}
+		each::<BigEndian, u8>();
+		each::<LittleEndian, u16>();
+		each::<BigEndian, u32>();
+		each::<Morton, u16>();
+	}
+
+	#[test]
+	fn floats() {
+		let mut data = [0u8; 10];
+		let bits = BitSlice::<BigEndian, u8>::from_slice_mut(&mut data);
+		let nan = f64::from_bits(0x7FF0_0000_DEAD_BEEF);
+		for &value in &[0.0, -0.0, 1.5e-300, -7.25, nan] {
+			for from in 0 .. 16 {
+				bits[from .. from + 64].store_f64(value);
+				let out = bits[from .. from + 64].load_f64();
+				assert_eq!(out.to_bits(), value.to_bits());
+				bits[from .. from + 32].store_f32(value as f32);
+				let out = bits[from .. from + 32].load_f32();
+				assert_eq!(out.to_bits(), (value as f32).to_bits());
+			}
+		}
+	}
+
+	#[test]
+	fn packed_struct() {
+		bitfields! {
+			struct Frame: Morton, [u16; 4] {
+				flag, set_flag: bool [1],
+				delta, set_delta: i16 [11],
+				ratio, set_ratio: f32 [32],
+				rest, set_rest: u32 [20],
+			}
+		}
+		let mut frame = Frame::new([0; 4]);
+		frame.set_flag(true);
+		frame.set_delta(-1000);
+		frame.set_ratio(0.75);
+		frame.set_rest(0xA_BCDE);
+		assert_eq!(Frame::BITS, 64);
+		assert!(frame.flag());
+		assert_eq!(frame.delta(), -1000);
+		assert_eq!(frame.ratio().to_bits(), 0.75f32.to_bits());
+		assert_eq!(frame.rest(), 0xA_BCDE);
+
+		let bits = frame.as_bitslice();
+		assert!(bits[0]);
+		assert_eq!(bits[1 .. 12].load_signed::<i16>(), -1000);
+		assert_eq!(bits[12 .. 44].load::<u32>(), 0.75f32.to_bits());
+		assert_eq!(bits[44 ..].load::<u32>(), 0xA_BCDE);
+		frame.as_mut_bitslice()[44 .. 48].set_all(false);
+		assert_eq!(frame.rest(), 0xA_BCD0);
+	}
+
+	#[test]
+	fn register_map() {
+		const BASE: usize = 8;
+
+		bitfields! {
+			struct Reg: BigEndian, [u8; 3] {
+				low, set_low: u8 [(BASE + 12) .. 24],
+				flag, set_flag: bool [BASE .. 9],
+				high, set_high: i8 [1 .. 6],
+			}
+		}
+		let mut reg = Reg::new([!0; 3]);
+		reg.set_low(0x5);
+		reg.set_flag(false);
+		reg.set_high(-9);
+		assert_eq!(Reg::BITS, 10);
+		assert_eq!(reg.low(), 0x5);
+		assert!(!reg.flag());
+		assert_eq!(reg.high(), -9);
+		assert_eq!(reg.into_inner(), [0b1101_1111, 0x7F, 0xF5]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn float_width() {
+		let data = [0u32; 2];
+		BitSlice::<LittleEndian, u32>::from_slice(&data)[.. 33].load_f32();
+	}
+
+	#[test]
+	#[should_panic]
+	fn field_bounds() {
+		let data = [0u8; 2];
+		BitSlice::<BigEndian, u8>::from_slice(&data)[1 ..].extract::<u8>(12, 4);
+	}
+
+	#[test]
+	#[should_panic]
+	fn partial_bytes() {
+		let data = [0u16; 2];
+		let bits = BitSlice::<BigEndian, u16>::from_slice(&data);
+		bits[.. 12].load_be_bytes::<u16>();
+	}
+
+	#[test]
+	#[should_panic]
+	fn too_wide() {
+		let data = [0u8; 2];
+		BitSlice::<BigEndian, u8>::from_slice(&data)[.. 9].load::<u8>();
+	}
+}
@@ -0,0 +1,930 @@
+/*! `HybridBitSet` structure
+
+This module holds a set of `usize` values that uses memory in proportion to the
+number of its members, rather than to its largest member. The values are split
+into fixed-width chunks, and each chunk that has any members is stored in
+whichever of two forms is smaller: a sorted list of the members’ offsets in the
+chunk, or a `BitArray` with one bit per offset. This is the layout of the
+“roaring” bitmaps used by many query engines.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	array::BitArray,
+	bits::BitsMut,
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	clone::Clone,
+	cmp::{
+		Eq,
+		Ordering,
+		PartialEq,
+	},
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::{
+		ExactSizeIterator,
+		Extend,
+		FromIterator,
+		FusedIterator,
+		IntoIterator,
+		Iterator,
+	},
+	marker::Copy,
+	mem,
+	slice,
+};
+
+/// The number of bits that a member of a sparse chunk occupies.
+const SPARSE_BITS: usize = 16;
+
+/** A set of `usize` values, stored as a sorted list of chunks.
+
+The values `k * W .. (k + 1) * W`, where `W` is the number of bits in the
+storage array `A`, form chunk `k`. The set stores only the chunks that have
+members. A chunk with few members keeps their offsets in a sorted list, two
+bytes per member; once the list would outgrow the chunk’s `BitArray`, the
+chunk switches to the `BitArray`, and it switches back when removals shrink it
+again. The set never needs to be told which form to use.
+
+The set algebra operations work a chunk at a time. Two dense chunks are
+combined a storage element at a time, two sparse chunks by merging their lists,
+and a sparse chunk is intersected with, or removed from, a dense one by testing
+each of its members.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `A: BitsMut`: The storage array of a dense chunk, such as `[u32; 32]` or
+  `[u64; 16]`. Its width in bits is the width of every chunk, and must be no
+  less than 1 and no greater than 2<sup>16</sup>.
+
+# Examples
+
+```rust
+use bitvec::hybrid::HybridBitSet;
+
+let mut evens: HybridBitSet = (0 .. 2000).step_by(2).collect();
+let sparse: HybridBitSet = [3, 4, 1_000_000].iter().cloned().collect();
+assert!(evens.contains(1998));
+assert!(sparse.contains(1_000_000));
+
+evens.intersect_with(&sparse);
+assert_eq!(evens.iter().collect::<Vec<_>>(), [4]);
+assert_eq!(format!("{:?}", sparse), "{3, 4, 1000000}");
+```
+
+[`Cursor`]: ../cursor/trait.Cursor.html
+**/
+pub struct HybridBitSet<C = BigEndian, A = [u32; 32]>
+where C: Cursor, A: BitsMut + Copy + Default {
+	/// The chunks that have members, with their numbers, in ascending order.
+	chunks: Vec<(usize, Chunk<C, A>)>,
+	/// The number of members in the set.
+	len: usize,
+}
+
+/// The members of one chunk of a `HybridBitSet`.
+enum Chunk<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	/// The offsets of the members, in ascending order.
+	Sparse(Vec<u16>),
+	/// The membership bits, and the number of them that are set.
+	Dense(BitArray<C, A>, usize),
+}
+
+/// The set operations, as applied to chunks.
+#[derive(Clone, Copy, Debug)]
+enum Op {
+	Union,
+	Intersection,
+	Difference,
+	SymmetricDifference,
+}
+
+impl<C, A> HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	/// Constructs a new, empty, `HybridBitSet<C, A>`.
+	///
+	/// # Returns
+	///
+	/// An empty, unallocated, `HybridBitSet`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let set: HybridBitSet = HybridBitSet::new();
+	/// assert!(set.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self { chunks: Vec::new(), len: 0 }
+	}
+
+	/// Counts the members of the set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of values in the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let set: HybridBitSet = [3, 30, 3_000_000].iter().cloned().collect();
+	/// assert_eq!(set.len(), 3);
+	/// ```
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the set is empty.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the set has no members.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Removes every member of the set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	pub fn clear(&mut self) {
+		self.chunks.clear();
+		self.len = 0;
+	}
+
+	/// Tests if a value is in the set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The value to look up.
+	///
+	/// # Returns
+	///
+	/// Whether `value` is a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let set: HybridBitSet = [1, 4].iter().cloned().collect();
+	/// assert!(set.contains(4));
+	/// assert!(!set.contains(2));
+	/// assert!(!set.contains(1_000_000));
+	/// ```
+	pub fn contains(&self, value: usize) -> bool {
+		let (key, offset) = Self::split(value);
+		match self.find(key) {
+			Ok(n) => self.chunks[n].1.contains(offset),
+			Err(_) => false,
+		}
+	}
+
+	/// Adds a value to the set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to add.
+	///
+	/// # Returns
+	///
+	/// Whether `value` was newly added, as opposed to already present.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let mut set: HybridBitSet = HybridBitSet::new();
+	/// assert!(set.insert(70));
+	/// assert!(!set.insert(70));
+	/// assert!(set.contains(70));
+	/// ```
+	pub fn insert(&mut self, value: usize) -> bool {
+		let (key, offset) = Self::split(value);
+		let n = match self.find(key) {
+			Ok(n) => n,
+			Err(n) => {
+				self.chunks.insert(n, (key, Chunk::Sparse(Vec::new())));
+				n
+			},
+		};
+		let added = self.chunks[n].1.insert(offset);
+		self.len += added as usize;
+		added
+	}
+
+	/// Removes a value from the set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to remove.
+	///
+	/// # Returns
+	///
+	/// Whether `value` was present.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let mut set: HybridBitSet = [5].iter().cloned().collect();
+	/// assert!(set.remove(5));
+	/// assert!(!set.remove(5));
+	/// assert!(!set.remove(500));
+	/// ```
+	pub fn remove(&mut self, value: usize) -> bool {
+		let (key, offset) = Self::split(value);
+		let n = match self.find(key) {
+			Ok(n) => n,
+			Err(_) => return false,
+		};
+		let removed = self.chunks[n].1.remove(offset);
+		if self.chunks[n].1.len() == 0 {
+			self.chunks.remove(n);
+		}
+		self.len -= removed as usize;
+		removed
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the values in the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let set: HybridBitSet = [40_000, 7, 19].iter().cloned().collect();
+	/// assert_eq!(set.iter().collect::<Vec<_>>(), [7, 19, 40_000]);
+	/// ```
+	pub fn iter(&self) -> Iter<C, A> {
+		Iter {
+			chunks: self.chunks.iter(),
+			front: None,
+			len: self.len,
+		}
+	}
+
+	/// Adds every member of another set to this one.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are added.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let mut a: HybridBitSet = [1, 2].iter().cloned().collect();
+	/// let b: HybridBitSet = [2, 30_000].iter().cloned().collect();
+	/// a.union_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 2, 30_000]);
+	/// ```
+	pub fn union_with(&mut self, other: &Self) {
+		self.apply(other, Op::Union);
+	}
+
+	/// Removes every member of this set that is not in another set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are kept.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let mut a: HybridBitSet = [1, 2, 30_000].iter().cloned().collect();
+	/// let b: HybridBitSet = [2, 3].iter().cloned().collect();
+	/// a.intersect_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [2]);
+	/// ```
+	pub fn intersect_with(&mut self, other: &Self) {
+		self.apply(other, Op::Intersection);
+	}
+
+	/// Removes every member of another set from this one.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are removed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let mut a: HybridBitSet = [1, 2, 30_000].iter().cloned().collect();
+	/// let b: HybridBitSet = [2, 3].iter().cloned().collect();
+	/// a.difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 30_000]);
+	/// ```
+	pub fn difference_with(&mut self, other: &Self) {
+		self.apply(other, Op::Difference);
+	}
+
+	/// Keeps the values that are in exactly one of this set and another.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are toggled in this one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let mut a: HybridBitSet = [1, 2, 30_000].iter().cloned().collect();
+	/// let b: HybridBitSet = [2, 3].iter().cloned().collect();
+	/// a.symmetric_difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 3, 30_000]);
+	/// ```
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		self.apply(other, Op::SymmetricDifference);
+	}
+
+	/// Tests if every member of this set is in another.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// Whether `self` is a subset of `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let a: HybridBitSet = [2, 3].iter().cloned().collect();
+	/// let b: HybridBitSet = [1, 2, 3].iter().cloned().collect();
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// ```
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.len <= other.len
+			&& self.chunks.iter().all(|&(key, ref chunk)| {
+				match other.find(key) {
+					Ok(n) => chunk.offsets().all(|o| other.chunks[n].1.contains(o)),
+					Err(_) => false,
+				}
+			})
+	}
+
+	/// Tests if every member of another set is in this one.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// Whether `self` is a superset of `other`.
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if this set and another have no members in common.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// Whether the intersection of the two sets is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let a: HybridBitSet = [1, 9].iter().cloned().collect();
+	/// let b: HybridBitSet = [2, 3].iter().cloned().collect();
+	/// assert!(a.is_disjoint(&b));
+	/// ```
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.chunks.iter().all(|&(key, ref chunk)| match other.find(key) {
+			Ok(n) => {
+				let theirs = &other.chunks[n].1;
+				chunk.offsets().all(|o| !theirs.contains(o))
+			},
+			Err(_) => true,
+		})
+	}
+
+	/// Counts the values in a chunk.
+	fn width() -> usize {
+		let width = mem::size_of::<A>() * 8;
+		assert!(
+			width > 0 && width <= 1 << 16,
+			"Chunk width out of range: {}",
+			width,
+		);
+		width
+	}
+
+	/// Splits a value into its chunk number and its offset in the chunk.
+	fn split(value: usize) -> (usize, usize) {
+		let width = Self::width();
+		(value / width, value % width)
+	}
+
+	/// Finds a chunk by its number.
+	fn find(&self, key: usize) -> Result<usize, usize> {
+		self.chunks.binary_search_by_key(&key, |&(k, _)| k)
+	}
+
+	/// Combines every chunk of another set into this one.
+	///
+	/// Chunks present in only one of the sets are kept, copied, or dropped,
+	/// according to what `op` does to a member of only that set.
+	fn apply(&mut self, other: &Self, op: Op) {
+		let keep_own = op.bit(true, false);
+		let take_theirs = op.bit(false, true);
+		let mine = mem::replace(&mut self.chunks, Vec::new());
+		let mut mine = mine.into_iter().peekable();
+		let mut theirs = other.chunks.iter().peekable();
+		loop {
+			let order = match (mine.peek(), theirs.peek()) {
+				(Some(a), Some(b)) => a.0.cmp(&b.0),
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => break,
+			};
+			match order {
+				Ordering::Less => {
+					let own = mine.next().unwrap();
+					if keep_own {
+						self.chunks.push(own);
+					}
+				},
+				Ordering::Greater => {
+					let &(key, ref chunk) = theirs.next().unwrap();
+					if take_theirs {
+						self.chunks.push((key, chunk.clone()));
+					}
+				},
+				Ordering::Equal => {
+					let (key, mut chunk) = mine.next().unwrap();
+					chunk.apply(&theirs.next().unwrap().1, op);
+					if chunk.len() > 0 {
+						self.chunks.push((key, chunk));
+					}
+				},
+			}
+		}
+		self.len = self.chunks.iter().map(|&(_, ref c)| c.len()).sum();
+	}
+}
+
+impl<C, A> Chunk<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	/// The largest number of members that a chunk keeps in sparse form, as
+	/// their offsets then take no more space than the dense bits.
+	fn sparse_limit() -> usize {
+		HybridBitSet::<C, A>::width() / SPARSE_BITS
+	}
+
+	/// Counts the members of the chunk.
+	fn len(&self) -> usize {
+		match *self {
+			Chunk::Sparse(ref offsets) => offsets.len(),
+			Chunk::Dense(_, count) => count,
+		}
+	}
+
+	/// Tests if an offset is a member of the chunk.
+	fn contains(&self, offset: usize) -> bool {
+		match *self {
+			Chunk::Sparse(ref offsets) =>
+				offsets.binary_search(&(offset as u16)).is_ok(),
+			Chunk::Dense(ref bits, _) => bits[offset],
+		}
+	}
+
+	/// Adds an offset to the chunk, and reports if it was newly added.
+	fn insert(&mut self, offset: usize) -> bool {
+		let added = match *self {
+			Chunk::Sparse(ref mut offsets) => {
+				match offsets.binary_search(&(offset as u16)) {
+					Ok(_) => false,
+					Err(n) => {
+						offsets.insert(n, offset as u16);
+						true
+					},
+				}
+			},
+			Chunk::Dense(ref mut bits, ref mut count) => {
+				let added = !bits[offset];
+				bits.set(offset, true);
+				*count += added as usize;
+				added
+			},
+		};
+		self.normalize();
+		added
+	}
+
+	/// Removes an offset from the chunk, and reports if it was present.
+	fn remove(&mut self, offset: usize) -> bool {
+		let removed = match *self {
+			Chunk::Sparse(ref mut offsets) => {
+				match offsets.binary_search(&(offset as u16)) {
+					Ok(n) => {
+						offsets.remove(n);
+						true
+					},
+					Err(_) => false,
+				}
+			},
+			Chunk::Dense(ref mut bits, ref mut count) => {
+				let removed = bits[offset];
+				bits.set(offset, false);
+				*count -= removed as usize;
+				removed
+			},
+		};
+		self.normalize();
+		removed
+	}
+
+	/// Iterates over the offsets of the members, in ascending order.
+	fn offsets(&self) -> Offsets<C, A> {
+		Offsets { chunk: self, next: 0 }
+	}
+
+	/// Combines another chunk into this one.
+	fn apply(&mut self, other: &Self, op: Op) {
+		let out = match (&*self, other) {
+			(&Chunk::Sparse(ref a), &Chunk::Sparse(ref b)) => {
+				Chunk::Sparse(Self::merge(a, b, op))
+			},
+			//  When the result can only hold members of a sparse chunk,
+			//  filtering its list is cheaper than building its bits.
+			(&Chunk::Sparse(ref a), _) if !op.bit(false, true) => {
+				Chunk::Sparse(
+					a.iter()
+						.cloned()
+						.filter(|&o| op.bit(true, other.contains(o as usize)))
+						.collect(),
+				)
+			},
+			(_, &Chunk::Sparse(ref b)) if !op.bit(true, false) => {
+				Chunk::Sparse(
+					b.iter()
+						.cloned()
+						.filter(|&o| op.bit(self.contains(o as usize), true))
+						.collect(),
+				)
+			},
+			_ => {
+				let mut bits = self.to_bits();
+				match *other {
+					Chunk::Dense(ref theirs, _) => {
+						let theirs = theirs.as_bitslice();
+						let bits = bits.as_mut_bitslice();
+						match op {
+							Op::Union => bits.zip_assign(theirs, |mut a, b| {
+								a |= b;
+								a
+							}),
+							Op::Intersection => bits.zip_assign(theirs, |a, b| a & b),
+							Op::Difference => bits.zip_assign(theirs, |a, b| a & !b),
+							Op::SymmetricDifference =>
+								bits.zip_assign(theirs, |a, b| a ^ b),
+						}
+					},
+					//  The operations that reach here leave the bits outside
+					//  `theirs` unchanged.
+					Chunk::Sparse(ref theirs) => for &o in theirs {
+						let o = o as usize;
+						let bit = op.bit(bits[o], true);
+						bits.set(o, bit);
+					},
+				}
+				let count = bits.count_ones();
+				Chunk::Dense(bits, count)
+			},
+		};
+		*self = out;
+		self.normalize();
+	}
+
+	/// Merges two sorted offset lists, keeping the offsets that `op` keeps.
+	fn merge(a: &[u16], b: &[u16], op: Op) -> Vec<u16> {
+		let mut out = Vec::with_capacity(a.len() + b.len());
+		let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+		loop {
+			let (offset, in_a, in_b) = match (a.peek(), b.peek()) {
+				(Some(&&x), Some(&&y)) => (x.min(y), x <= y, y <= x),
+				(Some(&&x), None) => (x, true, false),
+				(None, Some(&&y)) => (y, false, true),
+				(None, None) => break,
+			};
+			if in_a {
+				a.next();
+			}
+			if in_b {
+				b.next();
+			}
+			if op.bit(in_a, in_b) {
+				out.push(offset);
+			}
+		}
+		out
+	}
+
+	/// Copies the membership bits of the chunk into a `BitArray`.
+	fn to_bits(&self) -> BitArray<C, A> {
+		match *self {
+			Chunk::Sparse(ref offsets) => {
+				let mut bits = BitArray::<C, A>::default();
+				for &o in offsets {
+					bits.set(o as usize, true);
+				}
+				bits
+			},
+			Chunk::Dense(bits, _) => bits,
+		}
+	}
+
+	/// Moves the chunk into whichever form is smaller for its member count.
+	fn normalize(&mut self) {
+		let limit = Self::sparse_limit();
+		let out = match *self {
+			Chunk::Sparse(ref offsets) if offsets.len() > limit => {
+				Chunk::Dense(self.to_bits(), offsets.len())
+			},
+			Chunk::Dense(_, count) if count <= limit => {
+				Chunk::Sparse(self.offsets().map(|o| o as u16).collect())
+			},
+			_ => return,
+		};
+		*self = out;
+	}
+}
+
+impl Op {
+	/// Applies the operation to one value’s membership in each set.
+	fn bit(self, a: bool, b: bool) -> bool {
+		match self {
+			Op::Union => a | b,
+			Op::Intersection => a & b,
+			Op::Difference => a & !b,
+			Op::SymmetricDifference => a ^ b,
+		}
+	}
+}
+
+impl<C, A> Clone for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	fn clone(&self) -> Self {
+		Self {
+			chunks: self.chunks.clone(),
+			len: self.len,
+		}
+	}
+}
+
+impl<C, A> Clone for Chunk<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	fn clone(&self) -> Self {
+		match *self {
+			Chunk::Sparse(ref offsets) => Chunk::Sparse(offsets.clone()),
+			Chunk::Dense(bits, count) => Chunk::Dense(bits, count),
+		}
+	}
+}
+
+impl<C, A> Eq for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {}
+
+impl<C, A> PartialEq for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	/// Tests if two sets have the same members.
+	///
+	/// Each chunk’s form is determined by its member count, so equal sets
+	/// store equal chunks, and the chunks are compared directly.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.len == rhs.len
+			&& self.chunks.len() == rhs.chunks.len()
+			&& self.chunks.iter().zip(rhs.chunks.iter()).all(|(a, b)| {
+				a.0 == b.0 && match (&a.1, &b.1) {
+					(&Chunk::Sparse(ref x), &Chunk::Sparse(ref y)) => x == y,
+					(&Chunk::Dense(ref x, _), &Chunk::Dense(ref y, _)) => x == y,
+					_ => false,
+				}
+			})
+	}
+}
+
+impl<C, A> Default for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C, A> Debug for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	/// Renders the members of the set, as the standard library sets do.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::hybrid::HybridBitSet;
+	///
+	/// let set: HybridBitSet = [4, 1].iter().cloned().collect();
+	/// assert_eq!(format!("{:?}", set), "{1, 4}");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<C, A> Hash for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		hasher.write_usize(self.len);
+		for value in self.iter() {
+			hasher.write_usize(value);
+		}
+	}
+}
+
+impl<C, A> Extend<usize> for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item=usize> {
+		for value in src {
+			self.insert(value);
+		}
+	}
+}
+
+impl<C, A> FromIterator<usize> for HybridBitSet<C, A>
+where C: Cursor, A: BitsMut + Copy + Default {
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item=usize> {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<'a, C, A> IntoIterator for &'a HybridBitSet<C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {
+	type Item = usize;
+	type IntoIter = Iter<'a, C, A>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An iterator over the offsets of the members of a chunk.
+struct Offsets<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {
+	/// The chunk being searched.
+	chunk: &'a Chunk<C, A>,
+	/// The index of the next sparse offset, or the next dense bit to search.
+	next: usize,
+}
+
+impl<'a, C, A> Iterator for Offsets<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match *self.chunk {
+			Chunk::Sparse(ref offsets) => {
+				let out = offsets.get(self.next).map(|&o| o as usize);
+				self.next += 1;
+				out
+			},
+			Chunk::Dense(ref bits, _) => {
+				if self.next >= bits.len() {
+					return None;
+				}
+				let out = bits[self.next ..].first_one()? + self.next;
+				self.next = out + 1;
+				Some(out)
+			},
+		}
+	}
+}
+
+/** An iterator over the members of a `HybridBitSet`, in ascending order.
+
+Sparse chunks yield their stored offsets, and dense chunks are searched for
+their set bits a storage element at a time.
+**/
+pub struct Iter<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {
+	/// The chunks not yet begun.
+	chunks: slice::Iter<'a, (usize, Chunk<C, A>)>,
+	/// The first value of the current chunk, and its remaining members.
+	front: Option<(usize, Offsets<'a, C, A>)>,
+	/// The number of members not yet yielded.
+	len: usize,
+}
+
+impl<'a, C, A> Clone for Iter<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {
+	fn clone(&self) -> Self {
+		Self {
+			chunks: self.chunks.clone(),
+			front: self.front.as_ref().map(|&(base, ref offsets)| {
+				(base, Offsets { chunk: offsets.chunk, next: offsets.next })
+			}),
+			len: self.len,
+		}
+	}
+}
+
+impl<'a, C, A> Iterator for Iter<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((base, ref mut offsets)) = self.front {
+				if let Some(offset) = offsets.next() {
+					self.len -= 1;
+					return Some(base + offset);
+				}
+			}
+			let &(key, ref chunk) = self.chunks.next()?;
+			let base = key * HybridBitSet::<C, A>::width();
+			self.front = Some((base, chunk.offsets()));
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+
+impl<'a, C, A> ExactSizeIterator for Iter<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {}
+
+impl<'a, C, A> FusedIterator for Iter<'a, C, A>
+where C: Cursor, A: 'a + BitsMut + Copy + Default {}
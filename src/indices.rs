@@ -215,7 +215,7 @@ where T: BitStore {
 
 	fn try_from(idx: u8) -> Result<Self, Self::Error> {
 		if idx < T::BITS {
-			Ok(unsafe { Self { idx, ty: PhantomData } })
+			Ok(unsafe { Self::new_unchecked(idx) })
 		}
 		else {
 			Err("Attempted to construct a `BitIdx` with an index out of range")
@@ -238,7 +238,7 @@ This type has no behavior other than viewing its internal `u8` for arithmetic.
 - `T`: The memory element type controlled by this tail.
 **/
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub(crate) struct BitTail<T>
+pub struct BitTail<T>
 where T: BitStore {
 	/// Semantic index *after* an element. Constrained to `0 ..= T::BITS`.
 	end: u8,
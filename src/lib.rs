@@ -42,9 +42,15 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate core;
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
 #[cfg(feature = "serde")]
 extern crate serde;
 
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
+
 #[cfg(all(test, feature = "serde"))]
 extern crate serde_test;
 
@@ -52,36 +58,104 @@ extern crate serde_test;
 mod macros;
 
 mod access;
+pub mod array;
+pub mod array_vec;
 pub mod bits;
+pub mod cells;
 pub mod cursor;
 mod domain;
+pub mod dynamic;
+pub mod fields;
 pub mod indices;
+pub mod notation;
 mod pointer;
 pub mod prelude;
+pub mod reader;
 pub mod slice;
+pub mod span;
 pub mod store;
 
+#[cfg(feature = "alloc")]
+pub mod arena;
+
 #[cfg(feature = "alloc")]
 pub mod boxed;
 
+#[cfg(feature = "alloc")]
+pub mod deque;
+
+#[cfg(feature = "alloc")]
+pub mod elias_fano;
+
+#[cfg(feature = "alloc")]
+pub mod hybrid;
+
+#[cfg(feature = "alloc")]
+pub mod matrix;
+
+#[cfg(feature = "alloc")]
+pub mod packed;
+
 #[cfg(feature = "alloc")]
 pub mod vec;
 
+#[cfg(feature = "rayon")]
+mod par;
+
+#[cfg(feature = "alloc")]
+pub mod repr;
+
+#[cfg(feature = "alloc")]
+pub mod rrr;
+
+#[cfg(feature = "alloc")]
+pub mod set;
+
+#[cfg(feature = "alloc")]
+pub mod small;
+
 #[cfg(feature = "serde")]
 mod serdes;
 
+/// Gives `bit_newtype!` a path to `serde` in crates that do not depend on it.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod __serde {
+	pub use serde::{
+		Deserialize,
+		Deserializer,
+		Serialize,
+		Serializer,
+	};
+}
+
 /// Expose crate internals for use in doctests and external tests.
 #[cfg(feature = "testing")]
 pub mod testing {
 	pub use crate::{
+		arena::*,
+		array::*,
+		array_vec::*,
 		atomic::*,
 		bits::*,
 		boxed::*,
 		cursor::*,
+		deque::*,
 		domain::*,
+		dynamic::*,
+		elias_fano::*,
+		fields::*,
+		hybrid::*,
 		macros::*,
+		matrix::*,
+		notation::*,
+		packed::*,
 		pointer::*,
+		reader::*,
+		rrr::*,
+		set::*,
 		slice::*,
+		small::*,
 		store::*,
 		vec::*,
 	};
@@ -1,7 +1,8 @@
 /*! Utility macros for constructing data structures and implementing bulk types.
 
-The only public macro is `bitvec`; this module also provides convenience macros
-for code generation.
+The public macros are `bitvec`, `bitbox`, `bitarr`, `bit_newtype`, `getbit`,
+`setbit`, `verify_cursor`, `bit_cursor`, and `bitfields`; this module also
+provides convenience macros for code generation.
 !*/
 
 /** Construct a `BitVec` out of a literal array in source code, like `vec!`.
@@ -26,14 +27,9 @@ The bit list syntax `bitvec![expr, expr, expr...]` currently produces an
 `&[bool]` slice of the initial pattern, which is written into the final
 artifact’s static memory and may consume excessive space.
 
-The repetition syntax `bitec![expr; count]` currently zeros its allocated buffer
-before setting the first `count` bits to `expr`. This may result in a
-performance penalty when using `bitvec![1; N]`, as the allocation will be zeroed
-and then a subset will be set high.
-
-This behavior is currently required to maintain compatibility with `serde`
-expectations that dead bits are zero. As the `serdes` module removes those
-expectations, the repetition syntax implementation may speed up.
+The repetition syntax `bitvec![expr; count]` calls [`BitVec::repeat`], which
+writes each storage element once, with every bit set to `expr`. The dead bits
+after the end of the vector, in its last element, are cleared.
 
 # Examples
 
@@ -50,6 +46,8 @@ bitvec![BigEndian, u8; 1; 5];
 bitvec![LittleEndian; 0; 5];
 bitvec![1; 5];
 ```
+
+[`BitVec::repeat`]: vec/struct.BitVec.html#method.repeat
 **/
 #[cfg(feature = "alloc")]
 #[macro_export]
@@ -106,20 +104,12 @@ macro_rules! bitvec {
 		bv
 	}};
 
-	//  `[$val; $rep]` can just allocate a slab of at least `$rep` bits and then
-	//  use `.set_all` to force them to `$val`. This is much faster than
+	//  `[$val; $rep]` fills whole elements at once. This is much faster than
 	//  collecting from a bitstream.
 
-	( __bv_impl__ $cursor:path , $bits:ty ; $val:expr ; $rep:expr ) => {{
-		let mut bv = $crate::vec::BitVec::<$cursor, $bits>::with_capacity($rep);
-		bv.set_elements(0);
-		unsafe { bv.set_len($rep); }
-		let one = $val != 0;
-		if one {
-			bv.set_all(one);
-		}
-		bv
-	}};
+	( __bv_impl__ $cursor:path , $bits:ty ; $val:expr ; $rep:expr ) => {
+		$crate::vec::BitVec::<$cursor, $bits>::repeat($val != 0, $rep)
+	};
 }
 
 /** Construct a `BitBox` out of a literal array in source code, like `bitvec!`.
@@ -174,6 +164,954 @@ macro_rules! bitbox {
 	};
 }
 
+/** Construct a `BitArray` out of a literal array in source code, like `bitvec!`.
+
+This has the same syntax as [`bitvec!`]: an optional `Cursor` name, an optional
+`BitStore` type, and then either a list of bits or a bit and a repetition count.
+The storage array is the smallest array of the `BitStore` type that holds every
+requested bit. A `BitArray` always spans whole elements, so any bits in the last
+element after the requested ones are `0`.
+
+When the cursor is written as `BigEndian` or `LittleEndian`, as one of their
+aliases `Msb0`, `Lsb0`, `NetworkOrder`, `BitMsb0`, or `BitLsb0`, or is left out,
+the storage elements are computed with constant arithmetic, so the macro can be
+used to initialize `const` and `static` items. The bit values, and the
+repetition count, must then be constant expressions. The type of the item names
+the storage array, whose length is the number of bits divided by the element
+width, rounded up. Other cursors are set bit by bit at run time, and cannot be
+used in a `const` context.
+
+Unlike `bitvec!`, this does not require an allocator.
+
+# Examples
+
+```rust
+#[macro_use]
+extern crate bitvec;
+
+use bitvec::prelude::*;
+
+static PRIMES: BitArray<BigEndian, [u8; 2]> = bitarr![
+  0, 0, 1, 1, 0, 1, 0, 1,
+  0, 0, 0, 1, 0, 1,
+];
+const EVENS: BitArray<LittleEndian, [u16; 2]> = bitarr![LittleEndian, u16; 1; 20];
+const V4: BitArray<NetworkOrder, [u8; 1]> = bitarr![NetworkOrder; 0, 1, 0, 0];
+const LOW: BitArray<Lsb0, [u32; 1]> = bitarr![Lsb0, u32; 1; 12];
+
+fn main() {
+  assert_eq!(PRIMES.as_slice(), &[0b0011_0101, 0b0001_0100]);
+  assert_eq!(EVENS.into_inner(), [!0, 0x000F]);
+  assert_eq!(V4.into_inner(), [0x40]);
+  assert_eq!(LOW.into_inner(), [0xFFF]);
+
+  let mut flags = bitarr![LittleEndian; 0, 1, 0];
+  flags.set(0, true);
+  assert_eq!(flags.into_inner(), [0b011]);
+  assert_eq!(bitarr![BigEndian, u32; 1, 0, 1,].len(), 32);
+}
+```
+
+[`bitvec!`]: #macro.bitvec
+**/
+#[macro_export]
+macro_rules! bitarr {
+	//  bitarr![ endian , type ; 0 , 1 , … ]
+	( $( $cursor:ident )::+ , $bits:ty ; $( $val:expr ),* ) => {
+		$crate::bitarr![ __ba_impl__ [ $( $cursor )::+ ] $bits ; $( $val ),* ]
+	};
+	//  bitarr![ endian , type ; 0 , 1 , … , ]
+	( $( $cursor:ident )::+ , $bits:ty ; $( $val:expr , )* ) => {
+		$crate::bitarr![ __ba_impl__ [ $( $cursor )::+ ] $bits ; $( $val ),* ]
+	};
+
+	//  bitarr![ endian ; 0 , 1 , … ]
+	( $( $cursor:ident )::+ ; $( $val:expr ),* ) => {
+		$crate::bitarr![ __ba_impl__ [ $( $cursor )::+ ] u8 ; $( $val ),* ]
+	};
+	//  bitarr![ endian ; 0 , 1 , … , ]
+	( $( $cursor:ident )::+ ; $( $val:expr , )* ) => {
+		$crate::bitarr![ __ba_impl__ [ $( $cursor )::+ ] u8 ; $( $val ),* ]
+	};
+
+	//  bitarr![ 0 , 1 , … ]
+	( $( $val:expr ),* ) => {
+		$crate::bitarr![ __ba_impl__ [ BigEndian ] u8 ; $( $val ),* ]
+	};
+	//  bitarr![ 0 , 1 , … , ]
+	( $( $val:expr , )* ) => {
+		$crate::bitarr![ __ba_impl__ [ BigEndian ] u8 ; $( $val ),* ]
+	};
+
+	//  bitarr![ endian , type ; bit ; rep ]
+	( $( $cursor:ident )::+ , $bits:ty ; $val:expr ; $rep:expr ) => {
+		$crate::bitarr![ __ba_impl__ [ $( $cursor )::+ ] $bits ; $val ; $rep ]
+	};
+	//  bitarr![ endian ; bit ; rep ]
+	( $( $cursor:ident )::+ ; $val:expr ; $rep:expr ) => {
+		$crate::bitarr![ __ba_impl__ [ $( $cursor )::+ ] u8 ; $val ; $rep ]
+	};
+	//  bitarr![ bit ; rep ]
+	( $val:expr ; $rep:expr ) => {
+		$crate::bitarr![ __ba_impl__ [ BigEndian ] u8 ; $val ; $rep ]
+	};
+
+	//  The aliases of the two constant cursors are resolved to them by name.
+
+	( __ba_impl__ [ Msb0 ] $( $rest:tt )* ) => {
+		$crate::bitarr![ __ba_impl__ [ BigEndian ] $( $rest )* ]
+	};
+	( __ba_impl__ [ BitMsb0 ] $( $rest:tt )* ) => {
+		$crate::bitarr![ __ba_impl__ [ BigEndian ] $( $rest )* ]
+	};
+	( __ba_impl__ [ NetworkOrder ] $( $rest:tt )* ) => {
+		$crate::bitarr![ __ba_impl__ [ BigEndian ] $( $rest )* ]
+	};
+	( __ba_impl__ [ Lsb0 ] $( $rest:tt )* ) => {
+		$crate::bitarr![ __ba_impl__ [ LittleEndian ] $( $rest )* ]
+	};
+	( __ba_impl__ [ BitLsb0 ] $( $rest:tt )* ) => {
+		$crate::bitarr![ __ba_impl__ [ LittleEndian ] $( $rest )* ]
+	};
+
+	//  The two cursors named here have bit positions that constant arithmetic
+	//  can compute, and so are built without calling any trait methods.
+
+	( __ba_impl__ [ BigEndian ] $bits:ty ; $( $val:expr ),* ) => {
+		$crate::bitarr![ __ba_const__ BigEndian , $bits ; $( $val ),* ]
+	};
+	( __ba_impl__ [ LittleEndian ] $bits:ty ; $( $val:expr ),* ) => {
+		$crate::bitarr![ __ba_const__ LittleEndian , $bits ; $( $val ),* ]
+	};
+	( __ba_impl__ [ BigEndian ] $bits:ty ; $val:expr ; $rep:expr ) => {
+		$crate::bitarr![ __ba_const__ BigEndian , $bits ; $val ; $rep ]
+	};
+	( __ba_impl__ [ LittleEndian ] $bits:ty ; $val:expr ; $rep:expr ) => {
+		$crate::bitarr![ __ba_const__ LittleEndian , $bits ; $val ; $rep ]
+	};
+
+	//  Any other cursor is applied at run time, through `BitSlice::set`.
+
+	( __ba_impl__ [ $cursor:path ] $bits:ty ; $( $val:expr ),* ) => {{
+		let init: &[bool] = &[ $( $val != 0 ),* ];
+		let mut arr = $crate::array::BitArray::<$cursor, _>::new(
+			[0 as $bits; $crate::bitarr![
+				__ba_elts__ $bits ; 0 $( + $crate::bitarr![__ba_one__ $val] )*
+			]],
+		);
+		for (idx, &bit) in init.iter().enumerate() {
+			arr.set(idx, bit);
+		}
+		arr
+	}};
+	( __ba_impl__ [ $cursor:path ] $bits:ty ; $val:expr ; $rep:expr ) => {{
+		let mut arr = $crate::array::BitArray::<$cursor, _>::new(
+			[0 as $bits; $crate::bitarr![__ba_elts__ $bits ; $rep]],
+		);
+		arr[.. $rep].set_all($val != 0);
+		arr
+	}};
+
+	//  Each bit is OR-ed into its element in turn. Constant evaluation permits
+	//  this sequence of assignments, but not loops or branches.
+
+	( __ba_const__ $cursor:ident , $bits:ty ; $( $val:expr ),* ) => {{
+		#[allow(unused_mut)]
+		let mut data = [0 as $bits; $crate::bitarr![
+			__ba_elts__ $bits ; 0 $( + $crate::bitarr![__ba_one__ $val] )*
+		]];
+		let mut _idx = 0usize;
+		$(
+			data[_idx / $crate::bitarr![__ba_width__ $bits]] |=
+				(($val != 0) as $bits)
+				<< $crate::bitarr![__ba_pos__ $cursor , $bits ; _idx];
+			_idx += 1;
+		)*
+		$crate::array::BitArray {
+			_cursor: ::core::marker::PhantomData::<$crate::cursor::$cursor>,
+			data,
+		}
+	}};
+
+	//  Every element is filled with the bit, and the bits after the last
+	//  requested one are then cleared. The buffer holds one spare element in
+	//  front of the storage array, so that the last element written is in
+	//  bounds even when the array is empty; a union then drops the spare.
+
+	( __ba_const__ $cursor:ident , $bits:ty ; $val:expr ; $rep:expr ) => {{
+		#[repr(C)]
+		#[derive(Clone, Copy)]
+		struct __BitarrParts {
+			_spare: $bits,
+			data: [$bits; $crate::bitarr![__ba_elts__ $bits ; $rep]],
+		}
+		#[repr(C)]
+		union __BitarrBuffer {
+			full: [$bits; $crate::bitarr![__ba_elts__ $bits ; $rep] + 1],
+			parts: __BitarrParts,
+		}
+		let fill = (0 as $bits).wrapping_sub(($val != 0) as $bits);
+		let mut full = [fill; $crate::bitarr![__ba_elts__ $bits ; $rep] + 1];
+		full[$crate::bitarr![__ba_elts__ $bits ; $rep]] &=
+			$crate::bitarr![__ba_mask__ $cursor , $bits ; $rep];
+		$crate::array::BitArray {
+			_cursor: ::core::marker::PhantomData::<$crate::cursor::$cursor>,
+			data: unsafe { __BitarrBuffer { full }.parts.data },
+		}
+	}};
+
+	//  Constant arithmetic helpers.
+
+	( __ba_one__ $val:expr ) => { 1usize };
+	( __ba_width__ $bits:ty ) => {
+		(<$bits as $crate::store::BitStore>::BITS as usize)
+	};
+	( __ba_elts__ $bits:ty ; $len:expr ) => {
+		(($len) + $crate::bitarr![__ba_width__ $bits] - 1)
+			/ $crate::bitarr![__ba_width__ $bits]
+	};
+	//  The electrical position of a semantic index.
+	( __ba_pos__ $cursor:ident , $bits:ty ; $idx:expr ) => {
+		$crate::cursor::$cursor::const_at(
+			$crate::bitarr![__ba_width__ $bits] as u8,
+			($idx % $crate::bitarr![__ba_width__ $bits]) as u8,
+		)
+	};
+	//  The live bits of the element that holds the last of `$len` bits. When
+	//  `$len` fills its last element, this is every bit.
+	( __ba_mask__ $cursor:ident , $bits:ty ; $len:expr ) => {
+		$crate::cursor::$cursor::const_mask_range(
+			$crate::bitarr![__ba_width__ $bits] as u8,
+			0,
+			((($len) + $crate::bitarr![__ba_width__ $bits] - 1)
+				% $crate::bitarr![__ba_width__ $bits]) as u8 + 1,
+		) as $bits
+	};
+}
+
+/** Declare a `#[repr(transparent)]` newtype over `BitVec`.
+
+Domain types such as permission masks or page maps are usually a `BitVec` with
+a distinct name, and every such type needs the same trait forwarding. This macro
+declares the wrapper struct and implements, by forwarding to the inner
+`BitVec`:
+
+- `Clone`, `Default`, `Eq`, `Hash`, `Ord`, `PartialEq`, and `PartialOrd`, by
+  derive;
+- `Deref` and `DerefMut` to `BitSlice`;
+- `Debug`, which prints the newtype name before the bits, and `Display`;
+- `FromIterator<bool>`;
+- `From` conversions to and from the inner `BitVec`; and
+- `Serialize` and `Deserialize`, when `bitvec` is built with its `serde`
+  feature. These are transparent, and use the same format as `BitVec`.
+
+The inner `BitVec` is a private field, so the code in the module that declares
+the newtype can reach it as `.0`, and other code cannot. Attributes, including
+doc comments, are passed through to the struct.
+
+The cursor and storage type may be omitted, in which case they are
+`BigEndian` and `u8`, as for `bitvec!`.
+
+# Examples
+
+```rust
+#[macro_use]
+extern crate bitvec;
+
+use bitvec::prelude::*;
+
+bit_newtype! {
+  /// The pages of a region that are mapped.
+  pub struct PageMap(LittleEndian, u64);
+}
+
+bit_newtype! {
+  struct PermissionMask;
+}
+
+fn main() {
+  let mut pages: PageMap = (0 .. 100).map(|n| n % 3 == 0).collect();
+  pages.set(1, true);
+  assert_eq!(pages.count_ones(), 35);
+  pages.0.push(true);
+  assert_eq!(pages.len(), 101);
+
+  let perms = PermissionMask::from(bitvec![1, 0, 1]);
+  assert_eq!(format!("{:?}", perms), "PermissionMask [101]");
+  assert_eq!(BitVec::from(perms), bitvec![1, 0, 1]);
+}
+```
+**/
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! bit_newtype {
+	//  bit_newtype! { struct Name ( endian , type ) ; }
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis struct $name:ident ( $cursor:path , $bits:ty ) ;
+	) => {
+		$( #[ $attr ] )*
+		#[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+		#[repr(transparent)]
+		$vis struct $name($crate::vec::BitVec<$cursor, $bits>);
+
+		impl ::core::ops::Deref for $name {
+			type Target = $crate::slice::BitSlice<$cursor, $bits>;
+
+			fn deref(&self) -> &Self::Target {
+				&self.0
+			}
+		}
+
+		impl ::core::ops::DerefMut for $name {
+			fn deref_mut(&mut self) -> &mut Self::Target {
+				&mut self.0
+			}
+		}
+
+		impl ::core::fmt::Debug for $name {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+				f.write_str(stringify!($name))?;
+				f.write_str(" ")?;
+				::core::fmt::Display::fmt(&self.0, f)
+			}
+		}
+
+		impl ::core::fmt::Display for $name {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+				::core::fmt::Display::fmt(&self.0, f)
+			}
+		}
+
+		impl ::core::iter::FromIterator<bool> for $name {
+			fn from_iter<I>(src: I) -> Self
+			where I: ::core::iter::IntoIterator<Item=bool> {
+				$name(src.into_iter().collect())
+			}
+		}
+
+		impl ::core::convert::From<$crate::vec::BitVec<$cursor, $bits>>
+		for $name {
+			fn from(bv: $crate::vec::BitVec<$cursor, $bits>) -> Self {
+				$name(bv)
+			}
+		}
+
+		impl ::core::convert::From<$name>
+		for $crate::vec::BitVec<$cursor, $bits> {
+			fn from(wrapper: $name) -> Self {
+				wrapper.0
+			}
+		}
+
+		$crate::__bit_newtype_serde!($name, $cursor, $bits);
+	};
+
+	//  bit_newtype! { struct Name ; }
+	( $( #[ $attr:meta ] )* $vis:vis struct $name:ident ; ) => {
+		$crate::bit_newtype! {
+			$( #[ $attr ] )*
+			$vis struct $name ( $crate::prelude::BigEndian , u8 ) ;
+		}
+	};
+}
+
+/// Implements the `serde` traits for a `bit_newtype!` wrapper, when `serde` is
+/// enabled. This must be exported for `bit_newtype!` to reach it.
+#[cfg(all(feature = "alloc", feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bit_newtype_serde {
+	( $name:ident , $cursor:path , $bits:ty ) => {
+		impl $crate::__serde::Serialize for $name {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where S: $crate::__serde::Serializer {
+				$crate::__serde::Serialize::serialize(&self.0, serializer)
+			}
+		}
+
+		impl<'de> $crate::__serde::Deserialize<'de> for $name {
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where D: $crate::__serde::Deserializer<'de> {
+				<$crate::vec::BitVec<$cursor, $bits>
+				as $crate::__serde::Deserialize<'de>>::deserialize(deserializer)
+					.map($name)
+			}
+		}
+	};
+}
+
+/// Without `serde`, `bit_newtype!` wrappers have no `serde` traits.
+#[cfg(all(feature = "alloc", not(feature = "serde")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bit_newtype_serde {
+	( $( $t:tt )* ) => {};
+}
+
+/** Assign to a bit with indexing syntax, as if `BitSlice` were `[bool]`.
+
+Rust has no way for `IndexMut` to produce anything but a reference, and bits do
+not have addresses, so `bits[i] = x` cannot compile for a `BitSlice`. Code
+migrating from `Vec<bool>` can write `setbit!(bits[i] = x)` instead, which
+expands to `bits.set(i, x)`. The compound operators `&=`, `|=`, and `^=` are
+also accepted, and are applied through the [`at`] write guard.
+
+The collection may be a local name, a chain of fields, or any expression in
+parentheses. Indices are checked, and out-of-bounds writes panic, as with
+`BitSlice::set`.
+
+# Examples
+
+```rust
+#[macro_use]
+extern crate bitvec;
+
+use bitvec::prelude::*;
+
+struct Flags {
+  bits: BitVec,
+}
+
+fn main() {
+  let mut bv = bitvec![0; 4];
+  setbit!(bv[1] = true);
+  setbit!(bv[2] |= 1 + 1 == 2);
+  setbit!(bv[1] ^= true);
+  assert_eq!(bv, bitvec![0, 0, 1, 0]);
+
+  let mut flags = Flags { bits: bitvec![0; 8] };
+  setbit!(flags.bits[7] = true);
+  setbit!((&mut flags.bits[4 ..])[0] = true);
+  assert_eq!(flags.bits, bitvec![0, 0, 0, 0, 1, 0, 0, 1]);
+  assert!(getbit!(flags.bits[7]));
+}
+```
+
+[`at`]: slice/struct.BitSlice.html#method.at
+**/
+#[macro_export]
+macro_rules! setbit {
+	//  setbit!(name.field[index] = value)
+	( $base:ident $( . $field:tt )* [ $idx:expr ] = $val:expr ) => {
+		$base $( . $field )* .set($idx, $val)
+	};
+	//  setbit!(name.field[index] op= value)
+	( $base:ident $( . $field:tt )* [ $idx:expr ] $op:tt $val:expr ) => {
+		*$base $( . $field )* .at($idx) $op $val
+	};
+	//  setbit!((expr)[index] = value)
+	( ( $base:expr ) [ $idx:expr ] = $val:expr ) => {
+		($base).set($idx, $val)
+	};
+	//  setbit!((expr)[index] op= value)
+	( ( $base:expr ) [ $idx:expr ] $op:tt $val:expr ) => {
+		*($base).at($idx) $op $val
+	};
+}
+
+/** Read a bit with indexing syntax, as the counterpart of [`setbit!`].
+
+`BitSlice` already implements `Index<usize>`, so `bits[i]` reads a bit directly,
+and this macro expands to exactly that. It exists so that code which writes
+bits with `setbit!` can read them in the same form, and accepts the same
+collection expressions.
+
+# Examples
+
+```rust
+#[macro_use]
+extern crate bitvec;
+
+use bitvec::prelude::*;
+
+fn main() {
+  let bv = bitvec![0, 1];
+  assert!(!getbit!(bv[0]));
+  assert!(getbit!((&bv[1 ..])[0]));
+}
+```
+
+[`setbit!`]: macro.setbit.html
+**/
+#[macro_export]
+macro_rules! getbit {
+	//  getbit!(name.field[index])
+	( $base:ident $( . $field:tt )* [ $idx:expr ] ) => {
+		$base $( . $field )* [$idx]
+	};
+	//  getbit!((expr)[index])
+	( ( $base:expr ) [ $idx:expr ] ) => {
+		($base)[$idx]
+	};
+}
+
+/** Checks `Cursor` implementations against every storage type.
+
+This runs [`cursor::verify`] for each listed cursor over `u8`, `u16`, `u32`, and,
+on 64-bit targets, `u64`, and panics on the first requirement that a cursor
+fails. Call it from a test in the crate that implements the cursors.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::verify_cursor;
+
+verify_cursor!(BigEndian, LittleEndian);
+```
+
+[`cursor::verify`]: cursor/fn.verify.html
+**/
+#[macro_export]
+macro_rules! verify_cursor {
+	( $( $cursor:ty ),+ $(,)? ) => {{ $(
+		$crate::cursor::verify::<$cursor, u8>();
+		$crate::cursor::verify::<$cursor, u16>();
+		$crate::cursor::verify::<$cursor, u32>();
+		#[cfg(target_pointer_width = "64")]
+		$crate::cursor::verify::<$cursor, u64>();
+	)+ }};
+}
+
+/** Declare a `Cursor` from a table of bit positions.
+
+A hand-written `Cursor` that maps two indices to the same position, or skips a
+position, compiles without complaint and silently corrupts data. This macro
+declares a cursor type from a permutation table instead, and refuses to compile
+unless the table is a permutation.
+
+The table lists, for each semantic index in a group of bits, the electrical
+position that it occupies within the group. The group width is the length of
+the table, which must be a power of two no greater than 64. Each element is
+divided into groups of that width, which are visited from the least significant
+to the most significant, as in `LittleEndian`; semantic index `n` therefore
+occupies electrical position `n - n % W + table[n % W]`.
+
+The macro declares a unit struct, deriving `Clone`, `Copy`, `Debug`, `Eq`, and
+`PartialEq`, with the table as its associated constant `TABLE`, and implements
+`Cursor` for it. Attributes, including doc comments, are passed through to the
+struct.
+
+# Panics
+
+The cursor panics when it orders an element narrower than its table.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::{bit_cursor, verify_cursor};
+
+bit_cursor! {
+  /// Visits each nibble from its most significant bit.
+  pub struct NibbleMsb0 = [3, 2, 1, 0, 7, 6, 5, 4];
+}
+
+verify_cursor!(NibbleMsb0);
+assert_eq!(NibbleMsb0::TABLE.len(), 8);
+
+let byte = 0x1Au8;
+let bits = byte.as_bitslice::<NibbleMsb0>();
+assert_eq!(bits, &bitvec![1, 0, 1, 0, 0, 0, 0, 1]);
+```
+
+A table that repeats or omits a position is rejected:
+
+```rust,compile_fail
+use bitvec::bit_cursor;
+
+bit_cursor! {
+  pub struct Broken = [0, 1, 2, 3, 4, 5, 6, 6];
+}
+```
+**/
+#[macro_export]
+macro_rules! bit_cursor {
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis struct $name:ident = [ $( $pos:expr ),+ $(,)? ] ;
+	) => {
+		$( #[ $attr ] )*
+		#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+		$vis struct $name;
+
+		impl $name {
+			/// The number of bits in each group that the table orders.
+			pub const WIDTH: usize =
+				0 $( + $crate::bit_cursor!(__bc_one__ $pos) )+;
+
+			/// The electrical position of each semantic index in a group.
+			pub const TABLE: [u8; $name::WIDTH] = [ $( $pos ),+ ];
+
+			//  Constant evaluation cannot branch, so an invalid table indexes
+			//  past the end of a one-element array, and fails to compile. A
+			//  position of 64 or more overflows its shift, which also fails.
+			//  The test is an array length, so that it is evaluated when the
+			//  type is checked, whether or not the cursor is ever used.
+			#[allow(dead_code)]
+			const PERMUTATION: [(); [0][(
+				($name::WIDTH.count_ones() != 1)
+				| ($name::WIDTH > 64)
+				| ((0u64 $( | 1u64 << $pos )+)
+					!= ((1u128 << $name::WIDTH) - 1) as u64)
+			) as usize]] = [];
+		}
+
+		impl $crate::cursor::Cursor for $name {
+			const TYPENAME: &'static str = stringify!($name);
+
+			fn at<T>(cursor: $crate::indices::BitIdx<T>)
+			-> $crate::indices::BitPos<T>
+			where T: $crate::store::BitStore {
+				let width = $name::WIDTH as u8;
+				assert!(
+					T::BITS >= width,
+					"{} orders {}-bit groups, and cannot order a {}-bit element",
+					stringify!($name),
+					width,
+					T::BITS,
+				);
+				let n = *cursor;
+				let pos = n - n % width + $name::TABLE[(n % width) as usize];
+				$crate::indices::BitPos::new(pos)
+			}
+		}
+	};
+
+	( __bc_one__ $pos:expr ) => { 1usize };
+}
+
+/** Declare a structure of packed bit fields.
+
+This macro declares a structure that wraps a [`BitArray`], and divides its bits
+into a sequence of named fields of fixed widths. Fields are packed in the order
+in which they are written, from the front of the array, and each field is read
+and written with the `Cursor` of the array, through [`FieldValue`]. This is the
+same significance order that [`BitField::load`] uses.
+
+Each field is written as a getter name, a setter name, a type, and a width in
+brackets:
+
+```text
+pub version, set_version: u8 [4],
+```
+
+which declares `fn version(&self) -> u8` and `fn set_version(&mut self, u8)`.
+The type may be any implementor of `FieldValue`: an unsigned or signed integer,
+`bool`, `f32`, or `f64`, or a type of your own. Signed fields are sign-extended
+when read. Attributes on a field, including doc comments, are applied to both of
+its methods, and attributes on the structure are passed through to it.
+
+Instead of a width, every field of a structure may give the range of bits that
+it occupies, as in a register map:
+
+```text
+pub mode, set_mode: u8 [4 .. 6],
+```
+
+Fields with ranges may appear in any order, and may leave bits between them
+unused. Each bound must be a single token: a literal, a constant, or an
+expression in parentheses. A structure uses either widths or ranges for all of
+its fields.
+
+The structure also receives the associated constant `BITS`, the total width of
+its fields, and the methods `new`, `into_inner`, `as_bitslice`, and
+`as_mut_bitslice`.
+
+# Compile-Time Checks
+
+The macro refuses to compile fields that do not fit in the storage array, and
+fields whose widths are zero or wider than 128 bits. Fields with ranges must
+also not overlap each other. Accessors therefore index a range that is known to
+be valid, and do not check the layout when they run.
+
+# Panics
+
+An accessor panics if its field is wider than its type, such as a `u8` field of
+nine bits, or an `f32` field of any width other than 32.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::bitfields;
+
+bitfields! {
+  /// The first word of an IPv4 header.
+  #[derive(Clone, Copy, Debug, Default, PartialEq)]
+  pub struct Ipv4Word: BigEndian, [u8; 4] {
+    /// The protocol version.
+    pub version, set_version: u8 [4],
+    pub ihl, set_ihl: u8 [4],
+    pub dscp, set_dscp: u8 [6],
+    pub ecn, set_ecn: u8 [2],
+    pub total_len, set_total_len: u16 [16],
+  }
+}
+
+let mut word = Ipv4Word::new([0x45, 0x00, 0x05, 0xDC]);
+assert_eq!(Ipv4Word::BITS, 32);
+assert_eq!(word.version(), 4);
+assert_eq!(word.ihl(), 5);
+assert_eq!(word.total_len(), 1500);
+word.set_ecn(3);
+assert_eq!(word.into_inner(), [0x45, 0x03, 0x05, 0xDC]);
+```
+
+Fields of other types, least significant bit first:
+
+```rust
+use bitvec::prelude::*;
+use bitvec::bitfields;
+
+bitfields! {
+  struct Status: LittleEndian, [u16; 1] {
+    ready, set_ready: bool [1],
+    temp, set_temp: i8 [7],
+    level, set_level: u8 [8],
+  }
+}
+
+let mut status = Status::new([0]);
+status.set_ready(true);
+status.set_temp(-12);
+status.set_level(200);
+assert!(status.ready());
+assert_eq!(status.temp(), -12);
+assert_eq!(status.level(), 200);
+assert_eq!(status.into_inner(), [0xC8E9]);
+```
+
+A register with explicit offsets and reserved bits:
+
+```rust
+use bitvec::prelude::*;
+use bitvec::bitfields;
+
+const PRESCALE: usize = 16;
+
+bitfields! {
+  pub struct Control: LittleEndian, [u32; 1] {
+    pub enable, set_enable: bool [0 .. 1],
+    pub prescale, set_prescale: u16 [PRESCALE .. 32],
+    pub mode, set_mode: u8 [4 .. 6],
+  }
+}
+
+let mut ctrl = Control::new([0]);
+ctrl.set_enable(true);
+ctrl.set_mode(2);
+ctrl.set_prescale(0x1234);
+assert_eq!(Control::BITS, 19);
+assert_eq!(ctrl.into_inner(), [0x1234_0021]);
+```
+
+Fields that overflow the storage are rejected:
+
+```rust,compile_fail
+use bitvec::bitfields;
+
+bitfields! {
+  struct Overfull: bitvec::cursor::BigEndian, [u8; 1] {
+    high, set_high: u8 [4],
+    low, set_low: u8 [5],
+  }
+}
+```
+
+and so are fields that overlap:
+
+```rust,compile_fail
+use bitvec::bitfields;
+
+bitfields! {
+  struct Overlap: bitvec::cursor::LittleEndian, [u8; 1] {
+    high, set_high: u8 [4 .. 8],
+    mid, set_mid: u8 [2 .. 6],
+  }
+}
+```
+
+[`BitArray`]: array/struct.BitArray.html
+[`BitField::load`]: fields/trait.BitField.html#tymethod.load
+[`FieldValue`]: fields/trait.FieldValue.html
+**/
+#[macro_export]
+macro_rules! bitfields {
+	(
+		__bf_field__ [ $lo:expr , $hi:expr ]
+		$( #[ $attr:meta ] )*
+		$vis:vis $get:ident , $set:ident : $ty:ty
+	) => {
+		//  Packed fields end at `offset + width`, which is often `n + 1`.
+		$( #[ $attr ] )*
+		#[allow(clippy::range_plus_one)]
+		$vis fn $get(&self) -> $ty {
+			$crate::fields::FieldValue::load_field(&self.bits[$lo .. $hi])
+		}
+
+		$( #[ $attr ] )*
+		#[allow(clippy::range_plus_one)]
+		$vis fn $set(&mut self, value: $ty) {
+			$crate::fields::FieldValue::store_field(
+				value,
+				&mut self.bits[$lo .. $hi],
+			);
+		}
+	};
+
+	( __bf_packed__ [ $offset:expr ] ) => {};
+
+	(
+		__bf_packed__ [ $offset:expr ]
+		$( #[ $attr:meta ] )*
+		$vis:vis $get:ident , $set:ident : $ty:ty [ $width:expr ] ;
+		$( $rest:tt )*
+	) => {
+		$crate::bitfields! {
+			__bf_field__ [ $offset , $offset + $width ]
+			$( #[ $attr ] )*
+			$vis $get , $set : $ty
+		}
+
+		$crate::bitfields! { __bf_packed__ [ $offset + $width ] $( $rest )* }
+	};
+
+	//  Counts the pairs of fields, including each field with itself, that
+	//  share at least one bit.
+	( __bf_overlaps__ $all:tt $( [ $lo:expr , $hi:expr ] )* ) => {
+		0 $( + $crate::bitfields!(__bf_overlaps_one__ [ $lo , $hi ] $all) )*
+	};
+
+	(
+		__bf_overlaps_one__ [ $lo:expr , $hi:expr ]
+		[ $( [ $lo2:expr , $hi2:expr ] )* ]
+	) => {
+		0 $( + (($lo < $hi2) & ($lo2 < $hi)) as usize )*
+	};
+
+	(
+		__bf_struct__
+		$( #[ $attr:meta ] )*
+		$vis:vis $name:ident : $cursor:ty , $store:ty ;
+		bits $bits:expr ;
+		invalid $invalid:expr ;
+		$( $fields:tt )*
+	) => {
+		$( #[ $attr ] )*
+		$vis struct $name {
+			bits: $crate::array::BitArray<$cursor, $store>,
+		}
+
+		impl $name {
+			/// The number of bits that the fields occupy.
+			pub const BITS: usize = $bits;
+
+			//  As in `bit_cursor!`, an invalid layout indexes past the end of
+			//  a one-element array in an array length, and fails to compile.
+			#[allow(dead_code)]
+			const LAYOUT: [(); [0][($invalid) as usize]] = [];
+
+			/// Wraps a storage array.
+			#[allow(dead_code)]
+			pub fn new(data: $store) -> Self {
+				Self {
+					bits: $crate::array::BitArray::new(data),
+				}
+			}
+
+			/// Removes the structure from its storage array.
+			#[allow(dead_code)]
+			pub fn into_inner(self) -> $store {
+				self.bits.into_inner()
+			}
+
+			/// Views the storage array as bits.
+			#[allow(dead_code)]
+			pub fn as_bitslice(&self) -> &$crate::slice::BitSlice<
+				$cursor,
+				<$store as $crate::bits::Bits>::Store,
+			> {
+				self.bits.as_bitslice()
+			}
+
+			/// Views the storage array as mutable bits.
+			#[allow(dead_code)]
+			pub fn as_mut_bitslice(&mut self) -> &mut $crate::slice::BitSlice<
+				$cursor,
+				<$store as $crate::bits::Bits>::Store,
+			> {
+				self.bits.as_mut_bitslice()
+			}
+
+			$( $fields )*
+		}
+	};
+
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis struct $name:ident : $cursor:ty , $store:ty {
+			$(
+				$( #[ $fattr:meta ] )*
+				$fvis:vis $get:ident , $set:ident : $fty:ty [ $lo:tt .. $hi:tt ]
+			),* $(,)?
+		}
+	) => {
+		$crate::bitfields! {
+			__bf_struct__
+			$( #[ $attr ] )*
+			$vis $name : $cursor , $store ;
+			bits 0 $( + ($hi - $lo) )* ;
+			invalid
+				$(
+					($lo >= $hi)
+					| ($hi > 8 * ::core::mem::size_of::<$store>())
+					| ($hi > $lo + 128) |
+				)*
+				($crate::bitfields!(
+					__bf_overlaps__
+					[ $( [ $lo , $hi ] )* ]
+					$( [ $lo , $hi ] )*
+				) > 0 $( + ($lo < $hi) as usize )*) ;
+			$(
+				$crate::bitfields! {
+					__bf_field__ [ $lo , $hi ]
+					$( #[ $fattr ] )*
+					$fvis $get , $set : $fty
+				}
+			)*
+		}
+	};
+
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis struct $name:ident : $cursor:ty , $store:ty {
+			$(
+				$( #[ $fattr:meta ] )*
+				$fvis:vis $get:ident , $set:ident : $fty:ty [ $width:expr ]
+			),* $(,)?
+		}
+	) => {
+		$crate::bitfields! {
+			__bf_struct__
+			$( #[ $attr ] )*
+			$vis $name : $cursor , $store ;
+			bits 0 $( + $width )* ;
+			invalid
+				(0 $( + $width )* > 8 * ::core::mem::size_of::<$store>())
+				$( | ($width == 0) | ($width > 128) )* ;
+			$crate::bitfields! {
+				__bf_packed__ [ 0 ]
+				$(
+					$( #[ $fattr ] )*
+					$fvis $get , $set : $fty [ $width ] ;
+				)*
+			}
+		}
+	};
+}
+
 #[doc(hidden)]
 macro_rules! __bitslice_shift {
 	( $( $t:ty ),+ ) => { $(
@@ -203,6 +1141,61 @@ macro_rules! __bitslice_shift {
 	)+ };
 }
 
+/// Implements comparison between bit collections and unsigned integers.
+///
+/// Each collection is read with `BitSlice::int_value`, in the significance
+/// order of `BitField::load`; see the `BitSlice` documentation for the
+/// convention under each cursor. The bare form implements comparison for
+/// `BitSlice` and `&BitSlice`; the `for` form implements it for another
+/// collection which dereferences to `BitSlice`.
+#[doc(hidden)]
+macro_rules! __bitslice_int_cmp {
+	( for $g:tt $this:ty => $( $t:ty ),+ ) => { $(
+		__bitslice_int_cmp!(__impl $g $this ; $t);
+	)+ };
+
+	( __impl [ $( $g:tt )* ] $this:ty ; $t:ty ) => {
+		impl< $( $g )* > core::cmp::PartialEq<$t> for $this
+		where C: $crate::cursor::Cursor, T: $crate::store::BitStore {
+			fn eq(&self, rhs: &$t) -> bool {
+				self.int_value(core::mem::size_of::<$t>() * 8)
+					== Some(*rhs as u128)
+			}
+		}
+
+		impl< $( $g )* > core::cmp::PartialEq<$this> for $t
+		where C: $crate::cursor::Cursor, T: $crate::store::BitStore {
+			fn eq(&self, rhs: &$this) -> bool {
+				rhs == self
+			}
+		}
+
+		impl< $( $g )* > core::cmp::PartialOrd<$t> for $this
+		where C: $crate::cursor::Cursor, T: $crate::store::BitStore {
+			fn partial_cmp(&self, rhs: &$t) -> Option<core::cmp::Ordering> {
+				self.int_value(core::mem::size_of::<$t>() * 8)
+					.map(|val| val.cmp(&(*rhs as u128)))
+			}
+		}
+
+		impl< $( $g )* > core::cmp::PartialOrd<$this> for $t
+		where C: $crate::cursor::Cursor, T: $crate::store::BitStore {
+			fn partial_cmp(&self, rhs: &$this) -> Option<core::cmp::Ordering> {
+				rhs.partial_cmp(self).map(core::cmp::Ordering::reverse)
+			}
+		}
+	};
+
+	( $( $t:ty ),+ ) => { $(
+		__bitslice_int_cmp!(
+			__impl [C, T] $crate::slice::BitSlice<C, T> ; $t
+		);
+		__bitslice_int_cmp!(
+			__impl ['a, C, T] &'a $crate::slice::BitSlice<C, T> ; $t
+		);
+	)+ };
+}
+
 #[cfg(feature = "alloc")]
 #[doc(hidden)]
 macro_rules! __bitvec_shift {
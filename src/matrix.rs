@@ -0,0 +1,752 @@
+/*! `BitMatrix` structure
+
+This module holds a two-dimensional grid of bits, such as an adjacency matrix
+or an image mask. The rows are stored one after another in a single `BitVec`,
+and each row begins at the front of a storage element, so that a row is an
+ordinary `BitSlice` and operations between rows work a whole element at a time.
+Columns are strided across the rows, and are accessed one bit per row.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	clone::Clone,
+	cmp::{
+		self,
+		Eq,
+		PartialEq,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+		Write,
+	},
+	iter::{
+		DoubleEndedIterator,
+		ExactSizeIterator,
+		FusedIterator,
+		Iterator,
+	},
+	ops::Index,
+};
+
+/** A two-dimensional grid of bits, stored in row-major order.
+
+Row `r` of the matrix is the `BitSlice` at [`row`]`(r)`, and column `c` is the
+strided sequence of bits produced by [`column`]`(c)`. Every row begins at the
+front of a storage element: a row of `cols` bits occupies `cols` rounded up to
+a whole number of `T` elements, and the bits between the end of one row and the
+start of the next are always clear. The row operations, such as [`row_or`],
+therefore combine whole storage elements at a time, while the column
+operations, such as [`column_or`], touch one bit in each row.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  matrix will use to store data.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+//  A directed graph on four vertices: 0 → 1 → 2 → 3.
+let mut reach: BitMatrix = BitMatrix::new(4, 4);
+for v in 0 .. 3 {
+  reach.set(v, v + 1, true);
+}
+//  Warshall’s transitive closure, a row at a time.
+for k in 0 .. 4 {
+  for i in 0 .. 4 {
+    if reach[(i, k)] {
+      reach.row_or(i, k);
+    }
+  }
+}
+assert_eq!(reach.row(0), &bitvec![0, 1, 1, 1]);
+assert_eq!(reach.column(3).filter(|&b| b).count(), 3);
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`column`]: #method.column
+[`column_or`]: #method.column_or
+[`row`]: #method.row
+[`row_or`]: #method.row_or
+**/
+pub struct BitMatrix<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The rows of the matrix, each `stride` bits long.
+	bits: BitVec<C, T>,
+	/// The number of rows.
+	rows: usize,
+	/// The number of live bits in each row.
+	cols: usize,
+	/// The distance, in bits, from the start of one row to the start of the
+	/// next. This is `cols` rounded up to a multiple of `T::BITS`.
+	stride: usize,
+}
+
+impl<C, T> BitMatrix<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs a matrix of the given shape, with every bit clear.
+	///
+	/// # Parameters
+	///
+	/// - `rows`: The number of rows.
+	/// - `cols`: The number of bits in each row.
+	///
+	/// # Returns
+	///
+	/// A `rows` by `cols` matrix of zeros.
+	///
+	/// # Panics
+	///
+	/// Panics if the matrix is too large to store in a `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mat: BitMatrix<LittleEndian, u16> = BitMatrix::new(3, 20);
+	/// assert_eq!(mat.rows(), 3);
+	/// assert_eq!(mat.cols(), 20);
+	/// assert_eq!(mat.as_slice().len(), 6);
+	/// ```
+	pub fn new(rows: usize, cols: usize) -> Self {
+		let mask = T::MASK as usize;
+		let stride = cols.checked_add(mask)
+			.map(|n| n & !mask)
+			.expect("Matrix size overflow");
+		let len = rows.checked_mul(stride).expect("Matrix size overflow");
+		Self {
+			bits: BitVec::repeat(false, len),
+			rows,
+			cols,
+			stride,
+		}
+	}
+
+	/// Counts the rows in the matrix.
+	///
+	/// # Returns
+	///
+	/// The number of rows.
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Counts the bits in each row of the matrix.
+	///
+	/// # Returns
+	///
+	/// The number of columns.
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Reads the bit at a row and column.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `row`: The row of the bit.
+	/// - `col`: The column of the bit.
+	///
+	/// # Returns
+	///
+	/// The value of the bit.
+	///
+	/// # Panics
+	///
+	/// Panics if `row` or `col` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 3);
+	/// mat.set(1, 2, true);
+	/// assert!(mat.get(1, 2));
+	/// assert!(!mat.get(0, 2));
+	/// ```
+	pub fn get(&self, row: usize, col: usize) -> bool {
+		self.check_col(col);
+		self.row(row)[col]
+	}
+
+	/// Writes the bit at a row and column.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `row`: The row of the bit.
+	/// - `col`: The column of the bit.
+	/// - `value`: The new value of the bit.
+	///
+	/// # Panics
+	///
+	/// Panics if `row` or `col` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 3);
+	/// mat.set(0, 1, true);
+	/// assert_eq!(mat.row(0), &bitvec![0, 1, 0]);
+	/// ```
+	pub fn set(&mut self, row: usize, col: usize, value: bool) {
+		self.check_col(col);
+		self.row_mut(row).set(col, value);
+	}
+
+	/// Views a row of the matrix.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `row`: The index of the row.
+	///
+	/// # Returns
+	///
+	/// The `cols` bits of the row. The slice begins at the front of a storage
+	/// element.
+	///
+	/// # Panics
+	///
+	/// Panics if `row` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 12);
+	/// mat.set(1, 11, true);
+	/// assert_eq!(mat.row(1).len(), 12);
+	/// assert_eq!(mat.row(1).first_one(), Some(11));
+	/// assert!(mat.row(0).not_any());
+	/// ```
+	pub fn row(&self, row: usize) -> &BitSlice<C, T> {
+		let start = self.check_row(row);
+		&self.bits[start .. start + self.cols]
+	}
+
+	/// Views a row of the matrix mutably.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `row`: The index of the row.
+	///
+	/// # Returns
+	///
+	/// The `cols` bits of the row. The slice begins at the front of a storage
+	/// element.
+	///
+	/// # Panics
+	///
+	/// Panics if `row` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 4);
+	/// mat.row_mut(0).set_all(true);
+	/// assert_eq!(mat.as_slice(), &[0xF0, 0x00]);
+	/// ```
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<C, T> {
+		let start = self.check_row(row);
+		let cols = self.cols;
+		&mut self.bits[start .. start + cols]
+	}
+
+	/// Iterates over a column of the matrix, from the first row to the last.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `col`: The index of the column.
+	///
+	/// # Returns
+	///
+	/// An iterator over the bit at `col` in each row. It implements
+	/// `ExactSizeIterator` and `DoubleEndedIterator`.
+	///
+	/// # Panics
+	///
+	/// Panics if `col` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(3, 2);
+	/// mat.set(0, 1, true);
+	/// mat.set(2, 1, true);
+	/// assert_eq!(mat.column(1).collect::<Vec<_>>(), [true, false, true]);
+	/// assert_eq!(mat.column(1).rev().position(|b| b), Some(0));
+	/// ```
+	pub fn column(&self, col: usize) -> Column<C, T> {
+		self.check_col(col);
+		Column {
+			bits: &self.bits,
+			col,
+			stride: self.stride,
+			front: 0,
+			back: self.rows,
+		}
+	}
+
+	/// Produces the transpose of the matrix.
+	///
+	/// # Returns
+	///
+	/// A `cols` by `rows` matrix whose bit at `(c, r)` is the bit of `self` at
+	/// `(r, c)`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 3);
+	/// mat.set(0, 2, true);
+	/// mat.set(1, 0, true);
+	/// let t = mat.transpose();
+	/// assert_eq!((t.rows(), t.cols()), (3, 2));
+	/// assert_eq!(t.row(0), &bitvec![0, 1]);
+	/// assert_eq!(t.row(2), &bitvec![1, 0]);
+	/// assert_eq!(t.transpose(), mat);
+	/// ```
+	pub fn transpose(&self) -> Self {
+		let mut out = Self::new(self.cols, self.rows);
+		for r in 0 .. self.rows {
+			let row = self.row(r);
+			let mut at = 0;
+			while let Some(n) = row[at ..].first_one() {
+				out.set(at + n, r, true);
+				at += n + 1;
+			}
+		}
+		out
+	}
+
+	/// Sets each bit of row `dst` that is set in row `src`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `dst`: The row to modify.
+	/// - `src`: The row to combine into `dst`.
+	///
+	/// # Panics
+	///
+	/// Panics if `dst` or `src` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 3);
+	/// mat.set(0, 0, true);
+	/// mat.set(1, 2, true);
+	/// mat.row_or(0, 1);
+	/// assert_eq!(mat.row(0), &bitvec![1, 0, 1]);
+	/// ```
+	pub fn row_or(&mut self, dst: usize, src: usize) {
+		self.rows_assign(dst, src, |mut a, b| { a |= b; a });
+	}
+
+	/// Clears each bit of row `dst` that is clear in row `src`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `dst`: The row to modify.
+	/// - `src`: The row to combine into `dst`.
+	///
+	/// # Panics
+	///
+	/// Panics if `dst` or `src` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 3);
+	/// mat.row_mut(0).set_all(true);
+	/// mat.set(1, 1, true);
+	/// mat.row_and(0, 1);
+	/// assert_eq!(mat.row(0), &bitvec![0, 1, 0]);
+	/// ```
+	pub fn row_and(&mut self, dst: usize, src: usize) {
+		self.rows_assign(dst, src, |a, b| a & b);
+	}
+
+	/// Inverts each bit of row `dst` that is set in row `src`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `dst`: The row to modify.
+	/// - `src`: The row to combine into `dst`.
+	///
+	/// # Panics
+	///
+	/// Panics if `dst` or `src` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 3);
+	/// mat.row_mut(0).set_all(true);
+	/// mat.set(1, 1, true);
+	/// mat.row_xor(0, 1);
+	/// assert_eq!(mat.row(0), &bitvec![1, 0, 1]);
+	/// mat.row_xor(1, 1);
+	/// assert!(mat.row(1).not_any());
+	/// ```
+	pub fn row_xor(&mut self, dst: usize, src: usize) {
+		self.rows_assign(dst, src, |a, b| a ^ b);
+	}
+
+	/// Sets each bit of column `dst` that is set in column `src`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `dst`: The column to modify.
+	/// - `src`: The column to combine into `dst`.
+	///
+	/// # Panics
+	///
+	/// Panics if `dst` or `src` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 2);
+	/// mat.set(1, 1, true);
+	/// mat.column_or(0, 1);
+	/// assert_eq!(mat.column(0).collect::<Vec<_>>(), [false, true]);
+	/// ```
+	pub fn column_or(&mut self, dst: usize, src: usize) {
+		self.columns_assign(dst, src, |a, b| a | b);
+	}
+
+	/// Clears each bit of column `dst` that is clear in column `src`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `dst`: The column to modify.
+	/// - `src`: The column to combine into `dst`.
+	///
+	/// # Panics
+	///
+	/// Panics if `dst` or `src` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 2);
+	/// mat.set(0, 0, true);
+	/// mat.set(1, 0, true);
+	/// mat.set(1, 1, true);
+	/// mat.column_and(0, 1);
+	/// assert_eq!(mat.column(0).collect::<Vec<_>>(), [false, true]);
+	/// ```
+	pub fn column_and(&mut self, dst: usize, src: usize) {
+		self.columns_assign(dst, src, |a, b| a & b);
+	}
+
+	/// Inverts each bit of column `dst` that is set in column `src`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `dst`: The column to modify.
+	/// - `src`: The column to combine into `dst`.
+	///
+	/// # Panics
+	///
+	/// Panics if `dst` or `src` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 2);
+	/// mat.set(0, 0, true);
+	/// mat.set(0, 1, true);
+	/// mat.set(1, 1, true);
+	/// mat.column_xor(0, 1);
+	/// assert_eq!(mat.column(0).collect::<Vec<_>>(), [false, true]);
+	/// ```
+	pub fn column_xor(&mut self, dst: usize, src: usize) {
+		self.columns_assign(dst, src, |a, b| a ^ b);
+	}
+
+	/// Views the underlying storage of the matrix.
+	///
+	/// Row `r` begins at element `r * ⌈cols / T::BITS⌉`. The bits after the end
+	/// of each row, up to the start of the next, are always clear.
+	///
+	/// # Returns
+	///
+	/// The storage elements of every row, in order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 10);
+	/// mat.set(0, 0, true);
+	/// mat.set(1, 9, true);
+	/// assert_eq!(mat.as_slice(), &[0x80, 0x00, 0x00, 0x40]);
+	/// ```
+	pub fn as_slice(&self) -> &[T] {
+		self.bits.as_slice()
+	}
+
+	/// Panics if `row` is out of bounds, and otherwise finds where it starts.
+	fn check_row(&self, row: usize) -> usize {
+		assert!(
+			row < self.rows,
+			"Row index out of range: {} >= {}",
+			row,
+			self.rows,
+		);
+		row * self.stride
+	}
+
+	/// Panics if `col` is out of bounds.
+	fn check_col(&self, col: usize) {
+		assert!(
+			col < self.cols,
+			"Column index out of range: {} >= {}",
+			col,
+			self.cols,
+		);
+	}
+
+	/// Combines row `src` into row `dst` with an element-wise operator.
+	///
+	/// Both rows are taken with their dead bits, which are clear and stay
+	/// clear under every operator used here, so that the rows are made only of
+	/// whole elements.
+	fn rows_assign<F>(&mut self, dst: usize, src: usize, func: F)
+	where F: Fn(T, T) -> T {
+		let (d, s) = (self.check_row(dst), self.check_row(src));
+		let stride = self.stride;
+		if d == s {
+			let row = &mut self.bits[d .. d + stride];
+			let copy = BitVec::<C, T>::from_bitslice(row);
+			row.zip_assign(&copy, func);
+			return;
+		}
+		let (lo, hi) = self.bits.split_at_mut(cmp::max(d, s));
+		let low = &mut lo[cmp::min(d, s) ..][.. stride];
+		let high = &mut hi[.. stride];
+		if d < s {
+			low.zip_assign(high, func);
+		}
+		else {
+			high.zip_assign(low, func);
+		}
+	}
+
+	/// Combines column `src` into column `dst` with a bitwise operator.
+	fn columns_assign<F>(&mut self, dst: usize, src: usize, func: F)
+	where F: Fn(bool, bool) -> bool {
+		self.check_col(dst);
+		self.check_col(src);
+		for r in 0 .. self.rows {
+			let row = &mut self.bits[r * self.stride ..];
+			let value = func(row[dst], row[src]);
+			row.set(dst, value);
+		}
+	}
+}
+
+impl<C, T> Clone for BitMatrix<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			rows: self.rows,
+			cols: self.cols,
+			stride: self.stride,
+		}
+	}
+}
+
+impl<C, T> Eq for BitMatrix<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> PartialEq for BitMatrix<C, T>
+where C: Cursor, T: BitStore {
+	/// Tests if two matrices have the same shape and the same bits.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.rows == rhs.rows && self.cols == rhs.cols && self.bits == rhs.bits
+	}
+}
+
+impl<C, T> Debug for BitMatrix<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitMatrix` type header and shape, then each row in turn.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix<LittleEndian, u16> = BitMatrix::new(2, 3);
+	/// mat.set(0, 1, true);
+	/// mat.set(1, 2, true);
+	/// assert_eq!(
+	///   "BitMatrix<LittleEndian, u16> 2x3 [010, 001]",
+	///   &format!("{:?}", mat),
+	/// );
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitMatrix<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		write!(f, "> {}x{} [", self.rows, self.cols)?;
+		for r in 0 .. self.rows {
+			if r > 0 {
+				f.write_str(", ")?;
+			}
+			for bit in self.row(r) {
+				f.write_char(if bit { '1' } else { '0' })?;
+			}
+		}
+		f.write_char(']')
+	}
+}
+
+/// Reads the bit at a `(row, col)` pair.
+impl<C, T> Index<(usize, usize)> for BitMatrix<C, T>
+where C: Cursor, T: BitStore {
+	type Output = bool;
+
+	/// Looks up a single bit by its row and column.
+	///
+	/// # Panics
+	///
+	/// Panics if the row or column is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut mat: BitMatrix = BitMatrix::new(2, 2);
+	/// mat.set(1, 0, true);
+	/// assert!(mat[(1, 0)]);
+	/// assert!(!mat[(0, 1)]);
+	/// ```
+	fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+		if self.get(row, col) { &true } else { &false }
+	}
+}
+
+/** An iterator over one column of a `BitMatrix`.
+
+This produces the bit at a fixed column in each row, from the first row to the
+last. Each step reads one bit, `stride` bits after the previous one.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying matrix.
+- `T: 'a + BitStore`: The storage type of the underlying matrix.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying matrix.
+**/
+#[derive(Clone, Debug)]
+pub struct Column<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The matrix storage.
+	bits: &'a BitSlice<C, T>,
+	/// The index of the column within each row.
+	col: usize,
+	/// The distance between the column bits of adjacent rows.
+	stride: usize,
+	/// The next row to produce from the front.
+	front: usize,
+	/// The row after the next to produce from the back.
+	back: usize,
+}
+
+impl<'a, C, T> DoubleEndedIterator for Column<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.bits[self.back * self.stride + self.col])
+	}
+}
+
+impl<'a, C, T> ExactSizeIterator for Column<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> FusedIterator for Column<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Column<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		let bit = self.bits[self.front * self.stride + self.col];
+		self.front += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
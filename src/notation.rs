@@ -0,0 +1,382 @@
+/*! Configurable text notations for bit sequences
+
+The `Display` rendering of a `BitSlice` lists the bits of each storage element
+in binary, which suits debugging but not the compact notations that protocols
+and logs use, such as nibble-grouped hexadecimal with a bit count appended
+(`A3F/12`). This module describes such notations as [`Notation`] values, which
+can render any `BitSlice` without allocating and, with an allocator, parse the
+same text back into a `BitVec`.
+
+# Digits
+
+A notation reads the bits in semantic order, and writes each run of one, three,
+or four bits as one binary, octal, or hexadecimal digit. The first bit of a run
+is the most significant bit of its digit. When the bit count is not a multiple
+of the digit width, the last digit is padded with zeros after the final bit;
+a length suffix records the true count, so that the padding is removed again
+when the text is parsed.
+
+The `FromStr` trait cannot carry a configuration, so text is parsed with
+[`Notation::parse`] rather than `str::parse`.
+
+[`Notation`]: struct.Notation.html
+[`Notation::parse`]: struct.Notation.html#method.parse
+!*/
+
+use crate::{
+	cursor::Cursor,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+		Write,
+	},
+};
+
+/// The digit systems that a `Notation` can write.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Radix {
+	/// One bit per digit, `0` and `1`.
+	Binary,
+	/// Three bits per digit, `0` through `7`.
+	Octal,
+	/// Four bits per digit, `0` through `9` and `A` through `F`.
+	Hex,
+}
+
+impl Radix {
+	/// Counts the bits in each digit.
+	fn width(self) -> usize {
+		match self {
+			Radix::Binary => 1,
+			Radix::Octal => 3,
+			Radix::Hex => 4,
+		}
+	}
+}
+
+/** A description of how to write bits as text.
+
+A `Notation` chooses a [`Radix`], how many digits to gather into each group and
+the character between groups, the case of hexadecimal digits, and whether to
+append the bit count after a marker character. It is built from a radix, and
+adjusted with the chaining methods below.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::notation::{Notation, Radix};
+
+let log = Notation::new(Radix::Hex).uppercase().with_length('/');
+let bits = bitvec![1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1];
+assert_eq!(log.render(&bits).to_string(), "A3F/12");
+assert_eq!(log.parse::<BigEndian, u8>("A3F/12").unwrap(), bits);
+
+let dotted = Notation::new(Radix::Hex).grouped(2, '.');
+assert_eq!(dotted.render(&bitvec![1; 20]).to_string(), "ff.ff.f");
+```
+
+[`Radix`]: enum.Radix.html
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Notation {
+	/// The digit system.
+	radix: Radix,
+	/// The number of digits in each group, or zero for no grouping.
+	group: usize,
+	/// The character written between groups.
+	separator: char,
+	/// Whether hexadecimal digits above nine are capitals.
+	uppercase: bool,
+	/// The character written before the bit count, if the count is written.
+	length: Option<char>,
+}
+
+impl Notation {
+	/// Describes an ungrouped notation in a radix, with lowercase digits and no
+	/// bit count.
+	///
+	/// # Parameters
+	///
+	/// - `radix`: The digit system to use.
+	///
+	/// # Returns
+	///
+	/// The simplest notation in `radix`.
+	pub fn new(radix: Radix) -> Self {
+		Self {
+			radix,
+			group: 0,
+			separator: ' ',
+			uppercase: false,
+			length: None,
+		}
+	}
+
+	/// Gathers the digits into groups, counted from the first digit.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `size`: The number of digits in each group. Zero turns grouping off.
+	/// - `separator`: The character written between groups. It must not be a
+	///   digit of the radix, nor the length marker.
+	///
+	/// # Returns
+	///
+	/// The notation, with grouping.
+	pub fn grouped(mut self, size: usize, separator: char) -> Self {
+		self.group = size;
+		self.separator = separator;
+		self
+	}
+
+	/// Writes hexadecimal digits above nine as capitals.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The notation, with uppercase digits.
+	pub fn uppercase(mut self) -> Self {
+		self.uppercase = true;
+		self
+	}
+
+	/// Appends the bit count, in decimal, after a marker character.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `marker`: The character written between the digits and the count.
+	///   It must not be a digit of the radix, nor the group separator.
+	///
+	/// # Returns
+	///
+	/// The notation, with the bit count.
+	pub fn with_length(mut self, marker: char) -> Self {
+		self.length = Some(marker);
+		self
+	}
+
+	/// Prepares a bit slice for rendering in this notation.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `bits`: The bits to render.
+	///
+	/// # Returns
+	///
+	/// A value whose `Display` implementation writes `bits` in this notation,
+	/// without allocating.
+	pub fn render<C, T>(self, bits: &BitSlice<C, T>) -> Rendered<C, T>
+	where C: Cursor, T: BitStore {
+		Rendered { notation: self, bits }
+	}
+
+	/// Writes a bit slice in this notation into a text sink.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `bits`: The bits to write.
+	/// - `sink`: The destination of the text.
+	///
+	/// # Returns
+	///
+	/// The result of the writes into `sink`.
+	pub fn write_to<C, T, W>(self, bits: &BitSlice<C, T>, sink: &mut W)
+	-> fmt::Result
+	where C: Cursor, T: BitStore, W: Write + ?Sized {
+		let width = self.radix.width();
+		for (n, chunk) in bits.chunks(width).enumerate() {
+			if self.group != 0 && n != 0 && n % self.group == 0 {
+				sink.write_char(self.separator)?;
+			}
+			let value = chunk
+				.iter()
+				.fold(0, |acc, bit| (acc << 1) | bit as u32)
+				<< (width - chunk.len());
+			let digit = core::char::from_digit(value, 16)
+				.expect("a digit is narrower than four bits");
+			sink.write_char(if self.uppercase {
+				digit.to_ascii_uppercase()
+			}
+			else {
+				digit
+			})?;
+		}
+		if let Some(marker) = self.length {
+			write!(sink, "{}{}", marker, bits.len())?;
+		}
+		Ok(())
+	}
+
+	/// Parses text written in this notation.
+	///
+	/// Group separators are skipped wherever they appear, and hexadecimal
+	/// digits are accepted in either case. Without a length suffix, every bit
+	/// of every digit is kept, including any padding.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `text`: The text to parse.
+	///
+	/// # Returns
+	///
+	/// The bits that `text` describes, or the first problem found in it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::notation::{Notation, ParseError, Radix};
+	///
+	/// let n = Notation::new(Radix::Octal).grouped(3, '_').with_length('/');
+	/// let bits = n.parse::<LittleEndian, u16>("755_4/10").unwrap();
+	/// assert_eq!(bits, bitvec![1, 1, 1, 1, 0, 1, 1, 0, 1, 1]);
+	///
+	/// assert_eq!(
+	///   n.parse::<LittleEndian, u16>("758/9"),
+	///   Err(ParseError::Digit { index: 2, found: '8' }),
+	/// );
+	/// assert_eq!(
+	///   n.parse::<LittleEndian, u16>("7/2"),
+	///   Err(ParseError::Padding),
+	/// );
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn parse<C, T>(self, text: &str) -> Result<BitVec<C, T>, ParseError>
+	where C: Cursor, T: BitStore {
+		let width = self.radix.width();
+		let (digits, len) = match self.length {
+			Some(marker) => match text.rfind(marker) {
+				Some(at) => {
+					let count = &text[at + marker.len_utf8() ..];
+					let len = count.parse::<usize>()
+						.map_err(|_| ParseError::Length)?;
+					(&text[.. at], Some(len))
+				},
+				None => return Err(ParseError::Length),
+			},
+			None => (text, None),
+		};
+		let mut out = BitVec::with_capacity(digits.len() * width);
+		for (index, ch) in digits.char_indices() {
+			if self.group != 0 && ch == self.separator {
+				continue;
+			}
+			let value = ch.to_digit(1 << width)
+				.ok_or(ParseError::Digit { index, found: ch })?;
+			for shift in (0 .. width).rev() {
+				out.push((value >> shift) & 1 == 1);
+			}
+		}
+		if let Some(len) = len {
+			let digits = out.len() / width;
+			if digits != (len + width - 1) / width {
+				return Err(ParseError::DigitCount {
+					expected: (len + width - 1) / width,
+					found: digits,
+				});
+			}
+			if out[len ..].any() {
+				return Err(ParseError::Padding);
+			}
+			out.truncate(len);
+		}
+		Ok(out)
+	}
+}
+
+impl Default for Notation {
+	/// Plain binary digits, as written by `BitStyle::Plain`.
+	fn default() -> Self {
+		Self::new(Radix::Binary)
+	}
+}
+
+/** A bit slice, paired with the notation in which to display it.
+
+This is produced by [`Notation::render`], and writes nothing until it is
+formatted.
+
+[`Notation::render`]: struct.Notation.html#method.render
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct Rendered<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	notation: Notation,
+	bits: &'a BitSlice<C, T>,
+}
+
+impl<'a, C, T> Display for Rendered<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.notation.write_to(self.bits, f)
+	}
+}
+
+/** The ways in which text can fail to parse in a `Notation`.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseError {
+	/// A character that is neither a digit of the radix nor a separator.
+	Digit {
+		/// The byte index of the character in the text.
+		index: usize,
+		/// The character.
+		found: char,
+	},
+	/// The length marker, or the decimal count after it, is missing or
+	/// malformed.
+	Length,
+	/// The number of digits does not match the bit count.
+	DigitCount {
+		/// The number of digits that the bit count requires.
+		expected: usize,
+		/// The number of digits in the text.
+		found: usize,
+	},
+	/// The last digit has set bits past the bit count.
+	Padding,
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			ParseError::Digit { index, found } => write!(
+				f,
+				"Invalid digit {:?} at index {}",
+				found,
+				index,
+			),
+			ParseError::Length => f.write_str("Missing or invalid bit count"),
+			ParseError::DigitCount { expected, found } => write!(
+				f,
+				"Bit count requires {} digits, but {} were provided",
+				expected,
+				found,
+			),
+			ParseError::Padding => {
+				f.write_str("Padding bits after the bit count are set")
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
@@ -0,0 +1,492 @@
+/*! Vectors of fixed-width integers
+
+This module stores a sequence of unsigned integers that all have the same
+number of bits, chosen when the vector is made, packed end to end in a
+`BitVec`. A vector of `n` values of width `k` occupies `n * k` bits, no matter
+how wide the integer type used to read and write them is, so columns of small
+codes, dictionary indices, and the samples of succinct structures cost only the
+bits that they need.
+
+Each value is read and written as one field through [`BitField::extract`] and
+[`BitField::insert_field`], in the significance order of the vector’s cursor.
+
+[`BitField::extract`]: ../fields/trait.BitField.html#tymethod.extract
+[`BitField::insert_field`]: ../fields/trait.BitField.html#tymethod.insert_field
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	fields::BitField,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	clone::Clone,
+	cmp::{
+		Eq,
+		PartialEq,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		DoubleEndedIterator,
+		ExactSizeIterator,
+		Extend,
+		FusedIterator,
+		IntoIterator,
+		Iterator,
+	},
+};
+
+/** A vector of unsigned integers of a fixed bit width.
+
+Every value in the vector has `width` bits, where `width` is between 1 and 64
+and is chosen when the vector is made. Values are read as `u64`, and a value
+that does not fit in `width` bits is rejected when it is written, rather than
+truncated.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  vector will use to store data.
+
+# Examples
+
+```rust
+use bitvec::packed::PackedIntVec;
+
+let mut pv: PackedIntVec = PackedIntVec::new(5);
+pv.push(3);
+pv.push(31);
+pv.push(17);
+assert_eq!(pv.len(), 3);
+assert_eq!(pv.as_bitslice().len(), 15);
+assert_eq!(pv.get(1), Some(31));
+pv.set(1, 8);
+assert_eq!(pv.iter().collect::<Vec<_>>(), [3, 8, 17]);
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+**/
+pub struct PackedIntVec<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The values, each `width` bits long, end to end.
+	bits: BitVec<C, T>,
+	/// The number of bits in each value.
+	width: u8,
+}
+
+impl<C, T> PackedIntVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs a new, empty, vector of values of a fixed width.
+	///
+	/// # Parameters
+	///
+	/// - `width`: The number of bits in each value.
+	///
+	/// # Returns
+	///
+	/// An empty vector, which does not allocate.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or more than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let pv: PackedIntVec = PackedIntVec::new(12);
+	/// assert_eq!(pv.width(), 12);
+	/// assert!(pv.is_empty());
+	/// ```
+	pub fn new(width: u8) -> Self {
+		Self::with_capacity(width, 0)
+	}
+
+	/// Constructs a new, empty, vector with room for some number of values.
+	///
+	/// # Parameters
+	///
+	/// - `width`: The number of bits in each value.
+	/// - `capacity`: The number of values that the vector can hold without
+	///   reallocating.
+	///
+	/// # Returns
+	///
+	/// An empty vector, with storage for at least `capacity` values.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or more than 64, or if the vector cannot
+	/// address `capacity` values.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let pv: PackedIntVec = PackedIntVec::with_capacity(3, 100);
+	/// assert!(pv.capacity() >= 100);
+	/// ```
+	pub fn with_capacity(width: u8, capacity: usize) -> Self {
+		assert!(
+			width > 0 && width <= 64,
+			"Packed integers must be between 1 and 64 bits wide, not {}",
+			width,
+		);
+		let bits = capacity
+			.checked_mul(width as usize)
+			.expect("Packed vector capacity overflow");
+		Self {
+			bits: BitVec::with_capacity(bits),
+			width,
+		}
+	}
+
+	/// Reads the width of each value.
+	///
+	/// # Returns
+	///
+	/// The number of bits in each value.
+	pub fn width(&self) -> u8 {
+		self.width
+	}
+
+	/// Counts the values in the vector.
+	///
+	/// # Returns
+	///
+	/// The number of values.
+	pub fn len(&self) -> usize {
+		self.bits.len() / self.width as usize
+	}
+
+	/// Tests if the vector is empty.
+	///
+	/// # Returns
+	///
+	/// Whether the vector has no values.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Counts the values that the vector can hold without reallocating.
+	///
+	/// # Returns
+	///
+	/// The number of values that fit in the allocated storage.
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity() / self.width as usize
+	}
+
+	/// Reads a value.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The position of a value in the vector.
+	///
+	/// # Returns
+	///
+	/// The value at `index`, or `None` if `index` is not less than
+	/// `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut pv: PackedIntVec = PackedIntVec::new(3);
+	/// pv.extend(vec![1, 7, 4]);
+	/// assert_eq!(pv.get(2), Some(4));
+	/// assert_eq!(pv.get(3), None);
+	/// ```
+	pub fn get(&self, index: usize) -> Option<u64> {
+		if index >= self.len() {
+			return None;
+		}
+		let width = self.width as usize;
+		Some(self.bits.extract(index * width, width))
+	}
+
+	/// Writes a value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The position of a value in the vector.
+	/// - `value`: The new value at `index`.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is not less than `self.len()`, or if `value` does not
+	/// fit in the width of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut pv: PackedIntVec = PackedIntVec::new(4);
+	/// pv.extend(vec![0, 0, 0]);
+	/// pv.set(1, 15);
+	/// assert_eq!(pv.get(1), Some(15));
+	/// assert_eq!(pv.as_bitslice().as_slice(), &[0x0F, 0x00]);
+	/// ```
+	pub fn set(&mut self, index: usize, value: u64) {
+		let len = self.len();
+		assert!(
+			index < len,
+			"Index {} out of range: {}",
+			index,
+			len,
+		);
+		self.check(value);
+		let width = self.width as usize;
+		self.bits.insert_field(index * width, width, value);
+	}
+
+	/// Appends a value to the back of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to append.
+	///
+	/// # Panics
+	///
+	/// Panics if `value` does not fit in the width of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut pv: PackedIntVec = PackedIntVec::new(10);
+	/// pv.push(1000);
+	/// assert_eq!(pv.get(0), Some(1000));
+	/// ```
+	pub fn push(&mut self, value: u64) {
+		self.check(value);
+		let start = self.bits.len();
+		let width = self.width as usize;
+		self.bits.resize(start + width, false);
+		self.bits.insert_field(start, width, value);
+	}
+
+	/// Removes the last value from the vector.
+	///
+	/// # Returns
+	///
+	/// The last value, or `None` if the vector is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut pv: PackedIntVec = PackedIntVec::new(2);
+	/// pv.extend(vec![1, 2]);
+	/// assert_eq!(pv.pop(), Some(2));
+	/// assert_eq!(pv.pop(), Some(1));
+	/// assert_eq!(pv.pop(), None);
+	/// ```
+	pub fn pop(&mut self) -> Option<u64> {
+		let value = self.get(self.len().checked_sub(1)?)?;
+		let len = self.bits.len() - self.width as usize;
+		self.bits.truncate(len);
+		Some(value)
+	}
+
+	/// Shortens the vector to a number of values.
+	///
+	/// This has no effect if `len` is not less than `self.len()`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The number of values to keep.
+	pub fn truncate(&mut self, len: usize) {
+		if len < self.len() {
+			self.bits.truncate(len * self.width as usize);
+		}
+	}
+
+	/// Removes all values from the vector.
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Iterates over the values in the vector, in order.
+	///
+	/// # Returns
+	///
+	/// An iterator over the values.
+	pub fn iter(&self) -> Iter<C, T> {
+		Iter {
+			pv: self,
+			front: 0,
+			back: self.len(),
+		}
+	}
+
+	/// Views the packed values as bits.
+	///
+	/// # Returns
+	///
+	/// The bits of every value, end to end, `self.width()` bits per value.
+	pub fn as_bitslice(&self) -> &BitSlice<C, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Removes the packed values from the vector.
+	///
+	/// # Returns
+	///
+	/// The bits of every value, end to end, `self.width()` bits per value.
+	pub fn into_bitvec(self) -> BitVec<C, T> {
+		self.bits
+	}
+
+	/// Panics if a value does not fit in the width of the vector.
+	fn check(&self, value: u64) {
+		assert!(
+			value.checked_shr(u32::from(self.width)).unwrap_or(0) == 0,
+			"Value {:#x} does not fit in {} bits",
+			value,
+			self.width,
+		);
+	}
+}
+
+impl<C, T> Clone for PackedIntVec<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			width: self.width,
+		}
+	}
+}
+
+impl<C, T> Eq for PackedIntVec<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> PartialEq for PackedIntVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Tests if two vectors hold the same values at the same width.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.width == rhs.width && self.bits == rhs.bits
+	}
+}
+
+impl<C, T> Debug for PackedIntVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the values of the vector, as a list.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut pv: PackedIntVec = PackedIntVec::new(6);
+	/// pv.extend(vec![40, 2]);
+	/// assert_eq!(format!("{:?}", pv), "[40, 2]");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<C, T> Extend<u64> for PackedIntVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Appends each value of an iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if a value does not fit in the width of the vector.
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item = u64> {
+		let iter = src.into_iter();
+		let (lower, _) = iter.size_hint();
+		self.bits.reserve(lower.saturating_mul(self.width as usize));
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<'a, C, T> IntoIterator for &'a PackedIntVec<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = u64;
+	type IntoIter = Iter<'a, C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** An iterator over the values of a `PackedIntVec`.
+**/
+#[derive(Clone, Debug)]
+pub struct Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The vector being read.
+	pv: &'a PackedIntVec<C, T>,
+	/// The position of the next value to produce from the front.
+	front: usize,
+	/// The position after the next value to produce from the back.
+	back: usize,
+}
+
+impl<'a, C, T> DoubleEndedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		self.pv.get(self.back)
+	}
+}
+
+impl<'a, C, T> ExactSizeIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> FusedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = u64;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		self.pv.get(self.front - 1)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
@@ -0,0 +1,265 @@
+/*! `rayon`-powered parallel reductions
+
+This module provides data-parallel versions of the `BitSlice` reductions. Each
+one splits the slice at its element boundaries: the partial elements at either
+edge are handled on the calling thread, and the fully-live elements in the
+interior are distributed across the `rayon` thread pool a whole element at a
+time.
+
+It also provides [`par_apply`], which distributes mutable work over chunks of a
+slice. The chunks are cut at element boundaries, so that no two tasks write into
+the same element.
+
+Parallelism requires that `BitSlice` be `Sync`, so these reductions are only
+available when the `atomic` feature is enabled in addition to `rayon` and `std`.
+
+[`par_apply`]: ../slice/struct.BitSlice.html#method.par_apply
+!*/
+
+#![cfg(all(feature = "rayon", feature = "std", feature = "atomic"))]
+
+use crate::{
+	cursor::Cursor,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::cmp;
+
+use rayon::prelude::*;
+
+use alloc::vec::Vec;
+
+/// The number of bits each parallel task handles when a reduction cannot be
+/// performed over whole elements.
+const PAR_CHUNK: usize = 1 << 16;
+
+impl<C, T> BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Counts how many bits are set high, in parallel.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of high bits in the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![1; 100_000];
+	/// assert_eq!(bv[3 ..].par_count_ones(), 99_997);
+	/// ```
+	pub fn par_count_ones(&self) -> usize {
+		let (head, body, tail) = self.split_edges();
+		head.count_ones()
+			+ body.par_iter().map(BitStore::count_ones).sum::<usize>()
+			+ tail.count_ones()
+	}
+
+	/// Counts how many bits are set low, in parallel.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of low bits in the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0; 100_000];
+	/// assert_eq!(bv[.. 99_995].par_count_zeros(), 99_995);
+	/// ```
+	pub fn par_count_zeros(&self) -> usize {
+		self.len() - self.par_count_ones()
+	}
+
+	/// Tests if any bit in the slice is set, in parallel.
+	///
+	/// This stops as soon as any task finds a set bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether any bit in the slice domain is set. The empty slice returns
+	/// `false`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0; 100_000];
+	/// assert!(!bv.par_any());
+	/// bv.set(54_321, true);
+	/// assert!(bv.par_any());
+	/// ```
+	pub fn par_any(&self) -> bool {
+		let (head, body, tail) = self.split_edges();
+		let zero = T::bits(false);
+		head.any() || tail.any() || body.par_iter().any(|&elt| elt != zero)
+	}
+
+	/// Tests if all bits in the slice are set, in parallel.
+	///
+	/// This stops as soon as any task finds a cleared bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether all bits in the slice domain are set. The empty slice returns
+	/// `true`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1; 100_000];
+	/// assert!(bv.par_all());
+	/// bv.set(12_345, false);
+	/// assert!(!bv.par_all());
+	/// ```
+	pub fn par_all(&self) -> bool {
+		let (head, body, tail) = self.split_edges();
+		let ones = T::bits(true);
+		head.all() && tail.all() && body.par_iter().all(|&elt| elt == ones)
+	}
+
+	/// Counts the positions at which two slices differ, in parallel.
+	///
+	/// When both slices begin at the same bit in their first element, their
+	/// interiors are compared a whole element at a time. Otherwise, the slices
+	/// are compared bit by bit, in parallel runs of bits.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The slice against which to compare `self`.
+	///
+	/// # Returns
+	///
+	/// The Hamming distance between `self` and `other`.
+	///
+	/// # Panics
+	///
+	/// This panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![0; 100_000];
+	/// let mut b = bitvec![0; 100_000];
+	/// b[10 .. 20].set_all(true);
+	/// assert_eq!(a.par_hamming_distance(&b), 10);
+	/// assert_eq!(a[1 ..].par_hamming_distance(&b[.. 99_999]), 10);
+	/// ```
+	pub fn par_hamming_distance(&self, other: &Self) -> usize {
+		let len = self.len();
+		assert_eq!(
+			len,
+			other.len(),
+			"Hamming distance requires slices of equal length",
+		);
+		if self.bitptr().head() == other.bitptr().head() {
+			//  Equal heads and lengths imply equal edges, and so bodies of
+			//  equal length.
+			let (lhead, lbody, ltail) = self.split_edges();
+			let (rhead, rbody, rtail) = other.split_edges();
+			return lhead.iter().zip(rhead.iter()).filter(|(l, r)| l != r).count()
+				+ lbody.par_iter()
+					.zip(rbody.par_iter())
+					.map(|(&l, &r)| (l & !r).count_ones() + (!l & r).count_ones())
+					.sum::<usize>()
+				+ ltail.iter().zip(rtail.iter()).filter(|(l, r)| l != r).count();
+		}
+		let chunks = (len + PAR_CHUNK - 1) / PAR_CHUNK;
+		(0 .. chunks).into_par_iter()
+			.map(|n| {
+				let start = n * PAR_CHUNK;
+				let end = cmp::min(start + PAR_CHUNK, len);
+				self[start .. end].iter()
+					.zip(other[start .. end].iter())
+					.filter(|(l, r)| l != r)
+					.count()
+			})
+			.sum()
+	}
+
+	/// Runs a function over chunks of the slice, in parallel.
+	///
+	/// The slice is divided into chunks of `width` bits, rounded up to a whole
+	/// number of `T` elements. Chunk boundaries fall on element boundaries in
+	/// memory, so the first chunk is shorter than the rest when the slice
+	/// begins partway through an element, and the last chunk holds whatever
+	/// remains. No two chunks share an element; the elements at the edges of
+	/// the slice, which may be shared with other handles, are written through
+	/// the atomic access path.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The minimum number of bits in each chunk. Each chunk is one
+	///   task for the `rayon` thread pool, so this should be large enough to
+	///   outweigh the cost of scheduling.
+	/// - `func`: A function which receives the index in `self` at which a chunk
+	///   begins, and the chunk itself.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0; 100_000];
+	/// bv[3 ..].par_apply(4096, |start, chunk| {
+	///   for (n, mut bit) in chunk.iter_mut().enumerate() {
+	///     *bit = (start + n) % 3 == 0;
+	///   }
+	/// });
+	/// assert!(!bv[.. 3].any());
+	/// assert!(bv[3 ..].iter().enumerate().all(|(n, b)| b == (n % 3 == 0)));
+	/// ```
+	pub fn par_apply<F>(&mut self, width: usize, func: F)
+	where F: Fn(usize, &mut Self) + Sync + Send {
+		assert_ne!(width, 0, "Chunk width cannot be zero");
+		let mask = T::MASK as usize;
+		let width = width.checked_add(mask)
+			.expect("Chunk width overflowed")
+			& !mask;
+		let head = *self.bitptr().head() as usize;
+		let len = self.len();
+		//  Run the first chunk up to an element boundary, after which every
+		//  chunk is element-aligned.
+		let (first, rest) = self.split_at_mut(cmp::min(width - head, len));
+		let skip = first.len();
+		let mut chunks = Vec::with_capacity(1 + rest.len() / width);
+		if !first.is_empty() {
+			chunks.push((0, first));
+		}
+		chunks.extend(rest.chunks_mut(width)
+			.enumerate()
+			.map(|(n, chunk)| (skip + n * width, chunk)));
+		chunks.into_par_iter().for_each(|(start, chunk)| func(start, chunk));
+	}
+}
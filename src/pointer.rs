@@ -344,7 +344,7 @@ where T: BitStore {
 	pub fn empty() -> Self {
 		Self {
 			_ty: PhantomData,
-			ptr: NonNull::dangling(),
+			ptr: NonNull::<T>::dangling().cast(),
 			len: 0,
 		}
 	}
@@ -383,7 +383,7 @@ where T: BitStore {
 		Self {
 			_ty: PhantomData,
 			ptr: NonNull::new(ptr.w() as *mut u8)
-				.unwrap_or_else(NonNull::dangling),
+				.unwrap_or_else(|| NonNull::<T>::dangling().cast()),
 			len: 0,
 		}
 	}
@@ -693,6 +693,21 @@ where T: BitStore {
 		self.len | Self::LEN_HEAD_MASK == !0
 	}
 
+	/// Checks if the pointer uses every bit of every element it covers.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the slice begins at the front of its first element and ends at
+	/// the back of its last. The empty slice trivially does.
+	#[inline]
+	pub fn is_whole(&self) -> bool {
+		*self.head() == 0 && self.len() & T::MASK as usize == 0
+	}
+
 	/// Accesses the element slice behind the pointer as a Rust slice.
 	///
 	/// # Parameters
@@ -905,7 +920,7 @@ where T: BitStore {
 		let src = unsafe { &*(bs as *const BitSlice<C, T> as *const [()]) };
 		let ptr = Pointer::from(src.as_ptr() as *const u8);
 		let (ptr, len) = match (ptr.w(), src.len()) {
-			(_, 0) => (NonNull::dangling(), 0),
+			(_, 0) => (NonNull::<T>::dangling().cast(), 0),
 			(p, _) if p.is_null() => unreachable!("Rust forbids null refs"),
 			(p, l) => (unsafe { NonNull::new_unchecked(p) }, l),
 		};
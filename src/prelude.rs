@@ -5,6 +5,8 @@ This collects the general public API into a single spot for inclusion, as
 !*/
 
 pub use crate::{
+	array::BitArray,
+	array_vec::BitArrayVec,
 	bits::{
 		Bits,
 		BitsMut,
@@ -13,15 +15,100 @@ pub use crate::{
 		Cursor,
 		BigEndian,
 		LittleEndian,
+		Lsb0,
+		Msb0,
+		NetworkOrder,
 	},
+	fields::BitField,
+	reader::BitReader,
 	slice::BitSlice,
+	span::BitSpan,
 	store::BitStore,
 };
 
 #[cfg(feature = "alloc")]
 pub use crate::{
+	arena::BitArena,
 	bitbox,
 	bitvec,
 	boxed::BitBox,
-	vec::BitVec,
+	deque::BitDeque,
+	matrix::BitMatrix,
+	set::BitSet,
+	vec::{
+		BitConcat,
+		BitVec,
+	},
 };
+
+/** A prelude which does not collide with other crates’ names.
+
+The main prelude exports `Cursor`, `BigEndian`, and `LittleEndian` under their
+own names, which clash with `byteorder` and with other crates that have a
+“cursor” concept when both are glob-imported. This module exports the same API
+with every name prefixed, and with the two traits that provide methods on
+fundamental types named as extension traits:
+
+| Main prelude   | `prelude::v1`  |
+| -------------- | -------------- |
+| `BigEndian`    | `BitMsb0`      |
+| `LittleEndian` | `BitLsb0`      |
+| `Cursor`       | `BitCursor`    |
+| `Bits`         | `BitsExt`      |
+| `BitsMut`      | `BitsMutExt`   |
+
+All other names are already prefixed with `Bit`, and are exported unchanged.
+These are re-exports rather than new types, so values and trait
+implementations interoperate freely with code that uses the main prelude.
+
+The `bitvec!` and `bitbox!` macros accept any path to a cursor type, so the
+renamed cursors can be used in them directly.
+
+# Examples
+
+```rust
+use bitvec::prelude::v1::*;
+
+let mut bv = bitvec![BitLsb0, u8; 0; 10];
+bv.set(1, true);
+assert_eq!(bv.as_slice()[0], 2);
+
+let bits: &BitSlice<BitMsb0, u8> = 0x80u8.as_bitslice::<BitMsb0>();
+assert!(bits[0]);
+```
+**/
+pub mod v1 {
+	pub use crate::{
+		array::BitArray,
+		array_vec::BitArrayVec,
+		bits::{
+			Bits as BitsExt,
+			BitsMut as BitsMutExt,
+		},
+		cursor::{
+			BigEndian as BitMsb0,
+			Cursor as BitCursor,
+			LittleEndian as BitLsb0,
+		},
+		fields::BitField,
+		reader::BitReader,
+		slice::BitSlice,
+		span::BitSpan,
+		store::BitStore,
+	};
+
+	#[cfg(feature = "alloc")]
+	pub use crate::{
+		arena::BitArena,
+		bitbox,
+		bitvec,
+		boxed::BitBox,
+		deque::BitDeque,
+		matrix::BitMatrix,
+		set::BitSet,
+		vec::{
+			BitConcat,
+			BitVec,
+		},
+	};
+}
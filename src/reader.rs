@@ -0,0 +1,566 @@
+/*! `BitReader` structure
+
+This module holds a cursor that consumes a bit slice from front to back, as the
+decoders of compressed formats and wire protocols do. It reads single bits,
+subslices, and integers of up to 64 bits in either significance order, looks
+ahead without consuming, skips to alignment boundaries, and counts unary runs.
+
+Decoders of prefix codes usually look ahead by the length of the longest code,
+even when the final code in a stream is shorter than that. The [`Tail`] policy
+of a reader decides whether such a request near the end of the slice fails, or
+reads the missing bits as zeros.
+
+[`Tail`]: enum.Tail.html
+!*/
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+};
+
+/// The most bits that one integer read can request.
+const MAX_READ: usize = 64;
+
+/** The ways a `BitReader` can answer a request for more bits than remain.
+
+This applies to the integer reads and peeks, to [`read_bit`], and to the skips.
+Subslice reads can never be padded, and always require the full length.
+
+[`read_bit`]: struct.BitReader.html#method.read_bit
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Tail {
+	/// The request fails, and consumes nothing.
+	Strict,
+	/// The missing bits are read as zeros. A read consumes the bits that do
+	/// remain, and records the rest in [`overrun`].
+	///
+	/// [`overrun`]: struct.BitReader.html#method.overrun
+	Zeros,
+}
+
+impl Default for Tail {
+	fn default() -> Self {
+		Tail::Strict
+	}
+}
+
+/** A cursor that reads a bit slice from front to back.
+
+The reader borrows its slice, and every subslice it produces borrows the same
+memory, so no bit is copied until it is read into an integer.
+
+Integers are read in one of two significance orders, independent of the cursor
+of the slice:
+
+- the `_be` methods make the first bit read the most significant bit of the
+  value, as prefix codes are written;
+- the `_le` methods make the first bit read the least significant bit of the
+  value, as the fields of DEFLATE and of many serial protocols are written.
+
+# Type Parameters
+
+- `C`: The cursor type of the slice being read.
+- `T`: The storage type of the slice being read.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let data = [0b1010_0011u8, 0b1111_0000];
+let mut r = BitReader::new(data.as_bitslice::<BigEndian>());
+
+assert_eq!(r.read_be(4), Some(0b1010));
+assert_eq!(r.read_le(4), Some(0b1100));
+assert_eq!(r.peek_be(3), Some(0b111));
+assert_eq!(r.read_unary(), Some(0));
+assert_eq!(r.position(), 9);
+assert_eq!(r.read_be(8), None);
+assert_eq!(r.remaining(), 7);
+```
+**/
+#[derive(Clone, Copy)]
+pub struct BitReader<'a, C = BigEndian, T = u8>
+where C: Cursor, T: 'a + BitStore {
+	/// The whole slice, including the bits already read.
+	bits: &'a BitSlice<C, T>,
+	/// The number of bits already read. This never exceeds `bits.len()`.
+	pos: usize,
+	/// The policy for requests past the end of the slice.
+	tail: Tail,
+	/// The number of padding bits consumed past the end of the slice.
+	overrun: usize,
+}
+
+impl<'a, C, T> BitReader<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Begins reading a slice at its first bit, with the `Strict` policy.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The bits to read.
+	///
+	/// # Returns
+	///
+	/// A reader at the front of `bits`.
+	pub fn new(bits: &'a BitSlice<C, T>) -> Self {
+		Self {
+			bits,
+			pos: 0,
+			tail: Tail::Strict,
+			overrun: 0,
+		}
+	}
+
+	/// Sets the policy for requests past the end of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `tail`: The new policy.
+	///
+	/// # Returns
+	///
+	/// The reader, with its policy changed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::reader::Tail;
+	///
+	/// let bits = bitvec![1, 1, 0];
+	/// let mut r = BitReader::new(&bits).with_tail(Tail::Zeros);
+	/// assert_eq!(r.peek_be(5), Some(0b11000));
+	/// assert_eq!(r.read_be(5), Some(0b11000));
+	/// assert!(r.is_empty());
+	/// assert_eq!(r.overrun(), 2);
+	/// ```
+	pub fn with_tail(mut self, tail: Tail) -> Self {
+		self.tail = tail;
+		self
+	}
+
+	/// Gets the policy for requests past the end of the slice.
+	pub fn tail(&self) -> Tail {
+		self.tail
+	}
+
+	/// Counts the bits already read.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index, in the original slice, of the next bit to be read.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Counts the bits not yet read.
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Tests if every bit of the slice has been read.
+	pub fn is_empty(&self) -> bool {
+		self.remaining() == 0
+	}
+
+	/// Counts the padding bits that the `Zeros` policy has consumed past the
+	/// end of the slice.
+	///
+	/// A decoder that looks ahead with padding can check this once it is done,
+	/// to learn whether it consumed a code that the input did not contain.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits that reads and skips consumed beyond the last bit of
+	/// the slice. This is always zero under the `Strict` policy.
+	pub fn overrun(&self) -> usize {
+		self.overrun
+	}
+
+	/// Views the bits not yet read.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The unread tail of the slice, with the lifetime of the original slice.
+	pub fn rest(&self) -> &'a BitSlice<C, T> {
+		&self.bits[self.pos ..]
+	}
+
+	/// Reads one bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The next bit. At the end of the slice, this is `None` under the
+	/// `Strict` policy, and a consumed `false` under the `Zeros` policy.
+	pub fn read_bit(&mut self) -> Option<bool> {
+		self.read_le(1).map(|bit| bit == 1)
+	}
+
+	/// Views a run of bits without consuming them.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The number of bits to view.
+	///
+	/// # Returns
+	///
+	/// The next `count` bits, or `None` if fewer than `count` remain. The
+	/// policy does not apply, as a slice cannot be padded.
+	pub fn peek_bits(&self, count: usize) -> Option<&'a BitSlice<C, T>> {
+		if count > self.remaining() {
+			return None;
+		}
+		Some(&self.bits[self.pos .. self.pos + count])
+	}
+
+	/// Reads a run of bits as a subslice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The number of bits to read.
+	///
+	/// # Returns
+	///
+	/// The next `count` bits, or `None`, consuming nothing, if fewer than
+	/// `count` remain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let mut r = BitReader::new(data.as_bitslice::<BigEndian>());
+	/// r.read_be(4);
+	/// let middle = r.read_bits(16).unwrap();
+	/// let bytes = middle.iter_fields::<u8>(8).collect::<Vec<_>>();
+	/// assert_eq!(bytes, [0x23, 0x45]);
+	/// assert_eq!(r.read_bits(5), None);
+	/// assert_eq!(r.remaining(), 4);
+	/// ```
+	pub fn read_bits(&mut self, count: usize) -> Option<&'a BitSlice<C, T>> {
+		let out = self.peek_bits(count)?;
+		self.pos += count;
+		Some(out)
+	}
+
+	/// Views an integer, whose first bit is its most significant bit, without
+	/// consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The width of the integer.
+	///
+	/// # Returns
+	///
+	/// The value of the next `count` bits, or `None` if the policy is `Strict`
+	/// and fewer than `count` bits remain. Under the `Zeros` policy, missing
+	/// bits are the low bits of the value, and are zero.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is greater than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bitvec![LittleEndian, u16; 1, 0, 0, 1, 1];
+	/// let r = BitReader::new(&bits);
+	/// assert_eq!(r.peek_be(5), Some(0b10011));
+	/// assert_eq!(r.peek_be(6), None);
+	/// assert_eq!(r.position(), 0);
+	/// ```
+	pub fn peek_be(&self, count: usize) -> Option<u64> {
+		let bits = self.available(count)?;
+		let value = bits.iter().fold(0u64, |acc, bit| acc << 1 | bit as u64);
+		Some(value.checked_shl((count - bits.len()) as u32).unwrap_or(0))
+	}
+
+	/// Views an integer, whose first bit is its least significant bit, without
+	/// consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The width of the integer.
+	///
+	/// # Returns
+	///
+	/// The value of the next `count` bits, or `None` if the policy is `Strict`
+	/// and fewer than `count` bits remain. Under the `Zeros` policy, missing
+	/// bits are the high bits of the value, and are zero.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is greater than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bitvec![LittleEndian, u16; 1, 0, 0, 1, 1];
+	/// let r = BitReader::new(&bits);
+	/// assert_eq!(r.peek_le(5), Some(0b11001));
+	/// ```
+	pub fn peek_le(&self, count: usize) -> Option<u64> {
+		let bits = self.available(count)?;
+		Some(bits.iter().enumerate().fold(0u64, |acc, (n, bit)| {
+			acc | (bit as u64) << n
+		}))
+	}
+
+	/// Reads an integer, whose first bit is its most significant bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The width of the integer.
+	///
+	/// # Returns
+	///
+	/// The value that [`peek_be`] would produce. The bits are consumed only if
+	/// a value is produced.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is greater than 64.
+	///
+	/// [`peek_be`]: #method.peek_be
+	pub fn read_be(&mut self, count: usize) -> Option<u64> {
+		let out = self.peek_be(count)?;
+		self.consume(count);
+		Some(out)
+	}
+
+	/// Reads an integer, whose first bit is its least significant bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The width of the integer.
+	///
+	/// # Returns
+	///
+	/// The value that [`peek_le`] would produce. The bits are consumed only if
+	/// a value is produced.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is greater than 64.
+	///
+	/// [`peek_le`]: #method.peek_le
+	pub fn read_le(&mut self, count: usize) -> Option<u64> {
+		let out = self.peek_le(count)?;
+		self.consume(count);
+		Some(out)
+	}
+
+	/// Consumes bits without reading them.
+	///
+	/// This is the second half of a look-ahead decode: peek at the longest
+	/// possible code, find its true length, and then skip that many bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The number of bits to skip.
+	///
+	/// # Returns
+	///
+	/// Whether the bits were skipped. Under the `Strict` policy, nothing is
+	/// skipped when fewer than `count` bits remain; under the `Zeros` policy,
+	/// every skip succeeds.
+	pub fn skip(&mut self, count: usize) -> bool {
+		if count > self.remaining() && self.tail == Tail::Strict {
+			return false;
+		}
+		self.consume(count);
+		true
+	}
+
+	/// Tests if the reader is at a multiple of `width` bits from the front of
+	/// its slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The alignment, in bits.
+	///
+	/// # Returns
+	///
+	/// Whether the number of bits read is a multiple of `width`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero.
+	pub fn is_aligned(&self, width: usize) -> bool {
+		assert!(width != 0, "Alignment cannot be zero");
+		self.pos % width == 0
+	}
+
+	/// Skips forward to the next multiple of `width` bits from the front of
+	/// the slice.
+	///
+	/// Alignment is measured from the first bit of the slice, not from the
+	/// storage elements beneath it. A reader over a whole buffer of bytes finds
+	/// byte boundaries with `align(8)`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The alignment, in bits.
+	///
+	/// # Returns
+	///
+	/// The number of bits skipped, or `None` if the policy is `Strict` and the
+	/// boundary is past the end of the slice.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0u8, 0xA5];
+	/// let mut r = BitReader::new(data.as_bitslice::<LittleEndian>());
+	/// r.read_le(3);
+	/// assert_eq!(r.align(8), Some(5));
+	/// assert_eq!(r.align(8), Some(0));
+	/// assert_eq!(r.read_le(8), Some(0xA5));
+	/// ```
+	pub fn align(&mut self, width: usize) -> Option<usize> {
+		assert!(width != 0, "Alignment cannot be zero");
+		let count = (width - self.pos % width) % width;
+		if self.skip(count) {
+			Some(count)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Reads a unary number: a run of cleared bits, ended by a set bit.
+	///
+	/// This is the prefix of Elias gamma and Golomb codes.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The number of cleared bits before the next set bit, which is consumed
+	/// along with them. If no set bit remains, this produces `None` and
+	/// consumes nothing, under either policy.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// //  The Elias gamma code of 5 is 00 101.
+	/// let bits = bitvec![0, 0, 1, 0, 1];
+	/// let mut r = BitReader::new(&bits);
+	/// let zeros = r.read_unary().unwrap();
+	/// let value = 1 << zeros | r.read_be(zeros).unwrap();
+	/// assert_eq!(value, 5);
+	/// assert_eq!(r.read_unary(), None);
+	/// ```
+	pub fn read_unary(&mut self) -> Option<usize> {
+		let zeros = self.rest().first_one()?;
+		self.pos += zeros + 1;
+		Some(zeros)
+	}
+
+	/// Finds the bits that an integer read of `count` bits would consume.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The width of the read.
+	///
+	/// # Returns
+	///
+	/// The next `count` bits, or as many as remain if the policy allows
+	/// padding.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is greater than 64.
+	fn available(&self, count: usize) -> Option<&'a BitSlice<C, T>> {
+		assert!(
+			count <= MAX_READ,
+			"Cannot read {} bits into a {}-bit integer",
+			count,
+			MAX_READ,
+		);
+		if count > self.remaining() && self.tail == Tail::Strict {
+			return None;
+		}
+		let end = cmp::min(self.pos + count, self.bits.len());
+		Some(&self.bits[self.pos .. end])
+	}
+
+	/// Advances the reader, recording any bits consumed past the end.
+	fn consume(&mut self, count: usize) {
+		let take = cmp::min(count, self.remaining());
+		self.pos += take;
+		self.overrun += count - take;
+	}
+}
+
+impl<'a, C, T> Debug for BitReader<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"BitReader<{}, {}> {{ position: {}, remaining: {}, tail: {:?} }}",
+			C::TYPENAME,
+			T::TYPENAME,
+			self.pos,
+			self.remaining(),
+			self.tail,
+		)
+	}
+}
@@ -0,0 +1,299 @@
+/*! Typed interchange representation
+
+The `serde` implementations in this crate write every bit collection as a
+record named `BitSet` with three fields:
+
+- `head: u8`: the index of the first live bit in the first element of `data`.
+- `bits: u64`: the number of live bits.
+- `data: [T]`: every storage element that holds a live bit, in memory order.
+
+This module exposes that record as an ordinary Rust structure,
+[`BitVecRepr`], which does not require `serde`. Code which builds the record by
+hand, or which exchanges it with other languages, can convert to and from it
+with [`BitSlice::to_repr`], [`BitVec::into_repr`], and [`BitVec::from_repr`].
+Conversion into a `BitVec` checks the record against the rules below, and
+reports the first rule it breaks as a [`ReprError`] rather than panicking.
+
+# Validity
+
+A record is valid for the storage type `T` when:
+
+1. `head` is less than the bit width of `T`;
+2. `bits` fits in the length counter of a `BitSlice` on the current target; and
+3. `data` has exactly as many elements as the span of `bits` bits beginning at
+   `head` touches. An empty span touches no elements, whatever its `head`.
+
+The bits in `data` outside the live span are not part of the value, and may hold
+anything. The cursor type is not recorded, and must be agreed out of band.
+
+# Limits
+
+The `Deserialize` implementations of `BitBox` and `BitVec` check every record
+against these rules, but accept any length that a `BitSlice` can hold. Streams
+from untrusted peers should be read through [`BitVecSeed`] instead, which
+refuses a record longer than a chosen number of bits before allocating for it.
+
+[`BitSlice::to_repr`]: ../slice/struct.BitSlice.html#method.to_repr
+[`BitVec::from_repr`]: ../vec/struct.BitVec.html#method.from_repr
+[`BitVec::into_repr`]: ../vec/struct.BitVec.html#method.into_repr
+[`BitVecSeed`]: struct.BitVecSeed.html
+[`BitVecRepr`]: struct.BitVecRepr.html
+[`ReprError`]: enum.ReprError.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	boxed::BitBox,
+	cursor::Cursor,
+	indices::Indexable,
+	pointer::BitPtr,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	mem,
+};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+pub use crate::serdes::BitVecSeed;
+
+/** The `{ head, bits, data }` record of a bit collection.
+
+The fields have the same names, types, and meanings as those of the serialized
+`BitSet` record; see the [module documentation] for the rules a record must
+follow to describe a bit collection.
+
+# Type Parameters
+
+- `T: BitStore`: The storage type of the described collection.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::repr::BitVecRepr;
+
+let bv = bitvec![BigEndian, u8; 1, 0, 1, 1];
+let repr = bv[1 ..].to_repr();
+assert_eq!(repr, BitVecRepr { head: 1, bits: 3, data: vec![0b1011_0000] });
+
+let back = BitVec::<BigEndian, u8>::from_repr(repr).unwrap();
+assert_eq!(back, bitvec![0, 1, 1]);
+```
+
+[module documentation]: index.html
+**/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BitVecRepr<T = u8>
+where T: BitStore {
+	/// The index of the first live bit in `data[0]`.
+	pub head: u8,
+	/// The number of live bits.
+	pub bits: u64,
+	/// The storage elements that hold the live bits.
+	pub data: Vec<T>,
+}
+
+impl<T> BitVecRepr<T>
+where T: BitStore {
+	/// Checks the record against the validity rules.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// `Ok` if the record describes a bit collection over `T`, or the first
+	/// rule that it breaks.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::repr::{BitVecRepr, ReprError};
+	///
+	/// let repr = BitVecRepr::<u8> { head: 6, bits: 4, data: vec![0] };
+	/// assert_eq!(
+	///   repr.validate(),
+	///   Err(ReprError::DataLength { expected: 2, found: 1 }),
+	/// );
+	/// ```
+	pub fn validate(&self) -> Result<(), ReprError> {
+		if self.head >= T::BITS {
+			return Err(ReprError::Head { head: self.head, width: T::BITS });
+		}
+		let bits = self.bits as usize;
+		if bits as u64 != self.bits || bits > BitPtr::<T>::MAX_BITS {
+			return Err(ReprError::Bits(self.bits));
+		}
+		let expected = self.head.idx::<T>().span(bits).0;
+		if self.data.len() != expected {
+			return Err(ReprError::DataLength {
+				expected,
+				found: self.data.len(),
+			});
+		}
+		Ok(())
+	}
+}
+
+/** The ways in which a `BitVecRepr` can fail to describe a bit collection.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ReprError {
+	/// `head` is not a bit index in the storage type.
+	Head {
+		/// The `head` field of the record.
+		head: u8,
+		/// The bit width of the storage type.
+		width: u8,
+	},
+	/// `bits` is too large for a `BitSlice` on this target.
+	Bits(u64),
+	/// `data` does not have exactly the elements that the live bits touch.
+	DataLength {
+		/// The number of elements touched by the live bits.
+		expected: usize,
+		/// The number of elements in `data`.
+		found: usize,
+	},
+}
+
+impl Display for ReprError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			ReprError::Head { head, width } => write!(
+				f,
+				"Head index {} is out of range for a {}-bit element",
+				head,
+				width,
+			),
+			ReprError::Bits(bits) => write!(
+				f,
+				"Bit count {} exceeds the maximum length of a BitSlice",
+				bits,
+			),
+			ReprError::DataLength { expected, found } => write!(
+				f,
+				"Live bits span {} elements, but {} were provided",
+				expected,
+				found,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReprError {}
+
+impl<C, T> BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Copies the slice into its interchange record.
+	///
+	/// The record holds every element that the slice touches, and the head
+	/// index is preserved, so this is the same record that `serde` writes.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A valid `BitVecRepr` describing `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let repr = 0x0Fu8.as_bitslice::<LittleEndian>()[2 .. 7].to_repr();
+	/// assert_eq!((repr.head, repr.bits), (2, 5));
+	/// assert_eq!(repr.data, [0x0F]);
+	/// ```
+	pub fn to_repr(&self) -> BitVecRepr<T> {
+		BitVecRepr {
+			head: *self.bitptr().head(),
+			bits: self.len() as u64,
+			data: self.as_slice().to_vec(),
+		}
+	}
+}
+
+impl<C, T> BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Builds a vector from an interchange record, after checking it.
+	///
+	/// The record’s buffer becomes the vector’s buffer, without copying.
+	///
+	/// # Parameters
+	///
+	/// - `repr`: A record describing a bit collection over `T`. The cursor
+	///   type `C` is not recorded, and is taken on trust.
+	///
+	/// # Returns
+	///
+	/// The vector that `repr` describes, or the first validity rule that
+	/// `repr` breaks.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::repr::{BitVecRepr, ReprError};
+	///
+	/// let repr = BitVecRepr { head: 4, bits: 8, data: vec![0x0Fu8, 0xF0] };
+	/// let bv = BitVec::<BigEndian, u8>::from_repr(repr).unwrap();
+	/// assert!(bv.all());
+	///
+	/// let bad = BitVecRepr { head: 9, bits: 0, data: Vec::<u8>::new() };
+	/// assert_eq!(
+	///   BitVec::<BigEndian, u8>::from_repr(bad),
+	///   Err(ReprError::Head { head: 9, width: 8 }),
+	/// );
+	/// ```
+	pub fn from_repr(repr: BitVecRepr<T>) -> Result<Self, ReprError> {
+		repr.validate()?;
+		let data = repr.data.into_boxed_slice();
+		let bitptr = BitPtr::new(
+			data.as_ptr(),
+			repr.head.idx(),
+			repr.bits as usize,
+		);
+		mem::forget(data);
+		Ok(unsafe { BitBox::from_bitptr(bitptr) }.into())
+	}
+
+	/// Converts the vector into its interchange record, without copying.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A valid `BitVecRepr` describing `self`, which owns its buffer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let repr = bitvec![LittleEndian, u16; 1; 20].into_repr();
+	/// assert_eq!(repr.data, [!0, 0x000F]);
+	/// ```
+	pub fn into_repr(self) -> BitVecRepr<T> {
+		let head = *self.bitptr().head();
+		let bits = self.len() as u64;
+		BitVecRepr { head, bits, data: self.into_vec() }
+	}
+}
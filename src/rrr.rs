@@ -0,0 +1,565 @@
+/*! RRR compressed bit vectors
+
+This module holds an immutable bit vector in the compressed form described by
+Raman, Raman, and Rao. The bits are cut into blocks of fifteen. Each block is
+stored as its *class*, the number of its bits that are set, in four bits, and
+its *offset*, the position of its pattern in the list of all patterns of that
+class, in just enough bits to count that list. Blocks that are all clear or all
+set need no offset at all, so sparse and dense regions both shrink, while a
+sample of running totals taken every few blocks keeps `rank` and `select`
+close to constant time.
+
+The structure is an auxiliary index in the sense of the `serde` support: it
+serializes with a version number, and is re-validated when deserialized.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	clone::Clone,
+	cmp::{
+		Eq,
+		PartialEq,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+		Write,
+	},
+};
+
+/// The number of bits in a block.
+const BLOCK: usize = 15;
+
+/// The number of bits used to store the class of a block.
+const CLASS_BITS: usize = 4;
+
+/// The number of blocks between samples of the running totals.
+const SAMPLE: usize = 32;
+
+/// `BINOMIAL[n][k]` is the number of ways to choose `k` of `n` bits.
+const BINOMIAL: [[u16; 16]; 16] = [
+	[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 3, 3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 4, 6, 4, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 5, 10, 10, 5, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 6, 15, 20, 15, 6, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 7, 21, 35, 35, 21, 7, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+	[1, 8, 28, 56, 70, 56, 28, 8, 1, 0, 0, 0, 0, 0, 0, 0],
+	[1, 9, 36, 84, 126, 126, 84, 36, 9, 1, 0, 0, 0, 0, 0, 0],
+	[1, 10, 45, 120, 210, 252, 210, 120, 45, 10, 1, 0, 0, 0, 0, 0],
+	[1, 11, 55, 165, 330, 462, 462, 330, 165, 55, 11, 1, 0, 0, 0, 0],
+	[1, 12, 66, 220, 495, 792, 924, 792, 495, 220, 66, 12, 1, 0, 0, 0],
+	[1, 13, 78, 286, 715, 1287, 1716, 1716, 1287, 715, 286, 78, 13, 1, 0, 0],
+	[1, 14, 91, 364, 1001, 2002, 3003, 3432, 3003, 2002, 1001, 364, 91, 14, 1, 0],
+	[1, 15, 105, 455, 1365, 3003, 5005, 6435, 6435, 5005, 3003, 1365, 455, 105, 15, 1],
+];
+
+/// `WIDTHS[c]` is the number of bits in the offset of a block of class `c`.
+const WIDTHS: [u8; 16] = [0, 4, 7, 9, 11, 12, 13, 13, 13, 13, 12, 11, 9, 7, 4, 0];
+
+/** A bit vector, compressed into blocks of class and offset.
+
+The vector is built once, from a `BitSlice`, and then answers [`get`],
+[`rank`], and [`select`] directly from the compressed form. The space it uses
+shrinks as the bits grow more skewed towards either value: a vector with equal
+numbers of set and cleared bits in random places takes slightly more space than
+the uncompressed bits, and one in which a tenth of the bits are set takes about
+sixty percent.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  vector will use to store data.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::rrr::RrrVec;
+
+let mut bv = bitvec![0; 1000];
+for n in (0 .. 1000).step_by(37) {
+  bv.set(n, true);
+}
+let rrr = RrrVec::from_bitslice(&bv);
+assert_eq!(rrr.len(), 1000);
+assert_eq!(rrr.count_ones(), 28);
+assert_eq!(rrr.get(74), Some(true));
+assert_eq!(rrr.rank(75), 3);
+assert_eq!(rrr.select(27), Some(999));
+assert_eq!(rrr.to_bitvec(), bv);
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`get`]: #method.get
+[`rank`]: #method.rank
+[`select`]: #method.select
+**/
+pub struct RrrVec<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The class of each block, most significant bit first.
+	classes: BitVec<C, T>,
+	/// The offset of each block, most significant bit first, in the width that
+	/// its class requires.
+	offsets: BitVec<C, T>,
+	/// The number of bits in the vector.
+	len: usize,
+	/// The number of set bits before every `SAMPLE`th block, and after the
+	/// last block.
+	ranks: Vec<usize>,
+	/// The position in `offsets` of every `SAMPLE`th block.
+	positions: Vec<usize>,
+}
+
+impl<C, T> RrrVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Compresses a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The bits to compress.
+	///
+	/// # Returns
+	///
+	/// A compressed copy of `bits`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rrr::RrrVec;
+	///
+	/// let rrr = RrrVec::from_bitslice(&bitvec![0, 1, 1, 0, 1]);
+	/// assert_eq!(rrr.count_ones(), 3);
+	/// ```
+	pub fn from_bitslice(bits: &BitSlice<C, T>) -> Self {
+		let len = bits.len();
+		let blocks = (len + BLOCK - 1) / BLOCK;
+		let mut classes = BitVec::with_capacity(blocks * CLASS_BITS);
+		let mut offsets = BitVec::new();
+		for block in bits.chunks(BLOCK) {
+			let (class, offset) = Self::encode(block);
+			Self::push_int(&mut classes, class as usize, CLASS_BITS);
+			Self::push_int(&mut offsets, offset as usize, WIDTHS[class] as usize);
+		}
+		Self::from_encoding(classes, offsets, len)
+	}
+
+	/// Counts the bits in the vector.
+	///
+	/// # Returns
+	///
+	/// The number of bits in the vector.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the vector is empty.
+	///
+	/// # Returns
+	///
+	/// Whether the vector has no bits.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Counts the set bits in the vector.
+	///
+	/// # Returns
+	///
+	/// The number of set bits in the vector.
+	pub fn count_ones(&self) -> usize {
+		self.ranks[self.ranks.len() - 1]
+	}
+
+	/// Reads a bit from the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the bit to read.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, or `None` if `index` is not less than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rrr::RrrVec;
+	///
+	/// let rrr = RrrVec::from_bitslice(&bitvec![0, 1, 0]);
+	/// assert_eq!(rrr.get(1), Some(true));
+	/// assert_eq!(rrr.get(2), Some(false));
+	/// assert_eq!(rrr.get(3), None);
+	/// ```
+	pub fn get(&self, index: usize) -> Option<bool> {
+		if index >= self.len {
+			return None;
+		}
+		let (block, pos, _) = self.seek(index / BLOCK);
+		let bits = Self::decode(self.class(block), self.offset(block, pos));
+		Some(bits >> (index % BLOCK) & 1 == 1)
+	}
+
+	/// Counts the set bits before an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The end of the range `0 .. index` in which to count set
+	///   bits. This must be no greater than `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The number of set bits in `0 .. index`.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rrr::RrrVec;
+	///
+	/// let rrr = RrrVec::from_bitslice(&bitvec![1, 1, 0, 1]);
+	/// assert_eq!(rrr.rank(0), 0);
+	/// assert_eq!(rrr.rank(3), 2);
+	/// assert_eq!(rrr.rank(4), 3);
+	/// ```
+	pub fn rank(&self, index: usize) -> usize {
+		assert!(
+			index <= self.len,
+			"Index out of range: {} > {}",
+			index,
+			self.len,
+		);
+		let (block, pos, rank) = self.seek(index / BLOCK);
+		let rem = index % BLOCK;
+		if rem == 0 {
+			return rank;
+		}
+		let bits = Self::decode(self.class(block), self.offset(block, pos));
+		rank + (bits & ((1 << rem) - 1)).count_ones() as usize
+	}
+
+	/// Finds the position of a set bit, by its count from the front.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `count`: The number of set bits before the one to find.
+	///
+	/// # Returns
+	///
+	/// The index of the set bit that has `count` set bits before it, or `None`
+	/// if the vector has no more than `count` set bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rrr::RrrVec;
+	///
+	/// let rrr = RrrVec::from_bitslice(&bitvec![0, 1, 0, 0, 1]);
+	/// assert_eq!(rrr.select(0), Some(1));
+	/// assert_eq!(rrr.select(1), Some(4));
+	/// assert_eq!(rrr.select(2), None);
+	/// ```
+	pub fn select(&self, count: usize) -> Option<usize> {
+		if count >= self.count_ones() {
+			return None;
+		}
+		//  Find the last sample that does not pass `count`, then walk its
+		//  blocks by class until the one that holds the bit.
+		let sample = match self.ranks.binary_search(&count) {
+			Ok(mut n) => {
+				while n + 1 < self.ranks.len() && self.ranks[n + 1] == count {
+					n += 1;
+				}
+				n
+			},
+			Err(n) => n - 1,
+		};
+		let (mut block, mut pos, mut rank) = (
+			sample * SAMPLE,
+			self.positions[sample],
+			self.ranks[sample],
+		);
+		loop {
+			let class = self.class(block);
+			if rank + class > count {
+				break;
+			}
+			rank += class;
+			pos += WIDTHS[class] as usize;
+			block += 1;
+		}
+		let mut bits = Self::decode(self.class(block), self.offset(block, pos));
+		for _ in rank .. count {
+			bits &= bits - 1;
+		}
+		Some(block * BLOCK + bits.trailing_zeros() as usize)
+	}
+
+	/// Decompresses the vector.
+	///
+	/// # Returns
+	///
+	/// The bits of the vector, uncompressed.
+	pub fn to_bitvec(&self) -> BitVec<C, T> {
+		let mut out = BitVec::with_capacity(self.len);
+		let mut pos = 0;
+		for block in 0 .. self.blocks() {
+			let class = self.class(block);
+			let bits = Self::decode(class, self.offset(block, pos));
+			pos += WIDTHS[class] as usize;
+			let live = if block + 1 == self.blocks() {
+				self.len - block * BLOCK
+			}
+			else {
+				BLOCK
+			};
+			for n in 0 .. live {
+				out.push(bits >> n & 1 == 1);
+			}
+		}
+		out
+	}
+
+	/// Assembles a vector from its parts, checking that they describe a
+	/// vector exactly as `from_bitslice` would have encoded it.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of bits in the vector.
+	/// - `classes`: The class of each block.
+	/// - `offsets`: The offset of each block.
+	///
+	/// # Returns
+	///
+	/// The vector, or a description of the first inconsistency found.
+	#[cfg(feature = "serde")]
+	pub(crate) fn from_parts(
+		len: usize,
+		classes: BitVec<C, T>,
+		offsets: BitVec<C, T>,
+	) -> Result<Self, &'static str> {
+		let blocks = len / BLOCK + (len % BLOCK != 0) as usize;
+		if blocks.checked_mul(CLASS_BITS) != Some(classes.len()) {
+			return Err("classes do not match the length");
+		}
+		let mut pos = 0;
+		for block in 0 .. blocks {
+			let class = Self::read_int(&classes, block * CLASS_BITS, CLASS_BITS);
+			let width = WIDTHS[class] as usize;
+			if pos + width > offsets.len() {
+				return Err("offsets are too short");
+			}
+			if Self::read_int(&offsets, pos, width) >= BINOMIAL[BLOCK][class] as usize {
+				return Err("offset is out of range for its class");
+			}
+			pos += width;
+		}
+		if pos != offsets.len() {
+			return Err("offsets are too long");
+		}
+		let out = Self::from_encoding(classes, offsets, len);
+		if Self::from_bitslice(&out.to_bitvec()) != out {
+			return Err("bits are not in canonical form");
+		}
+		Ok(out)
+	}
+
+	/// Views the parts of the vector, in the order that `from_parts` takes.
+	#[cfg(feature = "serde")]
+	pub(crate) fn parts(&self) -> (usize, &BitVec<C, T>, &BitVec<C, T>) {
+		(self.len, &self.classes, &self.offsets)
+	}
+
+	/// Builds the running-total samples over an encoding.
+	fn from_encoding(
+		classes: BitVec<C, T>,
+		offsets: BitVec<C, T>,
+		len: usize,
+	) -> Self {
+		let mut out = Self {
+			classes,
+			offsets,
+			len,
+			ranks: Vec::new(),
+			positions: Vec::new(),
+		};
+		let (mut rank, mut pos) = (0, 0);
+		for block in 0 .. out.blocks() {
+			if block % SAMPLE == 0 {
+				out.ranks.push(rank);
+				out.positions.push(pos);
+			}
+			let class = out.class(block);
+			rank += class;
+			pos += WIDTHS[class] as usize;
+		}
+		//  The final total lets `select` find the last sample without a
+		//  special case, and gives `count_ones` its answer.
+		out.ranks.push(rank);
+		out.positions.push(pos);
+		out
+	}
+
+	/// Counts the blocks in the vector.
+	fn blocks(&self) -> usize {
+		self.classes.len() / CLASS_BITS
+	}
+
+	/// Reads the class of a block.
+	fn class(&self, block: usize) -> usize {
+		Self::read_int(&self.classes, block * CLASS_BITS, CLASS_BITS)
+	}
+
+	/// Reads the offset of a block, given its position in `offsets`.
+	fn offset(&self, block: usize, pos: usize) -> u16 {
+		let width = WIDTHS[self.class(block)] as usize;
+		Self::read_int(&self.offsets, pos, width) as u16
+	}
+
+	/// Finds the position of a block's offset, and the number of set bits
+	/// before the block, starting from the nearest sample.
+	fn seek(&self, block: usize) -> (usize, usize, usize) {
+		let sample = block / SAMPLE;
+		let (mut pos, mut rank) = (self.positions[sample], self.ranks[sample]);
+		for n in sample * SAMPLE .. block {
+			let class = self.class(n);
+			rank += class;
+			pos += WIDTHS[class] as usize;
+		}
+		(block, pos, rank)
+	}
+
+	/// Computes the class and offset of a block of at most `BLOCK` bits.
+	///
+	/// The offset is the sum, over the set bits in ascending order, of the
+	/// number of ways to place the `k`th set bit below its position. This
+	/// numbers the patterns of each class from zero without gaps.
+	fn encode(block: &BitSlice<C, T>) -> (usize, u16) {
+		let (mut class, mut offset) = (0, 0);
+		for (n, bit) in block.iter().enumerate() {
+			if bit {
+				class += 1;
+				offset += BINOMIAL[n][class];
+			}
+		}
+		(class, offset)
+	}
+
+	/// Recovers the pattern of a block from its class and offset.
+	///
+	/// Bit `n` of the result is bit `n` of the block.
+	fn decode(class: usize, mut offset: u16) -> u16 {
+		let (mut bits, mut n) = (0u16, BLOCK);
+		for k in (1 ..= class).rev() {
+			n -= 1;
+			while BINOMIAL[n][k] > offset {
+				n -= 1;
+			}
+			bits |= 1 << n;
+			offset -= BINOMIAL[n][k];
+		}
+		bits
+	}
+
+	/// Appends the low `width` bits of a value, most significant bit first.
+	fn push_int(bits: &mut BitVec<C, T>, value: usize, width: usize) {
+		for n in (0 .. width).rev() {
+			bits.push(value >> n & 1 == 1);
+		}
+	}
+
+	/// Reads a `width`-bit value, most significant bit first.
+	fn read_int(bits: &BitSlice<C, T>, at: usize, width: usize) -> usize {
+		bits[at .. at + width]
+			.iter()
+			.fold(0, |acc, bit| acc << 1 | bit as usize)
+	}
+}
+
+impl<C, T> Clone for RrrVec<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self {
+			classes: self.classes.clone(),
+			offsets: self.offsets.clone(),
+			len: self.len,
+			ranks: self.ranks.clone(),
+			positions: self.positions.clone(),
+		}
+	}
+}
+
+impl<C, T> Eq for RrrVec<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> PartialEq for RrrVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Tests if two vectors hold the same bits.
+	///
+	/// Encodings are canonical, so this compares the encoded bits directly.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.len == rhs.len
+			&& self.classes == rhs.classes
+			&& self.offsets == rhs.offsets
+	}
+}
+
+impl<C, T> Debug for RrrVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `RrrVec` type header and its decompressed bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rrr::RrrVec;
+	///
+	/// let rrr = RrrVec::from_bitslice(&bitvec![LittleEndian, u16; 0, 1, 1]);
+	/// assert_eq!(
+	///   "RrrVec<LittleEndian, u16> [011]",
+	///   &format!("{:?}", rrr),
+	/// );
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("RrrVec<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str("> [")?;
+		for bit in self.to_bitvec() {
+			f.write_char(if bit { '1' } else { '0' })?;
+		}
+		f.write_char(']')
+	}
+}
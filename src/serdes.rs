@@ -5,6 +5,22 @@ This module implements the Serde traits for the `bitvec` types, as possible.
 Without an allocator, only `BitSlice` exists, and can only implement
 `Serialize`. With an allocator, the `BitBox` and `BitVec` types exist, and are
 able to implement `Deserialize` as well.
+
+The `BitSet` record that these implementations read and write is available
+without `serde` as `repr::BitVecRepr`, for code that produces or consumes it by
+other means.
+
+# Auxiliary Structures
+
+The `BitSet` record written for the slice types is the only unversioned format
+in this module, and it must stay that way for compatibility. Every auxiliary
+structure built on top of the bit collections (rank and select indices,
+compressed encodings, and the like) serializes as a struct whose first field is
+a `version: u16`, followed by the structure’s own fields and, where it owns one,
+its underlying bit collection. Deserialization rejects unknown versions, and
+re-validates the structure’s invariants against the loaded bits rather than
+trusting the stream, so a precomputed index can be shipped alongside its bitmap
+without being able to describe a bitmap other than the one it arrived with.
 !*/
 
 #![cfg(all(feature = "serde"))]
@@ -18,19 +34,30 @@ use crate::{
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
+	cursor::BigEndian,
+	elias_fano::EliasFano,
 	pointer::BitPtr,
+	repr::BitVecRepr,
+	rrr::RrrVec,
 	vec::BitVec,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "alloc")]
 use core::{
+	clone::Clone,
 	cmp,
 	fmt::{
 		self,
+		Debug,
 		Formatter,
 	},
-	marker::PhantomData,
-	mem,
+	marker::{
+		Copy,
+		PhantomData,
+	},
 };
 
 use serde::{
@@ -46,6 +73,7 @@ use serde::{
 	Deserialize,
 	de::{
 		self,
+		DeserializeSeed,
 		Deserializer,
 		MapAccess,
 		SeqAccess,
@@ -55,18 +83,60 @@ use serde::{
 
 /// A Serde visitor to pull `BitBox` data out of a serialized stream
 #[cfg(feature = "alloc")]
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct BitBoxVisitor<'de, C, T>
 where C: Cursor, T: BitStore + Deserialize<'de> {
 	_cursor: PhantomData<C>,
 	_storage: PhantomData<&'de T>,
+	/// The largest `bits` field that the visitor accepts.
+	max_bits: usize,
 }
 
 #[cfg(feature = "alloc")]
 impl<'de, C, T> BitBoxVisitor<'de, C, T>
 where C: Cursor, T: BitStore + Deserialize<'de> {
 	fn new() -> Self {
-		BitBoxVisitor { _cursor: PhantomData, _storage: PhantomData }
+		Self::with_limit(BitPtr::<T>::MAX_BITS)
+	}
+
+	fn with_limit(max_bits: usize) -> Self {
+		BitBoxVisitor {
+			_cursor: PhantomData,
+			_storage: PhantomData,
+			max_bits,
+		}
+	}
+
+	/// Rejects a `bits` field over the limit, before `data` is read.
+	fn check_bits<E>(&self, bits: u64) -> Result<(), E>
+	where E: de::Error {
+		if bits > self.max_bits as u64 {
+			Err(de::Error::custom(format_args!(
+				"Bit count {} exceeds the limit of {}",
+				bits,
+				self.max_bits,
+			)))
+		}
+		else {
+			Ok(())
+		}
+	}
+
+	/// Reads the `data` field, refusing more elements than any record within
+	/// the limit could need.
+	fn data(&self) -> DataSeed<T> {
+		DataSeed {
+			max_elts: (self.max_bits / T::BITS as usize).saturating_add(2),
+			_storage: PhantomData,
+		}
+	}
+
+	/// Checks the record, and takes its buffer as the box’s buffer.
+	fn assemble<E>(head: u8, bits: u64, data: Vec<T>) -> Result<BitBox<C, T>, E>
+	where E: de::Error {
+		BitVec::from_repr(BitVecRepr { head, bits, data })
+			.map(BitVec::into_boxed_bitslice)
+			.map_err(de::Error::custom)
 	}
 }
 
@@ -80,39 +150,45 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 	}
 
 	/// Visit a sequence of anonymous data elements. These must be in the order
-	/// `usize', `u8`, `u8`, `[T]`.
+	/// `u8`, `u64`, `[T]`.
 	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
 	where V: SeqAccess<'de> {
 		let head: u8 = seq.next_element()?
 			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
-		let bits: usize = seq.next_element()?
+		let bits: u64 = seq.next_element()?
 			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
-		let data: Box<[T]> = seq.next_element()?
+		self.check_bits(bits)?;
+		let data: Vec<T> = seq.next_element_seed(self.data())?
 			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
 
-		let bitptr = BitPtr::new(data.as_ptr(), head, bits);
-		mem::forget(data);
-		Ok(unsafe { BitBox::from_raw(bitptr) })
+		Self::assemble(head, bits, data)
 	}
 
 	/// Visit a map of named data elements. These may be in any order, and must
-	/// be the pairs `head: u8`, `bits: usize`, and `data: [T]`.
+	/// be the pairs `head: u8`, `bits: u64`, and `data: [T]`.
 	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
 	where V: MapAccess<'de> {
 		let mut head: Option<u8> = None;
-		let mut bits: Option<usize> = None;
-		let mut data: Option<Box<[T]>> = None;
+		let mut bits: Option<u64> = None;
+		let mut data: Option<Vec<T>> = None;
 
 		while let Some(key) = map.next_key()? {
 			match key {
 				"head" => if head.replace(map.next_value()?).is_some() {
 					return Err(de::Error::duplicate_field("head"));
 				},
-				"bits" => if bits.replace(map.next_value()?).is_some() {
-					return Err(de::Error::duplicate_field("bits"));
+				"bits" => {
+					let b = map.next_value()?;
+					self.check_bits(b)?;
+					if bits.replace(b).is_some() {
+						return Err(de::Error::duplicate_field("bits"));
+					}
 				},
-				"data" => if data.replace(map.next_value()?).is_some() {
-					return Err(de::Error::duplicate_field("data"));
+				"data" => {
+					let d = map.next_value_seed(self.data())?;
+					if data.replace(d).is_some() {
+						return Err(de::Error::duplicate_field("data"));
+					}
 				},
 				f => return Err(de::Error::unknown_field(
 					f, &["head", "bits", "data"]
@@ -123,13 +199,165 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
 		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
 
-		let bitptr = BitPtr::new(
-			data.as_ptr(),
-			head,
-			cmp::min(bits, data.len() * T::BITS as usize),
-		);
-		mem::forget(data);
-		Ok(unsafe { BitBox::from_raw(bitptr) })
+		Self::assemble(head, bits, data)
+	}
+}
+
+/// Reads the `data` field of a `BitSet` record, up to a maximum length.
+#[cfg(feature = "alloc")]
+struct DataSeed<T> {
+	/// The most elements that the field may hold.
+	max_elts: usize,
+	_storage: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeSeed<'de> for DataSeed<T>
+where T: BitStore + Deserialize<'de> {
+	type Value = Vec<T>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> Visitor<'de> for DataSeed<T>
+where T: BitStore + Deserialize<'de> {
+	type Value = Vec<T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "A sequence of at most {} elements", self.max_elts)
+	}
+
+	/// Collects the elements, trusting the stream’s length hint only as far as
+	/// the limit.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let hint = seq.size_hint().unwrap_or(0);
+		let mut out = Vec::with_capacity(cmp::min(hint, self.max_elts));
+		while let Some(elt) = seq.next_element()? {
+			if out.len() == self.max_elts {
+				return Err(de::Error::invalid_length(out.len() + 1, &self));
+			}
+			out.push(elt);
+		}
+		Ok(out)
+	}
+}
+
+/** Deserializes a `BitVec`, refusing records longer than a limit.
+
+The `Deserialize` implementations of `BitBox` and `BitVec` accept any record up
+to the maximum length of a `BitSlice`, and so allocate as much memory as the
+stream claims to need. When the stream is not trusted, deserialize through this
+seed instead: it rejects a `bits` field over the limit as soon as it is read,
+and stops reading `data` once it holds more elements than any record within the
+limit could, so that an oversized record fails before it is stored.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the produced vector.
+- `T: BitStore`: The storage type of the produced vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::repr::BitVecSeed;
+use serde::de::DeserializeSeed;
+
+let json = r#"{"head":0,"bits":12,"data":[255,15]}"#;
+
+let mut de = serde_json::Deserializer::from_str(json);
+let bv = BitVecSeed::<BigEndian, u8>::new(16).deserialize(&mut de).unwrap();
+assert_eq!(bv.len(), 12);
+
+let mut de = serde_json::Deserializer::from_str(json);
+assert!(BitVecSeed::<BigEndian, u8>::new(8).deserialize(&mut de).is_err());
+```
+**/
+#[cfg(feature = "alloc")]
+pub struct BitVecSeed<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The largest number of bits to accept.
+	max_bits: usize,
+	_cursor: PhantomData<C>,
+	_storage: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> BitVecSeed<C, T>
+where C: Cursor, T: BitStore {
+	/// Builds a seed that accepts records of at most `max_bits` bits.
+	///
+	/// # Parameters
+	///
+	/// - `max_bits`: The largest `bits` field to accept.
+	///
+	/// # Returns
+	///
+	/// A seed which deserializes `BitVec<C, T>` values of at most `max_bits`
+	/// bits.
+	pub fn new(max_bits: usize) -> Self {
+		Self {
+			max_bits,
+			_cursor: PhantomData,
+			_storage: PhantomData,
+		}
+	}
+
+	/// Reads the limit of the seed.
+	///
+	/// # Returns
+	///
+	/// The largest number of bits that the seed accepts.
+	pub fn max_bits(&self) -> usize {
+		self.max_bits
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> Clone for BitVecSeed<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self::new(self.max_bits)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> Copy for BitVecSeed<C, T>
+where C: Cursor, T: BitStore {}
+
+#[cfg(feature = "alloc")]
+impl<C, T> Debug for BitVecSeed<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"BitVecSeed<{}, {}> {{ max_bits: {} }}",
+			C::TYPENAME,
+			T::TYPENAME,
+			self.max_bits,
+		)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> DeserializeSeed<'de> for BitVecSeed<C, T>
+where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
+	type Value = BitVec<C, T>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer
+			.deserialize_struct(
+				"BitSet",
+				&["head", "bits", "data"],
+				BitBoxVisitor::with_limit(self.max_bits),
+			)
+			.map(Into::into)
 	}
 }
 
@@ -189,6 +417,316 @@ where C: Cursor, T: BitStore + Serialize {
 	}
 }
 
+/// The current version of the `EliasFano` record.
+#[cfg(feature = "alloc")]
+const ELIAS_FANO_VERSION: u16 = 1;
+
+/// The fields of the `EliasFano` record, in order.
+#[cfg(feature = "alloc")]
+const ELIAS_FANO_FIELDS: &[&str] = &["version", "len", "low_bits", "low", "high"];
+
+#[cfg(feature = "alloc")]
+impl<C, T> Serialize for EliasFano<C, T>
+where C: Cursor, T: BitStore + Serialize {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		let (len, low_bits, low, high) = self.parts();
+		let mut state = serializer.serialize_struct("EliasFano", 5)?;
+
+		state.serialize_field("version", &ELIAS_FANO_VERSION)?;
+		state.serialize_field("len", &(len as u64))?;
+		state.serialize_field("low_bits", &low_bits)?;
+		state.serialize_field("low", low)?;
+		state.serialize_field("high", high)?;
+
+		state.end()
+	}
+}
+
+/// A Serde visitor to pull `EliasFano` data out of a serialized stream
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct EliasFanoVisitor<'de, C, T>
+where C: Cursor, T: BitStore + Deserialize<'de> {
+	_cursor: PhantomData<C>,
+	_storage: PhantomData<&'de T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> EliasFanoVisitor<'de, C, T>
+where C: Cursor, T: BitStore + Deserialize<'de> {
+	fn new() -> Self {
+		EliasFanoVisitor { _cursor: PhantomData, _storage: PhantomData }
+	}
+
+	/// Rejects every record version other than the current one.
+	fn check_version<E>(version: u16) -> Result<(), E>
+	where E: de::Error {
+		if version == ELIAS_FANO_VERSION {
+			Ok(())
+		}
+		else {
+			Err(de::Error::custom(format_args!(
+				"unsupported EliasFano version {}",
+				version,
+			)))
+		}
+	}
+
+	/// Re-validates the loaded parts, rather than trusting the stream.
+	fn assemble<E>(
+		len: usize,
+		low_bits: u8,
+		low: BitVec<C, T>,
+		high: BitVec<C, T>,
+	) -> Result<EliasFano<C, T>, E>
+	where E: de::Error {
+		EliasFano::from_parts(len, low_bits, low, high)
+			.map_err(|msg| de::Error::custom(format_args!(
+				"invalid EliasFano record: {}",
+				msg,
+			)))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Visitor<'de> for EliasFanoVisitor<'de, C, T>
+where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
+	type Value = EliasFano<C, T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("An EliasFano record")
+	}
+
+	/// Visit a sequence of anonymous data elements. These must be in the order
+	/// `u16`, `usize`, `u8`, `BitVec`, `BitVec`.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let version: u16 = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		Self::check_version(version)?;
+		let len: usize = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		let low_bits: u8 = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+		let low: BitVec<C, T> = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+		let high: BitVec<C, T> = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(4, &self))?;
+
+		Self::assemble(len, low_bits, low, high)
+	}
+
+	/// Visit a map of named data elements. These may be in any order, and must
+	/// be the pairs `version: u16`, `len: usize`, `low_bits: u8`, `low: BitVec`,
+	/// and `high: BitVec`.
+	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+	where V: MapAccess<'de> {
+		let mut version: Option<u16> = None;
+		let mut len: Option<usize> = None;
+		let mut low_bits: Option<u8> = None;
+		let mut low: Option<BitVec<C, T>> = None;
+		let mut high: Option<BitVec<C, T>> = None;
+
+		while let Some(key) = map.next_key()? {
+			match key {
+				"version" => {
+					let v = map.next_value()?;
+					Self::check_version(v)?;
+					if version.replace(v).is_some() {
+						return Err(de::Error::duplicate_field("version"));
+					}
+				},
+				"len" => if len.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("len"));
+				},
+				"low_bits" => if low_bits.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("low_bits"));
+				},
+				"low" => if low.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("low"));
+				},
+				"high" => if high.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("high"));
+				},
+				f => return Err(de::Error::unknown_field(f, ELIAS_FANO_FIELDS)),
+			}
+		}
+		version.ok_or_else(|| de::Error::missing_field("version"))?;
+		let len = len.ok_or_else(|| de::Error::missing_field("len"))?;
+		let low_bits = low_bits
+			.ok_or_else(|| de::Error::missing_field("low_bits"))?;
+		let low = low.ok_or_else(|| de::Error::missing_field("low"))?;
+		let high = high.ok_or_else(|| de::Error::missing_field("high"))?;
+
+		Self::assemble(len, low_bits, low, high)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Deserialize<'de> for EliasFano<C, T>
+where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct(
+			"EliasFano",
+			ELIAS_FANO_FIELDS,
+			EliasFanoVisitor::new(),
+		)
+	}
+}
+
+/// The current version of the `RrrVec` record.
+#[cfg(feature = "alloc")]
+const RRR_VERSION: u16 = 1;
+
+/// The fields of the `RrrVec` record, in order.
+#[cfg(feature = "alloc")]
+const RRR_FIELDS: &[&str] = &["version", "len", "classes", "offsets"];
+
+#[cfg(feature = "alloc")]
+impl<C, T> Serialize for RrrVec<C, T>
+where C: Cursor, T: BitStore + Serialize {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		let (len, classes, offsets) = self.parts();
+		let mut state = serializer.serialize_struct("RrrVec", 4)?;
+
+		state.serialize_field("version", &RRR_VERSION)?;
+		state.serialize_field("len", &(len as u64))?;
+		state.serialize_field("classes", classes)?;
+		state.serialize_field("offsets", offsets)?;
+
+		state.end()
+	}
+}
+
+/// A Serde visitor to pull `RrrVec` data out of a serialized stream
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct RrrVecVisitor<'de, C, T>
+where C: Cursor, T: BitStore + Deserialize<'de> {
+	_cursor: PhantomData<C>,
+	_storage: PhantomData<&'de T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> RrrVecVisitor<'de, C, T>
+where C: Cursor, T: BitStore + Deserialize<'de> {
+	fn new() -> Self {
+		RrrVecVisitor { _cursor: PhantomData, _storage: PhantomData }
+	}
+
+	/// Rejects every record version other than the current one.
+	fn check_version<E>(version: u16) -> Result<(), E>
+	where E: de::Error {
+		if version == RRR_VERSION {
+			Ok(())
+		}
+		else {
+			Err(de::Error::custom(format_args!(
+				"unsupported RrrVec version {}",
+				version,
+			)))
+		}
+	}
+
+	/// Re-validates the loaded parts, rather than trusting the stream.
+	fn assemble<E>(
+		len: usize,
+		classes: BitVec<C, T>,
+		offsets: BitVec<C, T>,
+	) -> Result<RrrVec<C, T>, E>
+	where E: de::Error {
+		RrrVec::from_parts(len, classes, offsets)
+			.map_err(|msg| de::Error::custom(format_args!(
+				"invalid RrrVec record: {}",
+				msg,
+			)))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Visitor<'de> for RrrVecVisitor<'de, C, T>
+where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
+	type Value = RrrVec<C, T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("An RrrVec record")
+	}
+
+	/// Visit a sequence of anonymous data elements. These must be in the order
+	/// `u16`, `usize`, `BitVec`, `BitVec`.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let version: u16 = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		Self::check_version(version)?;
+		let len: usize = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		let classes: BitVec<C, T> = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+		let offsets: BitVec<C, T> = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+
+		Self::assemble(len, classes, offsets)
+	}
+
+	/// Visit a map of named data elements. These may be in any order, and must
+	/// be the pairs `version: u16`, `len: usize`, `classes: BitVec`, and
+	/// `offsets: BitVec`.
+	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+	where V: MapAccess<'de> {
+		let mut version: Option<u16> = None;
+		let mut len: Option<usize> = None;
+		let mut classes: Option<BitVec<C, T>> = None;
+		let mut offsets: Option<BitVec<C, T>> = None;
+
+		while let Some(key) = map.next_key()? {
+			match key {
+				"version" => {
+					let v = map.next_value()?;
+					Self::check_version(v)?;
+					if version.replace(v).is_some() {
+						return Err(de::Error::duplicate_field("version"));
+					}
+				},
+				"len" => if len.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("len"));
+				},
+				"classes" => if classes.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("classes"));
+				},
+				"offsets" => if offsets.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("offsets"));
+				},
+				f => return Err(de::Error::unknown_field(f, RRR_FIELDS)),
+			}
+		}
+		version.ok_or_else(|| de::Error::missing_field("version"))?;
+		let len = len.ok_or_else(|| de::Error::missing_field("len"))?;
+		let classes = classes
+			.ok_or_else(|| de::Error::missing_field("classes"))?;
+		let offsets = offsets
+			.ok_or_else(|| de::Error::missing_field("offsets"))?;
+
+		Self::assemble(len, classes, offsets)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Deserialize<'de> for RrrVec<C, T>
+where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct(
+			"RrrVec",
+			RRR_FIELDS,
+			RrrVecVisitor::new(),
+		)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::prelude::*;
@@ -267,4 +805,189 @@ mod tests {
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1010]);
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1011]);
 	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn invalid() {
+		serde_test::assert_de_tokens_error::<BitVec>(
+			bvtok![d 1, 8, 0, U8, 0],
+			"Head index 8 is out of range for a 8-bit element",
+		);
+		serde_test::assert_de_tokens_error::<BitVec>(
+			bvtok![d 1, 4, 6, U8, 0],
+			"Live bits span 2 elements, but 1 were provided",
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn limits() {
+		use crate::repr::BitVecSeed;
+		use serde::de::DeserializeSeed;
+
+		let seed = BitVecSeed::<BigEndian, u8>::new(16);
+		let read = |json: &str| {
+			let mut de = serde_json::Deserializer::from_str(json);
+			seed.deserialize(&mut de).map_err(|e| e.to_string())
+		};
+
+		assert_eq!(
+			read(r#"{"head":0,"bits":16,"data":[255,0]}"#),
+			Ok(bitvec![1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+		);
+		//  The bit count is refused before the data is read.
+		assert!(read(r#"{"head":0,"bits":18446744073709551615,"data":[]}"#)
+			.unwrap_err()
+			.starts_with("Bit count 18446744073709551615 exceeds the limit of 16"));
+		//  Data placed before the bit count is still bounded.
+		assert!(read(r#"{"data":[0,0,0,0,0],"head":0,"bits":0}"#)
+			.unwrap_err()
+			.starts_with("invalid length 5, expected A sequence of at most 4"));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn elias_fano() {
+		use crate::elias_fano::EliasFano;
+
+		//  `[1, 4, 7]` keeps one low bit of each value, and stores the high
+		//  parts `0, 2, 3` in unary.
+		let ef: EliasFano = EliasFano::from_sorted(&[1, 4, 7]);
+		assert_ser_tokens(&ef, &[
+			Token::Struct { name: "EliasFano", len: 5, },
+			Token::Str("version"), Token::U16(1),
+			Token::Str("len"), Token::U64(3),
+			Token::Str("low_bits"), Token::U8(1),
+			Token::Str("low"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::Str("head"), Token::U8(0),
+			Token::Str("bits"), Token::U64(3),
+			Token::Str("data"), Token::Seq { len: Some(1) },
+			Token::U8(0b1010_0000),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::Str("high"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::Str("head"), Token::U8(0),
+			Token::Str("bits"), Token::U64(7),
+			Token::Str("data"), Token::Seq { len: Some(1) },
+			Token::U8(0b1001_0100),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::StructEnd,
+		]);
+
+		let record = |high| [
+			Token::Struct { name: "EliasFano", len: 5, },
+			Token::BorrowedStr("version"), Token::U16(1),
+			Token::BorrowedStr("len"), Token::U64(3),
+			Token::BorrowedStr("low_bits"), Token::U8(1),
+			Token::BorrowedStr("low"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::BorrowedStr("head"), Token::U8(0),
+			Token::BorrowedStr("bits"), Token::U64(3),
+			Token::BorrowedStr("data"), Token::Seq { len: Some(1) },
+			Token::U8(0b1010_0000),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::BorrowedStr("high"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::BorrowedStr("head"), Token::U8(0),
+			Token::BorrowedStr("bits"), Token::U64(7),
+			Token::BorrowedStr("data"), Token::Seq { len: Some(1) },
+			Token::U8(high),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::StructEnd,
+		];
+		assert_de_tokens(&ef, &record(0b1001_0100));
+		//  Moving the high bit of the last value to the end of the vector
+		//  leaves its bucket unterminated.
+		serde_test::assert_de_tokens_error::<EliasFano>(
+			&record(0b1001_0010),
+			"invalid EliasFano record: high bits do not end a bucket",
+		);
+		//  Unknown versions are rejected before the rest of the record is read.
+		serde_test::assert_de_tokens_error::<EliasFano>(
+			&[
+				Token::Struct { name: "EliasFano", len: 5, },
+				Token::BorrowedStr("version"), Token::U16(2),
+			],
+			"unsupported EliasFano version 2",
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn rrr() {
+		use crate::rrr::RrrVec;
+
+		//  `[0, 1, 1]` is a single block of class 2, whose set bits at 1 and 2
+		//  give it the offset `C(1, 1) + C(2, 2) = 2`, in seven bits.
+		let rrr: RrrVec = RrrVec::from_bitslice(&bitvec![0, 1, 1]);
+		assert_ser_tokens(&rrr, &[
+			Token::Struct { name: "RrrVec", len: 4, },
+			Token::Str("version"), Token::U16(1),
+			Token::Str("len"), Token::U64(3),
+			Token::Str("classes"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::Str("head"), Token::U8(0),
+			Token::Str("bits"), Token::U64(4),
+			Token::Str("data"), Token::Seq { len: Some(1) },
+			Token::U8(0b0010_0000),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::Str("offsets"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::Str("head"), Token::U8(0),
+			Token::Str("bits"), Token::U64(7),
+			Token::Str("data"), Token::Seq { len: Some(1) },
+			Token::U8(0b0000_0100),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::StructEnd,
+		]);
+
+		let record = |offset| [
+			Token::Struct { name: "RrrVec", len: 4, },
+			Token::BorrowedStr("version"), Token::U16(1),
+			Token::BorrowedStr("len"), Token::U64(3),
+			Token::BorrowedStr("classes"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::BorrowedStr("head"), Token::U8(0),
+			Token::BorrowedStr("bits"), Token::U64(4),
+			Token::BorrowedStr("data"), Token::Seq { len: Some(1) },
+			Token::U8(0b0010_0000),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::BorrowedStr("offsets"),
+			Token::Struct { name: "BitSet", len: 3, },
+			Token::BorrowedStr("head"), Token::U8(0),
+			Token::BorrowedStr("bits"), Token::U64(7),
+			Token::BorrowedStr("data"), Token::Seq { len: Some(1) },
+			Token::U8(offset),
+			Token::SeqEnd,
+			Token::StructEnd,
+			Token::StructEnd,
+		];
+		assert_de_tokens(&rrr, &record(0b0000_0100));
+		//  There are only 105 patterns of class 2.
+		serde_test::assert_de_tokens_error::<RrrVec>(
+			&record(0b1111_1110),
+			"invalid RrrVec record: offset is out of range for its class",
+		);
+		//  Offset 104 sets bits 13 and 14, which are past the end of the
+		//  vector.
+		serde_test::assert_de_tokens_error::<RrrVec>(
+			&record(0b1101_0000),
+			"invalid RrrVec record: bits are not in canonical form",
+		);
+		serde_test::assert_de_tokens_error::<RrrVec>(
+			&[
+				Token::Struct { name: "RrrVec", len: 4, },
+				Token::BorrowedStr("version"), Token::U16(2),
+			],
+			"unsupported RrrVec version 2",
+		);
+	}
 }
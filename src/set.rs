@@ -0,0 +1,863 @@
+/*! `BitSet` structure
+
+This module holds a set of small non-negative integers, stored as a bit vector
+in which bit `n` is set when `n` is a member. Membership tests and updates are
+single bit accesses, and the set algebra operations combine whole storage
+elements at a time.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	indices::Indexable,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	clone::Clone,
+	cmp::{
+		self,
+		Eq,
+		PartialEq,
+	},
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::{
+		Extend,
+		FromIterator,
+		FusedIterator,
+		IntoIterator,
+		Iterator,
+	},
+};
+
+/** A set of `usize` values, stored as a bit vector.
+
+The set holds `n` when bit `n` of its vector is set. The vector grows when a
+value past its end is inserted, and never shrinks on its own, so the memory
+used by the set is proportional to its largest member, not to its size. Use
+[`shrink_to_fit`] to release the space above the largest member.
+
+The in-place set operations, such as [`union_with`], combine the two vectors a
+storage element at a time. The iterator forms, such as [`union`], combine one
+pair of elements at a time as they are consumed, and find the members in each
+combined element with integer instructions when `C` is `BigEndian` or
+`LittleEndian`.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  set will use to store data.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut evens: BitSet = (0 .. 10).step_by(2).collect();
+let primes: BitSet = [2, 3, 5, 7].iter().cloned().collect();
+assert!(evens.contains(4));
+assert_eq!(evens.intersection(&primes).collect::<Vec<_>>(), [2]);
+
+evens.union_with(&primes);
+assert_eq!(evens.len(), 8);
+assert_eq!(format!("{:?}", evens), "{0, 2, 3, 4, 5, 6, 7, 8}");
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`shrink_to_fit`]: #method.shrink_to_fit
+[`union`]: #method.union
+[`union_with`]: #method.union_with
+**/
+pub struct BitSet<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The membership bits. Bit `n` is set when `n` is in the set.
+	bits: BitVec<C, T>,
+}
+
+impl<C, T> BitSet<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs a new, empty, `BitSet<C, T>`.
+	///
+	/// # Returns
+	///
+	/// An empty, unallocated, `BitSet`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet = BitSet::new();
+	/// assert!(set.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self { bits: BitVec::new() }
+	}
+
+	/// Constructs a new, empty, `BitSet<C, T>` with room for the values
+	/// `0 .. capacity` without reallocating.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: One more than the largest value that the set can hold
+	///   without reallocating.
+	///
+	/// # Returns
+	///
+	/// An empty `BitSet` with the requested capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet = BitSet::with_capacity(100);
+	/// assert!(set.is_empty());
+	/// assert!(set.capacity() >= 100);
+	/// ```
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { bits: BitVec::with_capacity(capacity) }
+	}
+
+	/// Counts the values that the set can hold without reallocating.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// One more than the largest value that the set can hold without
+	/// reallocating.
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity()
+	}
+
+	/// Counts the members of the set.
+	///
+	/// This counts the set bits of the vector, a storage element at a time, so
+	/// it takes time in proportion to the largest member.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of values in the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet = [3, 30, 300].iter().cloned().collect();
+	/// assert_eq!(set.len(), 3);
+	/// ```
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set is empty.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the set has no members.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set: BitSet = BitSet::new();
+	/// set.insert(9);
+	/// assert!(!set.is_empty());
+	/// set.remove(9);
+	/// assert!(set.is_empty());
+	/// ```
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Removes every member of the set, without releasing its buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Tests if a value is in the set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The value to look up.
+	///
+	/// # Returns
+	///
+	/// Whether `value` is a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet = [1, 4].iter().cloned().collect();
+	/// assert!(set.contains(4));
+	/// assert!(!set.contains(2));
+	/// assert!(!set.contains(1000));
+	/// ```
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).unwrap_or(false)
+	}
+
+	/// Adds a value to the set, growing the set if the value is past its end.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to add.
+	///
+	/// # Returns
+	///
+	/// Whether `value` was newly added, as opposed to already present.
+	///
+	/// # Panics
+	///
+	/// This panics if the set would grow past the maximum length of a
+	/// `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set: BitSet = BitSet::new();
+	/// assert!(set.insert(70));
+	/// assert!(!set.insert(70));
+	/// assert!(set.contains(70));
+	/// ```
+	pub fn insert(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			self.bits.resize(value + 1, false);
+		}
+		let old = self.bits[value];
+		self.bits.set(value, true);
+		!old
+	}
+
+	/// Removes a value from the set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to remove.
+	///
+	/// # Returns
+	///
+	/// Whether `value` was present.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set: BitSet = [5].iter().cloned().collect();
+	/// assert!(set.remove(5));
+	/// assert!(!set.remove(5));
+	/// assert!(!set.remove(500));
+	/// ```
+	pub fn remove(&mut self, value: usize) -> bool {
+		if self.contains(value) {
+			self.bits.set(value, false);
+			true
+		}
+		else {
+			false
+		}
+	}
+
+	/// Releases the space above the largest member of the set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set: BitSet = [2, 1000].iter().cloned().collect();
+	/// set.remove(1000);
+	/// set.shrink_to_fit();
+	/// assert!(set.capacity() < 1000);
+	/// assert!(set.contains(2));
+	/// ```
+	pub fn shrink_to_fit(&mut self) {
+		let len = self.trimmed_len();
+		self.bits.truncate(len);
+		self.bits.shrink_to_fit();
+	}
+
+	/// Views the membership bits of the set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice in which bit `n` is set when `n` is in the set. The slice may
+	/// have unset bits past the largest member.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet = [0, 2].iter().cloned().collect();
+	/// assert_eq!(set.as_bitslice(), &bitvec![1, 0, 1]);
+	/// ```
+	pub fn as_bitslice(&self) -> &BitSlice<C, T> {
+		&self.bits
+	}
+
+	/// Unwraps the set into its membership bits.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The vector in which bit `n` is set when `n` is in the set.
+	pub fn into_bitvec(self) -> BitVec<C, T> {
+		self.bits
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the values in the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet<LittleEndian, u16> = [40, 7, 19].iter().cloned().collect();
+	/// assert_eq!(set.iter().collect::<Vec<_>>(), [7, 19, 40]);
+	/// ```
+	pub fn iter(&self) -> Iter<C, T> {
+		Iter::new(&self.bits, &self.bits, |a, _| a)
+	}
+
+	/// Adds every member of another set to this one.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are added.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut a: BitSet = [1, 2].iter().cloned().collect();
+	/// let b: BitSet = [2, 30].iter().cloned().collect();
+	/// a.union_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 2, 30]);
+	/// ```
+	pub fn union_with(&mut self, other: &Self) {
+		if other.bits.len() > self.bits.len() {
+			self.bits.resize(other.bits.len(), false);
+		}
+		self.bits.zip_assign(&other.bits, |mut a, b| { a |= b; a });
+	}
+
+	/// Removes every member of this set that is not in another set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are kept.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut a: BitSet = [1, 2, 30].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// a.intersect_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [2]);
+	/// ```
+	pub fn intersect_with(&mut self, other: &Self) {
+		if self.bits.len() > other.bits.len() {
+			self.bits.truncate(other.bits.len());
+		}
+		self.bits.zip_assign(&other.bits, |a, b| a & b);
+	}
+
+	/// Removes every member of another set from this one.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are removed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut a: BitSet = [1, 2, 30].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// a.difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 30]);
+	/// ```
+	pub fn difference_with(&mut self, other: &Self) {
+		self.bits.zip_assign(&other.bits, |a, b| a & !b);
+	}
+
+	/// Keeps the values that are in exactly one of this set and another.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The set whose members are toggled in this one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut a: BitSet = [1, 2, 30].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// a.symmetric_difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 3, 30]);
+	/// ```
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		if other.bits.len() > self.bits.len() {
+			self.bits.resize(other.bits.len(), false);
+		}
+		self.bits.zip_assign(&other.bits, |a, b| a ^ b);
+	}
+
+	/// Iterates over the values in either this set or another.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// An iterator over the union of the two sets, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a: BitSet = [1, 2].iter().cloned().collect();
+	/// let b: BitSet = [2, 30].iter().cloned().collect();
+	/// assert_eq!(a.union(&b).collect::<Vec<_>>(), [1, 2, 30]);
+	/// ```
+	pub fn union<'a>(&'a self, other: &'a Self) -> Iter<'a, C, T> {
+		Iter::new(&self.bits, &other.bits, |a, b| a | b)
+	}
+
+	/// Iterates over the values in both this set and another.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// An iterator over the intersection of the two sets, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a: BitSet = [1, 2, 30].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// assert_eq!(a.intersection(&b).collect::<Vec<_>>(), [2]);
+	/// ```
+	pub fn intersection<'a>(&'a self, other: &'a Self) -> Iter<'a, C, T> {
+		Iter::new(&self.bits, &other.bits, |a, b| a & b)
+	}
+
+	/// Iterates over the values in this set that are not in another.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// An iterator over the difference of the two sets, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a: BitSet = [1, 2, 30].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// assert_eq!(a.difference(&b).collect::<Vec<_>>(), [1, 30]);
+	/// ```
+	pub fn difference<'a>(&'a self, other: &'a Self) -> Iter<'a, C, T> {
+		Iter::new(&self.bits, &other.bits, |a, b| a & !b)
+	}
+
+	/// Iterates over the values in exactly one of this set and another.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// An iterator over the symmetric difference of the two sets, in ascending
+	/// order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a: BitSet = [1, 2, 30].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// assert_eq!(
+	///   a.symmetric_difference(&b).collect::<Vec<_>>(),
+	///   [1, 3, 30],
+	/// );
+	/// ```
+	pub fn symmetric_difference<'a>(&'a self, other: &'a Self)
+	-> Iter<'a, C, T> {
+		Iter::new(&self.bits, &other.bits, |a, b| a ^ b)
+	}
+
+	/// Tests if every member of this set is in another.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// Whether `self` is a subset of `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a: BitSet = [2, 3].iter().cloned().collect();
+	/// let b: BitSet = [1, 2, 3].iter().cloned().collect();
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// ```
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.difference(other).next().is_none()
+	}
+
+	/// Tests if every member of another set is in this one.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// Whether `self` is a superset of `other`.
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if this set and another have no members in common.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set.
+	///
+	/// # Returns
+	///
+	/// Whether the intersection of the two sets is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a: BitSet = [1, 9].iter().cloned().collect();
+	/// let b: BitSet = [2, 3].iter().cloned().collect();
+	/// assert!(a.is_disjoint(&b));
+	/// ```
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).next().is_none()
+	}
+
+	/// Counts the membership bits up to and including the largest member.
+	fn trimmed_len(&self) -> usize {
+		self.bits.last_one().map(|n| n + 1).unwrap_or(0)
+	}
+}
+
+impl<C, T> Clone for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		Self { bits: self.bits.clone() }
+	}
+}
+
+impl<C, T> Eq for BitSet<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> PartialEq for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	/// Tests if two sets have the same members, however much space either
+	/// holds above its largest member.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.bits[.. self.trimmed_len()] == rhs.bits[.. rhs.trimmed_len()]
+	}
+}
+
+impl<C, T> Default for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C, T> Debug for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the members of the set, as the standard library sets do.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set: BitSet = [4, 1].iter().cloned().collect();
+	/// assert_eq!(format!("{:?}", set), "{1, 4}");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<C, T> Hash for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.bits[.. self.trimmed_len()].hash(hasher)
+	}
+}
+
+impl<C, T> Extend<usize> for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item=usize> {
+		for value in src {
+			self.insert(value);
+		}
+	}
+}
+
+impl<C, T> FromIterator<usize> for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item=usize> {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<C, T> From<BitVec<C, T>> for BitSet<C, T>
+where C: Cursor, T: BitStore {
+	/// Wraps a vector as a set, whose members are the indices of its set bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let set = BitSet::from(bitvec![0, 1, 1, 0]);
+	/// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2]);
+	/// ```
+	fn from(bits: BitVec<C, T>) -> Self {
+		Self { bits }
+	}
+}
+
+impl<C, T> From<BitSet<C, T>> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from(set: BitSet<C, T>) -> Self {
+		set.into_bitvec()
+	}
+}
+
+impl<'a, C, T> IntoIterator for &'a BitSet<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+	type IntoIter = Iter<'a, C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** An iterator over the members of a set, or of a combination of two sets.
+
+Each step loads the next storage element of both sets, combines them with a
+bitwise operator, and then yields the members found in the combined element,
+so that combinations of sets are never built in memory.
+**/
+#[derive(Clone, Debug)]
+pub struct Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The membership bits of the left set.
+	lhs: &'a BitSlice<C, T>,
+	/// The membership bits of the right set.
+	rhs: &'a BitSlice<C, T>,
+	/// The bitwise operator that combines an element of each set.
+	op: fn(u64, u64) -> u64,
+	/// The result of `BitSlice::orientation` for `C` and `T`.
+	orient: Option<bool>,
+	/// The index of the next element to load.
+	next: usize,
+	/// The number of elements in the longer set.
+	end: usize,
+	/// The value of the first bit of the current element.
+	base: usize,
+	/// The members of the current element that have not yet been yielded.
+	word: u64,
+}
+
+impl<'a, C, T> Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn new(
+		lhs: &'a BitSlice<C, T>,
+		rhs: &'a BitSlice<C, T>,
+		op: fn(u64, u64) -> u64,
+	) -> Self {
+		Self {
+			lhs,
+			rhs,
+			op,
+			orient: BitSlice::<C, T>::orientation(),
+			next: 0,
+			end: cmp::max(lhs.as_slice().len(), rhs.as_slice().len()),
+			base: 0,
+			word: 0,
+		}
+	}
+
+	/// Loads an element of a set, with only its live bits.
+	///
+	/// When `C` has no orientation, the element is rearranged so that its
+	/// semantic bit `n` is at electrical position `n`, and is then searched as
+	/// a `LittleEndian` element would be.
+	fn load(&self, bits: &BitSlice<C, T>, n: usize) -> u64 {
+		let elts = bits.as_slice();
+		if n >= elts.len() {
+			return 0;
+		}
+		let width = T::BITS as usize;
+		let live = cmp::min(bits.len() - n * width, width);
+		let low = if live == 64 { !0 } else { (1u64 << live) - 1 };
+		let elt = elts[n];
+		let raw: u64 = elt.into();
+		match self.orient {
+			Some(true) => raw & (low << (width - live)),
+			Some(false) => raw & low,
+			None => (0 .. live as u8)
+				.filter(|&bit| elt.get::<C>(bit.idx()))
+				.fold(0, |acc, bit| acc | 1 << bit),
+		}
+	}
+}
+
+impl<'a, C, T> FusedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.word == 0 {
+			if self.next >= self.end {
+				return None;
+			}
+			let n = self.next;
+			self.word = (self.op)(self.load(self.lhs, n), self.load(self.rhs, n));
+			self.base = n << T::INDX;
+			self.next += 1;
+		}
+		let bit = match self.orient {
+			//  Semantic order runs from the most significant bit.
+			Some(true) => {
+				let pos = 63 - self.word.leading_zeros();
+				self.word &= !(1 << pos);
+				u32::from(T::MASK) - pos
+			},
+			_ => {
+				let pos = self.word.trailing_zeros();
+				self.word &= self.word - 1;
+				pos
+			},
+		};
+		Some(self.base + bit as usize)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let rest = (self.end - self.next) << T::INDX;
+		(0, Some(rest + self.word.count_ones() as usize))
+	}
+}
@@ -6,28 +6,51 @@ operations defined in `BitPtr`.
 The `&BitSlice` handle has the same size and general layout as the standard Rust
 slice handle `&[T]`. Its binary layout is wholly incompatible with the layout of
 Rust slices, and must never be interchanged except through the provided APIs.
+
+# Iteration Order
+
+Every iterator in this module is deterministic. Iterating from the front visits
+bits in increasing index order: the `C` ordering within each element, and
+increasing address order across elements. Iterating from the back visits the
+same items in exactly the opposite order. Iterators that produce subslices, such
+as `chunks`, `windows`, and `iter_elements`, keep index order within each
+subslice in both directions; only the order of the subslices is reversed.
 !*/
 
 use crate::{
 	access::BitAccess,
+	cells::BitCells,
 	cursor::{
 		BigEndian,
 		Cursor,
+		Reorder,
 	},
 	domain::*,
+	fields::BitField,
 	indices::Indexable,
 	pointer::BitPtr,
+	span::BitSpan,
 	store::BitStore,
 };
 
+pub use crate::domain::{
+	Domain,
+	DomainMut,
+};
+
 #[cfg(feature = "alloc")]
 use {
 	crate::vec::BitVec,
-	alloc::borrow::ToOwned,
+	alloc::{
+		borrow::ToOwned,
+		string::String,
+		vec::Vec,
+	},
 };
 
 use core::{
 	cmp::{
+		self,
 		Eq,
 		Ord,
 		Ordering,
@@ -43,7 +66,6 @@ use core::{
 	fmt::{
 		self,
 		Debug,
-		DebugList,
 		Display,
 		Formatter,
 	},
@@ -57,18 +79,17 @@ use core::{
 		FusedIterator,
 		Iterator,
 		IntoIterator,
+		Map,
+		Rev,
 	},
-	marker::{
-		PhantomData,
-		Send,
-		Sync,
-	},
+	marker::PhantomData,
 	mem,
 	ops::{
 		AddAssign,
 		BitAndAssign,
 		BitOrAssign,
 		BitXorAssign,
+		Bound,
 		Deref,
 		DerefMut,
 		Drop,
@@ -79,6 +100,7 @@ use core::{
 		Range,
 		RangeFrom,
 		RangeFull,
+		RangeBounds,
 		RangeInclusive,
 		RangeTo,
 		RangeToInclusive,
@@ -89,6 +111,12 @@ use core::{
 	str,
 };
 
+#[cfg(feature = "atomic")]
+use core::marker::{
+	Send,
+	Sync,
+};
+
 /** A compact slice of bits, whose cursor and storage types can be customized.
 
 `BitSlice` is a specialized slice type, which can only ever be held by
@@ -145,6 +173,46 @@ assert_eq!(base[1], 4);
   `u64` (64-bit systems only). This is the actual type in memory that the slice
   will use to store data.
 
+# Integer Comparison
+
+A `BitSlice` can be compared with `==` and `<` against the unsigned integers
+`u8` through `u128`, which is convenient for short test patterns. The slice is
+read as an unsigned integer in the significance order of [`BitField::load`],
+which depends on the cursor:
+
+- `BigEndian`: index `0` is the most significant bit, and the last index is the
+  least significant bit, so the slice reads as it prints.
+- `LittleEndian`, and every other cursor: index `0` is the least significant
+  bit, and the last index is the most significant bit.
+
+A slice shorter than the integer is zero-extended. A full slice over an element
+therefore compares equal to that element’s value under either cursor, and a
+slice compares equal to an integer exactly when `load` reads that integer from
+it. The `add_assign_carry`, `div_rem_assign`, and `to_decimal_string` methods
+read slices in the same order; the `+=` and `-` operators do not, and always
+treat index `0` as the most significant bit.
+
+A slice that is longer than the integer has no value in that type: it compares
+unequal to every integer of the type, and `partial_cmp` returns `None`. This
+never panics.
+
+```rust
+use bitvec::prelude::*;
+
+let bv = bitvec![1, 0, 1, 0];
+assert_eq!(bv, 0b1010u8);
+assert!(bv[1 ..] < 3u16);
+assert_eq!(0b1011_0000u8.as_bitslice::<BigEndian>(), 0xB0u8);
+assert_eq!(0b1011_0000u8.as_bitslice::<LittleEndian>(), 0xB0u8);
+assert!(bitvec![0; 9] != 0u8);
+
+let mut le = bitvec![LittleEndian, u8; 1, 0, 1, 0, 0, 0, 0, 0];
+assert_eq!(le, 5u8);
+assert_eq!(le.load::<u8>(), 5);
+assert!(!le.increment());
+assert_eq!(le, 6u8);
+```
+
 # Safety
 
 The `&BitSlice` reference handle has the same *size* as standard Rust slice
@@ -152,6 +220,7 @@ handles, but it is ***extremely binary incompatible*** with them. Attempting to
 treat `&BitSlice<_, T>` as `&[T]` in any manner except through the provided APIs
 is ***catastrophically*** unsafe and unsound.
 
+[`BitField::load`]: ../fields/trait.BitField.html#tymethod.load
 [`BitVec`]: ../vec/struct.BitVec.html
 [`Bits`]: ../bits/trait.Bits.html
 [`BitsMut`]: ../bits/trait.BitsMut.html
@@ -166,8 +235,10 @@ where C: Cursor, T: BitStore {
 	/// Element type of the slice.
 	///
 	/// eddyb recommends using `PhantomData<T>` and `[()]` instead of `[T]`
-	/// alone.
-	_type: PhantomData<T>,
+	/// alone. The marker is a pointer so that `BitSlice` is neither `Send` nor
+	/// `Sync` by default: only the `atomic` feature makes access to shared
+	/// elements thread-safe, and it restores both traits below.
+	_type: PhantomData<*const T>,
 	/// Slice of elements `T` over which the `BitSlice` has usage.
 	_elts: [()],
 }
@@ -596,17 +667,23 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
-	/// Gets the bit value at the given position.
+	/// Gets the bit value at the given position, or a subslice over the given
+	/// range.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `index`: The bit index to retrieve.
+	/// - `index`: A bit index, or any of the standard ranges of bit indices.
 	///
 	/// # Returns
 	///
-	/// The bit at the specified index, if any. If `index` is beyond the bounds
-	/// of `self`, then `None` is produced.
+	/// The bit at the specified index, or the subslice over the specified
+	/// range, if it lies inside `self`. If `index` is out of bounds, or is a
+	/// range whose start is after its end, then `None` is produced.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: BitSliceIndex`: A `usize` index, or a range of `usize` indices.
 	///
 	/// # Examples
 	///
@@ -617,14 +694,83 @@ where C: Cursor, T: BitStore {
 	/// assert!(bits.get(4).unwrap());
 	/// assert!(!bits.get(3).unwrap());
 	/// assert!(bits.get(10).is_none());
+	///
+	/// assert_eq!(bits.get(2 .. 6).unwrap().count_ones(), 1);
+	/// assert!(bits.get(4 ..= 8).is_none());
 	/// ```
-	pub fn get(&self, index: usize) -> Option<bool> {
-		if index >= self.len() {
-			None
-		}
-		else {
-			Some(unsafe { self.get_unchecked(index) })
-		}
+	pub fn get<'a, I>(&'a self, index: I) -> Option<I::Immut>
+	where I: BitSliceIndex<'a, C, T> {
+		index.get(self)
+	}
+
+	/// Gets a write reference to the bit at the given position, or a mutable
+	/// subslice over the given range.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: A bit index, or any of the standard ranges of bit indices.
+	///
+	/// # Returns
+	///
+	/// A write guard over the bit at the specified index (see [`at`]), or the
+	/// mutable subslice over the specified range, if it lies inside `self`. If
+	/// `index` is out of bounds, or is a range whose start is after its end,
+	/// then `None` is produced.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: BitSliceIndex`: A `usize` index, or a range of `usize` indices.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	///
+	/// *bits.get_mut(1).unwrap() = true;
+	/// bits.get_mut(4 ..).unwrap().set_all(true);
+	/// assert!(bits.get_mut(8).is_none());
+	/// assert_eq!(src, 0b0100_1111);
+	/// ```
+	///
+	/// [`at`]: #method.at
+	pub fn get_mut<'a, I>(&'a mut self, index: I) -> Option<I::Mut>
+	where I: BitSliceIndex<'a, C, T> {
+		index.get_mut(self)
+	}
+
+	/// Looks up a bit at an index, returning `None` rather than panicking if
+	/// the index is out of bounds.
+	///
+	/// This is the checked counterpart of `self[index]`, named for symmetry
+	/// with the other `checked_` methods. It is equivalent to [`get`] with a
+	/// single index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to retrieve.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, if `index` is less than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x80u8.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.checked_index(0), Some(true));
+	/// assert_eq!(bits.checked_index(8), None);
+	/// ```
+	///
+	/// [`get`]: #method.get
+	pub fn checked_index(&self, index: usize) -> Option<bool> {
+		self.get(index)
 	}
 
 	/// Looks up a bit at an index, without doing bounds checking.
@@ -713,8 +859,9 @@ where C: Cursor, T: BitStore {
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `index`: The bit index to retrieve. This index is *not* checked
-	///   against the length of `self`.
+	/// - `index`: The bit index to set. This index is *not* checked against
+	///   the length of `self`.
+	/// - `value`: The value to be set, `true` for `1` and `false` for `0`.
 	///
 	/// # Effects
 	///
@@ -756,6 +903,67 @@ where C: Cursor, T: BitStore {
 		(&*data_ptr.offset(elt)).set::<C>(bit, value);
 	}
 
+	/// Sets many bits, named by their indices, to the same value.
+	///
+	/// Consecutive indices which fall in the same storage element are gathered
+	/// into one mask, and written to memory together. When the indices are
+	/// sorted, as in a posting list of document IDs, each element is written
+	/// at most once, no matter how many of its bits are named. Unsorted indices
+	/// are still written correctly, but gain less from the batching.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `indices`: The indices of the bits to set. Each must be in the domain
+	///   `0 .. self.len()`. Repeated indices are permitted.
+	/// - `value`: The value to be written into each named bit.
+	///
+	/// # Panics
+	///
+	/// This panics if any index is outside the slice domain. The bits named
+	/// before the failing index may or may not have been written.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let postings = [3, 5, 6, 7, 12, 40, 41];
+	/// let mut bv = bitvec![0; 48];
+	/// bv[2 ..].set_indices(postings.iter().cloned(), true);
+	/// assert_eq!(bv.count_ones(), postings.len());
+	/// assert!(postings.iter().all(|&n| bv[n + 2]));
+	///
+	/// bv.set_indices(vec![45, 7, 7, 9], false);
+	/// assert!(!bv[7] && !bv[9] && !bv[45]);
+	/// ```
+	pub fn set_indices<I>(&mut self, indices: I, value: bool)
+	where I: IntoIterator<Item=usize> {
+		let len = self.len();
+		let bitptr = self.bitptr();
+		let head = bitptr.head();
+		let data = bitptr.pointer().a();
+		//  The element offset and mask of bits waiting to be written.
+		let mut pending: Option<(isize, T)> = None;
+		for index in indices {
+			assert!(index < len, "Index out of range: {} >= {}", index, len);
+			let (elt, bit) = head.offset(index as isize);
+			let mask = *C::mask(bit);
+			match pending {
+				Some((cur, ref mut acc)) if cur == elt => *acc |= mask,
+				_ => {
+					if let Some((cur, acc)) = pending {
+						unsafe { (&*data.offset(cur)).set_bits(acc, value) };
+					}
+					pending = Some((elt, mask));
+				},
+			}
+		}
+		if let Some((cur, acc)) = pending {
+			unsafe { (&*data.offset(cur)).set_bits(acc, value) };
+		}
+	}
+
 	/// Produces a write reference to a single bit in the slice.
 	///
 	/// The structure returned by this method extends the borrow until it drops,
@@ -829,8 +1037,8 @@ where C: Cursor, T: BitStore {
 	/// and the rest) in order to hold multiple write references into the slice.
 	///
 	/// [`split_at_mut`]: #method.split_at_mut
-	pub fn at(&mut self, index: usize) -> BitGuard<C, T> {
-		BitGuard {
+	pub fn at(&mut self, index: usize) -> BitMut<C, T> {
+		BitMut {
 			_m: PhantomData,
 			bit: self[index],
 			slot: &mut self[index ..= index],
@@ -948,6 +1156,103 @@ where C: Cursor, T: BitStore {
 		self.set(b, bit_a);
 	}
 
+	/// Swaps the contents of two slices.
+	///
+	/// When both slices begin at the same bit position within their first
+	/// element, the interior elements are exchanged whole, rather than bit by
+	/// bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The slice with which to exchange contents.
+	///
+	/// # Panics
+	///
+	/// This panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut a = [0u8; 3];
+	/// let mut b = [!0u8; 3];
+	/// a.as_mut_bitslice::<BigEndian>()[2 ..]
+	///   .swap_with_bitslice(&mut b.as_mut_bitslice::<BigEndian>()[2 ..]);
+	/// assert_eq!(a, [0x3F, 0xFF, 0xFF]);
+	/// assert_eq!(b, [0xC0, 0, 0]);
+	/// ```
+	pub fn swap_with_bitslice(&mut self, other: &mut Self) {
+		let len = self.len();
+		assert_eq!(len, other.len(), "Swapped slices must have equal lengths");
+		let mut done = 0;
+		if self.bitptr().head() == other.bitptr().head() {
+			let bits = T::BITS as usize;
+			//  Swap bit by bit up to the first element boundary, then swap
+			//  every whole element after it.
+			let head = *self.bitptr().head() as usize;
+			let edge = cmp::min(len, (bits - head) & T::MASK as usize);
+			for n in 0 .. edge {
+				self.swap_bit_with(other, n);
+			}
+			let span = edge .. edge + (len - edge) / bits * bits;
+			done = span.end;
+			if span.start < span.end {
+				self[span.clone()].as_mut_slice()
+					.swap_with_slice(other[span].as_mut_slice());
+			}
+		}
+		for n in done .. len {
+			self.swap_bit_with(other, n);
+		}
+	}
+
+	/// Swaps two disjoint, equal-length ranges of the slice.
+	///
+	/// The ranges are exchanged with [`swap_with_bitslice`], and so move whole
+	/// elements at a time when they begin at the same bit position within an
+	/// element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `a`: The first range to be swapped.
+	/// - `b`: The second range to be swapped.
+	///
+	/// # Panics
+	///
+	/// This panics if either range leaves the slice, if the ranges have
+	/// different lengths, or if they overlap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b1110_0000u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// bits.swap_ranges(0 .. 3, 5 ..);
+	/// assert_eq!(src, 0b0000_0111);
+	/// ```
+	///
+	/// [`swap_with_bitslice`]: #method.swap_with_bitslice
+	pub fn swap_ranges<R1, R2>(&mut self, a: R1, b: R2)
+	where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+		let (a, b) = (self.bounds(a), self.bounds(b));
+		let len = a.end - a.start;
+		assert_eq!(len, b.end - b.start, "Swapped ranges must have equal lengths");
+		assert!(
+			a.end <= b.start || b.end <= a.start,
+			"Swapped ranges must not overlap: {:?} and {:?}",
+			a,
+			b,
+		);
+		let (front, back) = if a.start < b.start { (a, b) } else { (b, a) };
+		let (head, tail) = self.split_at_mut(back.start);
+		head[front].swap_with_bitslice(&mut tail[.. len]);
+	}
+
 	/// Reverses the order of bits in the slice, in place.
 	///
 	/// # Parameters
@@ -986,6 +1291,57 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Reverses the order of bits within each storage element, in place.
+	///
+	/// The bit at index `n` of each element moves to index `T::BITS - 1 - n`
+	/// of the same element, according to the cursor `C`. Whole elements are
+	/// rewritten with a few shifts and masks, rather than bit by bit. The
+	/// partial elements at either edge of the slice reverse only those of
+	/// their bits that are in the slice, and bits outside the slice are not
+	/// modified.
+	///
+	/// When the slice holds whole elements, this produces elements that hold
+	/// the same sequence of bits under the opposite cursor, and it performs
+	/// the byte reflection of the CRC algorithms that shift out the least
+	/// significant bit first.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0x01u8, 0xC8, 0x0F];
+	/// let before = data.as_bitslice::<BigEndian>().to_owned();
+	/// data.as_mut_bitslice::<BigEndian>().reverse_bits_in_elements();
+	/// assert_eq!(data, [0x80, 0x13, 0xF0]);
+	/// assert_eq!(data.as_bitslice::<LittleEndian>(), before);
+	///
+	/// let mut word = 0x00FFu16;
+	/// word.as_mut_bitslice::<LittleEndian>()[4 ..].reverse_bits_in_elements();
+	/// assert_eq!(word, 0xF00F);
+	/// ```
+	pub fn reverse_bits_in_elements(&mut self) {
+		let bits = T::BITS as usize;
+		let head = *self.bitptr().head() as usize;
+		let mut rest = self;
+		if head != 0 {
+			let mid = cmp::min(bits - head, rest.len());
+			let (edge, body) = rest.split_at_mut(mid);
+			edge.reverse();
+			rest = body;
+		}
+		let (body, edge) = rest.split_at_mut(rest.len() / bits * bits);
+		edge.reverse();
+		let plan = Reorder::reversal::<C, T>();
+		for elt in body.as_mut_slice() {
+			*elt = plan.apply(*elt);
+		}
+	}
+
 	/// Provides read-only iteration across the slice domain.
 	///
 	/// The iterator returned from this method implements `ExactSizeIterator`
@@ -1016,6 +1372,121 @@ where C: Cursor, T: BitStore {
 		self.into_iter()
 	}
 
+	/// Provides mutable iteration across the slice domain.
+	///
+	/// Each bit is produced as a [`BitMut`] proxy, which writes its value back
+	/// into the slice when it drops. Like `.iter()`, the returned iterator
+	/// implements `ExactSizeIterator` and `DoubleEndedIterator`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// An iterator over write proxies for all bits in the slice domain, in `C`
+	/// and `T` ordering.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b1101_0101u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// for (n, mut bit) in bits[2 ..].iter_mut().enumerate() {
+	///   *bit ^= n % 2 == 0;
+	/// }
+	/// assert_eq!(src, 0b1111_1111);
+	/// ```
+	///
+	/// [`BitMut`]: struct.BitMut.html
+	pub fn iter_mut(&mut self) -> IterMut<C, T> {
+		self.into_iter()
+	}
+
+	/// Provides read-only iteration across the storage elements of the slice.
+	///
+	/// Each item is the subslice of `self` that lives in one storage element,
+	/// so every item but the first and last is exactly `T::BITS` long. The
+	/// items are produced from the lowest address to the highest, and the bits
+	/// within each item are in `C` ordering, exactly as `.iter()` would yield
+	/// them. Concatenating the items reproduces `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the per-element subslices of `self`. It implements
+	/// `ExactSizeIterator` and `DoubleEndedIterator`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x0Fu8, 0xF0, 0xAA];
+	/// let bits = &src.as_bitslice::<BigEndian>()[4 .. 20];
+	/// let mut elts = bits.iter_elements();
+	/// assert_eq!(elts.len(), 3);
+	/// assert_eq!(elts.next(), Some(&bits[.. 4]));
+	/// assert_eq!(elts.next(), Some(&bits[4 .. 12]));
+	/// assert_eq!(elts.next(), Some(&bits[12 ..]));
+	/// assert!(elts.next().is_none());
+	/// ```
+	pub fn iter_elements(&self) -> Elements<C, T> {
+		Elements {
+			inner: self,
+		}
+	}
+
+	/// Provides read-only iteration across the storage elements of the slice,
+	/// from the back.
+	///
+	/// This produces the same items as [`iter_elements`], in the opposite
+	/// order: the element at the highest address comes first. Only the order
+	/// of the elements is reversed; the bits within each item remain in `C`
+	/// ordering, so a backward scan can walk memory in reverse while still
+	/// reading each element forward, rather than paying for a bit-by-bit
+	/// reverse iterator.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the per-element subslices of `self`, last first.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x01u16, 0x8000];
+	/// let bits = &src.as_bitslice::<LittleEndian>()[.. 20];
+	/// //  Find the last set bit by scanning whole elements backwards.
+	/// let mut end = bits.len();
+	/// let last = bits.reverse_iter_elements()
+	///   .find_map(|elt| {
+	///     end -= elt.len();
+	///     elt.iter().rposition(|b| b).map(|n| end + n)
+	///   });
+	/// assert_eq!(last, Some(0));
+	///
+	/// let mut elts = bits.reverse_iter_elements();
+	/// assert_eq!(elts.next(), Some(&bits[16 ..]));
+	/// assert_eq!(elts.next(), Some(&bits[.. 16]));
+	/// assert!(elts.next().is_none());
+	/// ```
+	///
+	/// [`iter_elements`]: #method.iter_elements
+	pub fn reverse_iter_elements(&self) -> Rev<Elements<C, T>> {
+		self.iter_elements().rev()
+	}
+
 	/// Produces a sliding iterator over consecutive windows in the slice. Each
 	/// windows has the width `size`. The windows overlap. If the slice is
 	/// shorter than `size`, the produced iterator is empty.
@@ -1128,6 +1599,27 @@ where C: Cursor, T: BitStore {
 	/// }
 	/// assert_eq!(src, 0b0110_1110);
 	/// ```
+	///
+	/// When the `atomic` feature is enabled, the chunks are `Send`, even where
+	/// neighboring chunks share an element, and so can be handed out to worker
+	/// threads. See [`split_at_mut`] for details.
+	///
+	/// ```rust
+	/// # #[cfg(all(feature = "atomic", feature = "std"))] {
+	/// use bitvec::prelude::*;
+	/// use std::thread;
+	///
+	/// let bits = bitbox![0; 30].leak();
+	/// let workers = bits.chunks_mut(7)
+	///   .map(|chunk| thread::spawn(move || { chunk.set(0, true); chunk }))
+	///   .collect::<Vec<_>>();
+	/// for worker in workers {
+	///   assert_eq!(worker.join().unwrap().count_ones(), 1);
+	/// }
+	/// # }
+	/// ```
+	///
+	/// [`split_at_mut`]: #method.split_at_mut
 	pub fn chunks_mut(&mut self, size: usize) -> ChunksMut<C, T> {
 		assert_ne!(size, 0, "Chunk width cannot be zero");
 		ChunksMut {
@@ -1179,42 +1671,109 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
-	/// Produces a galloping iterator over consecutive chunks in the slice. Each
-	/// chunk has the width `size`. If `size` does not evenly divide the slice,
-	/// then the remainder is not part of the iteration, and can be accessed
-	/// separately with the `.remainder()` method.
+	/// Produces an iterator over consecutive integer fields in the slice.
+	///
+	/// Each field is `width` bits wide, and is read as an unsigned integer in
+	/// the bit significance order of the cursor: `BigEndian` slices read the
+	/// first bit of a field as its most significant bit, and all other cursors
+	/// read it as the least significant bit. A field which fills an element
+	/// therefore has the value of that element. If `width` does not evenly
+	/// divide the slice, the bits left over after the last whole field are
+	/// available from the iterator’s `.remainder()` method.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `size`: The width of each chunk.
+	/// - `&self`
+	/// - `width`: The number of bits in each field.
 	///
 	/// # Returns
 	///
-	/// An iterator which yields consecutive mutable chunks of the slice.
+	/// An iterator which yields the value of each whole field in the slice.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type of the field values.
 	///
 	/// # Panics
 	///
-	/// This function panics if `size` is zero.
+	/// This function panics if `width` is zero, or is wider than `U`.
 	///
 	/// # Examples
 	///
+	/// Twelve-bit samples, packed most significant bit first:
+	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0b0100_1011u8;
-	/// {
-	///  let bits = src.as_mut_bitslice::<BigEndian>();
-	///  let mut chunks_exact = bits.chunks_exact_mut(3);
-	///  chunks_exact.next().unwrap().set(2, true);
-	///  chunks_exact.next().unwrap().set(2, true);
-	///  assert!(chunks_exact.next().is_none());
-	/// }
-	/// assert_eq!(src, 0b0110_1111);
+	/// let data = [0xABu8, 0xCD, 0xEF, 0x50];
+	/// let bits = data.as_bitslice::<BigEndian>();
+	/// let mut samples = bits.iter_fields::<u16>(12);
+	/// assert_eq!(samples.next(), Some(0xABC));
+	/// assert_eq!(samples.next(), Some(0xDEF));
+	/// assert!(samples.next().is_none());
+	/// assert_eq!(samples.remainder(), &bits[24 ..]);
 	/// ```
-	pub fn chunks_exact_mut(&mut self, size: usize) -> ChunksExactMut<C, T> {
-		assert_ne!(size, 0, "Chunk size cannot be zero");
-		let rem = self.len() % size;
+	///
+	/// and least significant bit first:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xBCu8, 0xFA, 0xDE];
+	/// let bits = data.as_bitslice::<LittleEndian>();
+	/// let samples = bits.iter_fields::<u16>(12).collect::<Vec<_>>();
+	/// assert_eq!(samples, [0xABC, 0xDEF]);
+	/// ```
+	pub fn iter_fields<U>(&self, width: usize) -> Fields<C, T, U>
+	where U: BitStore {
+		assert!(
+			width != 0 && width <= U::BITS as usize,
+			"Field width {} must be between 1 and {} bits",
+			width,
+			U::BITS,
+		);
+		Fields {
+			inner: self.chunks_exact(width),
+			_ty: PhantomData,
+		}
+	}
+
+	/// Produces a galloping iterator over consecutive chunks in the slice. Each
+	/// chunk has the width `size`. If `size` does not evenly divide the slice,
+	/// then the remainder is not part of the iteration, and can be accessed
+	/// separately with the `.remainder()` method.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `size`: The width of each chunk.
+	///
+	/// # Returns
+	///
+	/// An iterator which yields consecutive mutable chunks of the slice.
+	///
+	/// # Panics
+	///
+	/// This function panics if `size` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b0100_1011u8;
+	/// {
+	///  let bits = src.as_mut_bitslice::<BigEndian>();
+	///  let mut chunks_exact = bits.chunks_exact_mut(3);
+	///  chunks_exact.next().unwrap().set(2, true);
+	///  chunks_exact.next().unwrap().set(2, true);
+	///  assert!(chunks_exact.next().is_none());
+	/// }
+	/// assert_eq!(src, 0b0110_1111);
+	/// ```
+	pub fn chunks_exact_mut(&mut self, size: usize) -> ChunksExactMut<C, T> {
+		assert_ne!(size, 0, "Chunk size cannot be zero");
+		let rem = self.len() % size;
 		let len = self.len() - rem;
 		let (inner, extra) = self.split_at_mut(len);
 		ChunksExactMut {
@@ -1464,11 +2023,162 @@ where C: Cursor, T: BitStore {
 	/// # Panics
 	///
 	/// Panics if `mid > self.len()`.
+	///
+	/// # Concurrency
+	///
+	/// The two halves may share an element of memory, if `mid` does not fall on
+	/// an element boundary. When the `atomic` feature is enabled, `BitSlice`
+	/// performs all of its writes through atomic instructions, so both halves
+	/// are `Send` and may be moved to, and written by, different threads. In
+	/// builds without `atomic`, `BitSlice` is neither `Send` nor `Sync`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(all(feature = "atomic", feature = "std"))] {
+	/// use bitvec::prelude::*;
+	/// use std::thread;
+	///
+	/// //  `thread::spawn` requires `'static` borrows, so this leaks its buffer.
+	/// let bits = bitbox![0; 16].leak();
+	/// let (l, r) = bits.split_at_mut(5);
+	/// let a = thread::spawn(move || { l.set_all(true); l });
+	/// let b = thread::spawn(move || { r.set(0, true); r });
+	/// let (l, r) = (a.join().unwrap(), b.join().unwrap());
+	/// assert!(l.all());
+	/// assert_eq!(r.count_ones(), 1);
+	/// # }
+	/// ```
 	pub fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
 		let (head, tail) = self.split_at(mid);
 		(head.bitptr().into_bitslice_mut(), tail.bitptr().into_bitslice_mut())
 	}
 
+	/// Divides one slice into two at an index, returning `None` rather than
+	/// panicking if the index is out of bounds.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mid`: The index at which to split.
+	///
+	/// # Returns
+	///
+	/// The halves that [`split_at`] would produce, if `mid` is at most
+	/// `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x0Fu8.as_bitslice::<BigEndian>();
+	/// let (l, r) = bits.checked_split_at(4).unwrap();
+	/// assert!(l.not_any());
+	/// assert!(r.all());
+	/// assert!(bits.checked_split_at(9).is_none());
+	/// ```
+	///
+	/// [`split_at`]: #method.split_at
+	pub fn checked_split_at(&self, mid: usize) -> Option<(&Self, &Self)> {
+		if mid <= self.len() {
+			Some(self.split_at(mid))
+		}
+		else {
+			None
+		}
+	}
+
+	/// Divides one mutable slice into two at an index, returning `None` rather
+	/// than panicking if the index is out of bounds.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mid`: The index at which to split.
+	///
+	/// # Returns
+	///
+	/// The halves that [`split_at_mut`] would produce, if `mid` is at most
+	/// `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// if let Some((_, r)) = bits.checked_split_at_mut(6) {
+	///   r.set_all(true);
+	/// }
+	/// assert!(bits.checked_split_at_mut(9).is_none());
+	/// assert_eq!(src, 0b0000_0011);
+	/// ```
+	///
+	/// [`split_at_mut`]: #method.split_at_mut
+	pub fn checked_split_at_mut(&mut self, mid: usize)
+	-> Option<(&mut Self, &mut Self)> {
+		if mid <= self.len() {
+			Some(self.split_at_mut(mid))
+		}
+		else {
+			None
+		}
+	}
+
+	/// Shortens the slice to at most `len` bits, keeping the front.
+	///
+	/// Where `&self[.. len]` panics if `len` is greater than the length of the
+	/// slice, this clamps `len` to the length, and returns the whole slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `len`: The maximum length of the produced slice.
+	///
+	/// # Returns
+	///
+	/// The first `len` bits of `self`, or all of `self` if it is shorter.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0u16.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits.saturating_truncate(5).len(), 5);
+	/// assert_eq!(bits.saturating_truncate(100).len(), 16);
+	/// ```
+	pub fn saturating_truncate(&self, len: usize) -> &Self {
+		&self[.. cmp::min(len, self.len())]
+	}
+
+	/// Shortens the mutable slice to at most `len` bits, keeping the front.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The maximum length of the produced slice.
+	///
+	/// # Returns
+	///
+	/// The first `len` bits of `self`, or all of `self` if it is shorter.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// src.as_mut_bitslice::<BigEndian>().saturating_truncate_mut(20).set_all(true);
+	/// assert_eq!(src, !0);
+	/// ```
+	pub fn saturating_truncate_mut(&mut self, len: usize) -> &mut Self {
+		let len = cmp::min(len, self.len());
+		&mut self[.. len]
+	}
+
 	/// Tests if the slice begins with the given prefix.
 	///
 	/// # Parameters
@@ -1616,6 +2326,87 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Reorders the slice, in place, by a permutation of its indices.
+	///
+	/// After calling this method, the bit at index `n` is the bit that was at
+	/// index `perm[n]`. This is the order produced by sorting a list of indices
+	/// by a key, so a permutation computed once, such as a column ordering, can
+	/// be applied to many slices.
+	///
+	/// The permutation is applied by following each of its cycles, which
+	/// moves every bit exactly once and holds only one bit in flight. The
+	/// cycles already followed are recorded by marking the top bit of their
+	/// entries in `perm`, which no valid index uses, so no other memory is
+	/// needed. Every mark is removed before the method returns, and `perm` is
+	/// left unchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `perm`: A permutation of `0 .. self.len()`. It is borrowed mutably
+	///   only to hold the marks.
+	///
+	/// # Panics
+	///
+	/// Panics if `perm` is not the same length as `self`, or if it is not a
+	/// permutation of `0 .. self.len()`. The check runs before any bit moves,
+	/// so `self` is not modified when this panics.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0, 1, 1, 0];
+	/// let mut perm = [2, 4, 3, 0, 1];
+	/// bv.apply_permutation_in_place(&mut perm);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 0, 0]);
+	/// assert_eq!(perm, [2, 4, 3, 0, 1]);
+	/// ```
+	pub fn apply_permutation_in_place(&mut self, perm: &mut [usize]) {
+		let len = self.len();
+		assert_eq!(len, perm.len(), "Permutation length must match the slice");
+		let mark = !(!0usize >> 1);
+		if let Some(bad) = perm.iter().find(|&&n| n >= len) {
+			panic!("Permutation entry {} is out of range for {}", bad, len);
+		}
+		//  Each entry marks the entry that it names. An entry that is already
+		//  marked has been named twice.
+		let mut repeat = None;
+		for n in 0 .. len {
+			let to = perm[n] & !mark;
+			if perm[to] & mark != 0 {
+				repeat = Some(to);
+				break;
+			}
+			perm[to] |= mark;
+		}
+		perm.iter_mut().for_each(|n| *n &= !mark);
+		if let Some(n) = repeat {
+			panic!("Permutation names index {} more than once", n);
+		}
+
+		for start in 0 .. len {
+			if perm[start] & mark != 0 {
+				continue;
+			}
+			let tmp = self[start];
+			let mut here = start;
+			loop {
+				let from = perm[here];
+				perm[here] |= mark;
+				if from == start {
+					self.set(here, tmp);
+					break;
+				}
+				let bit = self[from];
+				self.set(here, bit);
+				here = from;
+			}
+		}
+		perm.iter_mut().for_each(|n| *n &= !mark);
+	}
+
 	/// Tests if *all* bits in the slice domain are set (logical `∧`).
 	///
 	/// # Truth Table
@@ -1646,49 +2437,28 @@ where C: Cursor, T: BitStore {
 	/// assert!(!bits[4 ..].all());
 	/// ```
 	pub fn all(&self) -> bool {
+		let ones = T::bits(true);
+		//  The live bits of an edge element are tested with a single load.
+		let full = |elt: &T::Access, from: u8, upto: u8| {
+			let mask = Self::live_mask(from, upto);
+			elt.load() & mask == mask
+		};
 		match self.bitptr().domain() {
-			BitDomain::Empty => {},
-			BitDomain::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					if !elt.get::<C>(n.idx()) {
-						return false;
-					}
-				}
-			},
+			BitDomain::Empty => true,
+			BitDomain::Minor(head, elt, tail) => full(elt, *head, *tail),
 			BitDomain::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					if !head.get::<C>(n.idx()) {
-						return false;
-					}
-				}
-				for n in 0 .. *t {
-					if !tail.get::<C>(n.idx()) {
-						return false;
-					}
-				}
-				return body.iter().all(|e| *e == T::bits(true));
+				full(head, *h, T::BITS)
+				&& full(tail, 0, *t)
+				&& body.iter().all(|e| *e == ones)
 			},
 			BitDomain::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					if !head.get::<C>(n.idx()) {
-						return false;
-					}
-				}
-				return body.iter().all(|e| *e == T::bits(true));
+				full(head, *h, T::BITS) && body.iter().all(|e| *e == ones)
 			},
 			BitDomain::PartialTail(body, tail, t) => {
-				for n in 0 .. *t {
-					if !tail.get::<C>(n.idx()) {
-						return false;
-					}
-				}
-				return body.iter().all(|e| *e == T::bits(true));
-			},
-			BitDomain::Spanning(body) => {
-				return body.iter().all(|e| *e == T::bits(true));
+				full(tail, 0, *t) && body.iter().all(|e| *e == ones)
 			},
+			BitDomain::Spanning(body) => body.iter().all(|e| *e == ones),
 		}
-		true
 	}
 
 	/// Tests if *any* bit in the slice is set (logical `∨`).
@@ -1721,49 +2491,27 @@ where C: Cursor, T: BitStore {
 	/// assert!(!bits[4 ..].any());
 	/// ```
 	pub fn any(&self) -> bool {
+		let zero = T::bits(false);
+		//  The live bits of an edge element are tested with a single load.
+		let some = |elt: &T::Access, from: u8, upto: u8| {
+			elt.load() & Self::live_mask(from, upto) != zero
+		};
 		match self.bitptr().domain() {
-			BitDomain::Empty => {},
-			BitDomain::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					if elt.get::<C>(n.idx()) {
-						return true;
-					}
-				}
-			},
+			BitDomain::Empty => false,
+			BitDomain::Minor(head, elt, tail) => some(elt, *head, *tail),
 			BitDomain::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					if head.get::<C>(n.idx()) {
-						return true;
-					}
-				}
-				for n in 0 .. *t {
-					if tail.get::<C>(n.idx()) {
-						return true;
-					}
-				}
-				return body.iter().any(|e| *e != T::bits(false));
+				some(head, *h, T::BITS)
+				|| some(tail, 0, *t)
+				|| body.iter().any(|e| *e != zero)
 			},
 			BitDomain::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					if head.get::<C>(n.idx()) {
-						return true;
-					}
-				}
-				return body.iter().any(|e| *e != T::bits(false));
+				some(head, *h, T::BITS) || body.iter().any(|e| *e != zero)
 			},
 			BitDomain::PartialTail(body, tail, t) => {
-				for n in 0 .. *t {
-					if tail.get::<C>(n.idx()) {
-						return true;
-					}
-				}
-				return body.iter().any(|e| *e != T::bits(false));
-			},
-			BitDomain::Spanning(body) => {
-				return body.iter().any(|e| *e != T::bits(false));
+				some(tail, 0, *t) || body.iter().any(|e| *e != zero)
 			},
+			BitDomain::Spanning(body) => body.iter().any(|e| *e != zero),
 		}
-		false
 	}
 
 	/// Tests if *any* bit in the slice is unset (logical `¬∧`).
@@ -1830,6 +2578,99 @@ where C: Cursor, T: BitStore {
 		!self.any()
 	}
 
+	/// Tests if *all* bits in a range of the slice are set.
+	///
+	/// This stops at the first storage element that contains an unset bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of bits in `self` to test.
+	///
+	/// # Returns
+	///
+	/// Whether all bits in `range` are set. The empty range returns `true`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is not within `0 .. self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x3Cu8.as_bitslice::<BigEndian>();
+	/// assert!(bits.all_in_range(2 .. 6));
+	/// assert!(!bits.all_in_range(1 ..= 5));
+	/// ```
+	pub fn all_in_range<R>(&self, range: R) -> bool
+	where R: RangeBounds<usize> {
+		self.in_range(range).all()
+	}
+
+	/// Tests if *any* bit in a range of the slice is set.
+	///
+	/// This stops at the first storage element that contains a set bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of bits in `self` to test.
+	///
+	/// # Returns
+	///
+	/// Whether any bit in `range` is set. The empty range returns `false`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is not within `0 .. self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x3Cu8.as_bitslice::<BigEndian>();
+	/// assert!(bits.any_in_range(5 ..));
+	/// assert!(!bits.any_in_range(.. 2));
+	/// ```
+	pub fn any_in_range<R>(&self, range: R) -> bool
+	where R: RangeBounds<usize> {
+		self.in_range(range).any()
+	}
+
+	/// Tests if *all* bits in a range of the slice are unset.
+	///
+	/// This stops at the first storage element that contains a set bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of bits in `self` to test.
+	///
+	/// # Returns
+	///
+	/// Whether all bits in `range` are unset. The empty range returns `true`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is not within `0 .. self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x3Cu8.as_bitslice::<BigEndian>();
+	/// assert!(bits.not_any_in_range(6 ..));
+	/// assert!(!bits.not_any_in_range(..));
+	/// ```
+	pub fn not_any_in_range<R>(&self, range: R) -> bool
+	where R: RangeBounds<usize> {
+		!self.any_in_range(range)
+	}
+
 	/// Tests whether the slice has some, but not all, bits set and some, but
 	/// not all, bits unset.
 	///
@@ -1867,7 +2708,120 @@ where C: Cursor, T: BitStore {
 		self.any() && self.not_all()
 	}
 
-	/// Counts how many bits are set high.
+	/// Finds the index of the first set bit in the slice.
+	///
+	/// This is the accelerated form of `.iter().position(|b| b)`: the fully
+	/// live storage elements are skipped a whole element at a time while they
+	/// are zero, and only the element containing the first set bit is
+	/// inspected bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the first bit in the slice that is set, or `None` if no
+	/// bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0u8, 0, 0x10, 0x80];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.first_one(), Some(19));
+	/// assert_eq!(bits[20 ..].first_one(), Some(4));
+	/// assert!(bits[.. 19].first_one().is_none());
+	/// ```
+	pub fn first_one(&self) -> Option<usize> {
+		self.first_of(true)
+	}
+
+	/// Finds the index of the first unset bit in the slice.
+	///
+	/// This is the accelerated form of `.iter().position(|b| !b)`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the first bit in the slice that is unset, or `None` if all
+	/// bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [!0u16, !0 ^ 4];
+	/// let bits = src.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits.first_zero(), Some(18));
+	/// assert!(bits[.. 18].first_zero().is_none());
+	/// ```
+	pub fn first_zero(&self) -> Option<usize> {
+		self.first_of(false)
+	}
+
+	/// Finds the index of the last set bit in the slice.
+	///
+	/// This is the accelerated form of `.iter().rposition(|b| b)`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the last bit in the slice that is set, or `None` if no
+	/// bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x08u8, 0, 0];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.last_one(), Some(4));
+	/// assert_eq!(bits[2 ..].last_one(), Some(2));
+	/// assert!(bits[5 ..].last_one().is_none());
+	/// ```
+	pub fn last_one(&self) -> Option<usize> {
+		self.last_of(true)
+	}
+
+	/// Finds the index of the last unset bit in the slice.
+	///
+	/// This is the accelerated form of `.iter().rposition(|b| !b)`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the last bit in the slice that is unset, or `None` if all
+	/// bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0u8, !0, !0];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.last_zero(), Some(7));
+	/// assert!(bits[8 ..].last_zero().is_none());
+	/// ```
+	pub fn last_zero(&self) -> Option<usize> {
+		self.last_of(false)
+	}
+
+	/// Counts how many bits are set high.
 	///
 	/// # Parameters
 	///
@@ -1889,29 +2843,17 @@ where C: Cursor, T: BitStore {
 		match self.bitptr().domain() {
 			BitDomain::Empty => 0,
 			BitDomain::Minor(head, elt, tail) => {
-				(*head .. *tail)
-					.map(|n| elt.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
+				Self::count_live(elt.load(), *head, *tail)
 			},
 			BitDomain::Major(h, head, body, tail, t) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count() +
+				Self::count_live(head.load(), *h, T::BITS) +
 				body.iter()
 					.map(T::count_ones)
 					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
+				Self::count_live(tail.load(), 0, *t)
 			},
 			BitDomain::PartialHead(h, head, body) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count() +
+				Self::count_live(head.load(), *h, T::BITS) +
 				body.iter()
 					.map(T::count_ones)
 					.sum::<usize>()
@@ -1920,10 +2862,7 @@ where C: Cursor, T: BitStore {
 				body.iter()
 					.map(T::count_ones)
 					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
+				Self::count_live(tail.load(), 0, *t)
 			},
 			BitDomain::Spanning(body) => {
 				body.iter()
@@ -1952,53 +2891,138 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(bits.count_zeros(), 6);
 	/// ```
 	pub fn count_zeros(&self) -> usize {
-		match self.bitptr().domain() {
-			BitDomain::Empty => 0,
-			BitDomain::Minor(head, elt, tail) => {
-				(*head .. *tail)
-					.map(|n| !elt.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
+		self.len() - self.count_ones()
+	}
+
+	/// Inverts every bit in the slice.
+	///
+	/// Fully-live elements are inverted a whole element at a time, and each
+	/// partial element at the edges is inverted with a single masked write.
+	/// Bits outside the slice are not affected. This is the same operation as
+	/// the `!` operator on `&mut BitSlice`, without consuming the reference.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 2];
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// bits[2 .. 14].invert_all();
+	/// assert_eq!(src, [0x3F, 0xFC]);
+	/// ```
+	pub fn invert_all(&mut self) {
+		match self.bitptr().domain_mut() {
+			BitDomainMut::Empty => {},
+			BitDomainMut::Minor(head, elt, tail) => {
+				elt.invert_bits(Self::live_mask(*head, *tail));
 			},
-			BitDomain::Major(h, head, body, tail, t) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count() +
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
+			BitDomainMut::Major(h, head, body, tail, t) => {
+				head.invert_bits(Self::live_mask(*h, T::BITS));
+				for elt in body {
+					*elt = !*elt;
+				}
+				tail.invert_bits(Self::live_mask(0, *t));
 			},
-			BitDomain::PartialHead(h, head, body) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count() +
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>()
+			BitDomainMut::PartialHead(h, head, body) => {
+				head.invert_bits(Self::live_mask(*h, T::BITS));
+				for elt in body {
+					*elt = !*elt;
+				}
 			},
-			BitDomain::PartialTail(body, tail, t) => {
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
+			BitDomainMut::PartialTail(body, tail, t) => {
+				for elt in body {
+					*elt = !*elt;
+				}
+				tail.invert_bits(Self::live_mask(0, *t));
 			},
-			BitDomain::Spanning(body) => {
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>()
+			BitDomainMut::Spanning(body) => {
+				for elt in body {
+					*elt = !*elt;
+				}
 			},
 		}
 	}
 
+	/// Inverts every bit in a range of the slice.
+	///
+	/// This is [`invert_all`] applied to a subslice, so it touches each element
+	/// in the range once rather than each bit, and is suited to sweeps which
+	/// toggle whole intervals at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The range of bits in `self` to invert.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is not within `0 .. self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// //  Bits which end up set are covered by an odd number of intervals.
+	/// let mut bv = bitvec![0; 40];
+	/// for &(from, upto) in &[(0, 20), (10, 30), (5, 12)] {
+	///   bv.toggle_range(from .. upto);
+	/// }
+	/// assert!(bv[.. 5].all());
+	/// assert!(bv[5 .. 10].not_any());
+	/// assert!(bv[10 .. 12].all());
+	/// assert!(bv[12 .. 20].not_any());
+	/// assert!(bv[20 .. 30].all());
+	/// assert!(bv[30 ..].not_any());
+	/// ```
+	///
+	/// [`invert_all`]: #method.invert_all
+	pub fn toggle_range<R>(&mut self, range: R)
+	where R: RangeBounds<usize> {
+		let span = self.bounds(range);
+		self[span].invert_all();
+	}
+
+	/// Views the slice with every bit inverted, without modifying it.
+	///
+	/// The view reads through to `self` and inverts each bit as it is read, so
+	/// nothing is allocated or written. It can be given to any operator that
+	/// accepts a stream of bits, and compared with other slices, which makes
+	/// `a & !b` available without building `!b`. The `!` operator on a shared
+	/// reference produces the same view.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A [`Complement`] view of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut live = bitvec![1, 1, 1, 1, 0, 0];
+	/// let dead = bitvec![0, 1, 0, 1, 0, 1];
+	/// live &= dead.complement();
+	/// assert_eq!(live, bitvec![1, 0, 1, 0, 0, 0]);
+	///
+	/// assert_eq!(!&dead[..], bitvec![1, 0, 1, 0, 1, 0]);
+	/// assert_eq!(dead.complement().count_ones(), 3);
+	/// ```
+	///
+	/// [`Complement`]: struct.Complement.html
+	pub fn complement(&self) -> Complement<C, T> {
+		Complement { inner: self }
+	}
+
 	/// Set all bits in the slice to a value.
 	///
 	/// # Parameters
@@ -2024,25 +3048,17 @@ where C: Cursor, T: BitStore {
 		match self.bitptr().domain_mut() {
 			BitDomainMut::Empty => {},
 			BitDomainMut::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					elt.set::<C>(n.idx(), value);
-				}
+				elt.set_bits(Self::live_mask(*head, *tail), value);
 			},
 			BitDomainMut::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					head.set::<C>(n.idx(), value);
-				}
+				head.set_bits(Self::live_mask(*h, T::BITS), value);
 				for elt in body {
 					*elt = T::bits(value);
 				}
-				for n in 0 .. *t {
-					tail.set::<C>(n.idx(), value);
-				}
+				tail.set_bits(Self::live_mask(0, *t), value);
 			},
 			BitDomainMut::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					head.set::<C>(n.idx(), value);
-				}
+				head.set_bits(Self::live_mask(*h, T::BITS), value);
 				for elt in body {
 					*elt = T::bits(value);
 				}
@@ -2051,9 +3067,7 @@ where C: Cursor, T: BitStore {
 				for elt in body {
 					*elt = T::bits(value);
 				}
-				for n in 0 .. *t {
-					tail.set::<C>(n.idx(), value);
-				}
+				tail.set_bits(Self::live_mask(0, *t), value);
 			},
 			BitDomainMut::Spanning(body) => {
 				for elt in body {
@@ -2063,226 +3077,265 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
-	/// Provides mutable traversal of the collection.
+	/// Sets every bit in a range of the slice to a value.
 	///
-	/// It is impossible to implement `IndexMut` on `BitSlice`, because bits do
-	/// not have addresses, so there can be no `&mut u1`. This method allows the
-	/// client to receive an enumerated bit, and provide a new bit to set at
-	/// each index.
+	/// This is [`set_all`] applied to a subslice: the elements in the interior
+	/// of the range are written whole, and each partial element at its edges
+	/// receives a single masked write. Bits outside the range are not
+	/// affected.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `func`: A function which receives a `(usize, bool)` pair of index and
-	///   value, and returns a bool. It receives the bit at each position, and
-	///   the return value is written back at that position.
+	/// - `range`: The range of bits in `self` to write.
+	/// - `value`: The bit value to write into the range.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is not within `0 .. self.len()`.
 	///
 	/// # Examples
 	///
+	/// Marking extents allocated in a block bitmap:
+	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// {
-	///  let bits = src.as_mut_bitslice::<BigEndian>();
-	///  bits.for_each(|idx, _bit| idx % 3 == 0);
-	/// }
-	/// assert_eq!(src, 0b1001_0010);
+	/// let mut blocks = bitvec![LittleEndian, u32; 0; 256];
+	/// blocks.set_range(10 .. 150, true);
+	/// blocks.set_range(BitSpan::new(200, 8), true);
+	/// assert_eq!(blocks.count_ones(), 148);
+	/// assert_eq!(blocks.first_one(), Some(10));
+	/// assert_eq!(blocks.as_slice()[1], !0);
 	/// ```
-	pub fn for_each<F>(&mut self, func: F)
-	where F: Fn(usize, bool) -> bool {
-		for idx in 0 .. self.len() {
-			let tmp = self[idx];
-			self.set(idx, func(idx, tmp));
-		}
+	///
+	/// [`set_all`]: #method.set_all
+	pub fn set_range<R>(&mut self, range: R, value: bool)
+	where R: RangeBounds<usize> {
+		let span = self.bounds(range);
+		self[span].set_all(value);
 	}
 
-	/// Performs “reverse” addition (left to right instead of right to left).
+	/// Clears every bit in a range of the slice.
 	///
-	/// This addition interprets the slice, and the other addend, as having its
-	/// least significant bits first in the order and its most significant bits
-	/// last. This is most likely to be numerically useful under a
-	/// `LittleEndian` `Cursor` type.
+	/// This is equivalent to `self.set_range(range, false)`.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`: The addition uses `self` as one addend, and writes the
-	///   sum back into `self`.
-	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits. When this is
-	///   another `BitSlice`, iteration proceeds from left to right.
+	/// - `&mut self`
+	/// - `range`: The range of bits in `self` to clear.
 	///
-	/// # Return
+	/// # Panics
 	///
-	/// The final carry bit is returned
+	/// This panics if `range` is not within `0 .. self.len()`.
 	///
-	/// # Effects
+	/// # Examples
 	///
-	/// Starting from index `0` and proceeding upwards until either `self` or
-	/// `addend` expires, the carry-propagated addition of `self[i]` and
-	/// `addend[i]` is written to `self[i]`.
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// ```text
-	///   101111
-	/// + 0010__ (the two missing bits are logically zero)
-	/// --------
-	///   100000 1 (the carry-out is returned)
+	/// let mut blocks = bitvec![1; 64];
+	/// blocks.clear_range(3 ..= 60);
+	/// assert_eq!(blocks.count_ones(), 6);
 	/// ```
+	pub fn clear_range<R>(&mut self, range: R)
+	where R: RangeBounds<usize> {
+		self.set_range(range, false);
+	}
+
+	/// `AND`s another slice into this one, leaving the bits of `self` past
+	/// the end of `rhs` unchanged.
+	///
+	/// Unlike the `&=` operator, which reads any stream of bits one bit at a
+	/// time, this borrows `rhs` as a slice. When both slices begin at the front
+	/// of an element, the elements they share are combined a whole element at
+	/// a time. Only the first `min(self.len(), rhs.len())` bits take part.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The slice to `AND` into `self`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut a = 0b0000_1010u8;
-	/// let     b = 0b0000_1100u8;
-	/// //      s =      1 0110
-	/// let ab = &mut a.as_mut_bitslice::<LittleEndian>()[.. 4];
-	/// let bb = &    b.as_bitslice::<LittleEndian>()[.. 4];
-	/// let c = ab.add_assign_reverse(bb);
-	/// assert!(c);
-	/// assert_eq!(ab.as_slice()[0], 0b0000_0110u8);
+	/// let mut lhs = bitvec![1; 8];
+	/// lhs.and_assign_truncate(&bitvec![1, 0, 1, 0]);
+	/// assert_eq!(lhs, bitvec![1, 0, 1, 0, 1, 1, 1, 1]);
 	/// ```
+	pub fn and_assign_truncate(&mut self, rhs: &Self) {
+		self.zip_assign(rhs, |a, b| a & b);
+	}
+
+	/// `AND`s another slice into this one, treating `rhs` as zero-extended to
+	/// the length of `self`.
 	///
-	/// # Performance Notes
+	/// This is [`and_assign_truncate`], followed by clearing the bits of `self`
+	/// past the end of `rhs`. It has the same effect as the `&=` operator with
+	/// a slice on the right, without reading `rhs` one bit at a time.
 	///
-	/// When using `LittleEndian` `Cursor` types, this can be accelerated by
-	/// delegating the addition to the underlying types. This is a software
-	/// implementation of the [ripple-carry adder], which has `O(n)` runtime in
-	/// the number of bits. The CPU is much faster, as it has access to
-	/// element-wise or vectorized addition operations.
+	/// # Parameters
 	///
-	/// If your use case sincerely needs binary-integer arithmetic operations on
-	/// bit sets
+	/// - `&mut self`
+	/// - `rhs`: The slice to `AND` into `self`.
 	///
-	/// [ripple-carry adder]: https://en.wikipedia.org/wiki/Ripple-carry_adder
-	pub fn add_assign_reverse<I>(&mut self, addend: I) -> bool
-	where I: IntoIterator<Item=bool> {
-		//  See AddAssign::add_assign for algorithm details
-		let mut c = false;
-		let len = self.len();
-		let zero = core::iter::repeat(false);
-		for (i, b) in addend.into_iter().chain(zero).enumerate().take(len) {
-			//  The iterator is clamped to the upper bound of `self`.
-			let a = unsafe { self.get_unchecked(i) };
-			let (y, z) = crate::rca1(a, b, c);
-			//  Write the sum into `self`
-			unsafe { self.set_unchecked(i, y); }
-			//  Propagate the carry
-			c = z;
-		}
-		c
-	}
-
-	/// Accesses the backing storage of the `BitSlice` as a slice of its
-	/// elements.
+	/// # Examples
 	///
-	/// # Parameters
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// - `&self`
+	/// let mut lhs = bitvec![1; 8];
+	/// lhs.and_assign_extend(&bitvec![1, 0, 1, 0]);
+	/// assert_eq!(lhs, bitvec![1, 0, 1, 0, 0, 0, 0, 0]);
+	/// ```
 	///
-	/// # Returns
+	/// [`and_assign_truncate`]: #method.and_assign_truncate
+	pub fn and_assign_extend(&mut self, rhs: &Self) {
+		self.and_assign_truncate(rhs);
+		if rhs.len() < self.len() {
+			self[rhs.len() ..].set_all(false);
+		}
+	}
+
+	/// `OR`s another slice into this one, leaving the bits of `self` past the
+	/// end of `rhs` unchanged.
 	///
-	/// A slice of all the elements that the `BitSlice` uses for storage.
+	/// Zero-extending `rhs` would leave those bits unchanged as well, so there
+	/// is no separate extending form. As with [`and_assign_truncate`], shared
+	/// elements are combined whole when both slices begin at the front of an
+	/// element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The slice to `OR` into `self`. Bits past the end of `self` are
+	///   ignored.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = [1u8, 66];
-	/// let bits = src.as_bitslice::<BigEndian>();
-	///
-	/// let accum = bits.as_slice()
-	///   .iter()
-	///   .map(|elt| elt.count_ones())
-	///   .sum::<usize>();
-	/// assert_eq!(accum, 3);
+	/// let mut lhs = bitvec![0, 0, 1, 1];
+	/// lhs.or_assign_truncate(&bitvec![0, 1, 0, 1, 1, 1]);
+	/// assert_eq!(lhs, bitvec![0, 1, 1, 1]);
 	/// ```
-	pub fn as_slice(&self) -> &[T] {
-		self.bitptr().as_slice()
+	///
+	/// [`and_assign_truncate`]: #method.and_assign_truncate
+	pub fn or_assign_truncate(&mut self, rhs: &Self) {
+		self.zip_assign(rhs, |mut a, b| {
+			a |= b;
+			a
+		});
 	}
 
-	/// Accesses the underlying store.
+	/// `XOR`s another slice into this one, leaving the bits of `self` past
+	/// the end of `rhs` unchanged.
+	///
+	/// Zero-extending `rhs` would leave those bits unchanged as well, so there
+	/// is no separate extending form. As with [`and_assign_truncate`], shared
+	/// elements are combined whole when both slices begin at the front of an
+	/// element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The slice to `XOR` into `self`. Bits past the end of `self` are
+	///   ignored.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = [1u8, 64];
-	/// let bits = src.as_mut_bitslice::<BigEndian>();
-	/// for elt in bits.as_mut_slice() {
-	///   *elt |= 2;
-	/// }
-	/// assert_eq!(&[3, 66], bits.as_slice());
+	/// let mut lhs = bitvec![0, 0, 1, 1, 1];
+	/// lhs.xor_assign_truncate(&bitvec![0, 1, 0, 1]);
+	/// assert_eq!(lhs, bitvec![0, 1, 1, 0, 1]);
 	/// ```
-	pub fn as_mut_slice(&mut self) -> &mut [T] {
-		self.bitptr().as_mut_slice()
+	///
+	/// [`and_assign_truncate`]: #method.and_assign_truncate
+	pub fn xor_assign_truncate(&mut self, rhs: &Self) {
+		self.zip_assign(rhs, |a, b| a ^ b);
 	}
 
-	/// Changes the cursor type of the slice handle.
+	/// Finds the first storage element wholly inside the slice whose value is
+	/// exactly `value`.
+	///
+	/// This is the `memchr` of bit slices: it scans the fully-live storage
+	/// elements a whole element at a time, and skips the partial elements at
+	/// either edge of the slice, which are not wholly governed by it.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `value`: The element value to find.
 	///
 	/// # Returns
 	///
-	/// An equivalent slice handle with a new cursor type.
-	///
-	/// # Type Parameters
-	///
-	/// - `D: Cursor` The new cursor type to use for the handle.
+	/// The bit index, within `self`, of the first bit of the first fully-live
+	/// element equal to `value`, or `None` if no such element exists.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = 2u8;
+	/// let src = [0x5Au8, 0x5A, 0x3C, 0x5A];
 	/// let bits = src.as_bitslice::<BigEndian>();
-	/// assert!(bits[6]);
-	/// let bits = bits.change_cursor::<LittleEndian>();
-	/// assert!(bits[1]);
+	/// assert_eq!(bits.find_element(0x5A), Some(0));
+	/// assert_eq!(bits[1 ..].find_element(0x5A), Some(7));
+	/// assert_eq!(bits[.. 30].find_element(0x3C), Some(16));
+	/// assert!(bits[.. 30].find_element(0x00).is_none());
 	/// ```
-	pub fn change_cursor<D>(&self) -> &BitSlice<D, T>
-	where D: Cursor {
-		self.bitptr().into_bitslice()
+	pub fn find_element(&self, value: T) -> Option<usize> {
+		let (offset, body) = match BitDomain::from(self.bitptr()) {
+			BitDomain::Empty | BitDomain::Minor(..) => return None,
+			BitDomain::Major(h, _, body, _, _) |
+			BitDomain::PartialHead(h, _, body) => {
+				((T::BITS - *h) as usize, body)
+			},
+			BitDomain::PartialTail(body, _, _) |
+			BitDomain::Spanning(body) => (0, body),
+		};
+		body.iter()
+			.position(|&elt| elt == value)
+			.map(|n| offset + (n << T::INDX))
 	}
 
-	/// Changes the cursor type of the slice handle.
+	/// Finds the first storage element wholly inside the slice with all of its
+	/// bits set.
+	///
+	/// This is useful for allocators that track used blocks with set bits,
+	/// and want to skip a fully-used run of blocks in one step.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
+	/// - `&self`
 	///
 	/// # Returns
 	///
-	/// An equivalent slice handle with a new cursor type.
-	///
-	/// # Type Parameters
-	///
-	/// - `D: Cursor` The new cursor type to use for the handle.
+	/// The bit index, within `self`, of the first bit of the first fully-live
+	/// element whose bits are all `1`, or `None` if there is no such element.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// *src.as_mut_bitslice::<BigEndian>().at(1) = true;
-	/// assert_eq!(src, 64);
-	/// src.as_mut_bitslice::<BigEndian>()
-	///    .change_cursor_mut::<LittleEndian>()
-	///    .set(1, true);
-	/// assert_eq!(src, 66);
+	/// let src = [0xFFu8, 0x7F, 0xFF];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.find_full_element(), Some(0));
+	/// assert_eq!(bits[2 ..].find_full_element(), Some(14));
+	/// assert!(bits[2 .. 20].find_full_element().is_none());
 	/// ```
-	pub fn change_cursor_mut<D>(&mut self) -> &mut BitSlice<D, T>
-	where D: Cursor {
-		self.bitptr().into_bitslice_mut()
+	pub fn find_full_element(&self) -> Option<usize> {
+		self.find_element(T::bits(true))
 	}
 
-	/// Accesses the underlying pointer structure.
+	/// Finds the first storage element wholly inside the slice with all of its
+	/// bits cleared.
 	///
 	/// # Parameters
 	///
@@ -2290,759 +3343,2992 @@ where C: Cursor, T: BitStore {
 	///
 	/// # Returns
 	///
-	/// The [`BitPtr`] structure of the slice handle.
-	///
-	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
-	pub fn bitptr(&self) -> BitPtr<T> {
-		BitPtr::from_bitslice(self)
-	}
-}
-
-/// Creates an owned `BitVec<C, T>` from a borrowed `BitSlice<C, T>`.
-#[cfg(feature = "alloc")]
-impl<C, T> ToOwned for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Owned = BitVec<C, T>;
-
-	/// Clones a borrowed `BitSlice` into an owned `BitVec`.
+	/// The bit index, within `self`, of the first bit of the first fully-live
+	/// element whose bits are all `0`, or `None` if there is no such element.
 	///
 	/// # Examples
 	///
 	/// ```rust
-	/// # #[cfg(feature = "alloc")] {
 	/// use bitvec::prelude::*;
 	///
-	/// let store = [0u8, 2];
-	/// let src = store.as_bitslice::<LittleEndian>();
-	/// let dst = src.to_owned();
-	/// assert_eq!(src, dst);
-	/// # }
+	/// let src = [0u16, 1, 0];
+	/// let bits = src.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits.find_empty_element(), Some(0));
+	/// assert_eq!(bits[4 ..].find_empty_element(), Some(28));
 	/// ```
-	fn to_owned(&self) -> Self::Owned {
-		Self::Owned::from_bitslice(self)
-	}
-}
-
-impl<C, T> Eq for BitSlice<C, T>
-where C: Cursor, T: BitStore {}
-
-impl<C, T> Ord for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn cmp(&self, rhs: &Self) -> Ordering {
-		self.partial_cmp(rhs)
-			.unwrap_or_else(|| unreachable!("`BitSlice` has a total ordering"))
+	pub fn find_empty_element(&self) -> Option<usize> {
+		self.find_element(T::bits(false))
 	}
-}
-
-/** Tests if two `BitSlice`s are semantically — not bitwise — equal.
-
-It is valid to compare two slices of different cursor or element types.
 
-The equality condition requires that they have the same number of total bits and
-that each pair of bits in semantic order are identical.
-**/
-impl<A, B, C, D> PartialEq<BitSlice<C, D>> for BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	/// Performas a comparison by `==`.
+	/// Calls a function with the indices of every set bit in the slice,
+	/// delivered in batches.
+	///
+	/// Calling a closure once per set bit spends much of its time in the call
+	/// itself. This method instead collects the indices into a buffer on the
+	/// stack, and calls `func` only when the buffer is full, and once more
+	/// with whatever remains at the end. Each storage element is read once,
+	/// and its set bits are found with integer instructions when `C` is
+	/// `BigEndian` or `LittleEndian`.
+	///
+	/// # Type Parameters
+	///
+	/// - `B`: The buffer type. Its length is the batch size. This is usually
+	///   an array, such as `[usize; 32]`, written as the first type argument.
+	/// - `F`: The function which receives each batch.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `rhs`: Another `BitSlice` against which to compare. This slice can
-	///   have different cursor or storage types.
+	/// - `func`: A function which receives a batch of indices. Every batch has
+	///   the length of `B`, except the last, which may be shorter. Indices are
+	///   delivered in ascending order, and `func` is not called at all if no
+	///   bit is set.
 	///
-	/// # Returns
+	/// # Panics
 	///
-	/// If the two slices are equal, by comparing the lengths and bit values at
-	/// each semantic index.
+	/// This panics if `B` has zero length.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let lsrc = [8u8, 16, 32, 0];
-	/// let rsrc = [0x10_08_04_00u32];
-	/// let lbits = lsrc.as_bitslice::<LittleEndian>();
-	/// let rbits = rsrc.as_bitslice::<BigEndian>();
-	///
-	/// assert_eq!(lbits, rbits);
+	/// let src = [0b0110_0001u8, 0b1000_0011];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// let mut batches = vec![];
+	/// bits[1 ..].for_each_one_batched::<[usize; 2], _>(|batch| {
+	///   batches.push(batch.to_vec());
+	/// });
+	/// assert_eq!(batches, [vec![0, 1], vec![6, 7], vec![13, 14]]);
 	/// ```
-	fn eq(&self, rhs: &BitSlice<C, D>) -> bool {
-		if self.len() != rhs.len() {
-			return false;
+	pub fn for_each_one_batched<B, F>(&self, mut func: F)
+	where B: AsMut<[usize]> + Default, F: FnMut(&[usize]) {
+		let mut buf = B::default();
+		let cap = buf.as_mut().len();
+		assert!(cap > 0, "Cannot deliver indices in batches of zero");
+		let bitptr = self.bitptr();
+		if bitptr.is_empty() {
+			return;
+		}
+		let (head, tail) = (*bitptr.head(), *bitptr.tail());
+		let last = bitptr.elements() - 1;
+		let data = bitptr.pointer().a();
+		let orient = Self::orientation();
+		let mut fill = 0;
+		let mut emit = |idx: usize| {
+			buf.as_mut()[fill] = idx;
+			fill += 1;
+			if fill == cap {
+				func(buf.as_mut());
+				fill = 0;
+			}
+		};
+		for n in 0 ..= last {
+			let from = if n == 0 { head } else { 0 };
+			let upto = if n == last { tail } else { T::BITS };
+			let elt = unsafe { (&*data.add(n)).load() };
+			let elt = elt & Self::live_mask(from, upto);
+			//  The first element begins `head` bits before the slice does, so
+			//  its live bits all have semantic indices of at least `head`.
+			let at = |bit: u32| (n << T::INDX) + bit as usize - head as usize;
+			let mut bits: u64 = elt.into();
+			match orient {
+				//  Semantic order runs from the most significant bit, so the
+				//  indices ascend as the leading set bit is stripped off.
+				Some(true) => while bits != 0 {
+					let pos = 63 - bits.leading_zeros();
+					emit(at(u32::from(T::MASK) - pos));
+					bits &= !(1 << pos);
+				},
+				Some(false) => while bits != 0 {
+					emit(at(bits.trailing_zeros()));
+					bits &= bits - 1;
+				},
+				None => for bit in from .. upto {
+					if elt.get::<C>(bit.idx()) {
+						emit(at(u32::from(bit)));
+					}
+				},
+			}
+		}
+		if fill > 0 {
+			func(&buf.as_mut()[.. fill]);
 		}
-		self.iter().zip(rhs.iter()).all(|(l, r)| l == r)
-	}
-}
-
-impl<A, B, C, D> PartialEq<BitSlice<C, D>> for &BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn eq(&self, rhs: &BitSlice<C, D>) -> bool {
-		(*self).eq(rhs)
-	}
-}
-
-/// Allow comparison against the allocated form.
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialEq<BitVec<C, D>> for BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn eq(&self, rhs: &BitVec<C, D>) -> bool {
-		self.eq(rhs.as_bitslice())
-	}
-}
-
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialEq<BitVec<C, D>> for &BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn eq(&self, rhs: &BitVec<C, D>) -> bool {
-		self.eq(rhs.as_bitslice())
 	}
-}
-
-/** Compares two `BitSlice`s by semantic — not bitwise — ordering.
-
-The comparison sorts by testing each index for one slice to have a set bit where
-the other has an unset bit. If the slices are different, the slice with the set
-bit sorts greater than the slice with the unset bit.
 
-If one of the slices is exhausted before they differ, the longer slice is
-greater.
-**/
-impl<A, B, C, D> PartialOrd<BitSlice<C, D>> for BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	/// Performs a comparison by `<` or `>`.
+	/// Sums the weights of the set bits in the slice.
+	///
+	/// This is the dot product of the slice with a vector of integer weights,
+	/// one per bit. The set bits are found a storage element at a time, as in
+	/// [`for_each_one_batched`], and only their weights are read, so it is
+	/// much faster than zipping the bits with the weights and filtering.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `rhs`: Another `BitSlice` against which to compare. This slice can
-	///   have different cursor or storage types.
+	/// - `weights`: The weight of each bit. `weights[n]` is added to the sum
+	///   when `self[n]` is set. Weights past the end of `self` are unused.
 	///
 	/// # Returns
 	///
-	/// The relative ordering of `self` against `rhs`. `self` is greater if it
-	/// has a `true` bit at an index where `rhs` has a `false`; `self` is lesser
-	/// if it has a `false` bit at an index where `rhs` has a `true`; if the two
-	/// slices do not disagree then they are compared by length.
+	/// The sum of `weights[n]` for every index `n` at which `self` is set.
+	///
+	/// # Panics
+	///
+	/// This panics if `weights` is shorter than `self`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = 0x45u8;
+	/// let src = [0b1010_0000u8, 0b0000_0001];
 	/// let bits = src.as_bitslice::<BigEndian>();
-	/// let a = &bits[0 .. 3]; // 010
-	/// let b = &bits[0 .. 4]; // 0100
-	/// let c = &bits[0 .. 5]; // 01000
-	/// let d = &bits[4 .. 8]; // 0101
-	///
-	/// assert!(a < b);
-	/// assert!(b < c);
-	/// assert!(c < d);
+	/// let weights: Vec<u32> = (1 ..= 16).collect();
+	/// assert_eq!(bits.weighted_sum(&weights), 1 + 3 + 16);
+	/// assert_eq!(bits[2 ..].weighted_sum(&weights[2 ..]), 3 + 16);
 	/// ```
-	fn partial_cmp(&self, rhs: &BitSlice<C, D>) -> Option<Ordering> {
-		for (l, r) in self.iter().zip(rhs.iter()) {
-			match (l, r) {
-				(true, false) => return Some(Ordering::Greater),
-				(false, true) => return Some(Ordering::Less),
-				_ => continue,
+	///
+	/// [`for_each_one_batched`]: #method.for_each_one_batched
+	pub fn weighted_sum(&self, weights: &[u32]) -> u64 {
+		assert!(
+			weights.len() >= self.len(),
+			"Weight slice length {} is shorter than bit slice length {}",
+			weights.len(),
+			self.len(),
+		);
+		let mut sum = 0u64;
+		self.for_each_one_batched::<[usize; 32], _>(|batch| {
+			for &idx in batch {
+				sum += u64::from(weights[idx]);
 			}
-		}
-		self.len().partial_cmp(&rhs.len())
-	}
-}
-
-impl<A, B, C, D> PartialOrd<BitSlice<C, D>> for &BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn partial_cmp(&self, rhs: &BitSlice<C, D>) -> Option<Ordering> {
-		(*self).partial_cmp(rhs)
-	}
-}
-
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialOrd<BitVec<C, D>> for BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn partial_cmp(&self, rhs: &BitVec<C, D>) -> Option<Ordering> {
-		self.partial_cmp(rhs.as_bitslice())
-	}
-}
-
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialOrd<BitVec<C, D>> for &BitSlice<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn partial_cmp(&self, rhs: &BitVec<C, D>) -> Option<Ordering> {
-		(*self).partial_cmp(rhs.as_bitslice())
+		});
+		sum
 	}
-}
 
-/// Provides write access to all elements in the underlying storage, including
-/// the partial head and tail elements if present.
-impl<C, T> AsMut<[T]> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Accesses the underlying store.
+	/// Writes the bits of the slice, as text, into any formatting sink.
+	///
+	/// This is the machinery behind the `Display` rendering, and likewise does
+	/// not allocate: each storage element is rendered into a buffer on the
+	/// stack and handed to `sink` as a `&str`. It is suitable for logging in
+	/// `#![no_std]` environments which cannot build a `String`.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
+	/// - `&self`
+	/// - `sink`: The writer which receives the text.
+	/// - `style`: How to mark the boundaries between storage elements.
 	///
 	/// # Returns
 	///
-	/// A mutable slice of all storage elements.
+	/// The first error reported by `sink`, if any.
+	///
+	/// # Type Parameters
+	///
+	/// - `W: fmt::Write`: Any text sink, including a `Formatter`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
+	/// use bitvec::slice::BitStyle;
+	/// use core::fmt::{self, Write};
 	///
-	/// let mut src = [0u8, 128];
-	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[1 .. 9];
+	/// struct Buf {
+	///   data: [u8; 16],
+	///   len: usize,
+	/// }
 	///
-	/// for elt in bits.as_mut() {
-	///   *elt += 2;
+	/// impl Write for Buf {
+	///   fn write_str(&mut self, s: &str) -> fmt::Result {
+	///     let end = self.len + s.len();
+	///     if end > self.data.len() {
+	///       return Err(fmt::Error);
+	///     }
+	///     self.data[self.len .. end].copy_from_slice(s.as_bytes());
+	///     self.len = end;
+	///     Ok(())
+	///   }
 	/// }
 	///
-	/// assert_eq!(&[2, 130], bits.as_ref());
+	/// let src = [0b01001011u8, 0b0100_0000];
+	/// let bits = &src.as_bitslice::<BigEndian>()[.. 10];
+	///
+	/// let mut buf = Buf { data: [0; 16], len: 0 };
+	/// bits.write_bits_to(&mut buf, BitStyle::Grouped).unwrap();
+	/// assert_eq!(&buf.data[.. buf.len], b"01001011 01");
+	///
+	/// buf.len = 0;
+	/// bits.write_bits_to(&mut buf, BitStyle::Plain).unwrap();
+	/// assert_eq!(&buf.data[.. buf.len], b"0100101101");
+	///
+	/// buf.len = 0;
+	/// bits.write_bits_to(&mut buf, BitStyle::List).unwrap();
+	/// assert_eq!(&buf.data[.. buf.len], b"[01001011, 01]");
 	/// ```
-	fn as_mut(&mut self) -> &mut [T] {
-		self.as_mut_slice()
+	pub fn write_bits_to<W>(&self, sink: &mut W, style: BitStyle) -> fmt::Result
+	where W: fmt::Write + ?Sized {
+		let sep = match style {
+			BitStyle::List => ", ",
+			BitStyle::Grouped => " ",
+			BitStyle::Plain => "",
+		};
+		if style == BitStyle::List {
+			sink.write_str("[")?;
+		}
+		let mut first = true;
+		self.render_elements(|part| {
+			if !first {
+				sink.write_str(sep)?;
+			}
+			first = false;
+			sink.write_str(part)
+		})?;
+		if style == BitStyle::List {
+			sink.write_str("]")?;
+		}
+		Ok(())
 	}
-}
 
-/// Provides read access to all elements in the underlying storage, including
-/// the partial head and tail elements if present.
-impl<C, T> AsRef<[T]> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Accesses the underlying store.
-	///
-	/// # Parameters
+	/// Provides mutable traversal of the collection.
 	///
-	/// - `&self`
+	/// It is impossible to implement `IndexMut` on `BitSlice`, because bits do
+	/// not have addresses, so there can be no `&mut u1`. This method allows the
+	/// client to receive an enumerated bit, and provide a new bit to set at
+	/// each index.
 	///
-	/// # Returns
+	/// # Parameters
 	///
-	/// An immutable slice of all storage elements.
+	/// - `&mut self`
+	/// - `func`: A function which receives a `(usize, bool)` pair of index and
+	///   value, and returns a bool. It receives the bit at each position, and
+	///   the return value is written back at that position.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = [0u8, 128];
-	/// let bits = &src.as_bitslice::<BigEndian>()[1 .. 9];
-	/// assert_eq!(&[0, 128], bits.as_ref());
+	/// let mut src = 0u8;
+	/// {
+	///  let bits = src.as_mut_bitslice::<BigEndian>();
+	///  bits.for_each(|idx, _bit| idx % 3 == 0);
+	/// }
+	/// assert_eq!(src, 0b1001_0010);
 	/// ```
-	fn as_ref(&self) -> &[T] {
-		self.as_slice()
-	}
-}
-
-impl<'a, C, T> From<&'a T> for &'a BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a T) -> Self {
-		BitSlice::<C, T>::from_element(src)
-	}
-}
-
-impl<'a, C, T> From<&'a [T]> for &'a BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a [T]) -> Self {
-		BitSlice::<C, T>::from_slice(src)
-	}
-}
-
-impl<'a, C, T> From<&'a mut T> for &'a mut BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a mut T) -> Self {
-		BitSlice::<C, T>::from_element_mut(src)
-	}
-}
-
-impl<'a, C, T> From<&'a mut [T]> for &'a mut BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a mut [T]) -> Self {
-		BitSlice::<C, T>::from_slice_mut(src)
-	}
-}
-
-impl<'a, C, T> Default for &'a BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn default() -> Self {
-		BitSlice::empty()
-	}
-}
-
-impl<'a, C, T> Default for &'a mut BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn default() -> Self {
-		BitSlice::empty_mut()
+	pub fn for_each<F>(&mut self, func: F)
+	where F: Fn(usize, bool) -> bool {
+		self.for_each_mut(func)
 	}
-}
 
-/** Prints the `BitSlice` for debugging.
-
-The output is of the form `BitSlice<C, T> [ELT, *]` where `<C, T>` is the cursor
-and element type, with square brackets on each end of the bits and all the
-elements of the array printed in binary. The printout is always in semantic
-order, and may not reflect the underlying buffer. To see the underlying buffer,
-use `.as_ref()`.
-
-The alternate character `{:#?}` prints each element on its own line, rather than
-having all elements on the same line.
-**/
-impl<C, T> Debug for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Renders the `BitSlice` type header and contents for debug.
+	/// Rewrites every bit in the slice with the output of a stateful function.
+	///
+	/// This is the general form of [`for_each`]: the function may carry state
+	/// from one bit to the next, and it is called on each bit in order from
+	/// the front of the slice to the back. Memory is read once per element,
+	/// rather than once per bit, and each element is written at most once,
+	/// after the function has seen all of its live bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives the index and current value of each
+	///   bit, and returns the value to write back into it.
 	///
 	/// # Examples
 	///
+	/// A running parity, which no single-bit operator can produce:
+	///
 	/// ```rust
-	/// # #[cfg(feature = "alloc")] {
 	/// use bitvec::prelude::*;
 	///
-	/// let src = [0b0101_0000_1111_0101u16, 0b00000000_0000_0010];
-	/// let bits = &src.as_bitslice::<LittleEndian>()[.. 18];
-	/// assert_eq!(
-    ///     "BitSlice<LittleEndian, u16> [1010111100001010, 01]",
-	///     &format!("{:?}", bits),
-	/// );
-	/// # }
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 0, 0, 0, 1, 1];
+	/// let mut parity = false;
+	/// bv[1 ..].for_each_mut(|_, bit| {
+	///   parity ^= bit;
+	///   parity
+	/// });
+	/// assert_eq!(bv, bitvec![0, 1, 0, 0, 1, 1, 1, 1, 0, 1]);
 	/// ```
-	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		f.write_str("BitSlice<")?;
-		f.write_str(C::TYPENAME)?;
-		f.write_str(", ")?;
-		f.write_str(T::TYPENAME)?;
-		f.write_str("> ")?;
-		Display::fmt(self, f)
+	///
+	/// [`for_each`]: #method.for_each
+	pub fn for_each_mut<F>(&mut self, mut func: F)
+	where F: FnMut(usize, bool) -> bool {
+		let bitptr = self.bitptr();
+		if bitptr.is_empty() {
+			return;
+		}
+		let (head, tail) = (*bitptr.head(), *bitptr.tail());
+		let last = bitptr.elements() - 1;
+		let data = bitptr.pointer().a();
+		let mut idx = 0;
+		for n in 0 ..= last {
+			let from = if n == 0 { head } else { 0 };
+			let upto = if n == last { tail } else { T::BITS };
+			let elt = unsafe { &*data.add(n) };
+			let old = elt.load();
+			let mut diff = T::bits(false);
+			for bit in from .. upto {
+				let place = bit.idx();
+				let val = old.get::<C>(place);
+				if func(idx, val) != val {
+					diff.set::<C>(place, true);
+				}
+				idx += 1;
+			}
+			//  Only the live bits can be in `diff`, so the edge elements do not
+			//  disturb the bits that other handles may hold.
+			if diff != T::bits(false) {
+				elt.invert_bits(diff);
+			}
+		}
 	}
-}
-
-/** Prints the `BitSlice` for displaying.
-
-This prints each element in turn, formatted in binary in semantic order (so the
-first bit seen is printed first and the last bit seen is printed last). Each
-element of storage is separated by a space for ease of reading.
-
-The alternate character `{:#}` prints each element on its own line.
 
-To see the in-memory representation, use `.as_ref()` to get access to the raw
-elements and print that slice instead.
-**/
-impl<C, T> Display for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Renders the `BitSlice` contents for display.
+	/// Replaces every bit in the slice with a function of its value.
 	///
-	/// # Parameters
+	/// A function from one bit to one bit can only be one of four operations:
+	/// keep the bit, invert it, set it, or clear it. This method calls `func`
+	/// exactly twice, once with `false` and once with `true`, to learn which of
+	/// these it is, and then performs that operation over whole elements
+	/// without calling `func` again. The slice is not read at all unless the
+	/// operation is inversion.
 	///
-	/// - `&self`
-	/// - `f`: The formatter into which `self` is written.
+	/// Because `func` is not called once per bit, it should be a pure function
+	/// of its argument. Transformations which depend on the position of the
+	/// bit, or which carry state, belong in [`map_indexed_in_place`].
 	///
-	/// # Returns
+	/// # Parameters
 	///
-	/// The result of the formatting operation.
+	/// - `&mut self`
+	/// - `func`: The transformation to apply to each bit.
 	///
 	/// # Examples
 	///
 	/// ```rust
-	/// # #[cfg(feature = "alloc")] {
 	/// use bitvec::prelude::*;
 	///
-	/// let src = [0b01001011u8, 0b0100_0000];
-	/// let bits = &src.as_bitslice::<BigEndian>()[.. 10];
-	/// assert_eq!("[01001011, 01]", &format!("{}", bits));
-	/// # }
+	/// let mut bv = bitvec![0, 1, 1, 0, 0, 1];
+	/// bv[1 ..].map_in_place(|bit| !bit);
+	/// assert_eq!(bv, bitvec![0, 0, 0, 1, 1, 0]);
+	/// bv[.. 3].map_in_place(|_| true);
+	/// assert_eq!(bv, bitvec![1, 1, 1, 1, 1, 0]);
+	/// bv.map_in_place(|bit| bit & false);
+	/// assert!(bv.not_any());
 	/// ```
-	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		struct Part<'a>(&'a str);
-		impl<'a> Debug for Part<'a> {
-			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-				f.write_str(&self.0)
-			}
-		}
-
-		let mut dbg = f.debug_list();
-		if !self.is_empty() {
-			//  Unfortunately, `T::BITS` cannot be used as the size for the
-			//  array, due to limitations in the type system. As such, set it to
-			//  the maximum used size.
-			//
-			//  This allows the writes to target a static buffer, rather
-			//  than a dynamic string, making the formatter usable in
-			//  `#![no_std]` contexts.
-			let mut w: [u8; 64] = [b'0'; 64];
-			fn writer<C, T>(
-				l: &mut DebugList,
-				w: &mut [u8; 64],
-				e: &T,
-				from: u8,
-				to: u8,
-			)
-			where C: Cursor, T: BitStore {
-				let (from, to) = (from as usize, to as usize);
-				for (n, byte) in w.iter_mut().enumerate().take(to).skip(from) {
-					*byte = b'0' + (e.get::<C>((n as u8).idx()) as u8);
-				}
-				l.entry(&Part(unsafe {
-					str::from_utf8_unchecked(&w[from .. to])
-				}));
-			}
-			match self.bitptr().domain() {
-				BitDomain::Empty => {},
-				BitDomain::Minor(head, elt, tail) => {
-					writer::<C, T>(&mut dbg, &mut w, &elt.load(), *head, *tail)
-				},
-				BitDomain::Major(h, head, body, tail, t) => {
-					writer::<C, T>(&mut dbg, &mut w, &head.load(), *h, T::BITS);
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, elt, 0, T::BITS);
-					}
-					writer::<C, T>(&mut dbg, &mut w, &tail.load(), 0, *t);
-				},
-				BitDomain::PartialHead(h, head, body) => {
-					writer::<C, T>(&mut dbg, &mut w, &head.load(), *h, T::BITS);
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, elt, 0, T::BITS);
-					}
-				},
-				BitDomain::PartialTail(body, tail, t) => {
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, elt, 0, T::BITS);
-					}
-					writer::<C, T>(&mut dbg, &mut w, &tail.load(), 0, *t);
-				},
-				BitDomain::Spanning(body) => {
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, elt, 0, T::BITS);
-					}
-				},
-			}
+	///
+	/// [`map_indexed_in_place`]: #method.map_indexed_in_place
+	pub fn map_in_place<F>(&mut self, func: F)
+	where F: Fn(bool) -> bool {
+		match (func(false), func(true)) {
+			(false, true) => {},
+			(true, false) => self.invert_all(),
+			(value, _) => self.set_all(value),
 		}
-		dbg.finish()
 	}
-}
 
-/// Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
-impl<C, T> Hash for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Writes each bit of the `BitSlice`, as a full `bool`, into the hasher.
+	/// Replaces every bit in the slice with a function of its index and value.
+	///
+	/// `func` is called once for each bit, in order from the front of the slice
+	/// to the back. As with [`for_each_mut`], which this calls, the slice is
+	/// read and written once per element rather than once per bit. When the
+	/// transformation does not depend on the index, [`map_in_place`] is
+	/// faster, as it can skip calling `func` for each bit entirely.
 	///
 	/// # Parameters
 	///
-	/// - `&self`
-	/// - `hasher`: The hashing state into which the slice will be written.
+	/// - `&mut self`
+	/// - `func`: A function which receives the index and current value of each
+	///   bit, and returns its new value.
 	///
-	/// # Type Parameters
+	/// # Examples
 	///
-	/// - `H: Hasher`: The type of the hashing algorithm which receives the bits
-	///   of `self`.
-	fn hash<H>(&self, hasher: &mut H)
-	where H: Hasher {
-		for bit in self {
-			hasher.write_u8(bit as u8);
-		}
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0; 12];
+	/// bv.map_indexed_in_place(|idx, bit| bit | (idx % 4 == 0));
+	/// assert_eq!(bv.count_ones(), 3);
+	/// ```
+	///
+	/// [`for_each_mut`]: #method.for_each_mut
+	/// [`map_in_place`]: #method.map_in_place
+	pub fn map_indexed_in_place<F>(&mut self, func: F)
+	where F: FnMut(usize, bool) -> bool {
+		self.for_each_mut(func)
 	}
-}
-
-/** Produces a read-only iterator over all the bits in the `BitSlice`.
-
-This iterator follows the ordering in the `BitSlice` type, and implements
-`ExactSizeIterator` as `BitSlice` has a known, fixed, length, and
-`DoubleEndedIterator` as it has known ends.
-**/
-impl<'a, C, T> IntoIterator for &'a BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	type Item = bool;
-	type IntoIter = Iter<'a, C, T>;
 
-	/// Iterates over the slice.
+	/// Performs “reverse” addition (left to right instead of right to left).
+	///
+	/// This addition interprets the slice, and the other addend, as having its
+	/// least significant bits first in the order and its most significant bits
+	/// last. This is most likely to be numerically useful under a
+	/// `LittleEndian` `Cursor` type.
 	///
 	/// # Parameters
 	///
-	/// - `self`
+	/// - `&mut self`: The addition uses `self` as one addend, and writes the
+	///   sum back into `self`.
+	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits. When this is
+	///   another `BitSlice`, iteration proceeds from left to right.
 	///
-	/// # Returns
+	/// # Return
 	///
-	/// An iterator over the slice domain.
+	/// The final carry bit is returned
+	///
+	/// # Effects
+	///
+	/// Starting from index `0` and proceeding upwards until either `self` or
+	/// `addend` expires, the carry-propagated addition of `self[i]` and
+	/// `addend[i]` is written to `self[i]`.
+	///
+	/// ```text
+	///   101111
+	/// + 0010__ (the two missing bits are logically zero)
+	/// --------
+	///   100000 1 (the carry-out is returned)
+	/// ```
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = 0b1010_1100u8.as_bitslice::<BigEndian>();
-	/// let mut count = 0;
-	/// for bit in bits {
-	///   if bit { count += 1; }
-	/// }
-	/// assert_eq!(count, 4);
+	/// let mut a = 0b0000_1010u8;
+	/// let     b = 0b0000_1100u8;
+	/// //      s =      1 0110
+	/// let ab = &mut a.as_mut_bitslice::<LittleEndian>()[.. 4];
+	/// let bb = &    b.as_bitslice::<LittleEndian>()[.. 4];
+	/// let c = ab.add_assign_reverse(bb);
+	/// assert!(c);
+	/// assert_eq!(ab.as_slice()[0], 0b0000_0110u8);
 	/// ```
-	fn into_iter(self) -> Self::IntoIter {
-		Iter {
-			inner: self
-		}
-	}
-}
-
-/** `BitSlice` is safe to move across thread boundaries, when atomic operations
-are enabled.
-
-Consider this (contrived) example:
-
-```rust
-# #[cfg(feature = "std")] {
-use bitvec::prelude::*;
-use std::thread;
-
-static mut SRC: u8 = 0;
-# {
-let bits = unsafe { SRC.as_mut_bitslice::<BigEndian>() };
-let (l, r) = bits.split_at_mut(4);
-
-let a = thread::spawn(move || l.set(2, true));
-let b = thread::spawn(move || r.set(2, true));
-a.join();
-b.join();
-# }
-
-println!("{:02X}", unsafe { SRC });
-# }
-```
-
-Without atomic operations, this is logically a data race. It *so happens* that,
-on x86, the read/modify/write cycles used in the crate are *basically* atomic by
-default, even when not specified as such. This is not necessarily true on other
-architectures, however.
-**/
-#[cfg(feature = "atomic")]
-unsafe impl<C, T> Send for BitSlice<C, T>
-where C: Cursor, T: BitStore {}
-
-/** Reading across threads still has synchronization concerns if one thread can
-mutate, so read access across threads requires atomicity in order to ensure that
-write operations from one thread to an element conclude before another thread
-can read from the element, even if the two `BitSlice`s do not collide.
-**/
-#[cfg(feature = "atomic")]
-unsafe impl<C, T> Sync for BitSlice<C, T>
-where C: Cursor, T: BitStore {}
-
-/** Performs unsigned addition in place on a `BitSlice`.
-
-If the addend bitstream is shorter than `self`, the addend is zero-extended at
-the left (so that its final bit matches with `self`’s final bit). If the addend
-is longer, the excess front length is unused.
-
-Addition proceeds from the right ends of each slice towards the left. Because
-this trait is forbidden from returning anything, the final carry-out bit is
-discarded.
-
-Note that, unlike `BitVec`, there is no subtraction implementation until I find
-a subtraction algorithm that does not require modifying the subtrahend.
-
-Subtraction can be implemented by negating the intended subtrahend yourself and
-then using addition, or by using `BitVec`s instead of `BitSlice`s.
-
-# Type Parameters
-
-- `I: IntoIterator<Item=bool, IntoIter: DoubleEndedIterator>`: The bitstream to
-  add into `self`. It must be finite and double-ended, since addition operates
-  in reverse.
-**/
-impl<C, T, I> AddAssign<I> for BitSlice<C, T>
-where C: Cursor, T: BitStore,
-	I: IntoIterator<Item=bool>, I::IntoIter: DoubleEndedIterator {
-	/// Performs unsigned wrapping addition in place.
 	///
-	/// # Examples
+	/// # Performance Notes
 	///
-	/// This example shows addition of a slice wrapping from max to zero.
+	/// When using `LittleEndian` `Cursor` types, this can be accelerated by
+	/// delegating the addition to the underlying types. This is a software
+	/// implementation of the [ripple-carry adder], which has `O(n)` runtime in
+	/// the number of bits. The CPU is much faster, as it has access to
+	/// element-wise or vectorized addition operations.
 	///
-	/// ```rust
-	/// use bitvec::prelude::*;
+	/// If your use case sincerely needs binary-integer arithmetic operations on
+	/// bit sets
 	///
-	/// let mut src = [0b1110_1111u8, 0b0000_0001];
-	/// let bits = src.as_mut_bitslice::<BigEndian>();
-	/// let (nums, one) = bits.split_at_mut(12);
-	/// let (accum, steps) = nums.split_at_mut(4);
-	/// *accum += &*one;
-	/// assert_eq!(accum, &steps[.. 4]);
-	/// *accum += &*one;
-	/// assert_eq!(accum, &steps[4 ..]);
-	/// ```
-	//  Clippy doesn’t like single-letter names (which is accurate) but this is
-	//  pretty standard mathematical notation in EE.
-	#[allow(clippy::many_single_char_names)]
-	fn add_assign(&mut self, addend: I) {
-		use core::iter::repeat;
-
-		//  I don't, at this time, want to implement a carry-lookahead adder in
-		//  software, so this is going to be a plain ripple-carry adder with
-		//  O(n) runtime. Furthermore, until I think of an optimization
-		//  strategy, it is going to build up another bitvec to use as a stack.
-		//
-		//  Computers are fast. Whatever.
+	/// [ripple-carry adder]: https://en.wikipedia.org/wiki/Ripple-carry_adder
+	pub fn add_assign_reverse<I>(&mut self, addend: I) -> bool
+	where I: IntoIterator<Item=bool> {
+		//  See AddAssign::add_assign for algorithm details
 		let mut c = false;
-		//  Reverse self, reverse addend and zero-extend, and zip both together.
-		//  This walks both slices from rightmost to leftmost, and considers an
-		//  early expiration of addend to continue with 0 bits.
-		//
-		//  100111
-		// +  0010
-		//  ^^---- semantically zero
-		let addend_iter = addend.into_iter().rev().chain(repeat(false));
-		for (i, b) in (0 .. self.len()).rev().zip(addend_iter) {
-			//  Bounds checks are performed in the loop header.
+		let len = self.len();
+		let zero = core::iter::repeat(false);
+		for (i, b) in addend.into_iter().chain(zero).enumerate().take(len) {
+			//  The iterator is clamped to the upper bound of `self`.
 			let a = unsafe { self.get_unchecked(i) };
 			let (y, z) = crate::rca1(a, b, c);
+			//  Write the sum into `self`
 			unsafe { self.set_unchecked(i, y); }
+			//  Propagate the carry
 			c = z;
 		}
+		c
 	}
-}
-
-/** Performs the Boolean `AND` operation against another bitstream and writes
-the result into `self`. If the other bitstream ends before `self,`, the
-remaining bits of `self` are cleared.
-
-# Type Parameters
 
-- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `BitSlice`
-  or some other bit producer as desired.
-**/
-impl<C, T, I> BitAndAssign<I> for BitSlice<C, T>
-where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
-	/// `AND`s a bitstream into a slice.
+	/// Adds another slice into this one, as unsigned integers, and reports the
+	/// carry out of the most significant bit.
+	///
+	/// Unlike the `+=` operator, which reads every slice most significant bit
+	/// first, this follows the significance order of [`BitField`]: the first
+	/// bit of a `BigEndian` slice is its most significant bit, and the first
+	/// bit of a slice with any other cursor is its least significant bit. The
+	/// bits are added 64 at a time with machine arithmetic, moving through
+	/// [`BitField::load`] and [`BitField::store`], rather than with a
+	/// ripple-carry adder one bit at a time.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `rhs`: The bitstream to `AND` into `self`.
+	/// - `rhs`: The number to add into `self`. It is zero-extended if it is
+	///   shorter than `self`.
+	///
+	/// # Returns
+	///
+	/// Whether the sum does not fit in `self.len()` bits. The sum, wrapped to
+	/// that width, is written into `self`. Set bits of `rhs` that are more
+	/// significant than any bit of `self` always overflow.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut store = [0b0101_0100u8];
-	/// let     other = [0b0011_0000u8];
-	/// let lhs = store.as_mut_bitslice::<BigEndian>();
-	/// let rhs = other.as_bitslice::<BigEndian>();
-	/// lhs[.. 6] &= &rhs[.. 4];
-	/// assert_eq!(store[0], 0b0001_0000);
+	/// let mut data = [0xFFu8, 0x00, 0x80];
+	/// let addend = [0x01u8];
+	/// let bits = data.as_mut_bitslice::<LittleEndian>();
+	/// assert!(!bits.add_assign_carry(addend.as_bitslice::<LittleEndian>()));
+	/// assert_eq!(bits.as_slice(), &[0x00, 0x01, 0x80]);
+	///
+	/// let mut data = [0xFFu8, 0xFF];
+	/// let bits = data.as_mut_bitslice::<BigEndian>();
+	/// assert!(bits.add_assign_carry(addend.as_bitslice::<BigEndian>()));
+	/// assert!(bits.not_any());
 	/// ```
-	fn bitand_assign(&mut self, rhs: I) {
-		use core::iter;
-		rhs.into_iter()
-			.chain(iter::repeat(false))
-			.enumerate()
-			.take(self.len())
-			.for_each(|(idx, bit)| {
-				let val = self[idx] & bit;
-				self.set(idx, val);
-			});
+	///
+	/// [`BitField`]: ../fields/trait.BitField.html
+	/// [`BitField::load`]: ../fields/trait.BitField.html#tymethod.load
+	/// [`BitField::store`]: ../fields/trait.BitField.html#tymethod.store
+	pub fn add_assign_carry(&mut self, rhs: &Self) -> bool {
+		self.ripple_lanes(rhs, false, |a, b, c| {
+			let (sum, x) = a.overflowing_add(b);
+			let (sum, y) = sum.overflowing_add(c as u64);
+			(sum, x || y)
+		})
 	}
-}
-
-/** Performs the Boolean `OR` operation against another bitstream and writes the
-result into `self`. If the other bitstream ends before `self`, the remaining
-bits of `self` are not affected.
-
-# Type Parameters
 
-- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `BitSlice`
-  or some other bit producer as desired.
-**/
-impl<C, T, I> BitOrAssign<I> for BitSlice<C, T>
-where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
-	/// `OR`s a bitstream into a slice.
+	/// Subtracts another slice from this one, as unsigned integers, and
+	/// reports the borrow out of the most significant bit.
+	///
+	/// The slices are read in the same significance order as
+	/// [`add_assign_carry`] reads them.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `rhs`: The bitstream to `OR` into `self`.
+	/// - `rhs`: The number to subtract from `self`. It is zero-extended if it
+	///   is shorter than `self`.
+	///
+	/// # Returns
+	///
+	/// Whether `rhs` is greater than `self`. The difference, wrapped to
+	/// `self.len()` bits, is written into `self`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut store = [0b0101_0100u8];
-	/// let     other = [0b0011_0000u8];
-	/// let lhs = store.as_mut_bitslice::<BigEndian>();
-	/// let rhs = other.as_bitslice::<BigEndian>();
-	/// lhs[.. 6] |= &rhs[.. 4];
-	/// assert_eq!(store[0], 0b0111_0100);
+	/// let mut data = [0x00u8, 0x01];
+	/// let one = [0x01u8];
+	/// let bits = data.as_mut_bitslice::<LittleEndian>();
+	/// assert!(!bits.sub_assign_borrow(one.as_bitslice::<LittleEndian>()));
+	/// assert_eq!(bits.as_slice(), &[0xFF, 0x00]);
+	///
+	/// let bits = &mut data.as_mut_bitslice::<BigEndian>()[8 ..];
+	/// assert!(bits.sub_assign_borrow(one.as_bitslice::<BigEndian>()));
+	/// assert_eq!(data, [0xFF, 0xFF]);
 	/// ```
-	fn bitor_assign(&mut self, rhs: I) {
-		rhs.into_iter()
-			.enumerate()
-			.take(self.len())
-			.for_each(|(idx, bit)| {
-				let val = self[idx] | bit;
-				self.set(idx, val);
-			});
+	///
+	/// [`add_assign_carry`]: #method.add_assign_carry
+	pub fn sub_assign_borrow(&mut self, rhs: &Self) -> bool {
+		self.ripple_lanes(rhs, false, |a, b, c| {
+			let (diff, x) = a.overflowing_sub(b);
+			let (diff, y) = diff.overflowing_sub(c as u64);
+			(diff, x || y)
+		})
 	}
-}
-
-/** Performs the Boolean `XOR` operation against another bitstream and writes
-the result into `self`. If the other bitstream ends before `self`, the remaining
-bits of `self` are not affected.
 
-# Type Parameters
-
-- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `BitSlice`
-  or some other bit producer as desired.
-**/
-impl<C, T, I> BitXorAssign<I> for BitSlice<C, T>
-where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
-	/// `XOR`s a bitstream into a slice.
+	/// Adds one to the slice, as an unsigned integer.
+	///
+	/// The slice is read in the same significance order as
+	/// [`add_assign_carry`] reads it. Only the 64-bit lanes that the carry
+	/// reaches are touched, so a counter usually costs one load and one store.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `rhs`: The bitstream to `XOR` into `self`.
+	///
+	/// # Returns
+	///
+	/// Whether the slice wrapped around from all ones to all zeros. An empty
+	/// slice always wraps.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut store = [0b0101_0100u8];
-	/// let     other = [0b0011_0000u8];
-	/// let lhs = store.as_mut_bitslice::<BigEndian>();
-	/// let rhs = other.as_bitslice::<BigEndian>();
-	/// lhs[.. 6] ^= &rhs[.. 4];
-	/// assert_eq!(store[0], 0b0110_0100);
+	/// let mut data = [0u8; 2];
+	/// let bits = &mut data.as_mut_bitslice::<BigEndian>()[.. 10];
+	/// for _ in 0 .. 0x3FF {
+	///   assert!(!bits.increment());
+	/// }
+	/// assert!(bits.all());
+	/// assert!(bits.increment());
+	/// assert!(bits.not_any());
 	/// ```
-	fn bitxor_assign(&mut self, rhs: I) {
-		rhs.into_iter()
-			.enumerate()
-			.take(self.len())
-			.for_each(|(idx, bit)| {
-				let val = self[idx] ^ bit;
-				self.set(idx, val);
-			})
+	///
+	/// [`add_assign_carry`]: #method.add_assign_carry
+	pub fn increment(&mut self) -> bool {
+		self.ripple_lanes(BitSlice::empty(), true, |a, _, c| {
+			a.overflowing_add(c as u64)
+		})
 	}
-}
 
-/// Indexes a single bit by semantic count. The index must be less than the
-/// length of the `BitSlice`.
-impl<C, T> Index<usize> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = bool;
-
-	/// Looks up a single bit by semantic index.
+	/// Divides the slice, read as an unsigned integer, by a small divisor.
+	///
+	/// The slice is read in the same significance order as
+	/// [`add_assign_carry`] reads it, which is that of [`BitField`]. The
+	/// quotient replaces the slice, at the same width, and the remainder is
+	/// returned.
+	///
+	/// This is schoolbook long division, carried out on 32 bits of the slice
+	/// at a time with 64-bit machine arithmetic.
 	///
 	/// # Parameters
 	///
-	/// - `&self`
-	/// - `index`: The semantic index of the bit to look up.
+	/// - `&mut self`
+	/// - `divisor`: The number by which to divide `self`.
 	///
 	/// # Returns
 	///
-	/// The value of the bit at the requested index.
+	/// The remainder of the division. This is always less than `divisor`.
+	///
+	/// # Panics
+	///
+	/// This panics if `divisor` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0x01u8, 0x00];
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// assert_eq!(bits.div_rem_assign(10), 6);
+	/// assert_eq!(bits.as_slice(), &[0x00, 0x19]);
+	///
+	/// let mut src = [0x00u8, 0x01];
+	/// let bits = src.as_mut_bitslice::<LittleEndian>();
+	/// assert_eq!(bits.div_rem_assign(10), 6);
+	/// assert_eq!(bits.as_slice(), &[0x19, 0x00]);
+	/// ```
+	///
+	/// [`BitField`]: ../fields/trait.BitField.html
+	/// [`add_assign_carry`]: #method.add_assign_carry
+	pub fn div_rem_assign(&mut self, divisor: u32) -> u32 {
+		assert!(divisor != 0, "Attempted to divide by zero");
+		let divisor = u64::from(divisor);
+		let len = self.len();
+		let mut rem = 0u64;
+		for n in (0 .. (len + 31) / 32).rev() {
+			let chunk = &mut self[Self::lane(len, n, 32)];
+			//  `rem` is less than `divisor`, so it has room for 32 more bits,
+			//  and the quotient fits in the width of the chunk.
+			let num = rem << chunk.len() | u64::from(chunk.load::<u32>());
+			chunk.store((num / divisor) as u32);
+			rem = num % divisor;
+		}
+		rem as u32
+	}
+
+	/// Renders the slice, read as an unsigned integer, in decimal.
+	///
+	/// The slice is read in the same significance order as
+	/// [`div_rem_assign`] reads it. The bits are copied once into machine
+	/// words, which are then divided by `10^9` repeatedly to produce nine
+	/// digits at a time. The slice itself is not modified.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The decimal digits of the value of `self`, without leading zeros. An
+	/// empty or all-zero slice renders as `"0"`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [!0u64, !0];
+	/// assert_eq!(
+	///   src.as_bitslice::<LittleEndian>().to_decimal_string(),
+	///   "340282366920938463463374607431768211455",
+	/// );
+	/// assert_eq!(bitvec![0, 1, 1, 0, 0, 1].to_decimal_string(), "25");
+	/// assert_eq!(bitvec![0; 5].to_decimal_string(), "0");
+	/// ```
+	///
+	/// The arithmetic methods all agree on the value of a slice, under every
+	/// cursor:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 3];
+	/// let bits = &mut src.as_mut_bitslice::<LittleEndian>()[2 .. 22];
+	/// let addend = 999_999u32.to_le_bytes();
+	/// let addend = &addend.as_bitslice::<LittleEndian>()[.. 20];
+	/// assert!(!bits.add_assign_carry(addend));
+	/// assert!(!bits.increment());
+	/// assert_eq!(bits.to_decimal_string(), "1000000");
+	/// assert_eq!(bits.div_rem_assign(7), 1);
+	/// assert_eq!(bits.to_decimal_string(), "142857");
+	/// assert_eq!(bits.load::<u32>(), 142_857);
+	/// ```
+	///
+	/// [`div_rem_assign`]: #method.div_rem_assign
+	#[cfg(feature = "alloc")]
+	pub fn to_decimal_string(&self) -> String {
+		use core::fmt::Write as _;
+
+		const BASE: u64 = 1_000_000_000;
+
+		//  Gather the bits into 32-bit limbs, most significant first. The
+		//  first limb takes the excess, so that the last limb ends at the least
+		//  significant bit.
+		let len = self.len();
+		let mut limbs = (0 .. (len + 31) / 32).rev()
+			.map(|n| self[Self::lane(len, n, 32)].load::<u32>())
+			.collect::<Vec<_>>();
+
+		//  Divide out nine decimal digits per pass, least significant first,
+		//  skipping limbs that have fallen to zero.
+		let mut groups = Vec::new();
+		let mut start = 0;
+		loop {
+			while start < limbs.len() && limbs[start] == 0 {
+				start += 1;
+			}
+			if start == limbs.len() {
+				break;
+			}
+			let mut rem = 0u64;
+			for limb in &mut limbs[start ..] {
+				let num = rem << 32 | u64::from(*limb);
+				*limb = (num / BASE) as u32;
+				rem = num % BASE;
+			}
+			groups.push(rem as u32);
+		}
+
+		let mut out = String::with_capacity(groups.len() * 9 + 1);
+		let mut groups = groups.into_iter().rev();
+		match groups.next() {
+			None => out.push('0'),
+			Some(top) => {
+				write!(out, "{}", top).expect("String formatting cannot fail");
+				for group in groups {
+					write!(out, "{:09}", group)
+						.expect("String formatting cannot fail");
+				}
+			},
+		}
+		out
+	}
+
+	/// Packs the slice into `u32` words, in the layout that compute shaders
+	/// read bit arrays from.
+	///
+	/// Bit `n` of the slice is stored in word `n / 32`, at bit `n % 32` of
+	/// that word, counting from the least significant bit. This layout does
+	/// not depend on the cursor or storage type of the slice, or on its
+	/// alignment in memory: the words hold the sequence of bits that the
+	/// slice presents, not a copy of its storage. The bits of the final word
+	/// that lie past the end of the slice are `0`.
+	///
+	/// The words are in native byte order, and can be handed to a GPU buffer
+	/// upload as they are. [`BitVec::from_gpu_words`] reads this layout back.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// `ceil(self.len() / 32)` words holding the bits of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0b1000_0001u8, 0b0100_0000];
+	/// let bits = &src.as_bitslice::<BigEndian>()[.. 10];
+	/// assert_eq!(bits.to_gpu_words(), [0b10_1000_0001]);
+	///
+	/// let src = [0x1234_5678u32, 0x9ABC_DEF0];
+	/// let bits = src.as_bitslice::<LittleEndian>();
+	/// assert_eq!(bits.to_gpu_words(), src);
+	/// assert_eq!(bits[4 ..].to_gpu_words(), [0x0123_4567, 0x09AB_CDEF]);
+	/// ```
+	///
+	/// [`BitVec::from_gpu_words`]: ../vec/struct.BitVec.html#method.from_gpu_words
+	#[cfg(feature = "alloc")]
+	pub fn to_gpu_words(&self) -> Vec<u32> {
+		//  Lanes follow the cursor’s orientation, so `BigEndian` lanes put
+		//  their first bit at the top and must be turned around.
+		let flip = Self::orientation() == Some(true);
+		let words = self.len() / 32 + (self.len() % 32 != 0) as usize;
+		let mut out = Vec::with_capacity(words + 1);
+		self.fold_lanes(|mut lane| {
+			if flip {
+				lane = lane.swap_bytes();
+				lane = (lane & 0x0F0F_0F0F_0F0F_0F0F) << 4
+					| (lane >> 4) & 0x0F0F_0F0F_0F0F_0F0F;
+				lane = (lane & 0x3333_3333_3333_3333) << 2
+					| (lane >> 2) & 0x3333_3333_3333_3333;
+				lane = (lane & 0x5555_5555_5555_5555) << 1
+					| (lane >> 1) & 0x5555_5555_5555_5555;
+			}
+			out.push(lane as u32);
+			out.push((lane >> 32) as u32);
+		});
+		//  The final lane may hold only one word of live bits.
+		out.truncate(words);
+		out
+	}
+
+	/// Copies the slice into a vector with a different cursor.
+	///
+	/// The produced vector holds the same sequence of bits as the slice, but
+	/// stores them in the ordering of `D`. Each storage element is rewritten
+	/// as a whole: it is copied unchanged when the two cursors agree, its bits
+	/// are reversed with a few shifts and masks when one cursor reverses the
+	/// other, and its set bits are moved by a table otherwise. A slice that
+	/// does not begin at the front of an element is first realigned.
+	///
+	/// This converts between a `BigEndian` wire format and a `LittleEndian`
+	/// working representation of the same storage type without reading or
+	/// writing any single bit through the slice API.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A vector equal to `self`, ordered by `D`.
+	///
+	/// # Type Parameters
+	///
+	/// - `D`: The cursor of the produced vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let wire = [0b1100_0101u8, 0b1000_0000];
+	/// let bits = &wire.as_bitslice::<BigEndian>()[.. 9];
+	/// let work = bits.reorder::<LittleEndian>();
+	/// assert_eq!(work, bits);
+	/// assert_eq!(work.as_slice(), [0b1010_0011, 0b0000_0001]);
+	///
+	/// let back = work.reorder::<BigEndian>();
+	/// assert_eq!(back.as_slice(), wire);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn reorder<D>(&self) -> BitVec<D, T>
+	where D: Cursor {
+		let aligned;
+		let src = if *self.bitptr().head() == 0 {
+			self
+		}
+		else {
+			let mut bv = BitVec::<C, T>::repeat(false, self.len());
+			bv.copy_bits_from(self);
+			aligned = bv;
+			&*aligned
+		};
+		let plan = Reorder::new::<C, D, T>();
+		let mut out = src.as_slice()
+			.iter()
+			.map(|&elt| plan.apply(elt))
+			.collect::<Vec<T>>();
+		//  The dead bits of a partial last element are cleared, rather than
+		//  carried into the new vector.
+		let tail = self.len() % T::BITS as usize;
+		if let (Some(last), true) = (out.last_mut(), tail != 0) {
+			*last &= D::mask_range(0u8.idx(), (tail as u8).tail());
+		}
+		let mut bv = BitVec::from_vec(out);
+		bv.truncate(self.len());
+		bv
+	}
+
+	/// Copies a rectangle of bits into a two-dimensional grid held in the
+	/// slice.
+	///
+	/// Both `self` and `src` are read as grids stored in row-major order: row
+	/// `r` of `self` is `self[r * stride ..][.. stride]`, and row `r` of `src`
+	/// is `src[r * width ..][.. width]`. Each row of `src` is copied into the
+	/// row of `self` that is `row` rows lower, beginning at column `col`. Bits
+	/// of `self` outside the copied rectangle are not modified.
+	///
+	/// Rows are copied as runs of bits, not one bit at a time. When a source
+	/// row and its destination begin at different bits of their elements, each
+	/// destination element is assembled from the two source elements it
+	/// straddles with integer shifts, and written once.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `stride`: The number of bits in each row of `self`.
+	/// - `src`: The rectangle to copy. Its length must be a multiple of
+	///   `width`.
+	/// - `width`: The number of bits in each row of `src`.
+	/// - `(row, col)`: The place in `self` of the first bit of `src`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` does not divide the length of `src`, or if any
+	/// part of the rectangle would fall outside the rows of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut canvas = bitvec![0; 6 * 4];
+	/// let sprite = bitvec![1, 1, 1, 0, 1, 0];
+	/// canvas.blit(6, &sprite, 3, (1, 2));
+	/// assert_eq!(canvas, bitvec![
+	///   0, 0, 0, 0, 0, 0,
+	///   0, 0, 1, 1, 1, 0,
+	///   0, 0, 0, 1, 0, 0,
+	///   0, 0, 0, 0, 0, 0,
+	/// ]);
+	/// ```
+	pub fn blit(
+		&mut self,
+		stride: usize,
+		src: &Self,
+		width: usize,
+		(row, col): (usize, usize),
+	) {
+		if src.is_empty() {
+			return;
+		}
+		assert!(
+			width != 0 && src.len() % width == 0,
+			"Source length {} is not a multiple of its row width {}",
+			src.len(),
+			width,
+		);
+		let rows = src.len() / width;
+		assert!(
+			col + width <= stride
+				&& (row + rows).saturating_mul(stride) <= self.len(),
+			"A {}x{} rectangle at ({}, {}) does not fit in rows of {} bits \
+			 over {} bits",
+			rows,
+			width,
+			row,
+			col,
+			stride,
+			self.len(),
+		);
+		for (r, line) in src.chunks(width).enumerate() {
+			let start = (row + r) * stride + col;
+			self[start .. start + width].copy_bits_from(line);
+		}
+	}
+
+	/// Accesses the backing storage of the `BitSlice` as a slice of its
+	/// elements.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice of all the elements that the `BitSlice` uses for storage.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [1u8, 66];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	///
+	/// let accum = bits.as_slice()
+	///   .iter()
+	///   .map(|elt| elt.count_ones())
+	///   .sum::<usize>();
+	/// assert_eq!(accum, 3);
+	/// ```
+	pub fn as_slice(&self) -> &[T] {
+		self.bitptr().as_slice()
+	}
+
+	/// Reinterprets the memory under the `BitSlice` as a structure, without
+	/// copying it.
+	///
+	/// This is only possible when the bits occupy whole storage elements that
+	/// together form exactly one properly aligned `U`. The structure sees the
+	/// bytes as they are in memory, whatever the cursor; a header in a
+	/// `BigEndian` stream of `u8` reads as it would from the byte slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A reference to the structure in the slice’s memory, if:
+	///
+	/// - the slice begins at the first bit of an element,
+	/// - the slice ends at the last bit of an element,
+	/// - the slice is exactly as long as `U`, and
+	/// - the first element is aligned for `U`.
+	///
+	/// Otherwise, `None`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: Any type which is valid for every bit pattern.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use zerocopy::FromBytes;
+	///
+	/// #[derive(FromBytes)]
+	/// #[repr(C)]
+	/// struct Header {
+	///   kind: u8,
+	///   flags: u8,
+	///   len: [u8; 2],
+	/// }
+	///
+	/// let data = [0xFFu8, 7, 0x80, 0, 2, 1];
+	/// let bits = data.as_bitslice::<BigEndian>();
+	///
+	/// let header = bits[8 .. 40].as_struct::<Header>().unwrap();
+	/// assert_eq!((header.kind, header.flags), (7, 0x80));
+	/// assert_eq!(header.len, [0, 2]);
+	///
+	/// assert!(bits[4 .. 36].as_struct::<Header>().is_none());
+	/// assert!(bits[8 .. 32].as_struct::<Header>().is_none());
+	/// ```
+	#[cfg(feature = "zerocopy")]
+	pub fn as_struct<U>(&self) -> Option<&U>
+	where U: zerocopy::FromBytes {
+		let bits = T::BITS as usize;
+		if *self.bitptr().head() != 0
+			|| self.len() % bits != 0
+			|| self.len() != mem::size_of::<U>() * 8
+		{
+			return None;
+		}
+		let ptr = self.as_slice().as_ptr() as *const U;
+		if ptr as usize % mem::align_of::<U>() != 0 {
+			return None;
+		}
+		//  The slice holds every bit of every element it touches, so no other
+		//  handle can write to this memory while the reference is alive, and
+		//  `FromBytes` permits any contents.
+		Some(unsafe { &*ptr })
+	}
+
+	/// Accesses the underlying store.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [1u8, 64];
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// for elt in bits.as_mut_slice() {
+	///   *elt |= 2;
+	/// }
+	/// assert_eq!(&[3, 66], bits.as_slice());
+	/// ```
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		self.bitptr().as_mut_slice()
+	}
+
+	/// Splits the backing storage into its partial edge elements and its
+	/// fully-live interior.
+	///
+	/// Unlike [`as_slice`], which produces every element the slice touches,
+	/// this separates out the edge elements that may be shared with other
+	/// handles, so that the interior can be processed a whole element at a
+	/// time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A [`Domain`] describing the element layout of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::Domain;
+	///
+	/// let src = [0u8, 0xA5, 0xFF, 0];
+	/// let bits = &src.as_bitslice::<BigEndian>()[4 .. 26];
+	/// match bits.domain() {
+	///   Domain::Region { head, body, tail } => {
+	///     assert_eq!(*head.unwrap().0, 4);
+	///     assert_eq!(body, &[0xA5, 0xFF]);
+	///     assert_eq!(*tail.unwrap().1, 2);
+	///   },
+	///   Domain::Enclave { .. } => unreachable!(),
+	/// }
+	///
+	/// assert!(bits[1 .. 3].domain().is_enclave());
+	/// ```
+	///
+	/// [`Domain`]: enum.Domain.html
+	/// [`as_slice`]: #method.as_slice
+	pub fn domain(&self) -> Domain<T> {
+		BitDomain::from(self.bitptr()).into()
+	}
+
+	/// Splits the backing storage into its partial edge elements and its
+	/// fully-live interior, permitting mutation of the interior.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A [`DomainMut`] describing the element layout of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::DomainMut;
+	///
+	/// let mut src = [0u8; 3];
+	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[4 ..];
+	/// if let DomainMut::Region { body, .. } = bits.domain_mut() {
+	///   for elt in body {
+	///     *elt = !0;
+	///   }
+	/// }
+	/// assert_eq!(src, [0, 0xFF, 0xFF]);
+	/// ```
+	///
+	/// [`DomainMut`]: enum.DomainMut.html
+	pub fn domain_mut(&mut self) -> DomainMut<T> {
+		BitDomainMut::from(self.bitptr()).into()
+	}
+
+	/// Views the slice as shared-mutable cells.
+	///
+	/// The returned view can be copied freely, and each copy can write into
+	/// the slice through `&self` methods, as with `Cell`. This suits
+	/// single-threaded code that needs several live handles to one bit set.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A [`BitCells`] view of the slice, which holds the borrow of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let cells = src.as_mut_bitslice::<BigEndian>().as_cells();
+	/// let (low, high) = cells.split_at(4);
+	/// low.set(0, true);
+	/// high.set(0, true);
+	/// cells.set(7, true);
+	/// assert_eq!(src, 0b1000_1001);
+	/// ```
+	///
+	/// [`BitCells`]: ../cells/struct.BitCells.html
+	pub fn as_cells(&mut self) -> &BitCells<C, T> {
+		BitCells::from_mut(self)
+	}
+
+	/// Changes the cursor type of the slice handle.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An equivalent slice handle with a new cursor type.
+	///
+	/// # Type Parameters
+	///
+	/// - `D: Cursor` The new cursor type to use for the handle.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = 2u8;
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// assert!(bits[6]);
+	/// let bits = bits.change_cursor::<LittleEndian>();
+	/// assert!(bits[1]);
+	/// ```
+	pub fn change_cursor<D>(&self) -> &BitSlice<D, T>
+	where D: Cursor {
+		self.bitptr().into_bitslice()
+	}
+
+	/// Changes the cursor type of the slice handle.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// An equivalent slice handle with a new cursor type.
+	///
+	/// # Type Parameters
+	///
+	/// - `D: Cursor` The new cursor type to use for the handle.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// *src.as_mut_bitslice::<BigEndian>().at(1) = true;
+	/// assert_eq!(src, 64);
+	/// src.as_mut_bitslice::<BigEndian>()
+	///    .change_cursor_mut::<LittleEndian>()
+	///    .set(1, true);
+	/// assert_eq!(src, 66);
+	/// ```
+	pub fn change_cursor_mut<D>(&mut self) -> &mut BitSlice<D, T>
+	where D: Cursor {
+		self.bitptr().into_bitslice_mut()
+	}
+
+	/// Accesses the underlying pointer structure.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The [`BitPtr`] structure of the slice handle.
+	///
+	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
+	pub fn bitptr(&self) -> BitPtr<T> {
+		BitPtr::from_bitslice(self)
+	}
+
+	/// Splits the slice into the bits in its partial edge elements, and the
+	/// fully-live elements between them.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `.0`: The bits of `self` in the partial head element. This is empty
+	///   when `self` begins at an element edge.
+	/// - `.1`: The fully-live elements of `self`.
+	/// - `.2`: The bits of `self` in the partial tail element. This is empty
+	///   when `self` ends at an element edge.
+	///
+	/// A slice inside a single element is returned entirely as its head.
+	pub(crate) fn split_edges(&self) -> (&Self, &[T], &Self) {
+		let len = self.len();
+		match self.domain() {
+			Domain::Enclave { .. } => (self, &[], BitSlice::empty()),
+			Domain::Region { head, body, tail } => {
+				let h = head.map(|(idx, _)| (T::BITS - *idx) as usize)
+					.unwrap_or(0);
+				let t = tail.map(|(_, idx)| *idx as usize).unwrap_or(0);
+				(&self[.. h], body, &self[len - t ..])
+			},
+		}
+	}
+
+	/// Finds the first bit in the slice equal to `value`.
+	fn first_of(&self, value: bool) -> Option<usize> {
+		let (head, body, tail) = self.split_edges();
+		let skip = T::bits(!value);
+		if let Some(n) = head.iter().position(|b| b == value) {
+			return Some(n);
+		}
+		let mut base = head.len();
+		if let Some(n) = body.iter().position(|&elt| elt != skip) {
+			base += n << T::INDX;
+			return (0 .. T::BITS)
+				.position(|idx| body[n].get::<C>(idx.idx()) == value)
+				.map(|idx| base + idx);
+		}
+		base += body.len() << T::INDX;
+		tail.iter().position(|b| b == value).map(|n| base + n)
+	}
+
+	/// Finds the last bit in the slice equal to `value`.
+	fn last_of(&self, value: bool) -> Option<usize> {
+		let (head, body, tail) = self.split_edges();
+		let skip = T::bits(!value);
+		let base = head.len() + (body.len() << T::INDX);
+		if let Some(n) = tail.iter().rposition(|b| b == value) {
+			return Some(base + n);
+		}
+		if let Some(n) = body.iter().rposition(|&elt| elt != skip) {
+			let base = head.len() + (n << T::INDX);
+			return (0 .. T::BITS)
+				.rposition(|idx| body[n].get::<C>(idx.idx()) == value)
+				.map(|idx| base + idx);
+		}
+		head.iter().rposition(|b| b == value)
+	}
+
+	/// Copies every bit of another slice of equal length into this one.
+	///
+	/// When both slices begin at the same bit of an element, the whole
+	/// elements between their edges are copied directly. Otherwise, each
+	/// element of `self` is stitched together from the two elements of `src`
+	/// that it straddles, and written once.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The slice to copy into `self`.
+	///
+	/// # Panics
+	///
+	/// This panics if the two slices have different lengths.
+	pub(crate) fn copy_bits_from(&mut self, src: &Self) {
+		let len = self.len();
+		assert_eq!(len, src.len(), "Copied slices must have equal lengths");
+		if self.bitptr().head() == src.bitptr().head() {
+			let bits = T::BITS as usize;
+			let head = *self.bitptr().head() as usize;
+			let edge = cmp::min(len, (bits - head) & T::MASK as usize);
+			let span = edge .. edge + (len - edge) / bits * bits;
+			if span.start < span.end {
+				self[span.clone()].as_mut_slice()
+					.copy_from_slice(src[span.clone()].as_slice());
+			}
+			//  The partial elements at either edge line up as well, and are
+			//  each copied by one masked store.
+			let data = self.bitptr().pointer().a();
+			let from = src.bitptr().pointer().a();
+			let copy_edge = |n: usize, lo: usize, hi: usize| unsafe {
+				Self::store_masked(
+					&*data.add(n),
+					(&*from.add(n)).load(),
+					Self::live_mask(lo as u8, hi as u8),
+				);
+			};
+			if edge > 0 {
+				copy_edge(0, head, head + edge);
+			}
+			if span.end < len {
+				copy_edge((head + span.end) / bits, 0, len - span.end);
+			}
+			return;
+		}
+		if len == 0 {
+			return;
+		}
+		let (dst, srcptr) = (self.bitptr(), src.bitptr());
+		let (head, tail) = (*dst.head(), *dst.tail());
+		let last = dst.elements() - 1;
+		let (data, from) = (dst.pointer().a(), srcptr.pointer().a());
+		let from_last = srcptr.elements() as isize - 1;
+		let load = |m: isize| {
+			if m < 0 || m > from_last {
+				return T::bits(false);
+			}
+			unsafe { (&*from.offset(m)).load() }
+		};
+		let width = T::BITS as isize;
+		let orient = Self::orientation();
+		for n in 0 ..= last {
+			//  The position, in `src`’s memory, of the bit that goes to
+			//  semantic index 0 of this element, one element higher so that it
+			//  is never negative.
+			let pos = n as isize * width + *srcptr.head() as isize
+				- head as isize + width;
+			let (m, rem) = (pos / width - 1, (pos % width) as u8);
+			let val = match rem {
+				0 => load(m),
+				_ => Self::stitch(load(m), load(m + 1), rem, orient),
+			};
+			let live = Self::live_mask(
+				if n == 0 { head } else { 0 },
+				if n == last { tail } else { T::BITS },
+			);
+			Self::store_masked(unsafe { &*data.add(n) }, val, live);
+		}
+	}
+
+	/// Exchanges one bit between two slices.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: Another slice, at least as long as `index`.
+	/// - `index`: The bit to exchange.
+	fn swap_bit_with(&mut self, other: &mut Self, index: usize) {
+		let (a, b) = (self[index], other[index]);
+		self.set(index, b);
+		other.set(index, a);
+	}
+
+	/// Narrows the slice to a range, in the manner of `Vec::drain`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: Any range of bit indices within `self`.
+	///
+	/// # Returns
+	///
+	/// The subslice of `self` over `range`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` runs backwards or leaves `0 .. self.len()`.
+	fn in_range<R>(&self, range: R) -> &Self
+	where R: RangeBounds<usize> {
+		&self[self.bounds(range)]
+	}
+
+	/// Resolves any range of bit indices into a concrete `Range`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: Any range of bit indices within `self`.
+	///
+	/// # Returns
+	///
+	/// The indices described by `range`, with unbounded ends filled in from
+	/// `0 .. self.len()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` runs backwards or leaves `0 .. self.len()`.
+	fn bounds<R>(&self, range: R) -> Range<usize>
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let from = match range.start_bound() {
+			Bound::Included(&n) => n,
+			Bound::Excluded(&n) => n + 1,
+			Bound::Unbounded => 0,
+		};
+		let upto = match range.end_bound() {
+			Bound::Included(&n) => n + 1,
+			Bound::Excluded(&n) => n,
+			Bound::Unbounded => len,
+		};
+		assert!(from <= upto, "Range starts at {} but ends at {}", from, upto);
+		assert!(upto <= len, "Range end {} out of bounds: {}", upto, len);
+		from .. upto
+	}
+
+	/// Reads the slice as an unsigned integer, in the significance order of
+	/// `BitField`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The width, in bits, of the integer type being compared. This
+	///   must be no more than 128.
+	///
+	/// # Returns
+	///
+	/// The value of the slice, or `None` if it has more than `width` bits.
+	pub(crate) fn int_value(&self, width: usize) -> Option<u128> {
+		if self.len() > width {
+			return None;
+		}
+		Some(self.load::<u128>())
+	}
+
+	/// Reads the slice as an unsigned integer, in the bit significance order
+	/// of the cursor.
+	///
+	/// Slices with a cursor that begins at the most significant bit of an
+	/// element are read most significant bit first; all others are read least
+	/// significant bit first.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A slice no wider than `U`.
+	///
+	/// # Returns
+	///
+	/// The value of the slice.
+	pub(crate) fn field_value<U>(&self) -> U
+	where U: BitStore {
+		debug_assert!(self.len() <= U::BITS as usize, "Field is too wide");
+		let mut out = U::from(0);
+		if Self::orientation() == Some(true) {
+			for bit in self {
+				out <<= 1;
+				if bit {
+					out |= U::from(1);
+				}
+			}
+		}
+		else {
+			for (n, bit) in self.iter().enumerate() {
+				if bit {
+					out |= U::from(1) << n as u8;
+				}
+			}
+		}
+		out
+	}
+
+	/// Combines another slice into this one with an element-wise operator.
+	///
+	/// When both slices begin at the front of an element, the whole elements
+	/// they share are combined a full element at a time. All other bits are
+	/// combined one at a time, by applying `func` to single-bit elements.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The slice to combine into `self`. Only the first
+	///   `min(self.len(), rhs.len())` bits of each slice take part.
+	/// - `func`: A bitwise operator, which must treat every bit position
+	///   independently.
+	pub(crate) fn zip_assign<F>(&mut self, rhs: &Self, func: F)
+	where F: Fn(T, T) -> T {
+		let len = cmp::min(self.len(), rhs.len());
+		let mut done = 0;
+		if *self.bitptr().head() == 0 && *rhs.bitptr().head() == 0 {
+			let src = rhs.bitptr().pointer().a();
+			let body = match BitDomainMut::from(self.bitptr()) {
+				BitDomainMut::PartialTail(body, _, _) => body,
+				BitDomainMut::Spanning(body) => body,
+				_ => &mut [],
+			};
+			for (n, elt) in body.iter_mut().take(len >> T::INDX).enumerate() {
+				*elt = func(*elt, unsafe { (&*src.add(n)).load() });
+				done += T::BITS as usize;
+			}
+		}
+		let zero = T::bits(false);
+		for idx in done .. len {
+			unsafe {
+				let (l, r) = (self.get_unchecked(idx), rhs.get_unchecked(idx));
+				self.set_unchecked(idx, func(T::bits(l), T::bits(r)) != zero);
+			}
+		}
+	}
+
+	/// Finds the `n`th lane of `width` bits in a slice, counting from its
+	/// least significant end in the order of `BitField`.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The length of the slice.
+	/// - `n`: The rank of the lane. Lane `0` holds the `width` least
+	///   significant bits, lane `1` the next `width`, and so on.
+	/// - `width`: The number of bits in each lane.
+	///
+	/// # Returns
+	///
+	/// The range of the slice under the lane. The most significant lane may be
+	/// narrower than `width` bits, and lanes past the end of the slice are
+	/// empty.
+	fn lane(len: usize, n: usize, width: usize) -> Range<usize> {
+		let lo = cmp::min(n.saturating_mul(width), len);
+		let hi = cmp::min(lo.saturating_add(width), len);
+		if Self::orientation() == Some(true) {
+			len - hi .. len - lo
+		}
+		else {
+			lo .. hi
+		}
+	}
+
+	/// Runs a carry through `self` and `rhs` one 64-bit lane at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The other operand, zero-extended to the length of `self`.
+	/// - `carry`: The carry into the least significant lane.
+	/// - `func`: Combines a lane of `self`, the lane of `rhs` at the same
+	///   significance, and the incoming carry, into the new lane and whether it
+	///   overflowed 64 bits.
+	///
+	/// # Returns
+	///
+	/// The carry out of the most significant lane of `self`, or `true` if
+	/// `rhs` has set bits more significant than any bit of `self`.
+	fn ripple_lanes<F>(&mut self, rhs: &Self, mut carry: bool, func: F) -> bool
+	where F: Fn(u64, u64, bool) -> (u64, bool) {
+		let (len, rlen) = (self.len(), rhs.len());
+		let lanes = (len + 63) / 64;
+		for n in 0 .. lanes {
+			let theirs = Self::lane(rlen, n, 64);
+			if theirs.start == theirs.end && !carry {
+				return false;
+			}
+			let field = &mut self[Self::lane(len, n, 64)];
+			let width = field.len();
+			let (val, over) =
+				func(field.load::<u64>(), rhs[theirs].load::<u64>(), carry);
+			field.store(val);
+			//  A short lane cannot overflow the machine word, so its carry is
+			//  the first bit above it.
+			carry = over || (width < 64 && val >> width != 0);
+		}
+		let rest = Self::lane(rlen, lanes, 64);
+		let excess = if Self::orientation() == Some(true) {
+			&rhs[.. rest.end]
+		}
+		else {
+			&rhs[rest.start ..]
+		};
+		carry || excess.any()
+	}
+
+	/// Produces a mask of the bits in an element that lie in `from .. upto`.
+	///
+	/// This is `Cursor::mask_range`, taking the plain indices that the domain
+	/// and range arithmetic in this module produce.
+	///
+	/// # Parameters
+	///
+	/// - `from`: The first semantic index in the mask.
+	/// - `upto`: The first semantic index after the mask. This may be
+	///   `T::BITS`.
+	///
+	/// # Returns
+	///
+	/// An element with the positions, as governed by `C`, of each index in
+	/// `from .. upto` set, and all other positions cleared.
+	fn live_mask(from: u8, upto: u8) -> T {
+		if from >= upto {
+			return T::bits(false);
+		}
+		C::mask_range::<T>(from.idx(), upto.tail())
+	}
+
+	/// Counts the set bits of an element that lie in `from .. upto`.
+	///
+	/// This is one masked population count, with the mask from
+	/// `Cursor::mask_range`.
+	fn count_live(elt: T, from: u8, upto: u8) -> usize {
+		(elt & Self::live_mask(from, upto)).count_ones()
+	}
+
+	/// Combines a mask into every element under the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `edge`: A function which receives a partial element at either edge
+	///   of the slice, and the electrical positions of its live bits.
+	/// - `body`: A function which receives each element that the slice fully
+	///   occupies.
+	fn mask_elements<F, G>(&mut self, edge: F, body: G)
+	where F: Fn(&T::Access, T), G: Fn(&mut T) {
+		match self.bitptr().domain_mut() {
+			BitDomainMut::Empty => {},
+			BitDomainMut::Minor(head, elt, tail) => {
+				edge(elt, Self::live_mask(*head, *tail));
+			},
+			BitDomainMut::Major(h, head, elts, tail, t) => {
+				edge(head, Self::live_mask(*h, T::BITS));
+				elts.iter_mut().for_each(body);
+				edge(tail, Self::live_mask(0, *t));
+			},
+			BitDomainMut::PartialHead(h, head, elts) => {
+				edge(head, Self::live_mask(*h, T::BITS));
+				elts.iter_mut().for_each(body);
+			},
+			BitDomainMut::PartialTail(elts, tail, t) => {
+				elts.iter_mut().for_each(body);
+				edge(tail, Self::live_mask(0, *t));
+			},
+			BitDomainMut::Spanning(elts) => {
+				elts.iter_mut().for_each(body);
+			},
+		}
+	}
+
+	/// Clears each bit that is clear in `mask`, in every element under the
+	/// slice.
+	fn and_mask(&mut self, mask: T) {
+		self.mask_elements(
+			|elt, live| elt.set_bits(live & !mask, false),
+			|elt| *elt &= mask,
+		);
+	}
+
+	/// Sets each bit that is set in `mask`, in every element under the slice.
+	fn or_mask(&mut self, mask: T) {
+		self.mask_elements(
+			|elt, live| elt.set_bits(live & mask, true),
+			|elt| *elt |= mask,
+		);
+	}
+
+	/// Inverts each bit that is set in `mask`, in every element under the
+	/// slice.
+	fn xor_mask(&mut self, mask: T) {
+		self.mask_elements(
+			|elt, live| elt.invert_bits(live & mask),
+			|elt| *elt = *elt ^ mask,
+		);
+	}
+
+	/// Renders the live bits of each element in turn as `0` and `1` text.
+	///
+	/// `T::BITS` cannot be used as the size of an array, due to limitations in
+	/// the type system, so the text is written into a stack buffer of the
+	/// largest element width. This keeps the rendering usable in `#![no_std]`
+	/// contexts, as no `String` is ever built.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `func`: A function which receives the text of the live bits in each
+	///   element, in order.
+	///
+	/// # Returns
+	///
+	/// The first error returned by `func`, if any.
+	fn render_elements<F>(&self, mut func: F) -> fmt::Result
+	where F: FnMut(&str) -> fmt::Result {
+		let mut w: [u8; 64] = [b'0'; 64];
+		let mut writer = |elt: &T, from: u8, to: u8| {
+			let (from, to) = (from as usize, to as usize);
+			for (n, byte) in w.iter_mut().enumerate().take(to).skip(from) {
+				*byte = b'0' + (elt.get::<C>((n as u8).idx()) as u8);
+			}
+			func(unsafe { str::from_utf8_unchecked(&w[from .. to]) })
+		};
+		match self.bitptr().domain() {
+			BitDomain::Empty => {},
+			BitDomain::Minor(head, elt, tail) => {
+				writer(&elt.load(), *head, *tail)?;
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				writer(&head.load(), *h, T::BITS)?;
+				for elt in body {
+					writer(elt, 0, T::BITS)?;
+				}
+				writer(&tail.load(), 0, *t)?;
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				writer(&head.load(), *h, T::BITS)?;
+				for elt in body {
+					writer(elt, 0, T::BITS)?;
+				}
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				for elt in body {
+					writer(elt, 0, T::BITS)?;
+				}
+				writer(&tail.load(), 0, *t)?;
+			},
+			BitDomain::Spanning(body) => {
+				for elt in body {
+					writer(elt, 0, T::BITS)?;
+				}
+			},
+		}
+		Ok(())
+	}
+
+	/// Packs the live bits of the slice into `u64` lanes, in semantic order.
+	///
+	/// Each lane holds the next 64 bits of the slice, whatever the storage
+	/// type and alignment, and the final lane is padded with `0` bits. When
+	/// `C` has an [`orientation`], the lanes follow it, so that runs of bits
+	/// move into a lane with a single shift; otherwise, the first bit of each
+	/// lane is its least significant bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `func`: A function which receives each lane in turn.
+	///
+	/// [`orientation`]: #method.orientation
+	fn fold_lanes<F>(&self, mut func: F)
+	where F: FnMut(u64) {
+		let orient = Self::orientation();
+		let width = T::BITS;
+		let mut lane = 0u64;
+		let mut fill = 0u8;
+		let mut step = |elt: T, mut from: u8, to: u8| {
+			let val: u64 = elt.into();
+			while from < to {
+				let take = cmp::min(to - from, 64 - fill);
+				let mask = if take == 64 { !0 } else { (1u64 << take) - 1 };
+				match orient {
+					Some(true) => {
+						let chunk = (val >> (width - from - take)) & mask;
+						lane |= chunk << (64 - fill - take);
+					},
+					Some(false) => {
+						lane |= ((val >> from) & mask) << fill;
+					},
+					None => for n in 0 .. take {
+						if elt.get::<C>((from + n).idx()) {
+							lane |= 1 << (fill + n);
+						}
+					},
+				}
+				from += take;
+				fill += take;
+				if fill == 64 {
+					func(lane);
+					lane = 0;
+					fill = 0;
+				}
+			}
+		};
+		match self.bitptr().domain() {
+			BitDomain::Empty => {},
+			BitDomain::Minor(head, elt, tail) => step(elt.load(), *head, *tail),
+			BitDomain::Major(h, head, body, tail, t) => {
+				step(head.load(), *h, width);
+				for elt in body {
+					step(*elt, 0, width);
+				}
+				step(tail.load(), 0, *t);
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				step(head.load(), *h, width);
+				for elt in body {
+					step(*elt, 0, width);
+				}
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				for elt in body {
+					step(*elt, 0, width);
+				}
+				step(tail.load(), 0, *t);
+			},
+			BitDomain::Spanning(body) => {
+				for elt in body {
+					step(*elt, 0, width);
+				}
+			},
+		}
+		if fill > 0 {
+			func(lane);
+		}
+	}
+
+	/// Reports whether `C` orders the bits of an element from the most
+	/// significant end, as `BigEndian` does, or from the least significant
+	/// end, as `LittleEndian` does.
+	///
+	/// # Returns
+	///
+	/// `Some(true)` for a most-significant-first ordering, `Some(false)` for a
+	/// least-significant-first ordering, and `None` for any other ordering.
+	///
+	/// Cursors that declare [`Cursor::IS_REVERSED`] or [`Cursor::IS_IDENTITY`]
+	/// are answered at compile time. Other cursors are probed, so that an
+	/// undeclared ordering by significance still reaches the fast kernels.
+	///
+	/// [`Cursor::IS_IDENTITY`]: ../cursor/trait.Cursor.html#associatedconstant.IS_IDENTITY
+	/// [`Cursor::IS_REVERSED`]: ../cursor/trait.Cursor.html#associatedconstant.IS_REVERSED
+	pub(crate) fn orientation() -> Option<bool> {
+		if C::IS_REVERSED {
+			return Some(true);
+		}
+		if C::IS_IDENTITY {
+			return Some(false);
+		}
+		let at = |n: u8| *C::at::<T>(n.idx());
+		if (0 .. T::BITS).all(|n| at(n) == T::MASK - n) {
+			Some(true)
+		}
+		else if (0 .. T::BITS).all(|n| at(n) == n) {
+			Some(false)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Joins the back of one element to the front of the next.
+	///
+	/// # Parameters
+	///
+	/// - `lo`: The element whose bits begin the result.
+	/// - `hi`: The element whose bits end the result.
+	/// - `rem`: The semantic index in `lo` of the first bit of the result. This
+	///   must be in `1 .. T::BITS`.
+	/// - `orient`: The [`orientation`] of `C`.
+	///
+	/// # Returns
+	///
+	/// An element whose semantic bit `n` is bit `n + rem` of `lo`, when that is
+	/// in `lo`, or else bit `n + rem - T::BITS` of `hi`. When `C` orders bits
+	/// by significance, this is two shifts; otherwise, the result is assembled
+	/// one bit at a time.
+	///
+	/// [`orientation`]: #method.orientation
+	fn stitch(lo: T, hi: T, rem: u8, orient: Option<bool>) -> T {
+		match orient {
+			//  Moving bits towards the front shifts towards the most
+			//  significant bit in `BigEndian` order, and towards the least
+			//  significant bit in `LittleEndian` order.
+			Some(true) => {
+				let mut val = lo << rem;
+				val |= hi >> (T::BITS - rem);
+				val
+			},
+			Some(false) => {
+				let mut val = lo >> rem;
+				val |= hi << (T::BITS - rem);
+				val
+			},
+			None => {
+				let mut val = T::bits(false);
+				for bit in 0 .. T::BITS {
+					let (src, from) = if bit < T::BITS - rem {
+						(lo, bit + rem)
+					}
+					else {
+						(hi, bit + rem - T::BITS)
+					};
+					val.set::<C>(bit.idx(), src.get::<C>(from.idx()));
+				}
+				val
+			},
+		}
+	}
+
+	/// Writes the bits of a value that lie under a mask into an element.
+	///
+	/// The write is a single read-modify-write that inverts only the masked
+	/// bits which differ, so bits outside the mask, which may belong to other
+	/// handles, are not disturbed.
+	///
+	/// # Parameters
+	///
+	/// - `elt`: The element to write.
+	/// - `val`: The value to write into `elt`.
+	/// - `mask`: The electrical positions in `elt` to write.
+	pub(crate) fn store_masked(elt: &T::Access, val: T, mask: T) {
+		let old = elt.load();
+		let mut diff = old & !val;
+		diff |= val & !old;
+		let diff = diff & mask;
+		if diff != T::bits(false) {
+			elt.invert_bits(diff);
+		}
+	}
+
+	/// Measures a shift in whole elements, if the slice can be shifted by
+	/// copying its elements.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `shamt`: The distance to move each bit. This must be less than
+	///   `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The shift distance in elements, and the number of elements that keep
+	/// live bits after the shift. This is `None` if `shamt` is not a multiple
+	/// of the element width, or if the slice has a partial edge element.
+	fn element_shift(&self, shamt: usize) -> Option<(usize, usize)> {
+		let bp = self.bitptr();
+		if shamt & T::MASK as usize != 0
+			|| *bp.head() != 0
+			|| *bp.tail() != T::BITS
+		{
+			return None;
+		}
+		let offset = shamt >> T::INDX;
+		Some((offset, bp.elements() - offset))
+	}
+
+	/// Moves every bit in the slice `shamt` places towards the front, or
+	/// towards the back, and clears the places that the move vacates.
+	///
+	/// Each element of the slice is read and written once. Its new value is
+	/// assembled from the two elements that its source bits straddle, with
+	/// integer shifts when `C` orders bits by significance, or one bit at a
+	/// time in a register otherwise. Elements are visited in the direction
+	/// opposite to the move, so that no source element is overwritten before
+	/// it is read.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `shamt`: The distance to move each bit. This must be in
+	///   `1 .. self.len()`.
+	/// - `forward`: Whether the bits move towards index `0`.
+	fn shunt(&mut self, shamt: usize, forward: bool) {
+		let bitptr = self.bitptr();
+		let (head, tail) = (*bitptr.head(), *bitptr.tail());
+		let last = bitptr.elements() - 1;
+		let data = bitptr.pointer().a();
+		let zero = T::bits(false);
+		let live = |n: usize| Self::live_mask(
+			if n == 0 { head } else { 0 },
+			if n == last { tail } else { T::BITS },
+		);
+		//  Elements outside the slice, and dead bits inside it, read as zero,
+		//  so that they move in as cleared bits.
+		let load = |n: isize| {
+			if n < 0 || n > last as isize {
+				return zero;
+			}
+			let n = n as usize;
+			let elt = unsafe { (&*data.add(n)).load() };
+			elt & live(n)
+		};
+		let step = if forward { 1 } else { -1 };
+		let skip = (shamt >> T::INDX) as isize * step;
+		let rem = (shamt & T::MASK as usize) as u8;
+		let orient = Self::orientation();
+		let apply = |n: usize| {
+			let near = load(n as isize + skip);
+			let far = load(n as isize + skip + step);
+			let val = match rem {
+				0 => near,
+				_ if forward => Self::stitch(near, far, rem, orient),
+				_ => Self::stitch(far, near, T::BITS - rem, orient),
+			};
+			Self::store_masked(unsafe { &*data.add(n) }, val, live(n));
+		};
+		if forward {
+			(0 ..= last).for_each(apply);
+		}
+		else {
+			(0 ..= last).rev().for_each(apply);
+		}
+	}
+}
+
+/// Creates an owned `BitVec<C, T>` from a borrowed `BitSlice<C, T>`.
+#[cfg(feature = "alloc")]
+impl<C, T> ToOwned for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Owned = BitVec<C, T>;
+
+	/// Clones a borrowed `BitSlice` into an owned `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let store = [0u8, 2];
+	/// let src = store.as_bitslice::<LittleEndian>();
+	/// let dst = src.to_owned();
+	/// assert_eq!(src, dst);
+	/// # }
+	/// ```
+	fn to_owned(&self) -> Self::Owned {
+		Self::Owned::from_bitslice(self)
+	}
+}
+
+impl<C, T> Eq for BitSlice<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> Ord for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.partial_cmp(rhs)
+			.unwrap_or_else(|| unreachable!("`BitSlice` has a total ordering"))
+	}
+}
+
+/** Tests if two `BitSlice`s are semantically — not bitwise — equal.
+
+It is valid to compare two slices of different cursor or element types.
+
+The equality condition requires that they have the same number of total bits and
+that each pair of bits in semantic order are identical.
+**/
+impl<A, B, C, D> PartialEq<BitSlice<C, D>> for BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	/// Performas a comparison by `==`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rhs`: Another `BitSlice` against which to compare. This slice can
+	///   have different cursor or storage types.
+	///
+	/// # Returns
+	///
+	/// If the two slices are equal, by comparing the lengths and bit values at
+	/// each semantic index.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lsrc = [8u8, 16, 32, 0];
+	/// let rsrc = [0x10_08_04_00u32];
+	/// let lbits = lsrc.as_bitslice::<LittleEndian>();
+	/// let rbits = rsrc.as_bitslice::<BigEndian>();
+	///
+	/// assert_eq!(lbits, rbits);
+	/// ```
+	fn eq(&self, rhs: &BitSlice<C, D>) -> bool {
+		if self.len() != rhs.len() {
+			return false;
+		}
+		self.iter().zip(rhs.iter()).all(|(l, r)| l == r)
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitSlice<C, D>> for &BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitSlice<C, D>) -> bool {
+		(*self).eq(rhs)
+	}
+}
+
+/// Allow comparison against the allocated form.
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialEq<BitVec<C, D>> for BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitVec<C, D>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialEq<BitVec<C, D>> for &BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitVec<C, D>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+/** Compares two `BitSlice`s by semantic — not bitwise — ordering.
+
+The comparison sorts by testing each index for one slice to have a set bit where
+the other has an unset bit. If the slices are different, the slice with the set
+bit sorts greater than the slice with the unset bit.
+
+If one of the slices is exhausted before they differ, the longer slice is
+greater.
+**/
+impl<A, B, C, D> PartialOrd<BitSlice<C, D>> for BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	/// Performs a comparison by `<` or `>`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rhs`: Another `BitSlice` against which to compare. This slice can
+	///   have different cursor or storage types.
+	///
+	/// # Returns
+	///
+	/// The relative ordering of `self` against `rhs`. `self` is greater if it
+	/// has a `true` bit at an index where `rhs` has a `false`; `self` is lesser
+	/// if it has a `false` bit at an index where `rhs` has a `true`; if the two
+	/// slices do not disagree then they are compared by length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = 0x45u8;
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// let a = &bits[0 .. 3]; // 010
+	/// let b = &bits[0 .. 4]; // 0100
+	/// let c = &bits[0 .. 5]; // 01000
+	/// let d = &bits[4 .. 8]; // 0101
+	///
+	/// assert!(a < b);
+	/// assert!(b < c);
+	/// assert!(c < d);
+	/// ```
+	fn partial_cmp(&self, rhs: &BitSlice<C, D>) -> Option<Ordering> {
+		for (l, r) in self.iter().zip(rhs.iter()) {
+			match (l, r) {
+				(true, false) => return Some(Ordering::Greater),
+				(false, true) => return Some(Ordering::Less),
+				_ => continue,
+			}
+		}
+		self.len().partial_cmp(&rhs.len())
+	}
+}
+
+impl<A, B, C, D> PartialOrd<BitSlice<C, D>> for &BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &BitSlice<C, D>) -> Option<Ordering> {
+		(*self).partial_cmp(rhs)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialOrd<BitVec<C, D>> for BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &BitVec<C, D>) -> Option<Ordering> {
+		self.partial_cmp(rhs.as_bitslice())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialOrd<BitVec<C, D>> for &BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &BitVec<C, D>) -> Option<Ordering> {
+		(*self).partial_cmp(rhs.as_bitslice())
+	}
+}
+
+/// Provides write access to all elements in the underlying storage, including
+/// the partial head and tail elements if present.
+impl<C, T> AsMut<[T]> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Accesses the underlying store.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable slice of all storage elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8, 128];
+	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[1 .. 9];
+	///
+	/// for elt in bits.as_mut() {
+	///   *elt += 2;
+	/// }
+	///
+	/// assert_eq!(&[2, 130], bits.as_ref());
+	/// ```
+	fn as_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+/// Provides read access to all elements in the underlying storage, including
+/// the partial head and tail elements if present.
+impl<C, T> AsRef<[T]> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Accesses the underlying store.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An immutable slice of all storage elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0u8, 128];
+	/// let bits = &src.as_bitslice::<BigEndian>()[1 .. 9];
+	/// assert_eq!(&[0, 128], bits.as_ref());
+	/// ```
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<'a, C, T> From<&'a T> for &'a BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a T) -> Self {
+		BitSlice::<C, T>::from_element(src)
+	}
+}
+
+impl<'a, C, T> From<&'a [T]> for &'a BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a [T]) -> Self {
+		BitSlice::<C, T>::from_slice(src)
+	}
+}
+
+impl<'a, C, T> From<&'a mut T> for &'a mut BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a mut T) -> Self {
+		BitSlice::<C, T>::from_element_mut(src)
+	}
+}
+
+impl<'a, C, T> From<&'a mut [T]> for &'a mut BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a mut [T]) -> Self {
+		BitSlice::<C, T>::from_slice_mut(src)
+	}
+}
+
+impl<'a, C, T> Default for &'a BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn default() -> Self {
+		BitSlice::empty()
+	}
+}
+
+impl<'a, C, T> Default for &'a mut BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn default() -> Self {
+		BitSlice::empty_mut()
+	}
+}
+
+/** Prints the `BitSlice` for debugging.
+
+The output is of the form `BitSlice<C, T> [ELT, *]` where `<C, T>` is the cursor
+and element type, with square brackets on each end of the bits and all the
+elements of the array printed in binary. The printout is always in semantic
+order, and may not reflect the underlying buffer. To see the underlying buffer,
+use `.as_ref()`.
+
+The alternate character `{:#?}` prints each element on its own line, rather than
+having all elements on the same line.
+**/
+impl<C, T> Debug for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitSlice` type header and contents for debug.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0b0101_0000_1111_0101u16, 0b00000000_0000_0010];
+	/// let bits = &src.as_bitslice::<LittleEndian>()[.. 18];
+	/// assert_eq!(
+    ///     "BitSlice<LittleEndian, u16> [1010111100001010, 01]",
+	///     &format!("{:?}", bits),
+	/// );
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitSlice<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self, f)
+	}
+}
+
+/** Prints the `BitSlice` for displaying.
+
+This prints each element in turn, formatted in binary in semantic order (so the
+first bit seen is printed first and the last bit seen is printed last). Each
+element of storage is separated by a space for ease of reading.
+
+The alternate character `{:#}` prints each element on its own line.
+
+The rendering is written directly into the formatter, and does not allocate.
+Use [`write_bits_to`] to choose a different layout, or to write into some other
+text sink.
+
+To see the in-memory representation, use `.as_ref()` to get access to the raw
+elements and print that slice instead.
+
+[`write_bits_to`]: #method.write_bits_to
+**/
+impl<C, T> Display for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitSlice` contents for display.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `f`: The formatter into which `self` is written.
+	///
+	/// # Returns
+	///
+	/// The result of the formatting operation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0b01001011u8, 0b0100_0000];
+	/// let bits = &src.as_bitslice::<BigEndian>()[.. 10];
+	/// assert_eq!("[01001011, 01]", &format!("{}", bits));
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		struct Part<'a>(&'a str);
+		impl<'a> Debug for Part<'a> {
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				f.write_str(&self.0)
+			}
+		}
+
+		let mut dbg = f.debug_list();
+		self.render_elements(|part| {
+			dbg.entry(&Part(part));
+			Ok(())
+		})?;
+		dbg.finish()
+	}
+}
+
+/** Layouts for [`BitSlice::write_bits_to`].
+
+Each layout writes the bits in semantic order, as `0` and `1` characters. The
+layouts differ only in how they mark the boundaries between storage elements.
+
+[`BitSlice::write_bits_to`]: struct.BitSlice.html#method.write_bits_to
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitStyle {
+	/// Each element is a list entry, as in the `Display` rendering:
+	/// `[01001011, 01]`.
+	List,
+	/// Elements are separated by a single space: `01001011 01`.
+	Grouped,
+	/// The bits are written without any separators: `0100101101`.
+	Plain,
+}
+
+impl Default for BitStyle {
+	fn default() -> Self {
+		BitStyle::List
+	}
+}
+
+/** A mask of electrical bit positions, applied to every element under a slice.
+
+`BitSlice` implements `&=`, `|=`, and `^=` against any stream of bits. A
+storage element cannot be such a stream, so a mask that is to be repeated in
+each element is wrapped in this type instead. The operators combine the mask
+with each element that the slice touches, a whole element at a time, without
+building a second slice. The mask is in electrical order, and is not translated
+by the cursor of the slice. Bits of the edge elements that lie outside the
+slice are not modified.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::slice::Mask;
+
+let mut pixels = [0x1234u16, 0xABCD, 0xFFFF];
+let bits = pixels.as_mut_bitslice::<BigEndian>();
+*bits &= Mask(0x0FF0);
+assert_eq!(pixels, [0x0230, 0x0BC0, 0x0FF0]);
+```
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Mask<T>(pub T);
+
+/// Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
+impl<C, T> Hash for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Writes the length of the `BitSlice`, then its bits packed into `u64`
+	/// lanes, into the hasher.
+	///
+	/// The lanes are assembled from the live bits alone, so equal slices hash
+	/// equally whatever their alignment in memory or storage type, and no
+	/// normalized copy of the slice is made.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `hasher`: The hashing state into which the slice will be written.
+	///
+	/// # Type Parameters
+	///
+	/// - `H: Hasher`: The type of the hashing algorithm which receives the bits
+	///   of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use std::collections::hash_map::DefaultHasher;
+	/// use std::hash::{Hash, Hasher};
+	///
+	/// fn digest<T: Hash + ?Sized>(val: &T) -> u64 {
+	///   let mut hasher = DefaultHasher::new();
+	///   val.hash(&mut hasher);
+	///   hasher.finish()
+	/// }
+	///
+	/// let src = [0x5Au8, 0xC3, 0x3C, 0xA5, 0x0F, 0xF0, 0x96, 0x69, 0x81];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// let moved = bits[3 ..].to_owned();
+	/// assert_eq!(digest(&bits[3 ..]), digest(&moved));
+	/// assert_ne!(digest(&bits[3 ..]), digest(&bits[4 ..]));
+	/// ```
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		hasher.write_usize(self.len());
+		self.fold_lanes(|lane| hasher.write_u64(lane));
+	}
+}
+
+/** Produces a read-only iterator over all the bits in the `BitSlice`.
+
+This iterator follows the ordering in the `BitSlice` type, and implements
+`ExactSizeIterator` as `BitSlice` has a known, fixed, length, and
+`DoubleEndedIterator` as it has known ends.
+**/
+impl<'a, C, T> IntoIterator for &'a BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+	type IntoIter = Iter<'a, C, T>;
+
+	/// Iterates over the slice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b1010_1100u8.as_bitslice::<BigEndian>();
+	/// let mut count = 0;
+	/// for bit in bits {
+	///   if bit { count += 1; }
+	/// }
+	/// assert_eq!(count, 4);
+	/// ```
+	fn into_iter(self) -> Self::IntoIter {
+		Iter {
+			inner: self,
+			front: None,
+			back: None,
+		}
+	}
+}
+
+impl<'a, C, T> IntoIterator for &'a mut BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = BitMut<'a, C, T>;
+	type IntoIter = IterMut<'a, C, T>;
+
+	/// Iterates mutably over the slice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// An iterator over write proxies for each bit in the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// for mut bit in src.as_mut_bitslice::<LittleEndian>() {
+	///   *bit = true;
+	/// }
+	/// assert_eq!(src, !0);
+	/// ```
+	fn into_iter(self) -> Self::IntoIter {
+		IterMut {
+			inner: self
+		}
+	}
+}
+
+/** `BitSlice` is safe to move across thread boundaries, when atomic operations
+are enabled.
+
+Consider this (contrived) example:
+
+```rust
+# #[cfg(feature = "std")] {
+use bitvec::prelude::*;
+use std::thread;
+
+let bits = bitbox![0; 8].leak();
+let (l, r) = bits.split_at_mut(4);
+
+let a = thread::spawn(move || { l.set(2, true); l });
+let b = thread::spawn(move || { r.set(2, true); r });
+let (l, r) = (a.join().unwrap(), b.join().unwrap());
+
+assert_eq!(l.as_slice()[0], 0b0010_0010);
+assert_eq!(r.as_slice()[0], 0b0010_0010);
+# }
+```
+
+Both halves are written through the same element, from different threads.
+Without atomic operations, this is logically a data race. It *so happens* that,
+on x86, the read/modify/write cycles used in the crate are *basically* atomic by
+default, even when not specified as such. This is not necessarily true on other
+architectures, however.
+**/
+#[cfg(feature = "atomic")]
+unsafe impl<C, T> Send for BitSlice<C, T>
+where C: Cursor, T: BitStore {}
+
+/** Reading across threads still has synchronization concerns if one thread can
+mutate, so read access across threads requires atomicity in order to ensure that
+write operations from one thread to an element conclude before another thread
+can read from the element, even if the two `BitSlice`s do not collide.
+**/
+#[cfg(feature = "atomic")]
+unsafe impl<C, T> Sync for BitSlice<C, T>
+where C: Cursor, T: BitStore {}
+
+/** Performs unsigned addition in place on a `BitSlice`.
+
+If the addend bitstream is shorter than `self`, the addend is zero-extended at
+the left (so that its final bit matches with `self`’s final bit). If the addend
+is longer, the excess front length is unused.
+
+Addition proceeds from the right ends of each slice towards the left. Because
+this trait is forbidden from returning anything, the final carry-out bit is
+discarded.
+
+Note that, unlike `BitVec`, there is no subtraction implementation until I find
+a subtraction algorithm that does not require modifying the subtrahend.
+
+Subtraction can be implemented by negating the intended subtrahend yourself and
+then using addition, or by using `BitVec`s instead of `BitSlice`s.
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool, IntoIter: DoubleEndedIterator>`: The bitstream to
+  add into `self`. It must be finite and double-ended, since addition operates
+  in reverse.
+**/
+impl<C, T, I> AddAssign<I> for BitSlice<C, T>
+where C: Cursor, T: BitStore,
+	I: IntoIterator<Item=bool>, I::IntoIter: DoubleEndedIterator {
+	/// Performs unsigned wrapping addition in place.
+	///
+	/// # Examples
+	///
+	/// This example shows addition of a slice wrapping from max to zero.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0b1110_1111u8, 0b0000_0001];
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// let (nums, one) = bits.split_at_mut(12);
+	/// let (accum, steps) = nums.split_at_mut(4);
+	/// *accum += &*one;
+	/// assert_eq!(accum, &steps[.. 4]);
+	/// *accum += &*one;
+	/// assert_eq!(accum, &steps[4 ..]);
+	/// ```
+	//  Clippy doesn’t like single-letter names (which is accurate) but this is
+	//  pretty standard mathematical notation in EE.
+	#[allow(clippy::many_single_char_names)]
+	fn add_assign(&mut self, addend: I) {
+		use core::iter::repeat;
+
+		//  I don't, at this time, want to implement a carry-lookahead adder in
+		//  software, so this is going to be a plain ripple-carry adder with
+		//  O(n) runtime. Furthermore, until I think of an optimization
+		//  strategy, it is going to build up another bitvec to use as a stack.
+		//
+		//  Computers are fast. Whatever.
+		let mut c = false;
+		//  Reverse self, reverse addend and zero-extend, and zip both together.
+		//  This walks both slices from rightmost to leftmost, and considers an
+		//  early expiration of addend to continue with 0 bits.
+		//
+		//  100111
+		// +  0010
+		//  ^^---- semantically zero
+		let addend_iter = addend.into_iter().rev().chain(repeat(false));
+		for (i, b) in (0 .. self.len()).rev().zip(addend_iter) {
+			//  Bounds checks are performed in the loop header.
+			let a = unsafe { self.get_unchecked(i) };
+			let (y, z) = crate::rca1(a, b, c);
+			unsafe { self.set_unchecked(i, y); }
+			c = z;
+		}
+	}
+}
+
+/** Performs the Boolean `AND` operation against another bitstream and writes
+the result into `self`. If the other bitstream ends before `self,`, the
+remaining bits of `self` are cleared.
+
+The stream is read one bit at a time. To combine another `BitSlice` a whole
+element at a time, with an explicit choice of what happens when the lengths
+differ, use [`and_assign_truncate`] or [`and_assign_extend`].
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `BitSlice`
+  or some other bit producer as desired.
+
+[`and_assign_extend`]: struct.BitSlice.html#method.and_assign_extend
+[`and_assign_truncate`]: struct.BitSlice.html#method.and_assign_truncate
+**/
+impl<C, T, I> BitAndAssign<I> for BitSlice<C, T>
+where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
+	/// `AND`s a bitstream into a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The bitstream to `AND` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut store = [0b0101_0100u8];
+	/// let     other = [0b0011_0000u8];
+	/// let lhs = store.as_mut_bitslice::<BigEndian>();
+	/// let rhs = other.as_bitslice::<BigEndian>();
+	/// lhs[.. 6] &= &rhs[.. 4];
+	/// assert_eq!(store[0], 0b0001_0000);
+	/// ```
+	fn bitand_assign(&mut self, rhs: I) {
+		use core::iter;
+		rhs.into_iter()
+			.chain(iter::repeat(false))
+			.enumerate()
+			.take(self.len())
+			.for_each(|(idx, bit)| {
+				let val = self[idx] & bit;
+				self.set(idx, val);
+			});
+	}
+}
+
+/** Performs the Boolean `OR` operation against another bitstream and writes the
+result into `self`. If the other bitstream ends before `self`, the remaining
+bits of `self` are not affected.
+
+The stream is read one bit at a time. To combine another `BitSlice` a whole
+element at a time, use [`or_assign_truncate`].
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `BitSlice`
+  or some other bit producer as desired.
+
+[`or_assign_truncate`]: struct.BitSlice.html#method.or_assign_truncate
+**/
+impl<C, T, I> BitOrAssign<I> for BitSlice<C, T>
+where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
+	/// `OR`s a bitstream into a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The bitstream to `OR` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut store = [0b0101_0100u8];
+	/// let     other = [0b0011_0000u8];
+	/// let lhs = store.as_mut_bitslice::<BigEndian>();
+	/// let rhs = other.as_bitslice::<BigEndian>();
+	/// lhs[.. 6] |= &rhs[.. 4];
+	/// assert_eq!(store[0], 0b0111_0100);
+	/// ```
+	fn bitor_assign(&mut self, rhs: I) {
+		rhs.into_iter()
+			.enumerate()
+			.take(self.len())
+			.for_each(|(idx, bit)| {
+				let val = self[idx] | bit;
+				self.set(idx, val);
+			});
+	}
+}
+
+/** Performs the Boolean `XOR` operation against another bitstream and writes
+the result into `self`. If the other bitstream ends before `self`, the remaining
+bits of `self` are not affected.
+
+The stream is read one bit at a time. To combine another `BitSlice` a whole
+element at a time, use [`xor_assign_truncate`].
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `BitSlice`
+  or some other bit producer as desired.
+
+[`xor_assign_truncate`]: struct.BitSlice.html#method.xor_assign_truncate
+**/
+impl<C, T, I> BitXorAssign<I> for BitSlice<C, T>
+where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
+	/// `XOR`s a bitstream into a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The bitstream to `XOR` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut store = [0b0101_0100u8];
+	/// let     other = [0b0011_0000u8];
+	/// let lhs = store.as_mut_bitslice::<BigEndian>();
+	/// let rhs = other.as_bitslice::<BigEndian>();
+	/// lhs[.. 6] ^= &rhs[.. 4];
+	/// assert_eq!(store[0], 0b0110_0100);
+	/// ```
+	fn bitxor_assign(&mut self, rhs: I) {
+		rhs.into_iter()
+			.enumerate()
+			.take(self.len())
+			.for_each(|(idx, bit)| {
+				let val = self[idx] ^ bit;
+				self.set(idx, val);
+			})
+	}
+}
+
+/** Performs the Boolean `AND` operation against a mask, repeated in every
+element under the slice.
+
+Bits of an element outside the slice are not affected.
+**/
+impl<C, T> BitAndAssign<Mask<T>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// `AND`s a mask into each element of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The mask to `AND` into each element of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::Mask;
+	///
+	/// let mut store = [0xFFu8; 3];
+	/// store.as_mut_bitslice::<BigEndian>()[4 .. 20] &= Mask(0x3C);
+	/// assert_eq!(store, [0xFC, 0x3C, 0x3F]);
+	/// ```
+	fn bitand_assign(&mut self, rhs: Mask<T>) {
+		self.and_mask(rhs.0);
+	}
+}
+
+/** Performs the Boolean `OR` operation against a mask, repeated in every
+element under the slice.
+
+Bits of an element outside the slice are not affected.
+**/
+impl<C, T> BitOrAssign<Mask<T>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// `OR`s a mask into each element of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The mask to `OR` into each element of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::Mask;
+	///
+	/// let mut store = [0u8; 3];
+	/// store.as_mut_bitslice::<BigEndian>()[4 .. 20] |= Mask(0x81);
+	/// assert_eq!(store, [0x01, 0x81, 0x80]);
+	/// ```
+	fn bitor_assign(&mut self, rhs: Mask<T>) {
+		self.or_mask(rhs.0);
+	}
+}
+
+/** Performs the Boolean `XOR` operation against a mask, repeated in every
+element under the slice.
+
+Bits of an element outside the slice are not affected.
+**/
+impl<C, T> BitXorAssign<Mask<T>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// `XOR`s a mask into each element of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The mask to `XOR` into each element of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::Mask;
+	///
+	/// let mut store = [0x00FFu16; 2];
+	/// store.as_mut_bitslice::<LittleEndian>()[.. 24] ^= Mask(0x0FF0);
+	/// assert_eq!(store, [0x0F0F, 0x000F]);
+	/// ```
+	fn bitxor_assign(&mut self, rhs: Mask<T>) {
+		self.xor_mask(rhs.0);
+	}
+}
+
+/// Indexes a single bit by semantic count. The index must be less than the
+/// length of the `BitSlice`.
+impl<C, T> Index<usize> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = bool;
+
+	/// Looks up a single bit by semantic index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The semantic index of the bit to look up.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at the requested index.
 	///
 	/// # Examples
 	///
@@ -3051,597 +6337,1148 @@ where C: Cursor, T: BitStore {
 	///
 	/// let src = 0b0010_0000u8;
 	/// let bits = src.as_bitslice::<BigEndian>();
-	/// assert!(bits[2]);
-	/// assert!(!bits[3]);
+	/// assert!(bits[2]);
+	/// assert!(!bits[3]);
+	/// ```
+	fn index(&self, index: usize) -> &Self::Output {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		if unsafe { self.get_unchecked(index) } { &true } else { &false }
+	}
+}
+
+impl<C, T> Index<Range<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, Range { start, end }: Range<usize>) -> &Self::Output {
+		let (data, head, len) = self.bitptr().raw_parts();
+		assert!(
+			start <= len,
+			"Index {} out of range: {}",
+			start,
+			len,
+		);
+		assert!(end <= len, "Index {} out of range: {}", end, len);
+		assert!(start <= end, "Ranges can only run from low to high");
+		//  Find the number of elements to drop from the front, and the index of
+		//  the new head
+		let (skip, new_head) = head.offset(start as isize);
+		let new_len = end - start;
+		unsafe { BitPtr::new_unchecked(
+			data.r().offset(skip),
+			new_head,
+			new_len,
+		) }.into_bitslice::<C>()
+	}
+}
+
+impl<C, T> IndexMut<Range<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		Range { start, end }: Range<usize>,
+	) -> &mut Self::Output {
+		//  Get an immutable slice, and then type-hack mutability back in.
+		(&self[start .. end]).bitptr().into_bitslice_mut()
+	}
+}
+
+impl<C, T> Index<RangeInclusive<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
+		let start = *index.start();
+		//  This check can never fail, due to implementation details of
+		//  `BitPtr<T>`.
+		if let Some(end) = index.end().checked_add(1) {
+			&self[start .. end]
+		}
+		else {
+			&self[start ..]
+		}
+	}
+}
+
+impl<C, T> IndexMut<RangeInclusive<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut Self::Output {
+		let start = *index.start();
+		//  This check can never fail, due to implementation details of
+		//  `BitPtr<T>`.
+		if let Some(end) = index.end().checked_add(1) {
+			&mut self[start .. end]
+		}
+		else {
+			&mut self[start ..]
+		}
+	}
+}
+
+impl<C, T> Index<RangeFrom<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, RangeFrom { start }: RangeFrom<usize>) -> &Self::Output {
+		&self[start .. self.len()]
+	}
+}
+
+impl<C, T> IndexMut<RangeFrom<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		RangeFrom { start }: RangeFrom<usize>,
+	) -> &mut Self::Output {
+		let len = self.len();
+		&mut self[start .. len]
+	}
+}
+
+impl<C, T> Index<RangeFull> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, _: RangeFull) -> &Self::Output {
+		self
+	}
+}
+
+impl<C, T> IndexMut<RangeFull> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
+		self
+	}
+}
+
+impl<C, T> Index<RangeTo<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, RangeTo { end }: RangeTo<usize>) -> &Self::Output {
+		&self[0 .. end]
+	}
+}
+
+impl<C, T> IndexMut<RangeTo<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		RangeTo { end }: RangeTo<usize>,
+	) -> &mut Self::Output {
+		&mut self[0 .. end]
+	}
+}
+
+impl<C, T> Index<RangeToInclusive<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(
+		&self,
+		RangeToInclusive { end }: RangeToInclusive<usize>,
+	) -> &Self::Output {
+		&self[0 ..= end]
+	}
+}
+
+impl<C, T> IndexMut<RangeToInclusive<usize>> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		RangeToInclusive { end }: RangeToInclusive<usize>,
+	) -> &mut Self::Output {
+		&mut self[0 ..= end]
+	}
+}
+
+impl<C, T> Index<BitSpan> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, span: BitSpan) -> &Self::Output {
+		&self[span.as_range()]
+	}
+}
+
+impl<C, T> IndexMut<BitSpan> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, span: BitSpan) -> &mut Self::Output {
+		&mut self[span.as_range()]
+	}
+}
+
+/** Performs fixed-width 2’s-complement negation of a `BitSlice`.
+
+Unlike the `!` operator (`Not` trait), the unary `-` operator treats the
+`BitSlice` as if it represents a signed 2’s-complement integer of fixed
+width. The negation of a number in 2’s complement is defined as its
+inversion (using `!`) plus one, and on fixed-width numbers has the following
+discontinuities:
+
+- A slice whose bits are all zero is considered to represent the number zero
+  which negates as itself.
+- A slice whose bits are all one is considered to represent the most negative
+  number, which has no correpsonding positive number, and thus negates as zero.
+
+This behavior was chosen so that all possible values would have *some*
+output, and so that repeated application converges at idempotence. The most
+negative input can never be reached by negation, but `--MOST_NEG` converges
+at the least unreasonable fallback value, 0.
+
+Because `BitSlice` cannot move, the negation is performed in place.
+**/
+impl<'a, C, T> Neg for &'a mut BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Output = Self;
+
+	/// Perform 2’s-complement fixed-width negation.
+	///
+	/// Negation is accomplished by inverting the bits and adding one. This has
+	/// one edge case: `1000…`, the most negative number for its width, will
+	/// negate to zero instead of itself. It thas no corresponding positive
+	/// number to which it can negate.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Examples
+	///
+	/// The contortions shown here are a result of this operator applying to a
+	/// mutable reference, and this example balancing access to the original
+	/// `BitVec` for comparison with aquiring a mutable borrow *as a slice* to
+	/// ensure that the `BitSlice` implementation is used, not the `BitVec`.
+	///
+	/// Negate an arbitrary positive number (first bit unset).
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b0110_1010u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// eprintln!("{:?}", bits.split_at(4));
+	/// let num = &mut bits[.. 4];
+	/// -num;
+	/// eprintln!("{:?}", bits.split_at(4));
+	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
+	/// ```
+	///
+	/// Negate an arbitrary negative number. This example will use the above
+	/// result to demonstrate round-trip correctness.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b1010_0110u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// let num = &mut bits[.. 4];
+	/// -num;
+	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
+	/// ```
+	///
+	/// Negate the most negative number, which will become zero, and show
+	/// convergence at zero.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 128u8;
+	/// let bits = src.as_mut_bitslice::<BigEndian>();
+	/// let num = &mut bits[..];
+	/// -num;
+	/// assert!(bits.not_any());
+	/// let num = &mut bits[..];
+	/// -num;
+	/// assert!(bits.not_any());
+	/// ```
+	fn neg(self) -> Self::Output {
+		//  negative zero is zero. The invert-and-add will result in zero, but
+		//  this case can be detected quickly.
+		if self.is_empty() || self.not_any() {
+			return self;
+		}
+		//  The most negative number (leading one, all zeroes else) negates to
+		//  zero.
+		if self[0] {
+			//  Testing the whole range, rather than [1 ..], is more likely to
+			//  hit the fast path.
+			self.set(0, false);
+			if self.not_any() {
+				return self;
+			}
+			self.set(0, true);
+		}
+		let _ = Not::not(&mut *self);
+		let one: &[T] = &[T::bits(true)];
+		let one_bs: &BitSlice<C, T> = one.into();
+		AddAssign::add_assign(&mut *self, &one_bs[.. 1]);
+		self
+	}
+}
+
+/// Flips all bits in the slice, in place.
+impl<'a, C, T> Not for &'a mut BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Output = Self;
+
+	/// Inverts all bits in the slice.
+	///
+	/// This will not affect bits outside the slice in slice storage elements.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 2];
+	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[2 .. 14];
+	/// let new_bits = !bits;
+	/// //  The `bits` binding is consumed by the `!` operator, and a new
+	/// //  reference is returned.
+	/// // assert_eq!(bits.as_ref(), &[!0, !0]);
+	/// assert_eq!(new_bits.as_ref(), &[0x3F, 0xFC]);
+	/// ```
+	fn not(self) -> Self::Output {
+		self.invert_all();
+		self
+	}
+}
+
+/// Views the slice with all bits inverted, without modifying it.
+impl<'a, C, T> Not for &'a BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Output = Complement<'a, C, T>;
+
+	/// Produces an inverting view of the slice.
+	///
+	/// This is [`BitSlice::complement`].
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = 0b1100_0000u8;
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// let inv = !bits;
+	/// assert_eq!(inv.get(0), Some(false));
+	/// assert_eq!(inv.get(7), Some(true));
+	/// assert_eq!(src, 0b1100_0000);
 	/// ```
-	fn index(&self, index: usize) -> &Self::Output {
+	///
+	/// [`BitSlice::complement`]: struct.BitSlice.html#method.complement
+	fn not(self) -> Self::Output {
+		self.complement()
+	}
+}
+
+__bitslice_shift!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+__bitslice_int_cmp!(u8, u16, u32, u64, u128);
+
+/** Shifts all bits in the array to the left — **DOWN AND TOWARDS THE FRONT**.
+
+On fundamentals, the left-shift operator `<<` moves bits away from the origin
+and  towards the ceiling. This is because we label the bits in a primitive with
+the  minimum on the right and the maximum on the left, which is big-endian bit
+order.  This increases the value of the primitive being shifted.
+
+**THAT IS NOT HOW `BitSlice` WORKS!**
+
+`BitSlice` defines its layout with the minimum on the left and the maximum on
+the right! Thus, left-shifting moves bits towards the **minimum**.
+
+In BigEndian order, the effect in memory will be what you expect the `<<`
+operator to do.
+
+**In LittleEndian order, the effect will be equivalent to using `>>` on the**
+**fundamentals in memory!**
+
+# Notes
+
+In order to preserve the effecs in memory that this operator traditionally
+expects, the bits that are emptied by this operation are zeroed rather than
+left to their old value.
+
+The shift amount is modulated against the array length, so it is not an
+error to pass a shift amount greater than the array length.
+
+A shift amount of zero is a no-op, and returns immediately.
+**/
+impl<C, T> ShlAssign<usize> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Shifts a slice left, in place.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `shamt`: The shift amount. If this is greater than the length, then
+	///   the slice is zeroed immediately.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0x4Bu8, 0xA5];
+	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[2 .. 14];
+	/// *bits <<= 3;
+	/// assert_eq!(bits.as_ref(), &[0b01_011_101, 0b001_000_01]);
+	/// ```
+	fn shl_assign(&mut self, shamt: usize) {
+		if shamt == 0 {
+			return;
+		}
 		let len = self.len();
-		assert!(index < len, "Index out of range: {} >= {}", index, len);
-		if unsafe { self.get_unchecked(index) } { &true } else { &false }
+		if shamt >= len {
+			self.set_all(false);
+			return;
+		}
+		//  If the slice fills each of its elements, and the shift amount is an
+		//  even multiple of the element width, use `ptr::copy` instead of a
+		//  bitwise shift. Slices with partial edge elements must shift, as the
+		//  element copy would move bits across their edges.
+		if let Some((offset, rem)) = self.element_shift(shamt) {
+			//  Memory model: suppose we have this slice of sixteen elements,
+			//  that is shifted five elements to the left. We have three
+			//  pointers and two lengths to manage.
+			//  - rem is 11
+			//  - offset is 5
+			//  - head is [0]
+			//  - body is [5; 11]
+			//  - tail is [11]
+			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
+			//              ^-------before------^
+			//    ^-------after-------^ 0 0 0 0 0
+			//  Pointer to the front of the slice
+			let head: *mut T = self.as_mut_ptr();
+			//  Pointer to the front of the section that will move and be
+			//  retained
+			let body: *const T = &self.as_ref()[offset];
+			//  Pointer to the back of the slice that will be zero-filled.
+			let tail: *mut T = &mut self.as_mut()[rem];
+			unsafe {
+				ptr::copy(body, head, rem);
+				ptr::write_bytes(tail, 0, offset);
+			}
+			return;
+		}
+		//  Otherwise, shift each element into place.
+		self.shunt(shamt, true);
+	}
+}
+
+/** Shifts all bits in the array to the right — **UP AND TOWARDS THE BACK**.
+
+On fundamentals, the right-shift operator `>>` moves bits towards the origin and
+away from the ceiling. This is because we label the bits in a primitive with the
+minimum on the right and the maximum on the left, which is big-endian bit order.
+This decreases the value of the primitive being shifted.
+
+**THAT IS NOT HOW `BitSlice` WORKS!**
+
+`BitSlice` defines its layout with the minimum on the left and the maximum on
+the right! Thus, right-shifting moves bits towards the **maximum**.
+
+In Big-Endian order, the effect in memory will be what you expect the `>>`
+operator to do.
+
+**In LittleEndian order, the effect will be equivalent to using `<<` on the**
+**fundamentals in memory!**
+
+# Notes
+
+In order to preserve the effects in memory that this operator traditionally
+expects, the bits that are emptied by this operation are zeroed rather than left
+to their old value.
+
+The shift amount is modulated against the array length, so it is not an error to
+pass a shift amount greater than the array length.
+
+A shift amount of zero is a no-op, and returns immediately.
+**/
+impl<C, T> ShrAssign<usize> for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Shifts a slice right, in place.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `shamt`: The shift amount. If this is greater than the length, then
+	///   the slice is zeroed immediately.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0x4Bu8, 0xA5];
+	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[2 .. 14];
+	/// *bits >>= 3;
+	/// assert_eq!(bits.as_ref(), &[0b01_000_00_1, 0b011_101_01])
+	/// ```
+	fn shr_assign(&mut self, shamt: usize) {
+		if shamt == 0 {
+			return;
+		}
+		let len = self.len();
+		if shamt >= len {
+			self.set_all(false);
+			return;
+		}
+		//  If the slice fills each of its elements, and the shift amount is an
+		//  even multiple of the element width, use `ptr::copy` instead of a
+		//  bitwise shift. Slices with partial edge elements must shift, as the
+		//  element copy would move bits across their edges.
+		if let Some((offset, rem)) = self.element_shift(shamt) {
+			//  Memory model: suppose we have this slice of sixteen elements,
+			//  that is shifted five elements to the right. We have two pointers
+			//  and two lengths to manage.
+			//  - rem is 11
+			//  - offset is 5
+			//  - head is [0; 11]
+			//  - body is [5]
+			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
+			//    ^-------before------^
+			//    0 0 0 0 0 ^-------after-------^
+			let head: *mut T = self.as_mut_ptr();
+			let body: *mut T = &mut self.as_mut()[offset];
+			unsafe {
+				ptr::copy(head, body, rem);
+				ptr::write_bytes(head, 0, offset);
+			}
+			return;
+		}
+		//  Otherwise, shift each element into place.
+		self.shunt(shamt, false);
 	}
 }
 
-impl<C, T> Index<Range<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+/** Write reference to a single bit.
+
+Rust requires that `DerefMut` produce the plain address of a value which can be
+written with a `memcpy`, so, there is no way to make plain write assignments
+work nicely in Rust. This reference structure is the second best option.
+
+It contains a write reference to a single-bit slice, and a local cache `bool`.
+This structure `Deref`s to the local cache, and commits the cache to the slice
+on drop. This allows writing to the guard with `=` assignment.
+
+`BitMut` is produced by [`BitSlice::at`], and by [`BitSlice::get_mut`] when it
+is given a single index.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut src = 0u8;
+let bits = src.as_mut_bitslice::<BigEndian>();
+
+*bits.at(0) = true;
+if let Some(mut bit) = bits.get_mut(7) {
+  *bit = !*bit;
+}
+assert_eq!(src, 0b1000_0001);
+```
+
+[`BitSlice::at`]: struct.BitSlice.html#method.at
+[`BitSlice::get_mut`]: struct.BitSlice.html#method.get_mut
+**/
+#[derive(Debug)]
+pub struct BitMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	slot: &'a mut BitSlice<C, T>,
+	bit: bool,
+	_m: PhantomData<*mut T>,
+}
 
-	fn index(&self, Range { start, end }: Range<usize>) -> &Self::Output {
-		let (data, head, len) = self.bitptr().raw_parts();
-		assert!(
-			start <= len,
-			"Index {} out of range: {}",
-			start,
-			len,
-		);
-		assert!(end <= len, "Index {} out of range: {}", end, len);
-		assert!(start <= end, "Ranges can only run from low to high");
-		//  Find the number of elements to drop from the front, and the index of
-		//  the new head
-		let (skip, new_head) = head.offset(start as isize);
-		let new_len = end - start;
-		unsafe { BitPtr::new_unchecked(
-			data.r().offset(skip),
-			new_head,
-			new_len,
-		) }.into_bitslice::<C>()
+/// Read from the local cache.
+impl<'a, C, T> Deref for BitMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Target = bool;
+
+	fn deref(&self) -> &Self::Target {
+		&self.bit
 	}
 }
 
-impl<C, T> IndexMut<Range<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		Range { start, end }: Range<usize>,
-	) -> &mut Self::Output {
-		//  Get an immutable slice, and then type-hack mutability back in.
-		(&self[start .. end]).bitptr().into_bitslice_mut()
+/// Write to the local cache.
+impl<'a, C, T> DerefMut for BitMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.bit
 	}
 }
 
-impl<C, T> Index<RangeInclusive<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+/// Commit the local cache to the backing slice.
+impl<'a, C, T> Drop for BitMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn drop(&mut self) {
+		self.slot.set(0, self.bit);
+	}
+}
 
-	fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
-		let start = *index.start();
-		//  This check can never fail, due to implementation details of
-		//  `BitPtr<T>`.
-		if let Some(end) = index.end().checked_add(1) {
-			&self[start .. end]
+/// This type is a mutable reference with extra steps, so, it should be moveable
+/// but not shareable. It may share its element with other handles, so it is only
+/// moveable when those handles use atomic access.
+#[cfg(feature = "atomic")]
+unsafe impl<'a, C, T> Send for BitMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+/// The former name of [`BitMut`].
+///
+/// [`BitMut`]: struct.BitMut.html
+#[deprecated(since = "0.17.0", note = "Use `BitMut` instead")]
+pub type BitGuard<'a, C, T> = BitMut<'a, C, T>;
+
+/** Indices which can look up a bit, or a subslice, in a `BitSlice`.
+
+This is the `BitSlice` equivalent of the standard library’s `SliceIndex` trait,
+which cannot be implemented outside of `core`. It is implemented for `usize`,
+which addresses a single bit, and for all the standard ranges of `usize`, which
+address subslices.
+
+# Lifetimes
+
+- `'a`: The lifetime of the `BitSlice` being indexed.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the indexed `BitSlice`.
+- `T: 'a + BitStore`: The storage type of the indexed `BitSlice`.
+**/
+pub trait BitSliceIndex<'a, C, T>
+where C: 'a + Cursor, T: 'a + BitStore {
+	/// The output of a successful immutable lookup.
+	type Immut;
+
+	/// The output of a successful mutable lookup.
+	type Mut;
+
+	/// Looks up `self` in `slice`, producing `None` if out of bounds.
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut>;
+
+	/// Looks up `self` in `slice`, producing `None` if out of bounds.
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut>;
+}
+
+impl<'a, C, T> BitSliceIndex<'a, C, T> for usize
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = bool;
+	type Mut = BitMut<'a, C, T>;
+
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		if self < slice.len() {
+			Some(unsafe { slice.get_unchecked(self) })
 		}
 		else {
-			&self[start ..]
+			None
 		}
 	}
-}
 
-impl<C, T> IndexMut<RangeInclusive<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut Self::Output {
-		let start = *index.start();
-		//  This check can never fail, due to implementation details of
-		//  `BitPtr<T>`.
-		if let Some(end) = index.end().checked_add(1) {
-			&mut self[start .. end]
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		if self < slice.len() {
+			Some(slice.at(self))
 		}
 		else {
-			&mut self[start ..]
+			None
 		}
 	}
 }
 
-impl<C, T> Index<RangeFrom<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+impl<'a, C, T> BitSliceIndex<'a, C, T> for Range<usize>
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = &'a BitSlice<C, T>;
+	type Mut = &'a mut BitSlice<C, T>;
 
-	fn index(&self, RangeFrom { start }: RangeFrom<usize>) -> &Self::Output {
-		&self[start .. self.len()]
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		let Range { start, end } = self;
+		if start <= end && end <= slice.len() {
+			Some(&slice[start .. end])
+		}
+		else {
+			None
+		}
 	}
-}
 
-impl<C, T> IndexMut<RangeFrom<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		RangeFrom { start }: RangeFrom<usize>,
-	) -> &mut Self::Output {
-		let len = self.len();
-		&mut self[start .. len]
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		self.get(&*slice).map(|s| s.bitptr().into_bitslice_mut())
 	}
 }
 
-impl<C, T> Index<RangeFull> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+impl<'a, C, T> BitSliceIndex<'a, C, T> for RangeInclusive<usize>
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = &'a BitSlice<C, T>;
+	type Mut = &'a mut BitSlice<C, T>;
 
-	fn index(&self, _: RangeFull) -> &Self::Output {
-		self
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		let start = *self.start();
+		self.end()
+			.checked_add(1)
+			.and_then(|end| (start .. end).get(slice))
 	}
-}
 
-impl<C, T> IndexMut<RangeFull> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
-		self
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		self.get(&*slice).map(|s| s.bitptr().into_bitslice_mut())
 	}
 }
 
-impl<C, T> Index<RangeTo<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+impl<'a, C, T> BitSliceIndex<'a, C, T> for RangeFrom<usize>
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = &'a BitSlice<C, T>;
+	type Mut = &'a mut BitSlice<C, T>;
 
-	fn index(&self, RangeTo { end }: RangeTo<usize>) -> &Self::Output {
-		&self[0 .. end]
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		(self.start .. slice.len()).get(slice)
+	}
+
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		self.get(&*slice).map(|s| s.bitptr().into_bitslice_mut())
 	}
 }
 
-impl<C, T> IndexMut<RangeTo<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		RangeTo { end }: RangeTo<usize>,
-	) -> &mut Self::Output {
-		&mut self[0 .. end]
+impl<'a, C, T> BitSliceIndex<'a, C, T> for RangeFull
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = &'a BitSlice<C, T>;
+	type Mut = &'a mut BitSlice<C, T>;
+
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		Some(slice)
+	}
+
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		Some(slice)
 	}
 }
 
-impl<C, T> Index<RangeToInclusive<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+impl<'a, C, T> BitSliceIndex<'a, C, T> for RangeTo<usize>
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = &'a BitSlice<C, T>;
+	type Mut = &'a mut BitSlice<C, T>;
 
-	fn index(
-		&self,
-		RangeToInclusive { end }: RangeToInclusive<usize>,
-	) -> &Self::Output {
-		&self[0 ..= end]
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		(0 .. self.end).get(slice)
+	}
+
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		self.get(&*slice).map(|s| s.bitptr().into_bitslice_mut())
 	}
 }
 
-impl<C, T> IndexMut<RangeToInclusive<usize>> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		RangeToInclusive { end }: RangeToInclusive<usize>,
-	) -> &mut Self::Output {
-		&mut self[0 ..= end]
+impl<'a, C, T> BitSliceIndex<'a, C, T> for RangeToInclusive<usize>
+where C: 'a + Cursor, T: 'a + BitStore {
+	type Immut = &'a BitSlice<C, T>;
+	type Mut = &'a mut BitSlice<C, T>;
+
+	fn get(self, slice: &'a BitSlice<C, T>) -> Option<Self::Immut> {
+		(0 ..= self.end).get(slice)
+	}
+
+	fn get_mut(self, slice: &'a mut BitSlice<C, T>) -> Option<Self::Mut> {
+		self.get(&*slice).map(|s| s.bitptr().into_bitslice_mut())
 	}
 }
 
-/** Performs fixed-width 2’s-complement negation of a `BitSlice`.
+/** State keeper for chunked iteration over a `BitSlice`.
 
-Unlike the `!` operator (`Not` trait), the unary `-` operator treats the
-`BitSlice` as if it represents a signed 2’s-complement integer of fixed
-width. The negation of a number in 2’s complement is defined as its
-inversion (using `!`) plus one, and on fixed-width numbers has the following
-discontinuities:
+# Type Parameters
 
-- A slice whose bits are all zero is considered to represent the number zero
-  which negates as itself.
-- A slice whose bits are all one is considered to represent the most negative
-  number, which has no correpsonding positive number, and thus negates as zero.
+- `C: Cursor`: The bit-order type of the underlying `BitSlice`.
+- `T: 'a + BitStore`: The storage type of the underlying `BitSlice`.
 
-This behavior was chosen so that all possible values would have *some*
-output, and so that repeated application converges at idempotence. The most
-negative input can never be reached by negation, but `--MOST_NEG` converges
-at the least unreasonable fallback value, 0.
+# Lifetimes
 
-Because `BitSlice` cannot move, the negation is performed in place.
+- `'a`: The lifetime of the underlying `BitSlice`.
 **/
-impl<'a, C, T> Neg for &'a mut BitSlice<C, T>
+#[derive(Clone, Debug)]
+pub struct Chunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Output = Self;
+	/// The `BitSlice` being iterated.
+	inner: &'a BitSlice<C, T>,
+	/// The width of the chunks.
+	width: usize,
+}
 
-	/// Perform 2’s-complement fixed-width negation.
+impl<'a, C, T> DoubleEndedIterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the next chunk from the back of the slice.
 	///
-	/// Negation is accomplished by inverting the bits and adding one. This has
-	/// one edge case: `1000…`, the most negative number for its width, will
-	/// negate to zero instead of itself. It thas no corresponding positive
-	/// number to which it can negate.
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 1u8.as_bitslice::<BigEndian>();
+	/// let mut chunks = bits.chunks(5);
+	/// assert_eq!(chunks.next_back(), Some(&bits[5 ..]));
+	/// assert_eq!(chunks.next_back(), Some(&bits[.. 5]));
+	/// assert!(chunks.next_back().is_none());
+	/// ```
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.inner.is_empty() {
+			return None;
+		}
+		let len = self.inner.len();
+		let rem = len % self.width;
+		let size = if rem == 0 { self.width } else { rem };
+		let (head, tail) = self.inner.split_at(len - size);
+		self.inner = head;
+		Some(tail)
+	}
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = &'a BitSlice<C, T>;
+
+	/// Advances the iterator by one, returning the first chunk in it (if any).
 	///
 	/// # Parameters
 	///
-	/// - `self`
+	/// - `&mut self`
 	///
-	/// # Examples
+	/// # Returns
 	///
-	/// The contortions shown here are a result of this operator applying to a
-	/// mutable reference, and this example balancing access to the original
-	/// `BitVec` for comparison with aquiring a mutable borrow *as a slice* to
-	/// ensure that the `BitSlice` implementation is used, not the `BitVec`.
+	/// The leading chunk in the iterator, if any.
 	///
-	/// Negate an arbitrary positive number (first bit unset).
+	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0b0110_1010u8;
-	/// let bits = src.as_mut_bitslice::<BigEndian>();
-	/// eprintln!("{:?}", bits.split_at(4));
-	/// let num = &mut bits[.. 4];
-	/// -num;
-	/// eprintln!("{:?}", bits.split_at(4));
-	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
+	/// let bits = 1u8.as_bitslice::<LittleEndian>();
+	/// let mut chunks = bits.chunks(5);
+	/// assert_eq!(chunks.next(), Some(&bits[.. 5]));
+	/// assert_eq!(chunks.next(), Some(&bits[5 ..]));
+	/// assert!(chunks.next().is_none());
 	/// ```
+	fn next(&mut self) -> Option<Self::Item> {
+		use core::cmp::min;
+		if self.inner.is_empty() {
+			return None;
+		}
+		let size = min(self.inner.len(), self.width);
+		let (head, tail) = self.inner.split_at(size);
+		self.inner = tail;
+		Some(head)
+	}
+
+	/// Hints at the number of chunks remaining in the iterator.
 	///
-	/// Negate an arbitrary negative number. This example will use the above
-	/// result to demonstrate round-trip correctness.
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
 	///
-	/// ```rust
-	/// use bitvec::prelude::*;
+	/// # Parameters
 	///
-	/// let mut src = 0b1010_0110u8;
-	/// let bits = src.as_mut_bitslice::<BigEndian>();
-	/// let num = &mut bits[.. 4];
-	/// -num;
-	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
-	/// ```
+	/// - `&self`
 	///
-	/// Negate the most negative number, which will become zero, and show
-	/// convergence at zero.
+	/// # Returns
+	///
+	/// - `usize`: The minimum chunks remaining.
+	/// - `Option<usize>`: The maximum chunks remaining.
+	///
+	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 128u8;
-	/// let bits = src.as_mut_bitslice::<BigEndian>();
-	/// let num = &mut bits[..];
-	/// -num;
-	/// assert!(bits.not_any());
-	/// let num = &mut bits[..];
-	/// -num;
-	/// assert!(bits.not_any());
+	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
+	/// let mut chunks = bits.chunks(5);
+	/// assert_eq!(chunks.size_hint(), (2, Some(2)));
+	/// chunks.next();
+	/// assert_eq!(chunks.size_hint(), (1, Some(1)));
+	/// chunks.next();
+	/// assert_eq!(chunks.size_hint(), (0, Some(0)));
 	/// ```
-	fn neg(self) -> Self::Output {
-		//  negative zero is zero. The invert-and-add will result in zero, but
-		//  this case can be detected quickly.
-		if self.is_empty() || self.not_any() {
-			return self;
-		}
-		//  The most negative number (leading one, all zeroes else) negates to
-		//  zero.
-		if self[0] {
-			//  Testing the whole range, rather than [1 ..], is more likely to
-			//  hit the fast path.
-			self.set(0, false);
-			if self.not_any() {
-				return self;
-			}
-			self.set(0, true);
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.inner.is_empty() {
+			return (0, Some(0));
 		}
-		let _ = Not::not(&mut *self);
-		let one: &[T] = &[T::bits(true)];
-		let one_bs: &BitSlice<C, T> = one.into();
-		AddAssign::add_assign(&mut *self, &one_bs[.. 1]);
-		self
+		let len = self.inner.len();
+		let (n, r) = (len / self.width, len % self.width);
+		let len = n + (r > 0) as usize;
+		(len, Some(len))
 	}
-}
-
-/// Flips all bits in the slice, in place.
-impl<'a, C, T> Not for &'a mut BitSlice<C, T>
-where C: Cursor, T: 'a + BitStore {
-	type Output = Self;
 
-	/// Inverts all bits in the slice.
-	///
-	/// This will not affect bits outside the slice in slice storage elements.
+	/// Counts how many chunks are live in the iterator, consuming it.
 	///
 	/// # Parameters
 	///
 	/// - `self`
 	///
+	/// # Returns
+	///
+	/// The number of chunks remaining in the iterator.
+	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = [0u8; 2];
-	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[2 .. 14];
-	/// let new_bits = !bits;
-	/// //  The `bits` binding is consumed by the `!` operator, and a new
-	/// //  reference is returned.
-	/// // assert_eq!(bits.as_ref(), &[!0, !0]);
-	/// assert_eq!(new_bits.as_ref(), &[0x3F, 0xFC]);
+	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.chunks(3).count(), 3);
 	/// ```
-	fn not(self) -> Self::Output {
-		match self.bitptr().domain_mut() {
-			BitDomainMut::Empty => {},
-			BitDomainMut::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					elt.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomainMut::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					head.invert_bit::<C>(n.idx());
-				}
-				for elt in body {
-					*elt = !*elt;
-				}
-				for n in 0 .. *t {
-					tail.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomainMut::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					head.invert_bit::<C>(n.idx());
-				}
-				for elt in body {
-					*elt = !*elt;
-				}
-			},
-			BitDomainMut::PartialTail(body, tail, t) => {
-				for elt in body {
-					*elt = !*elt;
-				}
-				for n in 0 .. *t {
-					tail.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomainMut::Spanning(body) => {
-				for elt in body {
-					*elt = !*elt;
-				}
-			},
-		}
-		self
+	fn count(self) -> usize {
+		self.len()
 	}
-}
-
-__bitslice_shift!(u8, u16, u32, u64, i8, i16, i32, i64);
-
-/** Shifts all bits in the array to the left — **DOWN AND TOWARDS THE FRONT**.
-
-On fundamentals, the left-shift operator `<<` moves bits away from the origin
-and  towards the ceiling. This is because we label the bits in a primitive with
-the  minimum on the right and the maximum on the left, which is big-endian bit
-order.  This increases the value of the primitive being shifted.
-
-**THAT IS NOT HOW `BitSlice` WORKS!**
-
-`BitSlice` defines its layout with the minimum on the left and the maximum on
-the right! Thus, left-shifting moves bits towards the **minimum**.
 
-In BigEndian order, the effect in memory will be what you expect the `<<`
-operator to do.
-
-**In LittleEndian order, the effect will be equivalent to using `>>` on the**
-**fundamentals in memory!**
-
-# Notes
-
-In order to preserve the effecs in memory that this operator traditionally
-expects, the bits that are emptied by this operation are zeroed rather than
-left to their old value.
-
-The shift amount is modulated against the array length, so it is not an
-error to pass a shift amount greater than the array length.
-
-A shift amount of zero is a no-op, and returns immediately.
-**/
-impl<C, T> ShlAssign<usize> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Shifts a slice left, in place.
+	/// Advances the iterator by `n` chunks, starting from zero.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `shamt`: The shift amount. If this is greater than the length, then
-	///   the slice is zeroed immediately.
+	/// - `n`: The number of chunks to skip, before producing the next bit after
+	///   skips. If this overshoots the iterator’s remaining length, then the
+	///   iterator is marked empty before returning `None`.
+	///
+	/// # Returns
+	///
+	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
+	/// bit after advancing the iterator to it, discarding the intermediate
+	/// chunks.
+	///
+	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
+	/// and returns `None`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = [0x4Bu8, 0xA5];
-	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[2 .. 14];
-	/// *bits <<= 3;
-	/// assert_eq!(bits.as_ref(), &[0b01_011_101, 0b001_000_01]);
+	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
+	/// let mut chunks = bits.chunks(3);
+	/// assert_eq!(chunks.nth(1), Some(&bits[3 .. 6]));
+	/// assert_eq!(chunks.nth(0), Some(&bits[6 ..]));
+	/// assert!(chunks.nth(0).is_none());
 	/// ```
-	fn shl_assign(&mut self, shamt: usize) {
-		use core::ops::Shr;
-		if shamt == 0 {
-			return;
-		}
-		let len = self.len();
-		if shamt >= len {
-			self.set_all(false);
-			return;
-		}
-		//  If the shift amount is an even multiple of the element width, use
-		//  `ptr::copy` instead of a bitwise crawl.
-		if shamt & T::MASK as usize == 0 {
-			//  Compute the shift distance measured in elements.
-			let offset = shamt.shr(T::INDX);
-			//  Compute the number of elements that will remain.
-			let rem = self.as_ref().len().saturating_sub(offset);
-			//  Clear the bits after the tail cursor before the move.
-			for n in *self.bitptr().tail() .. T::BITS {
-				self.as_mut()[len.saturating_sub(1)].set::<C>(n.into(), false);
-			}
-			//  Memory model: suppose we have this slice of sixteen elements,
-			//  that is shifted five elements to the left. We have three
-			//  pointers and two lengths to manage.
-			//  - rem is 11
-			//  - offset is 5
-			//  - head is [0]
-			//  - body is [5; 11]
-			//  - tail is [11]
-			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
-			//              ^-------before------^
-			//    ^-------after-------^ 0 0 0 0 0
-			//  Pointer to the front of the slice
-			let head: *mut T = self.as_mut_ptr();
-			//  Pointer to the front of the section that will move and be
-			//  retained
-			let body: *const T = &self.as_ref()[offset];
-			//  Pointer to the back of the slice that will be zero-filled.
-			let tail: *mut T = &mut self.as_mut()[rem];
-			unsafe {
-				ptr::copy(body, head, rem);
-				ptr::write_bytes(tail, 0, offset);
-			}
-			return;
-		}
-		//  Otherwise, crawl.
-		for (to, from) in (shamt .. len).enumerate() {
-			let val = self[from];
-			self.set(to, val);
-		}
-		for bit in (len.saturating_sub(shamt)) .. len {
-			self.set(bit, false);
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		use core::cmp::min;
+		let (start, ovf) = n.overflowing_mul(self.width);
+		let len = self.inner.len();
+		if start >= len || ovf {
+			self.inner = BitSlice::empty();
+			return None;
 		}
+		let end = start.checked_add(self.width)
+			.map(|s| min(s, len))
+			.unwrap_or(len);
+		let out = &self.inner[start .. end];
+		self.inner = &self.inner[end ..];
+		Some(out)
 	}
-}
-
-/** Shifts all bits in the array to the right — **UP AND TOWARDS THE BACK**.
-
-On fundamentals, the right-shift operator `>>` moves bits towards the origin and
-away from the ceiling. This is because we label the bits in a primitive with the
-minimum on the right and the maximum on the left, which is big-endian bit order.
-This decreases the value of the primitive being shifted.
-
-**THAT IS NOT HOW `BitSlice` WORKS!**
-
-`BitSlice` defines its layout with the minimum on the left and the maximum on
-the right! Thus, right-shifting moves bits towards the **maximum**.
-
-In Big-Endian order, the effect in memory will be what you expect the `>>`
-operator to do.
-
-**In LittleEndian order, the effect will be equivalent to using `<<` on the**
-**fundamentals in memory!**
-
-# Notes
-
-In order to preserve the effects in memory that this operator traditionally
-expects, the bits that are emptied by this operation are zeroed rather than left
-to their old value.
-
-The shift amount is modulated against the array length, so it is not an error to
-pass a shift amount greater than the array length.
 
-A shift amount of zero is a no-op, and returns immediately.
-**/
-impl<C, T> ShrAssign<usize> for BitSlice<C, T>
-where C: Cursor, T: BitStore {
-	/// Shifts a slice right, in place.
+	/// Consumes the iterator, returning only the final chunk.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `shamt`: The shift amount. If this is greater than the length, then
-	///   the slice is zeroed immediately.
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the iterator slice, if any.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = [0x4Bu8, 0xA5];
-	/// let bits = &mut src.as_mut_bitslice::<BigEndian>()[2 .. 14];
-	/// *bits >>= 3;
-	/// assert_eq!(bits.as_ref(), &[0b01_000_00_1, 0b011_101_01])
+	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
+	/// assert_eq!(bits.chunks(3).last(), Some(&bits[6 ..]));
 	/// ```
-	fn shr_assign(&mut self, shamt: usize) {
-		if shamt == 0 {
-			return;
-		}
-		let len = self.len();
-		if shamt >= len {
-			self.set_all(false);
-			return;
-		}
-		//  IF the shift amount is an even multiple of the element width, use
-		//  `ptr::copy` instead of a bitwise crawl.
-		if shamt & T::MASK as usize == 0 {
-			//  Compute the shift amount measured in elements.
-			let offset = shamt >> T::INDX;
-			// Compute the number of elements that will remain.
-			let rem = self.as_ref().len().saturating_sub(offset);
-			//  Clear the bits ahead of the head cursor before the move.
-			for n in 0 .. *self.bitptr().head() {
-				self.as_mut()[0].set::<C>(n.into(), false);
-			}
-			//  Memory model: suppose we have this slice of sixteen elements,
-			//  that is shifted five elements to the right. We have two pointers
-			//  and two lengths to manage.
-			//  - rem is 11
-			//  - offset is 5
-			//  - head is [0; 11]
-			//  - body is [5]
-			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
-			//    ^-------before------^
-			//    0 0 0 0 0 ^-------after-------^
-			let head: *mut T = self.as_mut_ptr();
-			let body: *mut T = &mut self.as_mut()[offset];
-			unsafe {
-				ptr::copy(head, body, rem);
-				ptr::write_bytes(head, 0, offset);
-			}
-			return;
-		}
-		//  Otherwise, crawl.
-		for (from, to) in (shamt .. len).enumerate().rev() {
-			let val = self[from];
-			self.set(to, val);
-		}
-		for bit in 0 .. shamt {
-			self.set(bit, false);
-		}
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 }
 
-/** Write reference to a single bit.
+/** State keeper for mutable chunked iteration over a `BitSlice`.
 
-Rust requires that `DerefMut` produce the plain address of a value which can be
-written with a `memcpy`, so, there is no way to make plain write assignments
-work nicely in Rust. This reference structure is the second best option.
+# Type Parameters
 
-It contains a write reference to a single-bit slice, and a local cache `bool`.
-This structure `Deref`s to the local cache, and commits the cache to the slice
-on drop. This allows writing to the guard with `=` assignment.
+- `C: Cursor`: The bit-order type of the underlying `BitSlice`.
+- `T: 'a + BitStore`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
 **/
 #[derive(Debug)]
-pub struct BitGuard<'a, C, T>
+pub struct ChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	slot: &'a mut BitSlice<C, T>,
-	bit: bool,
-	_m: PhantomData<*mut T>,
+	/// The `BitSlice` being iterated.
+	inner: &'a mut BitSlice<C, T>,
+	/// The width of the chunks.
+	width: usize,
 }
 
-/// Read from the local cache.
-impl<'a, C, T> Deref for BitGuard<'a, C, T>
+impl<'a, C, T> DoubleEndedIterator for ChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Target = bool;
-
-	fn deref(&self) -> &Self::Target {
-		&self.bit
+	/// Produces the next chunk from the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the slice, if any.
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.inner.is_empty() {
+			return None;
+		}
+		let len = self.inner.len();
+		let rem = len % self.width;
+		let size = if rem == 0 { self.width } else { rem };
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		let (head, tail) = tmp.split_at_mut(len - size);
+		self.inner = head;
+		Some(tail)
 	}
 }
 
-/// Write to the local cache.
-impl<'a, C, T> DerefMut for BitGuard<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> FusedIterator for ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for ChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	fn deref_mut(&mut self) -> &mut Self::Target {
-		&mut self.bit
+	type Item = &'a mut BitSlice<C, T>;
+
+	/// Advances the iterator by one, returning the first chunk in it (if any).
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The leading chunk in the iterator, if any.
+	fn next(&mut self) -> Option<Self::Item> {
+		use core::cmp::min;
+		if self.inner.is_empty() {
+			return None;
+		}
+		let size = min(self.inner.len(), self.width);
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		let (head, tail) = tmp.split_at_mut(size);
+		self.inner = tail;
+		Some(head)
 	}
-}
 
-/// Commit the local cache to the backing slice.
-impl<'a, C, T> Drop for BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn drop(&mut self) {
-		self.slot.set(0, self.bit);
+	/// Hints at the number of chunks remaining in the iterator.
+	///
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum chunks remaining.
+	/// - `Option<usize>`: The maximum chunks remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.inner.is_empty() {
+			return (0, Some(0));
+		}
+		let len = self.inner.len();
+		let (n, r) = (len / self.width, len % self.width);
+		let len = n + (r > 0) as usize;
+		(len, Some(len))
+	}
+
+	/// Counts how many chunks are live in the iterator, consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The number of chunks remaining in the iterator.
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	/// Advances the iterator by `n` chunks, starting from zero.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The number of chunks to skip, before producing the next bit after
+	///   skips. If this overshoots the iterator’s remaining length, then the
+	///   iterator is marked empty before returning `None`.
+	///
+	/// # Returns
+	///
+	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
+	/// bit after advancing the iterator to it, discarding the intermediate
+	/// chunks.
+	///
+	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
+	/// and returns `None`.
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		use core::cmp::min;
+		let (start, ovf) = n.overflowing_mul(self.width);
+		let len = self.inner.len();
+		if start >= len || ovf {
+			self.inner = BitSlice::empty_mut();
+			return None;
+		}
+		let end = start.checked_add(self.width)
+			.map(|s| min(s, len))
+			.unwrap_or(len);
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		let (head, tail) = tmp.split_at_mut(start);
+		let (_, nth) = head.split_at_mut(end - start);
+		self.inner = tail;
+		Some(nth)
+	}
+
+	/// Consumes the iterator, returning only the final chunk.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the iterator slice, if any.
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 }
 
-/// This type is a mutable reference with extra steps, so, it should be moveable
-/// but not shareable.
-unsafe impl<'a, C, T> Send for BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {}
-
-/** State keeper for chunked iteration over a `BitSlice`.
+/** State keeper for exact chunked iteration over a `BitSlice`.
 
 # Type Parameters
 
@@ -3653,15 +7490,44 @@ where C: Cursor, T: 'a + BitStore {}
 - `'a`: The lifetime of the underlying `BitSlice`.
 **/
 #[derive(Clone, Debug)]
-pub struct Chunks<'a, C, T>
+pub struct ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `BitSlice` being iterated.
 	inner: &'a BitSlice<C, T>,
+	/// The excess of the original `BitSlice`, which is not iterated.
+	extra: &'a BitSlice<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> DoubleEndedIterator for Chunks<'a, C, T>
+impl<'a, C, T> ChunksExact<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the remainder of the original slice, which will not be included
+	/// in the iteration.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice that iteration will not include.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
+	/// let chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.remainder(), &bits[6 ..]);
+	/// ```
+	pub fn remainder(&self) -> &'a BitSlice<C, T> {
+		self.extra
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// Produces the next chunk from the back of the slice.
 	///
@@ -3679,33 +7545,31 @@ where C: Cursor, T: 'a + BitStore {
 	/// use bitvec::prelude::*;
 	///
 	/// let bits = 1u8.as_bitslice::<BigEndian>();
-	/// let mut chunks = bits.chunks(5);
-	/// assert_eq!(chunks.next_back(), Some(&bits[5 ..]));
-	/// assert_eq!(chunks.next_back(), Some(&bits[.. 5]));
-	/// assert!(chunks.next_back().is_none());
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.next_back(), Some(&bits[3 .. 6]));
+	/// assert_eq!(chunks_exact.next_back(), Some(&bits[0 .. 3]));
+	/// assert!(chunks_exact.next_back().is_none());
 	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = BitSlice::empty();
 			return None;
 		}
-		let len = self.inner.len();
-		let rem = len % self.width;
-		let size = if rem == 0 { self.width } else { rem };
-		let (head, tail) = self.inner.split_at(len - size);
+		let (head, tail) = self.inner.split_at(self.inner.len() - self.width);
 		self.inner = head;
 		Some(tail)
 	}
 }
 
 /// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for Chunks<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
 /// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for Chunks<'a, C, T>
+impl<'a, C, T> FusedIterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for Chunks<'a, C, T>
+impl<'a, C, T> Iterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	type Item = &'a BitSlice<C, T>;
 
@@ -3725,18 +7589,17 @@ where C: Cursor, T: 'a + BitStore {
 	/// use bitvec::prelude::*;
 	///
 	/// let bits = 1u8.as_bitslice::<LittleEndian>();
-	/// let mut chunks = bits.chunks(5);
-	/// assert_eq!(chunks.next(), Some(&bits[.. 5]));
-	/// assert_eq!(chunks.next(), Some(&bits[5 ..]));
-	/// assert!(chunks.next().is_none());
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.next(), Some(&bits[0 .. 3]));
+	/// assert_eq!(chunks_exact.next(), Some(&bits[3 .. 6]));
+	/// assert!(chunks_exact.next().is_none());
 	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
-		use core::cmp::min;
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = BitSlice::empty();
 			return None;
 		}
-		let size = min(self.inner.len(), self.width);
-		let (head, tail) = self.inner.split_at(size);
+		let (head, tail) = self.inner.split_at(self.width);
 		self.inner = tail;
 		Some(head)
 	}
@@ -3761,20 +7624,15 @@ where C: Cursor, T: 'a + BitStore {
 	/// use bitvec::prelude::*;
 	///
 	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// let mut chunks = bits.chunks(5);
-	/// assert_eq!(chunks.size_hint(), (2, Some(2)));
-	/// chunks.next();
-	/// assert_eq!(chunks.size_hint(), (1, Some(1)));
-	/// chunks.next();
-	/// assert_eq!(chunks.size_hint(), (0, Some(0)));
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.size_hint(), (2, Some(2)));
+	/// chunks_exact.next();
+	/// assert_eq!(chunks_exact.size_hint(), (1, Some(1)));
+	/// chunks_exact.next();
+	/// assert_eq!(chunks_exact.size_hint(), (0, Some(0)));
 	/// ```
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		if self.inner.is_empty() {
-			return (0, Some(0));
-		}
-		let len = self.inner.len();
-		let (n, r) = (len / self.width, len % self.width);
-		let len = n + (r > 0) as usize;
+		let len = self.inner.len() / self.width;
 		(len, Some(len))
 	}
 
@@ -3794,7 +7652,7 @@ where C: Cursor, T: 'a + BitStore {
 	/// use bitvec::prelude::*;
 	///
 	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// assert_eq!(bits.chunks(3).count(), 3);
+	/// assert_eq!(bits.chunks_exact(3).count(), 2);
 	/// ```
 	fn count(self) -> usize {
 		self.len()
@@ -3823,26 +7681,20 @@ where C: Cursor, T: 'a + BitStore {
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// let mut chunks = bits.chunks(3);
-	/// assert_eq!(chunks.nth(1), Some(&bits[3 .. 6]));
-	/// assert_eq!(chunks.nth(0), Some(&bits[6 ..]));
-	/// assert!(chunks.nth(0).is_none());
+	/// let bits = 2u8.as_bitslice::<BigEndian>();
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.nth(1), Some(&bits[3 .. 6]));
+	/// assert!(chunks_exact.nth(0).is_none());
 	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		use core::cmp::min;
 		let (start, ovf) = n.overflowing_mul(self.width);
-		let len = self.inner.len();
-		if start >= len || ovf {
+		if start >= self.inner.len() || ovf {
 			self.inner = BitSlice::empty();
 			return None;
 		}
-		let end = start.checked_add(self.width)
-			.map(|s| min(s, len))
-			.unwrap_or(len);
-		let out = &self.inner[start .. end];
-		self.inner = &self.inner[end ..];
-		Some(out)
+		let (_, tail) = self.inner.split_at(start);
+		self.inner = tail;
+		self.next()
 	}
 
 	/// Consumes the iterator, returning only the final chunk.
@@ -3861,175 +7713,107 @@ where C: Cursor, T: 'a + BitStore {
 	/// use bitvec::prelude::*;
 	///
 	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// assert_eq!(bits.chunks(3).last(), Some(&bits[6 ..]));
+	/// assert_eq!(bits.chunks_exact(3).last(), Some(&bits[3 .. 6]));
 	/// ```
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for mutable chunked iteration over a `BitSlice`.
+/** State keeper for iteration over the integer fields of a `BitSlice`.
+
+This is produced by [`BitSlice::iter_fields`].
 
 # Type Parameters
 
 - `C: Cursor`: The bit-order type of the underlying `BitSlice`.
 - `T: 'a + BitStore`: The storage type of the underlying `BitSlice`.
+- `U: BitStore`: The integer type of the field values.
 
 # Lifetimes
 
 - `'a`: The lifetime of the underlying `BitSlice`.
+
+[`BitSlice::iter_fields`]: struct.BitSlice.html#method.iter_fields
 **/
-#[derive(Debug)]
-pub struct ChunksMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// The `BitSlice` being iterated.
-	inner: &'a mut BitSlice<C, T>,
-	/// The width of the chunks.
-	width: usize,
+#[derive(Clone, Debug)]
+pub struct Fields<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {
+	/// The fields of the slice, as subslices.
+	inner: ChunksExact<'a, C, T>,
+	/// The iterator produces values of this type.
+	_ty: PhantomData<U>,
 }
 
-impl<'a, C, T> DoubleEndedIterator for ChunksMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the back of the slice.
+impl<'a, C, T, U> Fields<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {
+	/// Produces the bits after the last whole field, which will not be
+	/// included in the iteration.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
+	/// - `&self`
 	///
 	/// # Returns
 	///
-	/// The last chunk in the slice, if any.
+	/// The remaining slice, shorter than one field, that iteration will not
+	/// include.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x4Bu8.as_bitslice::<LittleEndian>();
+	/// let fields = bits.iter_fields::<u8>(3);
+	/// assert_eq!(fields.remainder(), &bits[6 ..]);
+	/// ```
+	pub fn remainder(&self) -> &'a BitSlice<C, T> {
+		self.inner.remainder()
+	}
+}
+
+impl<'a, C, T, U> DoubleEndedIterator for Fields<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.is_empty() {
-			return None;
-		}
-		let len = self.inner.len();
-		let rem = len % self.width;
-		let size = if rem == 0 { self.width } else { rem };
-		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
-		let (head, tail) = tmp.split_at_mut(len - size);
-		self.inner = head;
-		Some(tail)
+		self.inner.next_back().map(BitSlice::field_value)
 	}
 }
 
-impl<'a, C, T> ExactSizeIterator for ChunksMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {}
+/// Mark that the iterator has an exact size.
+impl<'a, C, T, U> ExactSizeIterator for Fields<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {}
 
-impl<'a, C, T> FusedIterator for ChunksMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {}
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T, U> FusedIterator for Fields<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {}
 
-impl<'a, C, T> Iterator for ChunksMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	type Item = &'a mut BitSlice<C, T>;
+impl<'a, C, T, U> Iterator for Fields<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {
+	type Item = U;
 
-	/// Advances the iterator by one, returning the first chunk in it (if any).
-	///
-	/// # Parameters
-	///
-	/// - `&mut self`
-	///
-	/// # Returns
-	///
-	/// The leading chunk in the iterator, if any.
 	fn next(&mut self) -> Option<Self::Item> {
-		use core::cmp::min;
-		if self.inner.is_empty() {
-			return None;
-		}
-		let size = min(self.inner.len(), self.width);
-		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
-		let (head, tail) = tmp.split_at_mut(size);
-		self.inner = tail;
-		Some(head)
+		self.inner.next().map(BitSlice::field_value)
 	}
 
-	/// Hints at the number of chunks remaining in the iterator.
-	///
-	/// Because the exact size is always known, this always produces
-	/// `(len, Some(len))`.
-	///
-	/// # Parameters
-	///
-	/// - `&self`
-	///
-	/// # Returns
-	///
-	/// - `usize`: The minimum chunks remaining.
-	/// - `Option<usize>`: The maximum chunks remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		if self.inner.is_empty() {
-			return (0, Some(0));
-		}
-		let len = self.inner.len();
-		let (n, r) = (len / self.width, len % self.width);
-		let len = n + (r > 0) as usize;
-		(len, Some(len))
-	}
-
-	/// Counts how many chunks are live in the iterator, consuming it.
-	///
-	/// # Parameters
-	///
-	/// - `self`
-	///
-	/// # Returns
-	///
-	/// The number of chunks remaining in the iterator.
-	fn count(self) -> usize {
-		self.len()
-	}
-
-	/// Advances the iterator by `n` chunks, starting from zero.
-	///
-	/// # Parameters
-	///
-	/// - `&mut self`
-	/// - `n`: The number of chunks to skip, before producing the next bit after
-	///   skips. If this overshoots the iterator’s remaining length, then the
-	///   iterator is marked empty before returning `None`.
-	///
-	/// # Returns
-	///
-	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
-	/// bit after advancing the iterator to it, discarding the intermediate
-	/// chunks.
-	///
-	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
-	/// and returns `None`.
-	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		use core::cmp::min;
-		let (start, ovf) = n.overflowing_mul(self.width);
-		let len = self.inner.len();
-		if start >= len || ovf {
-			self.inner = BitSlice::empty_mut();
-			return None;
-		}
-		let end = start.checked_add(self.width)
-			.map(|s| min(s, len))
-			.unwrap_or(len);
-		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
-		let (head, tail) = tmp.split_at_mut(start);
-		let (_, nth) = head.split_at_mut(end - start);
-		self.inner = tail;
-		Some(nth)
+		self.inner.size_hint()
+	}
+
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth(n).map(BitSlice::field_value)
 	}
 
-	/// Consumes the iterator, returning only the final chunk.
-	///
-	/// # Parameters
-	///
-	/// - `self`
-	///
-	/// # Returns
-	///
-	/// The last chunk in the iterator slice, if any.
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for exact chunked iteration over a `BitSlice`.
+/** State keeper for mutable exact chunked iteration over a `BitSlice`.
 
 # Type Parameters
 
@@ -4040,18 +7824,18 @@ where C: Cursor, T: 'a + BitStore {
 
 - `'a`: The lifetime of the underlying `BitSlice`.
 **/
-#[derive(Clone, Debug)]
-pub struct ChunksExact<'a, C, T>
+#[derive(Debug)]
+pub struct ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `BitSlice` being iterated.
-	inner: &'a BitSlice<C, T>,
+	inner: &'a mut BitSlice<C, T>,
 	/// The excess of the original `BitSlice`, which is not iterated.
-	extra: &'a BitSlice<C, T>,
+	extra: &'a mut BitSlice<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> ChunksExact<'a, C, T>
+impl<'a, C, T> ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// Produces the remainder of the original slice, which will not be included
 	/// in the iteration.
@@ -4063,24 +7847,14 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The remaining slice that iteration will not include.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// let chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.remainder(), &bits[6 ..]);
-	/// ```
-	pub fn remainder(&self) -> &'a BitSlice<C, T> {
+	pub fn into_remainder(self) -> &'a mut BitSlice<C, T> {
 		self.extra
 	}
 }
 
-impl<'a, C, T> DoubleEndedIterator for ChunksExact<'a, C, T>
+impl<'a, C, T> DoubleEndedIterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the back of the slice.
+	/// Produces the next chunk from the back of th eslice.
 	///
 	/// # Parameters
 	///
@@ -4089,40 +7863,20 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The last chunk in the slice, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 1u8.as_bitslice::<BigEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.next_back(), Some(&bits[3 .. 6]));
-	/// assert_eq!(chunks_exact.next_back(), Some(&bits[0 .. 3]));
-	/// assert!(chunks_exact.next_back().is_none());
-	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = BitSlice::empty();
-			return None;
-		}
-		let (head, tail) = self.inner.split_at(self.inner.len() - self.width);
-		self.inner = head;
-		Some(tail)
+		unimplemented!()
 	}
 }
 
-/// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for ChunksExact<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-/// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for ChunksExact<'a, C, T>
+impl<'a, C, T> FusedIterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for ChunksExact<'a, C, T>
+impl<'a, C, T> Iterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Item = &'a BitSlice<C, T>;
+	type Item = &'a mut BitSlice<C, T>;
 
 	/// Advances the iterator by one, returning the first chunk in it (if any).
 	///
@@ -4133,24 +7887,13 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The leading chunk in the iterator, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 1u8.as_bitslice::<LittleEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.next(), Some(&bits[0 .. 3]));
-	/// assert_eq!(chunks_exact.next(), Some(&bits[3 .. 6]));
-	/// assert!(chunks_exact.next().is_none());
-	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
 		if self.inner.len() < self.width {
-			self.inner = BitSlice::empty();
+			self.inner = BitSlice::empty_mut();
 			return None;
 		}
-		let (head, tail) = self.inner.split_at(self.width);
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		let (head, tail) = tmp.split_at_mut(self.width);
 		self.inner = tail;
 		Some(head)
 	}
@@ -4168,20 +7911,6 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// - `usize`: The minimum chunks remaining.
 	/// - `Option<usize>`: The maximum chunks remaining.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.size_hint(), (2, Some(2)));
-	/// chunks_exact.next();
-	/// assert_eq!(chunks_exact.size_hint(), (1, Some(1)));
-	/// chunks_exact.next();
-	/// assert_eq!(chunks_exact.size_hint(), (0, Some(0)));
-	/// ```
 	fn size_hint(&self) -> (usize, Option<usize>) {
 		let len = self.inner.len() / self.width;
 		(len, Some(len))
@@ -4196,15 +7925,6 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The number of chunks remaining in the iterator.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// assert_eq!(bits.chunks_exact(3).count(), 2);
-	/// ```
 	fn count(self) -> usize {
 		self.len()
 	}
@@ -4226,24 +7946,14 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
 	/// and returns `None`.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 2u8.as_bitslice::<BigEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.nth(1), Some(&bits[3 .. 6]));
-	/// assert!(chunks_exact.nth(0).is_none());
-	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
 		let (start, ovf) = n.overflowing_mul(self.width);
 		if start >= self.inner.len() || ovf {
-			self.inner = BitSlice::empty();
+			self.inner = BitSlice::empty_mut();
 			return None;
 		}
-		let (_, tail) = self.inner.split_at(start);
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		let (_, tail) = tmp.split_at_mut(start);
 		self.inner = tail;
 		self.next()
 	}
@@ -4257,21 +7967,18 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The last chunk in the iterator slice, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bits = 0x4Bu8.as_bitslice::<BigEndian>();
-	/// assert_eq!(bits.chunks_exact(3).last(), Some(&bits[3 .. 6]));
-	/// ```
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for mutable exact chunked iteration over a `BitSlice`.
+/** A view of a `BitSlice` with every bit inverted.
+
+This is produced by [`BitSlice::complement`] and by the `!` operator on
+`&BitSlice`. It borrows the slice, and inverts each bit as it is read, so it
+never allocates and never writes to the slice. It is a stream of bits, and so
+can be the right-hand side of the `&`, `|`, and `^` operators on bit
+collections, and it compares equal to any slice that holds the inverted bits.
 
 # Type Parameters
 
@@ -4281,38 +7988,191 @@ where C: Cursor, T: 'a + BitStore {
 # Lifetimes
 
 - `'a`: The lifetime of the underlying `BitSlice`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut mask = bitvec![1; 8];
+let used = bitvec![0, 0, 1, 1, 0, 1, 0, 0];
+mask &= !&used[..];
+assert_eq!(mask.count_ones(), 5);
+assert!(used.complement() == mask);
+```
+
+[`BitSlice::complement`]: struct.BitSlice.html#method.complement
 **/
-#[derive(Debug)]
-pub struct ChunksExactMut<'a, C, T>
+#[derive(Clone, Copy)]
+pub struct Complement<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// The `BitSlice` being iterated.
-	inner: &'a mut BitSlice<C, T>,
-	/// The excess of the original `BitSlice`, which is not iterated.
-	extra: &'a mut BitSlice<C, T>,
-	/// The width of the chunks.
-	width: usize,
+	/// The slice whose bits are inverted.
+	inner: &'a BitSlice<C, T>,
 }
 
-impl<'a, C, T> ChunksExactMut<'a, C, T>
+impl<'a, C, T> Complement<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the remainder of the original slice, which will not be included
-	/// in the iteration.
+	/// Counts the bits in the view.
+	///
+	/// # Returns
+	///
+	/// The length of the underlying slice.
+	pub fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	/// Tests if the view is empty.
+	///
+	/// # Returns
+	///
+	/// Whether the underlying slice is empty.
+	pub fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+
+	/// Reads an inverted bit.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `index`: The index of a bit in the view.
 	///
 	/// # Returns
 	///
-	/// The remaining slice that iteration will not include.
-	pub fn into_remainder(self) -> &'a mut BitSlice<C, T> {
-		self.extra
+	/// The inverse of the bit at `index` in the underlying slice, or `None` if
+	/// `index` is out of bounds.
+	pub fn get(&self, index: usize) -> Option<bool> {
+		self.inner.get(index).map(|bit| !bit)
+	}
+
+	/// Counts the set bits in the view.
+	///
+	/// These are the cleared bits of the underlying slice, which are counted a
+	/// whole element at a time.
+	///
+	/// # Returns
+	///
+	/// The number of bits that read as set.
+	pub fn count_ones(&self) -> usize {
+		self.inner.count_zeros()
+	}
+
+	/// Counts the cleared bits in the view.
+	///
+	/// # Returns
+	///
+	/// The number of bits that read as cleared.
+	pub fn count_zeros(&self) -> usize {
+		self.inner.count_ones()
+	}
+
+	/// Iterates over the inverted bits, from front to back.
+	///
+	/// # Returns
+	///
+	/// An iterator over the bits of the view.
+	pub fn iter(&self) -> Map<Iter<'a, C, T>, fn(bool) -> bool> {
+		self.inner.iter().map(<bool as Not>::not as fn(bool) -> bool)
+	}
+
+	/// Removes the view from the slice that it inverts.
+	///
+	/// # Returns
+	///
+	/// The underlying slice, whose bits are not inverted.
+	pub fn into_inner(self) -> &'a BitSlice<C, T> {
+		self.inner
 	}
 }
 
-impl<'a, C, T> DoubleEndedIterator for ChunksExactMut<'a, C, T>
+impl<'a, C, T> Debug for Complement<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the back of th eslice.
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("Complement [")?;
+		for bit in self.iter() {
+			f.write_str(if bit { "1" } else { "0" })?;
+		}
+		f.write_str("]")
+	}
+}
+
+impl<'a, C, T> IntoIterator for Complement<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+	type IntoIter = Map<Iter<'a, C, T>, fn(bool) -> bool>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, 'b, A, B, C, D> PartialEq<Complement<'b, C, D>>
+for Complement<'a, A, B>
+where A: Cursor, B: 'a + BitStore, C: Cursor, D: 'b + BitStore {
+	fn eq(&self, rhs: &Complement<'b, C, D>) -> bool {
+		self.inner == rhs.inner
+	}
+}
+
+impl<'a, A, B, C, D> PartialEq<BitSlice<C, D>> for Complement<'a, A, B>
+where A: Cursor, B: 'a + BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitSlice<C, D>) -> bool {
+		if self.len() != rhs.len() {
+			return false;
+		}
+		self.iter().zip(rhs.iter()).all(|(l, r)| l == r)
+	}
+}
+
+impl<'a, A, B, C, D> PartialEq<Complement<'a, C, D>> for BitSlice<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: 'a + BitStore {
+	fn eq(&self, rhs: &Complement<'a, C, D>) -> bool {
+		rhs == self
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, A, B, C, D> PartialEq<BitVec<C, D>> for Complement<'a, A, B>
+where A: Cursor, B: 'a + BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitVec<C, D>) -> bool {
+		self == rhs.as_bitslice()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, A, B, C, D> PartialEq<Complement<'a, C, D>> for BitVec<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: 'a + BitStore {
+	fn eq(&self, rhs: &Complement<'a, C, D>) -> bool {
+		rhs == self.as_bitslice()
+	}
+}
+
+/** State keeper for per-element iteration over a `BitSlice`.
+
+Each item is the subslice of the underlying `BitSlice` that lives in one storage
+element. From the front, the items are produced in increasing address order;
+from the back, in decreasing address order. In either direction, the bits within
+an item keep their `C` ordering.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `BitSlice`.
+- `T: 'a + BitStore`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone, Debug)]
+pub struct Elements<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The `BitSlice` being iterated.
+	inner: &'a BitSlice<C, T>,
+}
+
+impl<'a, C, T> DoubleEndedIterator for Elements<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the subslice in the last element of the slice.
 	///
 	/// # Parameters
 	///
@@ -4320,23 +8180,32 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last chunk in the slice, if any.
+	/// The live bits of the last element in the slice, if any.
 	fn next_back(&mut self) -> Option<Self::Item> {
-		unimplemented!()
+		let len = self.inner.len();
+		if len == 0 {
+			return None;
+		}
+		let size = cmp::min(len, *self.inner.bitptr().tail() as usize);
+		let (head, tail) = self.inner.split_at(len - size);
+		self.inner = head;
+		Some(tail)
 	}
 }
 
-impl<'a, C, T> ExactSizeIterator for ChunksExactMut<'a, C, T>
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for Elements<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> FusedIterator for ChunksExactMut<'a, C, T>
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for Elements<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for ChunksExactMut<'a, C, T>
+impl<'a, C, T> Iterator for Elements<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Item = &'a mut BitSlice<C, T>;
+	type Item = &'a BitSlice<C, T>;
 
-	/// Advances the iterator by one, returning the first chunk in it (if any).
+	/// Produces the subslice in the first element of the slice.
 	///
 	/// # Parameters
 	///
@@ -4344,19 +8213,20 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The leading chunk in the iterator, if any.
+	/// The live bits of the first element in the slice, if any.
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = BitSlice::empty_mut();
+		let len = self.inner.len();
+		if len == 0 {
 			return None;
 		}
-		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
-		let (head, tail) = tmp.split_at_mut(self.width);
+		let head = *self.inner.bitptr().head() as usize;
+		let size = cmp::min(len, T::BITS as usize - head);
+		let (head, tail) = self.inner.split_at(size);
 		self.inner = tail;
 		Some(head)
 	}
 
-	/// Hints at the number of chunks remaining in the iterator.
+	/// Hints at the number of elements remaining in the iterator.
 	///
 	/// Because the exact size is always known, this always produces
 	/// `(len, Some(len))`.
@@ -4367,14 +8237,19 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// - `usize`: The minimum chunks remaining.
-	/// - `Option<usize>`: The maximum chunks remaining.
+	/// - `usize`: The minimum elements remaining.
+	/// - `Option<usize>`: The maximum elements remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let len = self.inner.len() / self.width;
+		let len = if self.inner.is_empty() {
+			0
+		}
+		else {
+			self.inner.bitptr().elements()
+		};
 		(len, Some(len))
 	}
 
-	/// Counts how many chunks are live in the iterator, consuming it.
+	/// Counts how many elements are live in the iterator, consuming it.
 	///
 	/// # Parameters
 	///
@@ -4382,41 +8257,12 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The number of chunks remaining in the iterator.
+	/// The number of elements remaining in the iterator.
 	fn count(self) -> usize {
 		self.len()
 	}
 
-	/// Advances the iterator by `n` chunks, starting from zero.
-	///
-	/// # Parameters
-	///
-	/// - `&mut self`
-	/// - `n`: The number of chunks to skip, before producing the next bit after
-	///   skips. If this overshoots the iterator’s remaining length, then the
-	///   iterator is marked empty before returning `None`.
-	///
-	/// # Returns
-	///
-	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
-	/// bit after advancing the iterator to it, discarding the intermediate
-	/// chunks.
-	///
-	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
-	/// and returns `None`.
-	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		let (start, ovf) = n.overflowing_mul(self.width);
-		if start >= self.inner.len() || ovf {
-			self.inner = BitSlice::empty_mut();
-			return None;
-		}
-		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
-		let (_, tail) = tmp.split_at_mut(start);
-		self.inner = tail;
-		self.next()
-	}
-
-	/// Consumes the iterator, returning only the final chunk.
+	/// Consumes the iterator, returning only the final element.
 	///
 	/// # Parameters
 	///
@@ -4424,7 +8270,7 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last chunk in the iterator slice, if any.
+	/// The live bits of the last element in the slice, if any.
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
@@ -4432,6 +8278,13 @@ where C: Cursor, T: 'a + BitStore {
 
 /** State keeper for iteration over a `BitSlice`.
 
+The `Iterator` searches that take a predicate, such as `position` and `any`,
+cannot see what their predicate computes, and so must run it once per bit.
+Searches for a set or unset bit are better served by [`BitSlice::first_one`]
+and its siblings, which skip storage elements that cannot contain a match. The
+part of the slice that an iterator has yet to yield is available from
+[`as_bitslice`].
+
 # Type Parameters
 
 - `C: Cursor`: The bit-order type of the underlying `BitSlice`.
@@ -4440,16 +8293,53 @@ where C: Cursor, T: 'a + BitStore {
 # Lifetimes
 
 - `'a`: The lifetime of the underlying `BitSlice`.
+
+[`BitSlice::first_one`]: struct.BitSlice.html#method.first_one
+[`as_bitslice`]: #method.as_bitslice
 **/
 #[derive(Clone, Debug)]
 pub struct Iter<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `BitSlice` being iterated.
 	inner: &'a BitSlice<C, T>,
+	/// A copy of the element holding the front bit of `inner`.
+	///
+	/// This is loaded when iteration from the front enters an element, and
+	/// discarded when it leaves, so that each element is read from memory only
+	/// once. No other handle can write to the bits of `inner` while the
+	/// iterator borrows them, so the copy cannot go stale.
+	front: Option<T>,
+	/// A copy of the element holding the back bit of `inner`, kept in the same
+	/// manner as `front`.
+	back: Option<T>,
 }
 
 impl<'a, C, T> Iter<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
+	/// Views the bits that the iterator has yet to produce.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice of the iterator.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0x21u8.as_bitslice::<BigEndian>();
+	/// let mut iter = bits.iter();
+	/// iter.nth(2);
+	/// assert_eq!(iter.as_bitslice().first_one(), Some(4));
+	/// ```
+	pub fn as_bitslice(&self) -> &'a BitSlice<C, T> {
+		self.inner
+	}
+
 	/// Accesses the `BitPtr` representation of the slice.
 	///
 	/// # Parameters
@@ -4490,10 +8380,22 @@ where C: Cursor, T: 'a + BitStore {
 	/// assert!(iter.next_back().is_none());
 	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		self.inner.split_last().map(|(b, r)| {
-			self.inner = r;
-			b
-		})
+		let mut bitptr = self.inner.bitptr();
+		if bitptr.is_empty() {
+			return None;
+		}
+		let (data, tail) = (bitptr.pointer().a(), *bitptr.tail());
+		let last = bitptr.elements() - 1;
+		let elt = match self.back {
+			Some(elt) => elt,
+			None => unsafe { (&*data.add(last)).load() },
+		};
+		let bit = elt.get::<C>((tail - 1).idx());
+		//  Drop the cache when the last bit of its element has been taken.
+		self.back = if tail == 1 { None } else { Some(elt) };
+		unsafe { bitptr.decr_tail(); }
+		self.inner = bitptr.into_bitslice();
+		Some(bit)
 	}
 }
 
@@ -4531,10 +8433,21 @@ where C: Cursor, T: 'a + BitStore {
 	/// assert!(iter.next().is_none());
 	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
-		self.inner.split_first().map(|(b, r)| {
-			self.inner = r;
-			b
-		})
+		let mut bitptr = self.inner.bitptr();
+		if bitptr.is_empty() {
+			return None;
+		}
+		let head = bitptr.head();
+		let elt = match self.front {
+			Some(elt) => elt,
+			None => unsafe { (&*bitptr.pointer().a()).load() },
+		};
+		let bit = elt.get::<C>(head);
+		//  Drop the cache when the last bit of its element has been taken.
+		self.front = if *head == T::MASK { None } else { Some(elt) };
+		unsafe { bitptr.incr_head(); }
+		self.inner = bitptr.into_bitslice();
+		Some(bit)
 	}
 
 	/// Hints at the number of bits remaining in the iterator.
@@ -4623,9 +8536,14 @@ where C: Cursor, T: 'a + BitStore {
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
 		if n >= self.len() {
 			self.inner = BitSlice::empty();
+			self.front = None;
+			self.back = None;
 			return None;
 		}
-		self.inner = &self.inner[n ..];
+		if n > 0 {
+			self.inner = &self.inner[n ..];
+			self.front = None;
+		}
 		self.next()
 	}
 
@@ -4652,6 +8570,223 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
+/** State keeper for mutable iteration over a `BitSlice`.
+
+Each bit is produced as a [`BitMut`] proxy, which caches the bit's value and
+writes it back to the slice when dropped.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `BitSlice`.
+- `T: 'a + BitStore`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+
+[`BitMut`]: struct.BitMut.html
+**/
+#[derive(Debug)]
+pub struct IterMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The `BitSlice` being iterated.
+	inner: &'a mut BitSlice<C, T>,
+}
+
+impl<'a, C, T> IterMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Consumes the iterator, producing the bits that it has yet to yield.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice of the iterator.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let mut iter = src.as_mut_bitslice::<BigEndian>().iter_mut();
+	/// iter.nth(3);
+	/// iter.into_bitslice().set_all(true);
+	/// assert_eq!(src, 0x0F);
+	/// ```
+	pub fn into_bitslice(self) -> &'a mut BitSlice<C, T> {
+		self.inner
+	}
+
+	/// Takes a bit off one edge of the remaining slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `back`: Whether to take the last bit, rather than the first.
+	///
+	/// # Returns
+	///
+	/// A write proxy for the taken bit, if the iterator is not empty.
+	fn take_bit(&mut self, back: bool) -> Option<BitMut<'a, C, T>> {
+		let len = self.inner.len();
+		if len == 0 {
+			return None;
+		}
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		let (slot, rest) = if back {
+			let (rest, slot) = tmp.split_at_mut(len - 1);
+			(slot, rest)
+		}
+		else {
+			tmp.split_at_mut(1)
+		};
+		self.inner = rest;
+		Some(BitMut {
+			_m: PhantomData,
+			bit: slot[0],
+			slot,
+		})
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for IterMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the next bit from the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A write proxy for the last bit in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let mut iter = src.as_mut_bitslice::<BigEndian>().iter_mut();
+	/// *iter.next_back().unwrap() = true;
+	/// assert_eq!(src, 1);
+	/// ```
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.take_bit(true)
+	}
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for IterMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for IterMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for IterMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = BitMut<'a, C, T>;
+
+	/// Advances the iterator by one, returning the first bit in it (if any).
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A write proxy for the leading bit in the iterator, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let mut iter = src.as_mut_bitslice::<BigEndian>().iter_mut();
+	/// *iter.next().unwrap() = true;
+	/// assert_eq!(src, 0x80);
+	/// ```
+	fn next(&mut self) -> Option<Self::Item> {
+		self.take_bit(false)
+	}
+
+	/// Hints at the number of bits remaining in the iterator.
+	///
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum bits remaining.
+	/// - `Option<usize>`: The maximum bits remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		(len, Some(len))
+	}
+
+	/// Counts how many bits are live in the iterator, consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The number of bits remaining in the iterator.
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	/// Advances the iterator by `n` bits, starting from zero.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The number of bits to skip, before producing the next bit after
+	///   skips. If this overshoots the iterator’s remaining length, then the
+	///   iterator is marked empty before returning `None`.
+	///
+	/// # Returns
+	///
+	/// If `n` does not overshoot the iterator’s bounds, this produces a write
+	/// proxy for the `n`th bit after advancing the iterator to it, discarding
+	/// the intermediate bits.
+	///
+	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
+	/// and returns `None`.
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.inner = BitSlice::empty_mut();
+			return None;
+		}
+		let tmp = mem::replace(&mut self.inner, BitSlice::empty_mut());
+		self.inner = &mut tmp[n ..];
+		self.next()
+	}
+
+	/// Consumes the iterator, returning only the final bit.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A write proxy for the last bit in the iterator slice, if any.
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
 /** State keeper for reverse chunked iteration over a `BitSlice`.
 
 # Type Parameters
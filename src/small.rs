@@ -0,0 +1,562 @@
+/*! `SmallBitVec` structure
+
+This module holds a growable bit vector that keeps short sequences inside the
+handle itself, and only allocates once it outgrows that space. Programs that
+hold many short bit sequences pay for one allocation per `BitVec`, which can
+cost more than the bits themselves; a `SmallBitVec` of up to two machine words
+of bits needs no allocation at all.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		BigEndian,
+		Cursor,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	clone::Clone,
+	cmp::{
+		Eq,
+		PartialEq,
+	},
+	default::Default,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::{
+		Extend,
+		FromIterator,
+		IntoIterator,
+	},
+	marker::PhantomData,
+	mem,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+	slice,
+};
+
+/// The number of bytes of bits held inside the handle.
+const INLINE_BYTES: usize = 2 * mem::size_of::<usize>();
+
+/** A bit vector which stores short sequences without allocating.
+
+Up to [`INLINE_BITS`] bits (128 on 64-bit targets, and 64 on 32-bit targets)
+are stored in a buffer inside the `SmallBitVec` value. Growing past that moves
+the bits into a [`BitVec`], transparently, and the vector stays on the heap
+until [`shrink_to_fit`] finds that its bits fit inline again.
+
+The full `BitSlice` API is available through `Deref` and `DerefMut`, whichever
+storage the vector is using.
+
+# Type Parameters
+
+- `C: Cursor`: An implementor of the [`Cursor`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `T: BitStore`: An implementor of the [`BitStore`] trait: `u8`, `u16`, `u32`,
+  or `u64` (64-bit systems only). This is the actual type in memory that the
+  vector will use to store data.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::small::SmallBitVec;
+
+let mut sv: SmallBitVec = SmallBitVec::new();
+sv.extend(bitvec![0, 1, 1, 0, 1].iter());
+assert!(sv.is_inline());
+assert_eq!(sv.count_ones(), 3);
+
+sv.extend(bitvec![1; 200].iter());
+assert!(!sv.is_inline());
+assert_eq!(sv.len(), 205);
+```
+
+[`BitStore`]: ../store/trait.BitStore.html
+[`BitVec`]: ../vec/struct.BitVec.html
+[`Cursor`]: ../cursor/trait.Cursor.html
+[`INLINE_BITS`]: #associatedconstant.INLINE_BITS
+[`shrink_to_fit`]: #method.shrink_to_fit
+**/
+pub struct SmallBitVec<C = BigEndian, T = u8>
+where C: Cursor, T: BitStore {
+	/// The storage currently in use.
+	repr: Repr<C, T>,
+}
+
+/// The two storage forms of a `SmallBitVec`.
+enum Repr<C, T>
+where C: Cursor, T: BitStore {
+	/// Bits held in the handle. Bits at and above `len` may hold anything.
+	Inline {
+		/// The buffer, reinterpreted as `T` elements when viewed. Its
+		/// alignment is at least that of every `BitStore` type.
+		data: [usize; 2],
+		/// The number of live bits in `data`.
+		len: usize,
+		_cursor: PhantomData<C>,
+		_storage: PhantomData<T>,
+	},
+	/// Bits moved onto the heap.
+	Heap(BitVec<C, T>),
+}
+
+impl<C, T> SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// The most bits that the vector can hold without allocating.
+	pub const INLINE_BITS: usize = INLINE_BYTES * 8;
+
+	/// Constructs a new, empty, `SmallBitVec<C, T>`.
+	///
+	/// # Returns
+	///
+	/// An empty vector, which does not allocate.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::small::SmallBitVec;
+	///
+	/// let sv: SmallBitVec = SmallBitVec::new();
+	/// assert!(sv.is_empty());
+	/// assert!(sv.is_inline());
+	/// ```
+	pub fn new() -> Self {
+		Self {
+			repr: Repr::Inline {
+				data: [0; 2],
+				len: 0,
+				_cursor: PhantomData,
+				_storage: PhantomData,
+			},
+		}
+	}
+
+	/// Copies a bit slice into a new vector, allocating only if it does not
+	/// fit inline.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: The bits to copy.
+	///
+	/// # Returns
+	///
+	/// A vector holding the bits of `slice`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::small::SmallBitVec;
+	///
+	/// let sv = SmallBitVec::from_bitslice(&bitvec![1, 0, 1]);
+	/// assert_eq!(sv.as_bitslice(), &bitvec![1, 0, 1]);
+	/// ```
+	pub fn from_bitslice(slice: &BitSlice<C, T>) -> Self {
+		let mut out = Self::new();
+		out.extend_from_bitslice(slice);
+		out
+	}
+
+	/// Tests if the vector is holding its bits inline.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// `true` if the bits are in the handle, and `false` if they are on the
+	/// heap.
+	pub fn is_inline(&self) -> bool {
+		match self.repr {
+			Repr::Inline { .. } => true,
+			Repr::Heap(_) => false,
+		}
+	}
+
+	/// Counts the bits the vector can hold without reallocating.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// `INLINE_BITS` while the vector is inline, or the capacity of its heap
+	/// buffer.
+	pub fn capacity(&self) -> usize {
+		match self.repr {
+			Repr::Inline { .. } => Self::INLINE_BITS,
+			Repr::Heap(ref bv) => bv.capacity(),
+		}
+	}
+
+	/// Appends a bit to the back of the vector, moving it onto the heap if it
+	/// is inline and full.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to append.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::small::SmallBitVec;
+	///
+	/// let mut sv: SmallBitVec = SmallBitVec::new();
+	/// for _ in 0 .. SmallBitVec::<bitvec::cursor::BigEndian, u8>::INLINE_BITS {
+	///   sv.push(true);
+	/// }
+	/// assert!(sv.is_inline());
+	/// sv.push(false);
+	/// assert!(!sv.is_inline());
+	/// assert!(sv.not_all());
+	/// ```
+	pub fn push(&mut self, value: bool) {
+		if let Repr::Inline { len, .. } = self.repr {
+			if len < Self::INLINE_BITS {
+				self.set_inline_len(len + 1);
+				self.set(len, value);
+				return;
+			}
+		}
+		self.spill(1).push(value);
+	}
+
+	/// Removes the last bit from the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last bit, or `None` if the vector is empty.
+	pub fn pop(&mut self) -> Option<bool> {
+		let last = self.last()?;
+		let len = self.len() - 1;
+		self.truncate(len);
+		Some(last)
+	}
+
+	/// Shortens the vector to `len` bits, if it is longer. This does not
+	/// move a heap vector back inline.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The number of bits to keep.
+	pub fn truncate(&mut self, len: usize) {
+		match self.repr {
+			Repr::Inline { len: ref mut live, .. } => if len < *live {
+				*live = len;
+			},
+			Repr::Heap(ref mut bv) => bv.truncate(len),
+		}
+	}
+
+	/// Removes every bit from the vector, keeping its current storage.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Appends a bit slice to the back of the vector, moving it onto the
+	/// heap if the result does not fit inline.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The bits to append.
+	pub fn extend_from_bitslice(&mut self, other: &BitSlice<C, T>) {
+		if let Repr::Inline { len, .. } = self.repr {
+			if other.len() <= Self::INLINE_BITS - len {
+				self.set_inline_len(len + other.len());
+				self[len ..].copy_bits_from(other);
+				return;
+			}
+		}
+		self.spill(other.len()).extend_from_bitslice(other);
+	}
+
+	/// Moves a heap vector back inline if its bits fit, or otherwise shrinks
+	/// its heap buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::small::SmallBitVec;
+	///
+	/// let mut sv: SmallBitVec = (0 .. 500).map(|n| n % 3 == 0).collect();
+	/// assert!(!sv.is_inline());
+	/// sv.truncate(10);
+	/// sv.shrink_to_fit();
+	/// assert!(sv.is_inline());
+	/// assert_eq!(sv.count_ones(), 4);
+	/// ```
+	pub fn shrink_to_fit(&mut self) {
+		let fits = match self.repr {
+			Repr::Heap(ref mut bv) => {
+				if bv.len() > Self::INLINE_BITS {
+					bv.shrink_to_fit();
+					false
+				}
+				else {
+					true
+				}
+			},
+			Repr::Inline { .. } => false,
+		};
+		if fits {
+			let inline = Self::from_bitslice(self);
+			*self = inline;
+		}
+	}
+
+	/// Views the bits of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `BitSlice` over the live bits, wherever they are stored.
+	pub fn as_bitslice(&self) -> &BitSlice<C, T> {
+		match self.repr {
+			Repr::Inline { ref data, len, .. } => {
+				//  Safety: `data` is aligned for every `BitStore` type, and
+				//  every bit pattern is a valid `T`.
+				let elts = unsafe {
+					slice::from_raw_parts(
+						data.as_ptr() as *const T,
+						INLINE_BYTES / mem::size_of::<T>(),
+					)
+				};
+				&BitSlice::from_slice(elts)[.. len]
+			},
+			Repr::Heap(ref bv) => bv.as_bitslice(),
+		}
+	}
+
+	/// Views the bits of the vector, mutably.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable `BitSlice` over the live bits, wherever they are stored.
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<C, T> {
+		match self.repr {
+			Repr::Inline { ref mut data, len, .. } => {
+				let elts = unsafe {
+					slice::from_raw_parts_mut(
+						data.as_mut_ptr() as *mut T,
+						INLINE_BYTES / mem::size_of::<T>(),
+					)
+				};
+				&mut BitSlice::from_slice_mut(elts)[.. len]
+			},
+			Repr::Heap(ref mut bv) => bv.as_mut_bitslice(),
+		}
+	}
+
+	/// Converts the vector into a `BitVec`, allocating if it is inline.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A `BitVec` holding the same bits.
+	pub fn into_bitvec(self) -> BitVec<C, T> {
+		match self.repr {
+			Repr::Inline { .. } => BitVec::from_bitslice(self.as_bitslice()),
+			Repr::Heap(bv) => bv,
+		}
+	}
+
+	/// Sets the live length of an inline vector. The caller ensures that the
+	/// vector is inline, and that `len` is within `INLINE_BITS`.
+	fn set_inline_len(&mut self, new: usize) {
+		if let Repr::Inline { ref mut len, .. } = self.repr {
+			*len = new;
+		}
+	}
+
+	/// Moves an inline vector onto the heap, with room for `additional` more
+	/// bits, and returns the heap vector.
+	fn spill(&mut self, additional: usize) -> &mut BitVec<C, T> {
+		if self.is_inline() {
+			let mut bv = BitVec::with_capacity(
+				Self::INLINE_BITS.max(self.len()).saturating_add(additional),
+			);
+			bv.extend_from_bitslice(self.as_bitslice());
+			self.repr = Repr::Heap(bv);
+		}
+		match self.repr {
+			Repr::Heap(ref mut bv) => bv,
+			Repr::Inline { .. } => unreachable!("the vector was just spilled"),
+		}
+	}
+}
+
+impl<C, T> Clone for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn clone(&self) -> Self {
+		match self.repr {
+			Repr::Inline { data, len, .. } => Self {
+				repr: Repr::Inline {
+					data,
+					len,
+					_cursor: PhantomData,
+					_storage: PhantomData,
+				},
+			},
+			Repr::Heap(ref bv) => Self { repr: Repr::Heap(bv.clone()) },
+		}
+	}
+}
+
+impl<C, T> Eq for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> PartialEq for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Tests if two vectors hold the same bits, wherever each stores them.
+	fn eq(&self, rhs: &Self) -> bool {
+		self.as_bitslice() == rhs.as_bitslice()
+	}
+}
+
+impl<C, T> Default for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C, T> Debug for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `SmallBitVec` type header, its storage, and its bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::small::SmallBitVec;
+	///
+	/// let sv = SmallBitVec::from_bitslice(&bitvec![LittleEndian, u16; 0, 1, 1]);
+	/// assert_eq!(
+	///   "SmallBitVec<LittleEndian, u16> inline [011]",
+	///   &format!("{:?}", sv),
+	/// );
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"SmallBitVec<{}, {}> {} [",
+			C::TYPENAME,
+			T::TYPENAME,
+			if self.is_inline() { "inline" } else { "heap" },
+		)?;
+		for bit in self.as_bitslice() {
+			f.write_str(if bit { "1" } else { "0" })?;
+		}
+		f.write_str("]")
+	}
+}
+
+impl<C, T> Hash for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<C, T> Deref for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	type Target = BitSlice<C, T>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T> DerefMut for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, T> Extend<bool> for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item=bool> {
+		for bit in src {
+			self.push(bit);
+		}
+	}
+}
+
+impl<C, T> FromIterator<bool> for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item=bool> {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<C, T> From<&BitSlice<C, T>> for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from(src: &BitSlice<C, T>) -> Self {
+		Self::from_bitslice(src)
+	}
+}
+
+impl<C, T> From<BitVec<C, T>> for SmallBitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Wraps a `BitVec` without copying it. The vector stays on the heap
+	/// until `shrink_to_fit` is called.
+	fn from(bv: BitVec<C, T>) -> Self {
+		Self { repr: Repr::Heap(bv) }
+	}
+}
+
+impl<C, T> From<SmallBitVec<C, T>> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from(sv: SmallBitVec<C, T>) -> Self {
+		sv.into_bitvec()
+	}
+}
@@ -0,0 +1,298 @@
+/*! Bit regions as values
+
+Code which keeps track of regions of a bit-slice – allocated runs, dirty areas,
+fields in a packet – tends to pass `(start, len)` tuples around, and to rebuild
+`start .. start + len` ranges at every call. This module provides [`BitSpan`], a
+small value type for such regions, with the set arithmetic that region
+bookkeeping needs.
+
+`BitSpan` implements `RangeBounds<usize>`, so it can be passed directly to every
+`bitvec` API that takes a range of bits, and `BitSlice`, `BitBox`, and `BitVec`
+can all be indexed by it.
+
+[`BitSpan`]: struct.BitSpan.html
+!*/
+
+use core::{
+	cmp,
+	ops::{
+		Bound,
+		Range,
+		RangeBounds,
+	},
+};
+
+/** A contiguous region of bit indices.
+
+A span covers the indices `start .. start + len`. Spans are plain values, and
+are not tied to any particular slice; they are checked against a slice only when
+used to index it, at which point an out-of-bounds span panics exactly as the
+equivalent `Range` would.
+
+The span stores both of its ends, rather than its length, so that it can lend
+them by reference through `RangeBounds`. Its fields are private so that the end
+can never fall before the start.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bv = bitvec![0; 24];
+let field = BitSpan::new(4, 8);
+bv[field].set_all(true);
+assert_eq!(bv.count_ones(), 8);
+assert!(bv.all_in_range(field));
+assert!(!bv.any_in_range(BitSpan::new(12, 12)));
+
+let moved = BitSpan::new(16, 8);
+bv.swap_ranges(field, moved);
+assert!(bv.all_in_range(moved));
+assert_eq!(bv.drain(moved).count(), 8);
+assert!(bv.not_any());
+```
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitSpan {
+	/// The index of the first bit in the span.
+	start: usize,
+	/// The index just past the last bit in the span. Never less than `start`.
+	end: usize,
+}
+
+impl BitSpan {
+	/// Describes a region by its first index and its length.
+	///
+	/// # Parameters
+	///
+	/// - `start`: The index of the first bit in the span.
+	/// - `len`: The number of bits in the span.
+	///
+	/// # Returns
+	///
+	/// A span over `start .. start + len`.
+	///
+	/// # Panics
+	///
+	/// This panics if the end of the span overflows `usize`.
+	pub fn new(start: usize, len: usize) -> Self {
+		let end = start.checked_add(len).expect("Span end overflowed");
+		Self { start, end }
+	}
+
+	/// Returns the index of the first bit in the span.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The first index in the span.
+	pub fn start(self) -> usize {
+		self.start
+	}
+
+	/// Returns the number of bits in the span.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The length of the span.
+	pub fn len(self) -> usize {
+		self.end - self.start
+	}
+
+	/// Computes the index just past the end of the span.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// `self.start() + self.len()`.
+	pub fn end(self) -> usize {
+		self.end
+	}
+
+	/// Tests if the span covers no bits.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// Whether `self.len()` is zero.
+	pub fn is_empty(self) -> bool {
+		self.start == self.end
+	}
+
+	/// Tests if an index lies in the span.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `index`: A bit index.
+	///
+	/// # Returns
+	///
+	/// Whether `index` is in `self.start() .. self.end()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::span::BitSpan;
+	///
+	/// let span = BitSpan::new(3, 2);
+	/// assert!(!span.contains(2));
+	/// assert!(span.contains(3));
+	/// assert!(span.contains(4));
+	/// assert!(!span.contains(5));
+	/// ```
+	pub fn contains(self, index: usize) -> bool {
+		self.start <= index && index < self.end
+	}
+
+	/// Tests if another span lies entirely within this one.
+	///
+	/// An empty span is contained by any span which it does not lie outside.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `other`: The span which may lie within `self`.
+	///
+	/// # Returns
+	///
+	/// Whether every index in `other` is also in `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::span::BitSpan;
+	///
+	/// let span = BitSpan::new(8, 8);
+	/// assert!(span.contains_span(BitSpan::new(10, 6)));
+	/// assert!(!span.contains_span(BitSpan::new(10, 7)));
+	/// ```
+	pub fn contains_span(self, other: Self) -> bool {
+		self.start <= other.start && other.end <= self.end
+	}
+
+	/// Finds the region covered by both this span and another.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `other`: Another span.
+	///
+	/// # Returns
+	///
+	/// The span of indices in both `self` and `other`, or `None` if they have
+	/// no index in common.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::span::BitSpan;
+	///
+	/// let a = BitSpan::new(0, 10);
+	/// let b = BitSpan::new(6, 10);
+	/// assert_eq!(a.intersection(b), Some(BitSpan::new(6, 4)));
+	/// assert_eq!(a.intersection(BitSpan::new(10, 2)), None);
+	/// ```
+	pub fn intersection(self, other: Self) -> Option<Self> {
+		let start = cmp::max(self.start, other.start);
+		let end = cmp::min(self.end, other.end);
+		if start < end {
+			Some(Self { start, end })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Joins this span with another into a single span.
+	///
+	/// Spans can only be joined when they overlap or touch, since a span
+	/// cannot describe a region with a gap in it. An empty span joins with any
+	/// span, and does not change it.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `other`: Another span.
+	///
+	/// # Returns
+	///
+	/// The span of indices in either `self` or `other`, or `None` if there are
+	/// indices between them which are in neither.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::span::BitSpan;
+	///
+	/// let a = BitSpan::new(0, 10);
+	/// assert_eq!(a.union(BitSpan::new(10, 2)), Some(BitSpan::new(0, 12)));
+	/// assert_eq!(a.union(BitSpan::new(11, 2)), None);
+	/// ```
+	pub fn union(self, other: Self) -> Option<Self> {
+		if other.is_empty() {
+			return Some(self);
+		}
+		if self.is_empty() {
+			return Some(other);
+		}
+		if cmp::max(self.start, other.start) <= cmp::min(self.end, other.end) {
+			Some(Self {
+				start: cmp::min(self.start, other.start),
+				end: cmp::max(self.end, other.end),
+			})
+		}
+		else {
+			None
+		}
+	}
+
+	/// Converts the span into the equivalent `Range`.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// `self.start() .. self.end()`.
+	pub fn as_range(self) -> Range<usize> {
+		self.start .. self.end
+	}
+}
+
+impl From<Range<usize>> for BitSpan {
+	/// Converts a range into a span. Backwards ranges become empty spans.
+	fn from(Range { start, end }: Range<usize>) -> Self {
+		Self { start, end: cmp::max(start, end) }
+	}
+}
+
+impl From<BitSpan> for Range<usize> {
+	fn from(span: BitSpan) -> Self {
+		span.as_range()
+	}
+}
+
+impl RangeBounds<usize> for BitSpan {
+	fn start_bound(&self) -> Bound<&usize> {
+		Bound::Included(&self.start)
+	}
+
+	fn end_bound(&self) -> Bound<&usize> {
+		Bound::Excluded(&self.end)
+	}
+}
@@ -34,6 +34,7 @@ use core::{
 		BitAnd,
 		BitAndAssign,
 		BitOrAssign,
+		BitXor,
 		Not,
 		Shl,
 		ShlAssign,
@@ -52,21 +53,93 @@ use core::cell::Cell;
 
 /** Generalizes over the fundamental types for use in `bitvec` data structures.
 
-This trait must only be implemented on unsigned integer primitives with full
-alignment. It cannot be implemented on `u128` on any architecture, or on `u64`
-on 32-bit systems.
-
-The `Sealed` supertrait ensures that this can only be implemented locally, and
-will never be implemented by downstream crates on new types.
+This trait is implemented for `u8`, `u16`, and `u32` on every target, and for
+`u64` on 64-bit targets. It is sealed: its supertrait lives in a private module,
+so no other crate can implement it, and no other type will ever satisfy it.
+
+# Guarantees
+
+The data structures of the crate rely on every implementor being a fundamental
+type with these properties:
+
+- It is an unsigned integer, so that shifts move zeros in from either end and
+  never extend a sign bit.
+- Every bit pattern is a valid value, including zero, so that any bit may be
+  written at any time, and fresh storage may be zeroed.
+- Its width is a power of two, from 8 to 64 bits, so that bit indices split into
+  an element index and a `BITS`-wide bit index with a shift and a mask.
+- Its size equals its alignment, so that an element-aligned address has room in
+  its low bits for the head index that `BitPtr` encodes there.
+- It has an atomic counterpart of the same width, which serves as its `Access`
+  type when the `atomic` feature is enabled.
+
+# Unsupported Types
+
+Each of these fails at least one guarantee, and is rejected at compile time:
+
+- `bool` and `char` are not integers, and have bit patterns that are undefined
+  behavior to produce.
+- The `NonZero` integers forbid the all-zero pattern, which every cleared
+  element holds.
+- The signed integers extend their sign bit when shifted down.
+- `u128` has no atomic counterpart, and `u64` has none on 32-bit targets.
+- `usize` has a width that depends on the target, so the memory layout of a
+  collection over it would not be portable.
+
+The compiler reports an unsatisfied `BitStore` bound, without a reason, when a
+collection is named over one of these types; this list is the reason.
+
+```rust,compile_fail
+use bitvec::prelude::*;
+let bv: BitVec<BigEndian, bool> = BitVec::new();
+```
+
+```rust,compile_fail
+use bitvec::prelude::*;
+let bv: BitVec<BigEndian, char> = BitVec::new();
+```
+
+```rust,compile_fail
+use bitvec::prelude::*;
+use core::num::NonZeroU8;
+let bv: BitVec<BigEndian, NonZeroU8> = BitVec::new();
+```
+
+```rust,compile_fail
+use bitvec::prelude::*;
+let bv: BitVec<BigEndian, i32> = BitVec::new();
+```
+
+```rust,compile_fail
+use bitvec::prelude::*;
+let bv: BitVec<BigEndian, u128> = BitVec::new();
+```
+
+```rust,compile_fail
+use bitvec::prelude::*;
+let bv: BitVec<BigEndian, usize> = BitVec::new();
+```
+
+The supported set is exactly the unsigned integers named above:
+
+```rust
+use bitvec::prelude::*;
+let a: BitVec<BigEndian, u8> = BitVec::new();
+let b: BitVec<BigEndian, u16> = BitVec::new();
+let c: BitVec<BigEndian, u32> = BitVec::new();
+# #[cfg(target_pointer_width = "64")]
+let d: BitVec<BigEndian, u64> = BitVec::new();
+```
 **/
 pub trait BitStore:
 	//  Forbid external implementation
-	Sealed
+	seal::Sealed
 	+ Binary
 	//  Element-wise binary manipulation
 	+ BitAnd<Self, Output=Self>
 	+ BitAndAssign<Self>
 	+ BitOrAssign<Self>
+	+ BitXor<Self, Output=Self>
 	//  Permit indexing into a generic array
 	+ Copy
 	+ Debug
@@ -303,22 +376,24 @@ impl BitStore for u64 {
 	type Access = Cell<Self>;
 }
 
-/** Marker trait to seal `BitStore` against downstream implementation.
+/// Holds the supertrait that seals `BitStore`.
+mod seal {
+	/** Marker trait to seal `BitStore` against downstream implementation.
 
-This trait is public in the module, so that other modules in the crate can use
-it, but so long as it is not exported by the crate root and this module is
-private, this trait effectively forbids downstream implementation of the
-`BitStore` trait.
-**/
-#[doc(hidden)]
-pub trait Sealed {}
+	The trait is public, so that it may appear in the public bound of
+	`BitStore`, but it is declared in a private module and never re-exported.
+	Downstream crates cannot name it, and so cannot implement it for any type,
+	and so cannot implement `BitStore` either.
+	**/
+	pub trait Sealed {}
 
-impl Sealed for u8 {}
-impl Sealed for u16 {}
-impl Sealed for u32 {}
+	impl Sealed for u8 {}
+	impl Sealed for u16 {}
+	impl Sealed for u32 {}
 
-#[cfg(target_pointer_width = "64")]
-impl Sealed for u64 {}
+	#[cfg(target_pointer_width = "64")]
+	impl Sealed for u64 {}
+}
 
 #[cfg(test)]
 mod tests {
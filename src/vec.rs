@@ -14,15 +14,24 @@ use crate::{
 	cursor::{
 		BigEndian,
 		Cursor,
+		Reorder,
 	},
 	indices::Indexable,
 	pointer::BitPtr,
-	slice::BitSlice,
+	slice::{
+		BitMut,
+		BitSlice,
+	},
+	span::BitSpan,
 	store::BitStore,
 };
 
 #[cfg(feature = "alloc")]
 use alloc::{
+	alloc::{
+		self as heap,
+		Layout,
+	},
 	borrow::{
 		Borrow,
 		BorrowMut,
@@ -35,6 +44,7 @@ use alloc::{
 use core::{
 	clone::Clone,
 	cmp::{
+		self,
 		Eq,
 		Ord,
 		Ordering,
@@ -342,6 +352,95 @@ where C: Cursor, T: BitStore {
 	capacity: usize,
 }
 
+/** Policy for initializing the bits created when a `BitVec` grows.
+
+The safe default is `Zero`. Growth that skips initialization entirely is not a
+policy here: it is only available through the `unsafe`
+[`BitVec::resize_uninit`] method, as it exposes whatever the allocator hands
+back.
+
+[`BitVec::resize_uninit`]: struct.BitVec.html#method.resize_uninit
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Fill {
+	/// New bits are cleared to `0`.
+	Zero,
+	/// New bits are set to `1`.
+	One,
+}
+
+impl Default for Fill {
+	fn default() -> Self {
+		Fill::Zero
+	}
+}
+
+impl From<bool> for Fill {
+	fn from(bit: bool) -> Self {
+		if bit { Fill::One } else { Fill::Zero }
+	}
+}
+
+/** The ways in which a fallible reservation on a `BitVec` can fail.
+
+This is returned by [`BitVec::try_reserve`] and [`BitVec::try_reserve_exact`],
+which leave the vector unchanged when they fail.
+
+[`BitVec::try_reserve`]: struct.BitVec.html#method.try_reserve
+[`BitVec::try_reserve_exact`]: struct.BitVec.html#method.try_reserve_exact
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TryReserveError {
+	/// The requested length exceeds the maximum length of a `BitSlice`, or the
+	/// requested buffer exceeds the maximum size of an allocation.
+	CapacityOverflow,
+	/// The allocator could not provide the requested buffer.
+	AllocError {
+		/// The capacity, in bits, of the buffer that could not be allocated.
+		bits: usize,
+	},
+}
+
+impl TryReserveError {
+	/// Reports the error the way an infallible collection method does.
+	///
+	/// Overflow panics, and allocation failure goes to the global allocation
+	/// error handler.
+	fn raise<T>(self) -> !
+	where T: BitStore {
+		match self {
+			TryReserveError::CapacityOverflow => panic!("Capacity overflow"),
+			TryReserveError::AllocError { bits } => {
+				let elts = bits / T::BITS as usize;
+				heap::handle_alloc_error(unsafe {
+					Layout::from_size_align_unchecked(
+						elts * mem::size_of::<T>(),
+						mem::align_of::<T>(),
+					)
+				})
+			},
+		}
+	}
+}
+
+impl Display for TryReserveError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			TryReserveError::CapacityOverflow => f.write_str(
+				"Requested capacity exceeds the maximum length of a BitVec",
+			),
+			TryReserveError::AllocError { bits } => write!(
+				f,
+				"Memory allocation failed for a capacity of {} bits",
+				bits,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {}
+
 impl<C, T> BitVec<C, T>
 where C: Cursor, T: BitStore {
 	/// Constructs a new, empty, `BitVec<C, T>`.
@@ -411,9 +510,55 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Constructs a `BitVec` of `len` bits, all set to `bit`.
+	///
+	/// This is the function form of `bitvec![bit; len]`, for use where the
+	/// cursor, storage type, or length are only known to generic or runtime
+	/// code. The buffer is filled a whole element at a time, and the dead bits
+	/// after the end of the vector in its last element are cleared.
+	///
+	/// # Parameters
+	///
+	/// - `bit`: The value of every bit in the vector.
+	/// - `len`: The number of bits in the vector.
+	///
+	/// # Returns
+	///
+	/// A vector of `len` bits, each equal to `bit`.
+	///
+	/// # Panics
+	///
+	/// Panics if `len` is too large for a `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<LittleEndian, u16>::repeat(true, 20);
+	/// assert_eq!(bv.len(), 20);
+	/// assert!(bv.all());
+	/// assert_eq!(bv.as_slice(), &[!0, 0x000F]);
+	/// assert_eq!(BitVec::<BigEndian, u8>::repeat(false, 3), bitvec![0; 3]);
+	/// ```
+	pub fn repeat(bit: bool, len: usize) -> Self {
+		let full = len >> T::INDX;
+		let mut bv = Self::with_capacity(len);
+		bv.do_unto_vec(|v| {
+			v.resize(full, T::bits(bit));
+			if len & T::MASK as usize != 0 {
+				v.push(T::bits(false));
+			}
+		});
+		unsafe { bv.set_len(len); }
+		bv[full << T::INDX ..].set_all(bit);
+		bv
+	}
+
 	/// Constructs a `BitVec` from a single element.
 	///
 	/// The produced `BitVec` will span the element, and include all bits in it.
+	/// Use [`repeat`] to fill a vector with one bit value instead.
 	///
 	/// # Parameters
 	///
@@ -431,6 +576,8 @@ where C: Cursor, T: BitStore {
 	/// let bv = BitVec::<BigEndian, u8>::from_element(5);
 	/// assert_eq!(bv.count_ones(), 2);
 	/// ```
+	///
+	/// [`repeat`]: #method.repeat
 	pub fn from_element(elt: T) -> Self {
 		Self::from_vec({
 			let mut v = Vec::with_capacity(1);
@@ -469,6 +616,10 @@ where C: Cursor, T: BitStore {
 
 	/// Consumes a `Vec<T>` and creates a `BitVec<C, T>` from it.
 	///
+	/// This does not copy or reallocate. The `BitVec` takes ownership of the
+	/// buffer and capacity of `vec` as they are, and every bit of every element
+	/// in `vec` becomes live.
+	///
 	/// # Parameters
 	///
 	/// - `vec`: The source vector whose memory will be used.
@@ -492,6 +643,12 @@ where C: Cursor, T: BitStore {
 	///   "[00000001, 00000010, 00000100, 00001000]",
 	///   &format!("{}", bv),
 	/// );
+	///
+	/// let buf = vec![0u8; 1 << 20];
+	/// let addr = buf.as_ptr();
+	/// let bv = BitVec::<BigEndian, u8>::from_vec(buf);
+	/// assert_eq!(bv.as_slice().as_ptr(), addr);
+	/// assert_eq!(bv.into_vec().as_ptr(), addr);
 	/// ```
 	pub fn from_vec(vec: Vec<T>) -> Self {
 		let len = vec.len();
@@ -536,6 +693,103 @@ where C: Cursor, T: BitStore {
 		Self::from_iter(slice.iter())
 	}
 
+	/// Unpacks a vector from `u32` words in the layout that compute shaders
+	/// write bit arrays in.
+	///
+	/// Bit `n` of the vector is read from word `n / 32`, at bit `n % 32` of
+	/// that word, counting from the least significant bit. This is the layout
+	/// that [`BitSlice::to_gpu_words`] produces, and does not depend on `C` or
+	/// `T`. Bits of `words` at or past `len` are ignored, and may hold
+	/// anything.
+	///
+	/// # Parameters
+	///
+	/// - `words`: The packed bits, as read back from a GPU buffer.
+	/// - `len`: The number of bits to unpack.
+	///
+	/// # Returns
+	///
+	/// A vector of `len` bits, holding the first `len` bits of `words`.
+	///
+	/// # Panics
+	///
+	/// This panics if `words` holds fewer than `len` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<BigEndian, u8>::from_gpu_words(&[0xFFFF_FC05], 10);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 0, 0, 0, 0, 0, 0, 0]);
+	/// assert_eq!(bv.to_gpu_words(), [0x0000_0005]);
+	/// ```
+	///
+	/// [`BitSlice::to_gpu_words`]: ../slice/struct.BitSlice.html#method.to_gpu_words
+	pub fn from_gpu_words(words: &[u32], len: usize) -> Self {
+		assert!(
+			len <= words.len().saturating_mul(32),
+			"Bit length {} exceeds the {} bits in {} words",
+			len,
+			words.len().saturating_mul(32),
+			words.len(),
+		);
+		let mut out = Self::repeat(false, len);
+		for (n, &word) in words.iter().enumerate().take((len + 31) / 32) {
+			let base = n * 32;
+			let mut word = word;
+			//  Clear the bits past the end, then visit each remaining set bit.
+			if len - base < 32 {
+				word &= (1 << (len - base)) - 1;
+			}
+			while word != 0 {
+				let idx = word.trailing_zeros() as usize;
+				unsafe { out.set_unchecked(base + idx, true); }
+				word &= word - 1;
+			}
+		}
+		out
+	}
+
+	/// Packs a byte mask, such as a thresholded image, into a bit vector.
+	///
+	/// Bit `n` of the vector is set when `bytes[n]` is at least `cutoff`. The
+	/// bits are gathered a storage element at a time, so each element of the
+	/// vector is written once.
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: One byte for each bit, such as the pixels of a grayscale
+	///   image in row-major order.
+	/// - `cutoff`: The smallest byte value that produces a set bit.
+	///
+	/// # Returns
+	///
+	/// A vector with one bit for each byte of `bytes`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let pixels = [0u8, 12, 200, 127, 128, 255, 3, 90, 140];
+	/// let mask = BitVec::<BigEndian, u8>::from_bytes_threshold(&pixels, 128);
+	/// assert_eq!(mask, bitvec![0, 0, 1, 0, 1, 1, 0, 0, 1]);
+	/// ```
+	pub fn from_bytes_threshold(bytes: &[u8], cutoff: u8) -> Self {
+		let mut out = Self::with_capacity(bytes.len());
+		let mut chunks = bytes.chunks_exact(T::BITS as usize);
+		for chunk in &mut chunks {
+			let mut elt = T::bits(false);
+			for (idx, &byte) in chunk.iter().enumerate() {
+				elt.set::<C>((idx as u8).idx(), byte >= cutoff);
+			}
+			out.push_element(elt);
+		}
+		out.extend(chunks.remainder().iter().map(|&byte| byte >= cutoff));
+		out
+	}
+
 	/// Converts a frozen `BitBox` allocation into a growable `BitVec`.
 	///
 	/// This does not copy or reallocate.
@@ -608,6 +862,13 @@ where C: Cursor, T: BitStore {
 
 	/// Returns the number of bits the vector can hold without reallocating.
 	///
+	/// The vector allocates whole `T` elements, so its capacity is always a
+	/// multiple of `T::BITS`. Every capacity request, whether to grow or to
+	/// shrink, is rounded up to the next whole element. The live bits need not
+	/// begin at the front edge of the first element (see [`force_align`]), and
+	/// any dead bits before the head count against the capacity, so a vector
+	/// with a capacity of `n` bits can hold up to `n - head` bits in total.
+	///
 	/// # Parameters
 	///
 	/// - `&self`
@@ -624,7 +885,10 @@ where C: Cursor, T: BitStore {
 	/// let bv: BitVec = BitVec::with_capacity(10);
 	/// assert!(bv.is_empty());
 	/// assert!(bv.capacity() >= 10);
+	/// assert_eq!(bv.capacity() % 8, 0);
 	/// ```
+	///
+	/// [`force_align`]: #method.force_align
 	pub fn capacity(&self) -> usize {
 		self.capacity
 			.checked_mul(T::BITS as usize)
@@ -677,6 +941,10 @@ where C: Cursor, T: BitStore {
 	/// equal to `self.len() + additional`. Does nothing if the capacity is
 	/// already sufficient.
 	///
+	/// The request is rounded up to the next whole element after the current
+	/// tail, so the capacity may exceed `self.len() + additional` by up to
+	/// `T::BITS - 1` bits even when the allocator is exact.
+	///
 	/// Note that the allocator may give the collection more space than it
 	/// requests. Therefore, the capacity cannot be relied upon to be precisely
 	/// minimal. Prefer `reserve` if future insertions are expected.
@@ -714,6 +982,96 @@ where C: Cursor, T: BitStore {
 		self.do_unto_vec(|v| v.reserve_exact(e));
 	}
 
+	/// Tries to reserve capacity for at least `additional` more bits.
+	///
+	/// This behaves as [`reserve`], and may reserve more space to avoid
+	/// frequent reallocations, but reports failure to the caller instead of
+	/// panicking or aborting the process. Does nothing if the capacity is
+	/// already sufficient.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of extra bits to be granted space.
+	///
+	/// # Returns
+	///
+	/// `Ok` if the capacity is now at least `self.len() + additional`. If the
+	/// new capacity would overflow the vector’s limits, or the allocator
+	/// cannot provide it, the error says which, and the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::TryReserveError;
+	///
+	/// let mut bv = bitvec![1; 5];
+	/// assert!(bv.try_reserve(10).is_ok());
+	/// assert!(bv.capacity() >= 15);
+	///
+	/// assert_eq!(
+	///   bv.try_reserve(!0),
+	///   Err(TryReserveError::CapacityOverflow),
+	/// );
+	/// assert_eq!(bv, bitvec![1; 5]);
+	/// ```
+	///
+	/// [`reserve`]: #method.reserve
+	pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		let needed = self.elements_for(additional)?;
+		if needed <= self.capacity {
+			return Ok(());
+		}
+		//  Grow geometrically, as `Vec` does, unless that would overflow.
+		let doubled = self.capacity.saturating_mul(2);
+		let layout_ok = doubled.checked_mul(mem::size_of::<T>())
+			.map(|size| size <= isize::max_value() as usize)
+			.unwrap_or(false);
+		let target = if layout_ok { cmp::max(needed, doubled) } else { needed };
+		self.try_realloc(target)
+	}
+
+	/// Tries to reserve the minimum capacity for at least `additional` more
+	/// bits.
+	///
+	/// This behaves as [`reserve_exact`], but reports failure to the caller
+	/// instead of panicking or aborting the process. Does nothing if the
+	/// capacity is already sufficient.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of extra bits to be granted space.
+	///
+	/// # Returns
+	///
+	/// `Ok` if the capacity is now at least `self.len() + additional`. If the
+	/// new capacity would overflow the vector’s limits, or the allocator
+	/// cannot provide it, the error says which, and the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv: BitVec<BigEndian, u32> = BitVec::new();
+	/// bv.try_reserve_exact(40).unwrap();
+	/// assert_eq!(bv.capacity(), 64);
+	/// ```
+	///
+	/// [`reserve_exact`]: #method.reserve_exact
+	pub fn try_reserve_exact(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		let needed = self.elements_for(additional)?;
+		if needed <= self.capacity {
+			return Ok(());
+		}
+		self.try_realloc(needed)
+	}
+
 	/// Shrinks the capacity of the vector as much as possible.
 	///
 	/// It will drop down as close as possible to the length, but the allocator
@@ -742,6 +1100,50 @@ where C: Cursor, T: BitStore {
 		self.do_unto_vec(Vec::shrink_to_fit);
 	}
 
+	/// Shrinks the capacity of the vector with a lower bound.
+	///
+	/// The capacity will remain at least as large as both the length and the
+	/// supplied value, rounded up to whole elements from the head of the
+	/// vector. Does nothing if the capacity is already at or below the bound.
+	///
+	/// Like [`shrink_to_fit`], this does not modify the contents of the memory
+	/// store.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `min_capacity`: The number of bits, counted from the head of the
+	///   vector, that must still fit without reallocating.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// use std::iter;
+	///
+	/// let mut bv: BitVec<BigEndian, u8> = BitVec::with_capacity(100);
+	/// bv.extend(iter::repeat(true).take(10));
+	/// bv.shrink_to(20);
+	/// assert_eq!(bv.capacity(), 24);
+	/// bv.shrink_to(0);
+	/// assert_eq!(bv.capacity(), 16);
+	/// ```
+	///
+	/// [`shrink_to_fit`]: #method.shrink_to_fit
+	pub fn shrink_to(&mut self, min_capacity: usize) {
+		let elts = self.pointer.elements();
+		let (floor, _) = self.pointer.head().span(min_capacity);
+		if floor <= elts {
+			self.shrink_to_fit();
+		}
+		else if floor < self.capacity {
+			if let Err(err) = self.try_realloc(floor) {
+				err.raise::<T>();
+			}
+		}
+	}
+
 	/// Shortens the vector, keeping the first `len` bits and dropping the rest.
 	///
 	/// If `len` is greater than the vector’s current length, this has no
@@ -905,7 +1307,9 @@ where C: Cursor, T: BitStore {
 
 	/// Inserts a bit at a position, shifting all bits after it to the right.
 	///
-	/// Note that this is `O(n)` runtime.
+	/// Note that this is `O(n)` runtime. The bits after `index` are moved a
+	/// whole element at a time, so the cost is proportional to the number of
+	/// elements after `index`, not the number of bits.
 	///
 	/// # Parameters
 	///
@@ -934,12 +1338,16 @@ where C: Cursor, T: BitStore {
 		let len = self.len();
 		assert!(index <= len, "Index {} is out of bounds: {}", index, len);
 		self.push(value);
-		self[index ..].rotate_right(1);
+		self[index ..] >>= 1;
+		self.set(index, value);
 	}
 
 	/// Removes and returns the bit at position `index`, shifting all bits after
 	/// it to the left.
 	///
+	/// Like [`insert`], this moves the bits after `index` a whole element at a
+	/// time.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
@@ -963,19 +1371,60 @@ where C: Cursor, T: BitStore {
 	/// assert!(bv.remove(2));
 	/// assert_eq!(bv, bitvec![0, 0, 0, 0]);
 	/// ```
+	///
+	/// [`insert`]: #method.insert
 	pub fn remove(&mut self, index: usize) -> bool {
 		let len = self.len();
 		assert!(index < len, "Index {} is out of bounds: {}", index, len);
-		self[index ..].rotate_left(1);
-		self.pop()
-			.expect("BitVec::remove cannot fail after index validation")
+		let out = self[index];
+		self[index ..] <<= 1;
+		self.pop();
+		out
+	}
+
+	/// Removes and returns the bit at position `index`, returning `None` rather
+	/// than panicking if the index is out of bounds.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The position whose bit is to be removed.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, if `index` is less than `self.len()`. The vector is
+	/// unchanged if it is not.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0];
+	/// assert_eq!(bv.checked_remove(3), None);
+	/// assert_eq!(bv.checked_remove(1), Some(true));
+	/// assert_eq!(bv, bitvec![0, 0]);
+	/// ```
+	pub fn checked_remove(&mut self, index: usize) -> Option<bool> {
+		if index < self.len() {
+			Some(self.remove(index))
+		}
+		else {
+			None
+		}
 	}
 
 	/// Retains only the bits that pass the predicate.
 	///
-	/// This removes all bits `b` where `f(e)` returns `false`. This method
-	/// operates in place and preserves the order of the retained bits. Because
-	/// it is in-place, it operates in `O(n²)` time.
+	/// This removes all bits `b` at indices `n` where `pred(n, b)` returns
+	/// `false`. This method operates in place and preserves the order of the
+	/// retained bits.
+	///
+	/// The predicate is called exactly once for each bit, in order from the
+	/// front of the vector, and always receives the bit's index in the vector
+	/// as it was before the call. The vector is compacted in a single pass:
+	/// each retained bit is written at most once, and bits in front of the
+	/// first rejected bit are not written at all.
 	///
 	/// # Parameters
 	///
@@ -999,14 +1448,32 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(bv, bitvec![1, 1, 1]);
 	/// ```
 	///
+	/// This filters a selection against a deletion mask of the same length:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut selection = bitvec![1, 1, 0, 1, 0, 0, 1, 1];
+	/// let deleted = bitvec![0, 1, 1, 0, 0, 1, 0, 1];
+	/// selection.retain(|n, _| !deleted[n]);
+	/// assert_eq!(selection, bitvec![1, 1, 0, 1]);
+	/// ```
+	///
 	/// [`BitSlice::for_each`]: ../slice/struct.BitSlice.html#method.for_each
 	pub fn retain<F>(&mut self, mut pred: F)
 	where F: FnMut(usize, bool) -> bool {
-		for n in (0 .. self.len()).rev() {
-			if !pred(n, self[n]) {
-				self.remove(n);
+		let len = self.len();
+		let mut kept = 0;
+		for n in 0 .. len {
+			let bit = unsafe { self.get_unchecked(n) };
+			if pred(n, bit) {
+				if kept != n {
+					unsafe { self.set_unchecked(kept, bit); }
+				}
+				kept += 1;
 			}
 		}
+		self.truncate(kept);
 	}
 
 	/// Appends a bit to the back of the vector.
@@ -1090,6 +1557,12 @@ where C: Cursor, T: BitStore {
 
 	/// Moves all the elements of `other` into `self`, leaving `other` empty.
 	///
+	/// When `other` has the same storage type as `self`, and a cursor that
+	/// orders each element the same way, its bits are copied in bulk by
+	/// [`extend_from_bitslice`]; and if `self` is empty, `self` takes the
+	/// buffer of `other` without copying at all. Otherwise, the bits are
+	/// copied one at a time.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
@@ -1112,50 +1585,290 @@ where C: Cursor, T: BitStore {
 	/// assert!(bv1[10]);
 	/// assert!(bv2.is_empty());
 	/// ```
+	///
+	/// [`extend_from_bitslice`]: #method.extend_from_bitslice
 	pub fn append<D, U>(&mut self, other: &mut BitVec<D, U>)
 	where D: Cursor, U: BitStore {
-		self.extend(other.iter());
-		other.clear();
+		if other.is_empty() {
+			return;
+		}
+		if !Self::same_layout::<D, U>() {
+			self.extend(other.iter());
+			other.clear();
+			return;
+		}
+		let bitptr = other.bitptr();
+		let theirs = BitPtr::<T>::new(
+			bitptr.pointer().r() as *const T,
+			(*bitptr.head()).idx(),
+			bitptr.len(),
+		);
+		if self.is_empty() {
+			//  Take the buffer of `other` outright, and release our own.
+			let capacity = other.capacity;
+			mem::forget(mem::replace(other, BitVec::new()));
+			*self = unsafe { Self::from_raw_parts(theirs, capacity) };
+		}
+		else {
+			self.extend_from_bitslice(theirs.into_bitslice());
+			other.clear();
+		}
 	}
 
-	/// Creates a draining iterator that removes the specified range from the
-	/// vector and yields the removed bits.
+	/// Appends a copy of a `BitSlice` to the end of the vector.
 	///
-	/// # Notes
-	///
-	/// 1. The element range is removed, regardless of whether the iterator is
-	///    consumed.
-	/// 2. The amount of items removed from the vector if the draining iterator
-	///    is leaked, is left unspecified.
+	/// The vector grows once, by the length of `other`, and the bits are then
+	/// copied in bulk. When `other` begins at the same position in its first
+	/// element as the end of `self` does, the whole elements in its interior
+	/// are copied directly from memory; otherwise, each new element of `self`
+	/// is assembled from `other` and written once. This is much faster than
+	/// `extend`, which pushes one bit at a time.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `range`: any range literal, which is used to define the range of the
-	///   vector that is drained.
+	/// - `other`: The bits to append to `self`. They must have the same cursor
+	///   and storage types as `self`.
 	///
-	/// # Returns
+	/// # Panics
 	///
-	/// An iterator over the specified range.
+	/// Panics if the joined vector is too large.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let record = 0xA5C3u16;
+	/// let record = record.as_bitslice::<BigEndian>();
+	/// let mut log: BitVec<BigEndian, u16> = BitVec::new();
+	/// log.extend_from_bitslice(&record[3 ..]);
+	/// log.extend_from_bitslice(record);
+	/// assert_eq!(log.len(), 29);
+	/// assert_eq!(&log[13 ..], record);
+	/// assert_eq!(log[.. 13], record[3 ..]);
+	/// ```
+	pub fn extend_from_bitslice(&mut self, other: &BitSlice<C, T>) {
+		let len = self.len();
+		self.resize(len + other.len(), false);
+		self[len ..].copy_bits_from(other);
+	}
+
+	/// Appends every bit of a storage element to the end of the vector.
+	///
+	/// The bits are appended in the order that the cursor `C` reads them, so
+	/// the new tail of the vector is `value.as_bitslice::<C>()`. This is the
+	/// single-element form of [`extend_from_raw_slice`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: An element whose `T::BITS` bits are appended to `self`.
 	///
 	/// # Panics
 	///
-	/// Panics if the range is ill-formed, or if it is beyond the vector bounds.
+	/// Panics if the vector would become too large.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut bv = bitvec![0, 0, 1, 1, 1, 0, 0];
-	/// assert_eq!(bv.len(), 7);
-	/// for bit in bv.drain(2 .. 5) {
-	///   assert!(bit);
-	/// }
-	/// assert!(bv.not_any());
-	/// assert_eq!(bv.len(), 4);
+	/// let mut bv: BitVec<BigEndian, u16> = bitvec![BigEndian, u16; 1; 3];
+	/// bv.push_element(0x8001);
+	/// assert_eq!(bv.len(), 19);
+	/// assert_eq!(&bv[3 ..], 0x8001u16.as_bitslice::<BigEndian>());
 	/// ```
-	pub fn drain<R>(&mut self, range: R) -> Drain<C, T>
+	///
+	/// [`extend_from_raw_slice`]: #method.extend_from_raw_slice
+	pub fn push_element(&mut self, value: T) {
+		self.extend_from_raw_slice(slice::from_ref(&value));
+	}
+
+	/// Appends every bit of a slice of storage elements to the end of the
+	/// vector.
+	///
+	/// The bits are appended in the order that the cursor `C` reads them, so
+	/// the new tail of the vector is `elts.as_bitslice::<C>()`. When the vector
+	/// ends at the back edge of an element, the elements are copied directly
+	/// into its buffer; otherwise, this behaves as [`extend_from_bitslice`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `elts`: Elements whose bits are all appended to `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the vector would become too large.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let records = [0x0123_4567u32, 0x89AB_CDEF];
+	/// let mut bv: BitVec<LittleEndian, u32> = BitVec::new();
+	/// bv.extend_from_raw_slice(&records);
+	/// assert_eq!(bv.as_slice(), &records);
+	///
+	/// bv.push(true);
+	/// bv.extend_from_raw_slice(&records);
+	/// assert_eq!(bv.len(), 129);
+	/// assert_eq!(&bv[65 ..], records.as_bitslice::<LittleEndian>());
+	/// ```
+	///
+	/// [`extend_from_bitslice`]: #method.extend_from_bitslice
+	pub fn extend_from_raw_slice(&mut self, elts: &[T]) {
+		let len = self.len();
+		let newlen = elts.len()
+			.checked_mul(T::BITS as usize)
+			.and_then(|bits| bits.checked_add(len))
+			.filter(|&n| n <= BitPtr::<T>::MAX_BITS)
+			.expect("Vector length overflow");
+		//  If the vector ends at an element edge, the new elements are exactly
+		//  the new bits.
+		let aligned = if self.is_empty() {
+			*self.pointer.head() == 0
+		}
+		else {
+			*self.pointer.tail() == T::BITS
+		};
+		if aligned {
+			self.do_unto_vec(|v| v.extend_from_slice(elts));
+			unsafe { self.set_len(newlen); }
+		}
+		else {
+			self.extend_from_bitslice(BitSlice::from_slice(elts));
+		}
+	}
+
+	/// Appends every bit of an owned vector of storage elements to the end of
+	/// the vector, reusing its allocation when possible.
+	///
+	/// If `self` is empty, and `vec` has at least as much capacity as `self`,
+	/// then `self` takes the buffer of `vec` without copying any elements, and
+	/// releases its own. Otherwise, the elements of `vec` are copied as in
+	/// [`extend_from_raw_slice`], and `vec` is dropped.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `vec`: Elements whose bits are all appended to `self`. The vector is
+	///   consumed.
+	///
+	/// # Panics
+	///
+	/// Panics if the vector would become too large.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let buf = vec![0x0Fu8; 1 << 10];
+	/// let addr = buf.as_ptr();
+	/// let mut bv: BitVec = BitVec::new();
+	/// bv.append_vec(buf);
+	/// assert_eq!(bv.as_slice().as_ptr(), addr);
+	///
+	/// bv.append_vec(vec![0xF0]);
+	/// assert_eq!(bv.len(), (1 << 13) + 8);
+	/// assert_eq!(bv.as_slice()[1 << 10], 0xF0);
+	/// ```
+	///
+	/// [`extend_from_raw_slice`]: #method.extend_from_raw_slice
+	pub fn append_vec(&mut self, vec: Vec<T>) {
+		if self.is_empty() && vec.capacity() >= self.capacity {
+			*self = Self::from_vec(vec);
+		}
+		else {
+			self.extend_from_raw_slice(&vec);
+		}
+	}
+
+	/// Appends the bits of an owned `BitVec`, reusing its allocation when
+	/// possible.
+	///
+	/// This follows the same rule as [`append_vec`]: an empty `self` adopts
+	/// the buffer of `other` when that buffer is at least as large as its own,
+	/// and otherwise the bits of `other` are copied.
+	///
+	/// [`append_vec`]: #method.append_vec
+	fn append_owned(&mut self, other: Self) {
+		if self.is_empty() && other.capacity >= self.capacity {
+			*self = other;
+		}
+		else {
+			self.extend_from_bitslice(&other);
+		}
+	}
+
+	/// Appends a sequence of owned `BitVec`s, in order.
+	///
+	/// The pieces are gathered first so that the vector grows at most once.
+	/// Only the first piece can donate its buffer, since it is the only one
+	/// whose bits are already at the front of their allocation; every later
+	/// piece is copied.
+	fn append_pieces<I>(&mut self, pieces: I)
+	where I: IntoIterator<Item=Self> {
+		let pieces = pieces.into_iter().collect::<Vec<Self>>();
+		let mut pieces = pieces.into_iter();
+		if self.is_empty() {
+			if let Some(first) = pieces.next() {
+				self.append_owned(first);
+			}
+		}
+		let rest = pieces.as_slice()
+			.iter()
+			.try_fold(0usize, |sum, piece| sum.checked_add(piece.len()))
+			.expect("Vector length overflow");
+		self.reserve(rest);
+		for piece in pieces {
+			self.extend_from_bitslice(&piece);
+		}
+	}
+
+	/// Creates a draining iterator that removes the specified range from the
+	/// vector and yields the removed bits.
+	///
+	/// # Notes
+	///
+	/// 1. The element range is removed, regardless of whether the iterator is
+	///    consumed.
+	/// 2. The amount of items removed from the vector if the draining iterator
+	///    is leaked, is left unspecified.
+	/// 3. When the iterator is dropped, the bits after the range move down to
+	///    close the gap a whole element at a time, as with `<<=`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: any range literal, which is used to define the range of the
+	///   vector that is drained.
+	///
+	/// # Returns
+	///
+	/// An iterator over the specified range.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is ill-formed, or if it is beyond the vector bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0, 1, 1, 1, 0, 0];
+	/// assert_eq!(bv.len(), 7);
+	/// for bit in bv.drain(2 .. 5) {
+	///   assert!(bit);
+	/// }
+	/// assert!(bv.not_any());
+	/// assert_eq!(bv.len(), 4);
+	/// ```
+	pub fn drain<R>(&mut self, range: R) -> Drain<C, T>
 	where R: RangeBounds<usize> {
 		use core::ops::Bound::*;
 		let len = self.len();
@@ -1229,6 +1942,10 @@ where C: Cursor, T: BitStore {
 	///
 	/// Note that the capacity of `self` does not change.
 	///
+	/// The returned vector is a copy of the storage elements under its bits,
+	/// and begins at the same position in its first element as bit `at` did in
+	/// `self`, so it is produced by a single `memcpy`.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
@@ -1265,9 +1982,20 @@ where C: Cursor, T: BitStore {
 			},
 			n if n == len => Self::new(),
 			_ => {
-				let out = self.as_bitslice().iter().skip(at).collect();
+				//  Copy the elements under the split-off bits as they stand,
+				//  and start the new vector at the same place in its first
+				//  element, so that no bit moves.
+				let rest = &self[at ..];
+				let mut data = rest.as_slice().to_vec();
+				let bitptr = BitPtr::new(
+					data.as_mut_ptr() as *const T,
+					rest.bitptr().head(),
+					rest.len(),
+				);
+				let capacity = data.capacity();
+				mem::forget(data);
 				self.truncate(at);
-				out
+				unsafe { Self::from_raw_parts(bitptr, capacity) }
 			},
 		}
 	}
@@ -1296,12 +2024,256 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(bv, bitvec![0, 0, 0, 0, 1]);
 	/// ```
 	pub fn resize(&mut self, new_len: usize, value: bool) {
+		self.resize_fill(new_len, value.into());
+	}
+
+	/// Resizes the `BitVec` in place so that `len` is equal to `new_len`,
+	/// filling any new bits with the outputs of a generator.
+	///
+	/// If `new_len` is greater than `len`, then the vector is extended by the
+	/// difference, and `func` is called once for each new bit, in order. If
+	/// `new_len` is less than `len`, then the vector is just truncated, and
+	/// `func` is not called.
+	///
+	/// The new bits are gathered into their storage elements and written an
+	/// element at a time, rather than pushed individually.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `new_len`: The new length of the vector.
+	/// - `func`: A generator for the value of each new bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1; 2];
+	/// let mut toggle = false;
+	/// bv.resize_with(7, || {
+	///   toggle = !toggle;
+	///   toggle
+	/// });
+	/// assert_eq!(bv, bitvec![1, 1, 1, 0, 1, 0, 1]);
+	/// ```
+	pub fn resize_with<F>(&mut self, new_len: usize, mut func: F)
+	where F: FnMut() -> bool {
+		let len = self.len();
+		self.resize_fill(new_len, Fill::Zero);
+		if new_len > len {
+			self[len ..].for_each_mut(|_, _| func());
+		}
+	}
+
+	/// Resizes the `BitVec` in place, filling any new bits according to a
+	/// [`Fill`] policy.
+	///
+	/// Growth is performed a whole element at a time: new storage elements
+	/// are written with the fill pattern directly, and only the dead bits of
+	/// the current last element are written individually.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `new_len`: The new length of the vector.
+	/// - `fill`: The policy used to initialize bits created by growth.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Fill;
+	///
+	/// let mut bv = bitvec![0; 3];
+	/// bv.resize_fill(20, Fill::One);
+	/// assert_eq!(bv.count_ones(), 17);
+	/// bv.resize_fill(2, Fill::default());
+	/// assert_eq!(bv, bitvec![0, 0]);
+	/// ```
+	///
+	/// [`Fill`]: enum.Fill.html
+	pub fn resize_fill(&mut self, new_len: usize, fill: Fill) {
+		let len = self.len();
+		if new_len <= len {
+			self.truncate(new_len);
+			return;
+		}
+		let value = fill == Fill::One;
+		//  Bits between the current end and the end of the current last
+		//  element hold stale data, and must be overwritten individually.
+		let stale = (self.pointer.elements() << T::INDX)
+			.saturating_sub(*self.pointer.head() as usize);
+		let (elts, _) = self.pointer.head().span(new_len);
+		self.do_unto_vec(|v| v.resize(elts, T::bits(value)));
+		unsafe { self.set_len(new_len); }
+		self[len .. cmp::min(stale, new_len)].set_all(value);
+	}
+
+	/// Resizes the `BitVec` in place, without initializing any new storage.
+	///
+	/// This is the cheapest way to grow a vector whose new contents will be
+	/// entirely overwritten before they are read, such as a reused buffer
+	/// about to receive a fresh payload. The vector reserves room for the new
+	/// bits and extends its length over them, and writes nothing.
+	///
+	/// If `new_len` is less than `len`, then the vector is just truncated.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `new_len`: The new length of the vector.
+	///
+	/// # Safety
+	///
+	/// The bits in `len .. new_len` have unspecified values, and the storage
+	/// elements added to hold them are uninitialized memory. The caller must
+	/// write every new bit before reading any of them.
+	///
+	/// Writing a single bit reads the whole element that holds it, so the new
+	/// elements must first be written whole, through [`as_mut_slice`], before
+	/// any bit in them is read or written through the `BitSlice` API. The new
+	/// bits that share the former last element with live bits are stale, but
+	/// initialized, and may be written one at a time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1; 8];
+	/// unsafe { bv.resize_uninit(24); }
+	/// assert_eq!(bv.len(), 24);
+	/// bv.as_mut_slice()[1 ..].copy_from_slice(&[0xA5, 0x0F]);
+	/// assert_eq!(bv.as_slice(), &[0xFF, 0xA5, 0x0F]);
+	/// ```
+	///
+	/// [`as_mut_slice`]: ../slice/struct.BitSlice.html#method.as_mut_slice
+	pub unsafe fn resize_uninit(&mut self, new_len: usize) {
 		let len = self.len();
-		if new_len < len {
+		if new_len <= len {
 			self.truncate(new_len);
+			return;
+		}
+		self.reserve(new_len - len);
+		self.set_len(new_len);
+	}
+
+	/// Moves the contents of the vector so that it begins at the front edge of
+	/// its first storage element.
+	///
+	/// A vector that has had bits discarded from its front in place may begin
+	/// partway into its first element. Many operations have faster paths when
+	/// the head offset is zero, and the storage slice from [`as_slice`] is
+	/// only laid out like that of a freshly built vector once it is. This has
+	/// no effect on a vector that is already aligned.
+	///
+	/// The bits are moved a whole element at a time: each element is written
+	/// once, assembled from the two elements that its new bits straddle, and
+	/// the bits vacated behind the last live bit are cleared.
+	///
+	/// This is only provided on `BitVec`, which owns every bit of its storage
+	/// elements. A `BitSlice` does not own the bits in front of its head, and
+	/// a `BitBox` cannot change the number of elements it will deallocate, so
+	/// neither can be re-homed in place.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0, 0, 1, 1, 0, 1, 1, 0, 1];
+	/// let mut tail = bv.split_off(3);
+	/// tail.force_align();
+	/// assert_eq!(tail, bitvec![1, 1, 0, 1, 1, 0, 1]);
+	/// assert_eq!(tail.as_slice(), &[0b1101_1010]);
+	/// ```
+	///
+	/// [`as_slice`]: ../slice/struct.BitSlice.html#method.as_slice
+	pub fn force_align(&mut self) {
+		let (data, head, bits) = self.pointer.raw_parts();
+		let head = *head as usize;
+		if head == 0 {
+			return;
+		}
+		if bits == 0 {
+			self.pointer = unsafe { BitPtr::new_unchecked(data, 0u8.idx(), 0) };
+			return;
+		}
+		//  View the storage from the front edge of the first element, so that
+		//  the live bits can be shifted down over the dead bits in front. The
+		//  shift clears the bits that it vacates behind them.
+		let full = unsafe {
+			BitPtr::new_unchecked(data, 0u8.idx(), head + bits)
+		}.into_bitslice_mut::<C>();
+		*full <<= head;
+		self.pointer = unsafe { BitPtr::new_unchecked(data, 0u8.idx(), bits) };
+	}
+
+	/// Applies a stream of sparse `(index, value)` assignments to the vector.
+	///
+	/// Each pair writes `value` into the bit at `index`. If `index` is beyond
+	/// the end of the vector, the vector is first grown to include it, and the
+	/// newly created bits are cleared. Consecutive pairs that land in the same
+	/// storage element are gathered into a single read/modify/write of that
+	/// element, so sorted patches are applied with one memory access per
+	/// touched element rather than one per bit.
+	///
+	/// When an index appears more than once, the last assignment to it wins.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `pairs`: A stream of `(index, value)` assignments.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item=(usize, bool)>`: The assignment stream.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0; 4];
+	/// bv.assign_from_pairs(vec![(1, true), (3, true), (1, false), (9, true)]);
+	/// assert_eq!(bv, bitvec![0, 0, 0, 1, 0, 0, 0, 0, 0, 1]);
+	/// ```
+	pub fn assign_from_pairs<I>(&mut self, pairs: I)
+	where I: IntoIterator<Item=(usize, bool)> {
+		//  The pending element, and the masks of bits to set and to clear in it.
+		let mut pending: Option<(usize, T, T)> = None;
+		for (index, value) in pairs {
+			if index >= self.len() {
+				self.resize(index + 1, false);
+			}
+			let (elt, bit) = self.pointer.head().offset(index as isize);
+			let elt = elt as usize;
+			let mask = *C::mask(bit);
+			let (mut set, mut clear) = match pending {
+				Some((cur, set, clear)) if cur == elt => (set, clear),
+				Some((cur, set, clear)) => {
+					self.flush_assignment(cur, set, clear);
+					(T::bits(false), T::bits(false))
+				},
+				None => (T::bits(false), T::bits(false)),
+			};
+			if value {
+				set |= mask;
+				clear &= !mask;
+			}
+			else {
+				set &= !mask;
+				clear |= mask;
+			}
+			pending = Some((elt, set, clear));
 		}
-		else if new_len > len {
-			self.extend(iter::repeat(value).take(new_len - len));
+		if let Some((elt, set, clear)) = pending {
+			self.flush_assignment(elt, set, clear);
 		}
 	}
 
@@ -1321,6 +2293,9 @@ where C: Cursor, T: BitStore {
 	///    - the tail (elements in the `BitVec` after `range`) is empty,
 	///    - `replace_with` yields fewer characters than `range`’s length,
 	///    - the lower bound of `replacement.size_hint()` is exact.
+	/// 5. If `replacement` yields exactly as many bits as `range` holds, they
+	///    are written directly into the gap, and the tail does not move.
+	///    Otherwise, the tail moves a whole element at a time.
 	///
 	/// # Parameters
 	///
@@ -1359,6 +2334,20 @@ where C: Cursor, T: BitStore {
 	/// assert!(!s[0]);
 	/// assert_eq!(bv, bitvec![0, 0, 1, 1, 1, 1, 0, 0]);
 	/// ```
+	///
+	/// This example patches a four-bit field in the middle of a packed record,
+	/// without splitting the record apart.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut record = bitvec![BigEndian, u8; 1, 1, 0, 0, 0, 0, 1, 1, 1, 0];
+	/// let old = record
+	///   .splice(2 .. 6, 0b1010u8.as_bitslice::<BigEndian>()[4 ..].iter())
+	///   .collect::<BitVec>();
+	/// assert_eq!(old, bitvec![0; 4]);
+	/// assert_eq!(record, bitvec![1, 1, 1, 0, 1, 0, 1, 1, 1, 0]);
+	/// ```
 	pub fn splice<R, I>(
 		&mut self,
 		range: R,
@@ -1528,15 +2517,161 @@ where C: Cursor, T: BitStore {
 	///
 	/// # Returns
 	///
-	/// Itself, with its size frozen and ungrowable.
-	pub fn into_boxed_bitslice(self) -> BitBox<C, T> {
-		let pointer = self.pointer;
-		//  Convert the Vec allocation into a Box<[T]> allocation
-		mem::forget(self.into_boxed_slice());
-		unsafe { BitBox::from_raw(pointer) }
+	/// Itself, with its size frozen and ungrowable.
+	pub fn into_boxed_bitslice(self) -> BitBox<C, T> {
+		let mut pointer = self.pointer;
+		//  Convert the Vec allocation into a Box<[T]> allocation. Shrinking
+		//  the allocation may move it, so the pointer must be refreshed.
+		let boxed = self.into_boxed_slice();
+		unsafe { pointer.set_pointer(boxed.as_ptr()); }
+		mem::forget(boxed);
+		unsafe { BitBox::from_bitptr(pointer) }
+	}
+
+	/// Degrades a `BitVec` to a standard boxed slice.
+	///
+	/// As with `Vec::into_boxed_slice`, any excess capacity is released first,
+	/// which may reallocate. A vector whose capacity is exactly its live
+	/// elements is converted without copying.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A boxed slice of the data the `BitVec` had owned.
+	pub fn into_boxed_slice(self) -> Box<[T]> {
+		self.into_vec().into_boxed_slice()
+	}
+
+	/// Degrades a `BitVec` to a standard `Vec`.
+	///
+	/// This does not copy or reallocate. The returned vector has the same
+	/// buffer and capacity as `self`, and holds every element that the live
+	/// bits touch. The dead bits in partially-live elements at either edge are
+	/// returned as they are.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The plain vector underlying the `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![LittleEndian, u16; 1; 20];
+	/// bv.reserve(100);
+	/// let (addr, cap) = (bv.as_slice().as_ptr(), bv.capacity());
+	/// let v = bv.into_vec();
+	/// assert_eq!(v.as_ptr(), addr);
+	/// assert_eq!(v.capacity() * 16, cap);
+	/// assert_eq!(v, [!0, 0x000F]);
+	/// ```
+	pub fn into_vec(self) -> Vec<T> {
+		let slice = self.pointer.as_mut_slice();
+		let out = unsafe {
+			Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), self.capacity)
+		};
+		mem::forget(self);
+		out
+	}
+
+	/// Changes the cursor of the vector, rewriting its elements in place.
+	///
+	/// This is the in-place form of [`BitSlice::reorder`]: each element of
+	/// the buffer is rewritten into the ordering of `D`, and the buffer is
+	/// kept, so no allocation is made. A vector that does not begin at the
+	/// front of its first element is copied by `reorder` instead.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A vector holding the same sequence of bits, ordered by `D`, in the
+	/// same allocation.
+	///
+	/// # Type Parameters
+	///
+	/// - `D`: The cursor of the produced vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let wire = bitvec![BigEndian, u16; 1, 1, 0, 0, 1];
+	/// let copy = wire.clone();
+	/// let addr = copy.as_slice().as_ptr();
+	/// let work = copy.into_reordered::<LittleEndian>();
+	/// assert_eq!(work, wire);
+	/// assert_eq!(work.as_slice(), [0b1_0011]);
+	/// assert_eq!(work.as_slice().as_ptr(), addr);
+	/// ```
+	///
+	/// [`BitSlice::reorder`]: ../slice/struct.BitSlice.html#method.reorder
+	pub fn into_reordered<D>(mut self) -> BitVec<D, T>
+	where D: Cursor {
+		if *self.pointer.head() != 0 {
+			return self.reorder();
+		}
+		let plan = Reorder::new::<C, D, T>();
+		let tail = self.len() % T::BITS as usize;
+		let elts = self.as_mut_slice();
+		for elt in elts.iter_mut() {
+			*elt = plan.apply(*elt);
+		}
+		if let (Some(last), true) = (elts.last_mut(), tail != 0) {
+			*last &= D::mask_range(0u8.idx(), (tail as u8).tail());
+		}
+		let (pointer, capacity) = (self.pointer, self.capacity);
+		mem::forget(self);
+		BitVec {
+			_cursor: PhantomData,
+			pointer,
+			capacity,
+		}
+	}
+
+	/// Degrades a `BitVec` to a standard boxed slice, if it has no partial
+	/// elements.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// If the vector begins at the front of its first element and ends at the
+	/// back of its last, so that its elements contain no dead bits, this
+	/// returns its data as a boxed slice. The allocation is reused, though it
+	/// may be shrunk to fit, as with `Vec::into_boxed_slice`.
+	///
+	/// Otherwise, this returns the vector unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![BigEndian, u8; 1; 16];
+	/// assert_eq!(bv.try_into_boxed_slice().unwrap(), vec![!0u8; 2].into_boxed_slice());
+	///
+	/// let bv = bitvec![BigEndian, u8; 1; 12];
+	/// assert_eq!(bv.try_into_boxed_slice().unwrap_err().len(), 12);
+	/// ```
+	pub fn try_into_boxed_slice(self) -> Result<Box<[T]>, Self> {
+		self.try_into_vec().map(Vec::into_boxed_slice)
 	}
 
-	/// Degrades a `BitVec` to a standard boxed slice.
+	/// Degrades a `BitVec` to a standard `Vec`, if it has no partial elements.
 	///
 	/// # Parameters
 	///
@@ -1544,27 +2679,36 @@ where C: Cursor, T: BitStore {
 	///
 	/// # Returns
 	///
-	/// A boxed slice of the data the `BitVec` had owned.
-	pub fn into_boxed_slice(self) -> Box<[T]> {
-		self.into_vec().into_boxed_slice()
-	}
-
-	/// Degrades a `BitVec` to a standard `Vec`.
+	/// If the vector begins at the front of its first element and ends at the
+	/// back of its last, so that its elements contain no dead bits, this
+	/// returns its data as a plain vector, reusing the allocation without
+	/// copying.
 	///
-	/// # Parameters
+	/// Otherwise, this returns the vector unchanged. [`force_align`] can move
+	/// the vector to the front of its first element, after which it converts
+	/// successfully if its length is a multiple of `T::BITS`.
 	///
-	/// - `self`
+	/// # Examples
 	///
-	/// # Returns
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// The plain vector underlying the `BitVec`.
-	pub fn into_vec(self) -> Vec<T> {
-		let slice = self.pointer.as_mut_slice();
-		let out = unsafe {
-			Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), self.capacity)
-		};
-		mem::forget(self);
-		out
+	/// let bv = bitvec![LittleEndian, u16; 0; 32];
+	/// assert_eq!(bv.try_into_vec().unwrap(), vec![0u16; 2]);
+	///
+	/// let bv = bitvec![LittleEndian, u16; 0; 20];
+	/// let bv = bv.try_into_vec().unwrap_err();
+	/// assert_eq!(bv.len(), 20);
+	/// ```
+	///
+	/// [`force_align`]: #method.force_align
+	pub fn try_into_vec(self) -> Result<Vec<T>, Self> {
+		if self.pointer.is_whole() {
+			Ok(self.into_vec())
+		}
+		else {
+			Err(self)
+		}
 	}
 
 	/// Gets the raw `BitPtr` powering the vector.
@@ -1615,6 +2759,25 @@ where C: Cursor, T: BitStore {
 		&mut self.pointer
 	}
 
+	/// Tests if another vector type stores its bits exactly as this one does.
+	///
+	/// # Type Parameters
+	///
+	/// - `D`: The cursor of the other vector type.
+	/// - `U`: The storage type of the other vector type.
+	///
+	/// # Returns
+	///
+	/// Whether `U` is `T`, and `D` places every index of `T` at the same
+	/// position as `C` does. When it does, the memory of a `BitSlice<D, U>`
+	/// can be read as a `BitSlice<C, T>` without changing its value.
+	fn same_layout<D, U>() -> bool
+	where D: Cursor, U: BitStore {
+		//  `BitStore` is sealed to the unsigned integers, whose names differ.
+		T::TYPENAME == U::TYPENAME
+			&& (0 .. T::BITS).all(|n| C::at::<T>(n.idx()) == D::at::<T>(n.idx()))
+	}
+
 	/// Permits a function to modify the `Vec<T>` underneath a `BitVec<_, T>`.
 	///
 	/// This produces a `Vec<T>` structure referring to the same data region as
@@ -1650,6 +2813,78 @@ where C: Cursor, T: BitStore {
 		out
 	}
 
+	/// Counts the elements needed to hold `additional` more bits.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `additional`: The number of extra bits to be granted space.
+	///
+	/// # Returns
+	///
+	/// The total number of elements the vector must allocate, or an overflow
+	/// error if the vector could not hold that many bits.
+	fn elements_for(&self, additional: usize) -> Result<usize, TryReserveError> {
+		let overflow = Err(TryReserveError::CapacityOverflow);
+		match self.len().checked_add(additional) {
+			Some(n) if n <= BitPtr::<T>::MAX_BITS => {},
+			_ => return overflow,
+		}
+		let (e, _) = self.pointer.tail().span(additional);
+		match self.pointer.elements().checked_add(e) {
+			Some(n) => Ok(n),
+			None => overflow,
+		}
+	}
+
+	/// Moves the buffer into an allocation of exactly `cap` elements.
+	///
+	/// `Vec` on this toolchain cannot report allocation failure, so this goes
+	/// to the global allocator directly, using the same layout `Vec` does.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `cap`: The new element capacity. This must be at least the number of
+	///   live elements, and not zero.
+	///
+	/// # Returns
+	///
+	/// `Ok` if the buffer moved. On error, the vector is unchanged.
+	fn try_realloc(&mut self, cap: usize) -> Result<(), TryReserveError> {
+		debug_assert!(cap > 0 && cap >= self.pointer.elements());
+		let layout = match cap.checked_mul(mem::size_of::<T>()) {
+			Some(size) if size <= isize::max_value() as usize => unsafe {
+				Layout::from_size_align_unchecked(size, mem::align_of::<T>())
+			},
+			_ => return Err(TryReserveError::CapacityOverflow),
+		};
+		let ptr = unsafe {
+			if self.capacity == 0 {
+				heap::alloc(layout)
+			}
+			else {
+				let old = Layout::from_size_align_unchecked(
+					self.capacity * mem::size_of::<T>(),
+					mem::align_of::<T>(),
+				);
+				heap::realloc(
+					self.pointer.pointer().w() as *mut u8,
+					old,
+					layout.size(),
+				)
+			}
+		};
+		if ptr.is_null() {
+			return Err(TryReserveError::AllocError {
+				bits: cap.saturating_mul(T::BITS as usize),
+			});
+		}
+		unsafe { self.bitptr_mut().set_pointer(ptr as *const T); }
+		self.capacity = cap;
+		Ok(())
+	}
+
 	/// Permits a function to view the `Vec<T>` underneath a `BitVec<_, T>`.
 	///
 	/// This produces a `Vec<T>` structure referring to the same data region as
@@ -1684,6 +2919,21 @@ where C: Cursor, T: BitStore {
 		mem::forget(v);
 		out
 	}
+
+	/// Writes a batch of gathered assignments into one storage element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `elt`: The index of the element, counted from the start of the
+	///   vector’s storage.
+	/// - `set`: A mask of bits in the element to set to `1`.
+	/// - `clear`: A mask of bits in the element to set to `0`.
+	fn flush_assignment(&mut self, elt: usize, set: T, clear: T) {
+		let slot = &mut self.as_mut_slice()[elt];
+		*slot &= !clear;
+		*slot |= set;
+	}
 }
 
 /// Signifies that `BitSlice` is the borrowed form of `BitVec`.
@@ -2101,7 +3351,6 @@ where C: Cursor, T: BitStore {
 impl<C, T> Write for BitVec<C, T>
 where C: Cursor, T: BitStore {
 	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-		use std::cmp;
 		let amt = cmp::min(buf.len(), BitPtr::<T>::MAX_BITS - self.len());
 		self.extend(<&BitSlice<C, u8>>::from(buf));
 		Ok(amt)
@@ -2149,6 +3398,126 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** Applies a stream of sparse `(index, value)` assignments to a `BitVec`.
+
+This is the `Extend` face of [`BitVec::assign_from_pairs`]; the vector grows to
+fit any index beyond its end.
+
+[`BitVec::assign_from_pairs`]: struct.BitVec.html#method.assign_from_pairs
+**/
+impl<C, T> Extend<(usize, bool)> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Writes each `(index, value)` pair from `src` into `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: A stream of `(index, value)` assignments.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item=(usize, bool)>`: The assignment stream.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0; 8];
+	/// bv.extend(vec![(0, true), (7, true), (12, true)]);
+	/// assert_eq!(bv.len(), 13);
+	/// assert_eq!(bv.count_ones(), 3);
+	/// assert!(bv[12]);
+	/// ```
+	fn extend<I: IntoIterator<Item=(usize, bool)>>(&mut self, src: I) {
+		self.assign_from_pairs(src);
+	}
+}
+
+/** Appends a sequence of owned `BitVec`s to a `BitVec`.
+
+The pieces are appended in order. The vector grows at most once, to fit all of
+them. If `self` is empty, it adopts the buffer of the first piece when that
+buffer is at least as large as its own; every other piece is copied in bulk, as
+by [`BitVec::extend_from_bitslice`], and then dropped.
+
+[`BitVec::extend_from_bitslice`]: struct.BitVec.html#method.extend_from_bitslice
+**/
+impl<C, T> Extend<BitVec<C, T>> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Appends each vector from `src` to `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: A stream of vectors to append.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item=BitVec<C, T>>`: The vectors to append.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut first = BitVec::with_capacity(128);
+	/// first.extend_from_bitslice(&bitvec![1; 100]);
+	/// let addr = first.as_slice().as_ptr();
+	///
+	/// let mut bv = BitVec::new();
+	/// bv.extend(vec![first, bitvec![0; 3], bitvec![1; 5]]);
+	/// assert_eq!(bv.len(), 108);
+	/// assert_eq!(bv.count_ones(), 105);
+	/// assert!(bv[100 .. 103].not_any());
+	/// //  The first piece had room for the rest, so nothing moved.
+	/// assert_eq!(bv.as_slice().as_ptr(), addr);
+	/// ```
+	fn extend<I: IntoIterator<Item=BitVec<C, T>>>(&mut self, src: I) {
+		self.append_pieces(src);
+	}
+}
+
+/** Appends a sequence of owned `BitBox`es to a `BitVec`.
+
+Each box is thawed into a `BitVec` without copying, and the sequence is then
+appended with the same allocation rules as `Extend<BitVec<C, T>>`: an empty
+vector can adopt the buffer of the first box, and every other box is copied.
+**/
+impl<C, T> Extend<BitBox<C, T>> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Appends each box from `src` to `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: A stream of boxed slices to append.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item=BitBox<C, T>>`: The boxes to append.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let only = bitbox![0, 1, 1, 0];
+	/// let addr = only.as_slice().as_ptr();
+	/// let mut bv = BitVec::new();
+	/// bv.extend(Some(only));
+	/// assert_eq!(bv, bitvec![0, 1, 1, 0]);
+	/// assert_eq!(bv.as_slice().as_ptr(), addr);
+	///
+	/// bv.extend(vec![bitbox![1; 4], bitbox![0; 8]]);
+	/// assert_eq!(bv.len(), 16);
+	/// assert_eq!(bv.as_slice()[0], 0b0110_1111);
+	/// ```
+	fn extend<I: IntoIterator<Item=BitBox<C, T>>>(&mut self, src: I) {
+		self.append_pieces(src.into_iter().map(BitVec::from_boxed_bitslice));
+	}
+}
+
 /// Permits the construction of a `BitVec` by using `.collect()` on an iterator
 /// of `bool`.
 impl<C, T> FromIterator<bool> for BitVec<C, T>
@@ -2181,6 +3550,32 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/// Permits the construction of a `BitVec` by using `.collect()` on an iterator
+/// of `BitSlice` references, concatenating them in order.
+impl<'a, C, T> FromIterator<&'a BitSlice<C, T>> for BitVec<C, T>
+where C: 'a + Cursor, T: 'a + BitStore {
+	/// Concatenates an iterator of bit slices into a vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0b1010_0000u8, 0b0000_1111];
+	/// let bits = src.as_bitslice::<BigEndian>();
+	/// let bv: BitVec = bits.chunks(4).step_by(3).collect();
+	/// assert_eq!(bv, bitvec![1, 0, 1, 0, 1, 1, 1, 1]);
+	/// ```
+	fn from_iter<I: IntoIterator<Item=&'a BitSlice<C, T>>>(src: I) -> Self {
+		let mut bv = Self::new();
+		for slice in src {
+			bv.reserve(slice.len());
+			slice.iter().for_each(|b| bv.push(b));
+		}
+		bv
+	}
+}
+
 /** Produces an iterator over all the bits in the vector.
 
 This iterator follows the ordering in the vector type, and implements
@@ -2224,6 +3619,16 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
+impl<'a, C, T> IntoIterator for &'a mut BitVec<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = BitMut<'a, C, T>;
+	type IntoIter = <&'a mut BitSlice<C, T> as IntoIterator>::IntoIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		<&'a mut BitSlice<C, T> as IntoIterator>::into_iter(self)
+	}
+}
+
 /// `BitVec` is safe to move across thread boundaries, as is `&mut BitVec`.
 unsafe impl<C, T> Send for BitVec<C, T>
 where C: Cursor, T: BitStore {}
@@ -2711,6 +4116,22 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+impl<C, T> Index<BitSpan> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	type Output = BitSlice<C, T>;
+
+	fn index(&self, span: BitSpan) -> &Self::Output {
+		&self.as_bitslice()[span]
+	}
+}
+
+impl<C, T> IndexMut<BitSpan> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, span: BitSpan) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[span]
+	}
+}
+
 /** 2’s-complement negation of a `BitVec`.
 
 In 2’s-complement, negation is defined as bit-inversion followed by adding one.
@@ -2772,6 +4193,8 @@ where C: Cursor, T: BitStore {
 
 __bitvec_shift!(u8, u16, u32, u64, i8, i16, i32, i64);
 
+__bitslice_int_cmp!(for [C, T] BitVec<C, T> => u8, u16, u32, u64, u128);
+
 /** Shifts all bits in the vector to the left – **DOWN AND TOWARDS THE FRONT**.
 
 On fundamentals, the left-shift operator `<<` moves bits away from origin and
@@ -2793,19 +4216,19 @@ operator to do.
 # Notes
 
 In order to preserve the effects in memory that this operator traditionally
-expects, the bits that are emptied by this operation are zeroed rather than
-left to their old value.
-
-The length of the vector is decreased by the shift amount.
+expects, the bits that are emptied by this operation are zeroed rather than left
+to their old value.
 
-If the shift amount is greater than the length, the vector calls `clear()` and
-zeroes its memory. This is *not* an error.
+The length of the vector does not change. Bits shifted past the front are
+discarded, and the bits at the back are cleared. A shift by the length of the
+vector or more clears every bit. Whole elements are moved at a time where the
+vector’s layout permits, as `BitSlice` does.
 **/
 impl<C, T> Shl<usize> for BitVec<C, T>
 where C: Cursor, T: BitStore {
 	type Output = Self;
 
-	/// Shifts a `BitVec` to the left, shortening it.
+	/// Shifts a `BitVec` to the left, keeping its length.
 	///
 	/// # Examples
 	///
@@ -2817,9 +4240,9 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(0b0001_1100, bv.as_slice()[0]);
 	/// assert_eq!(bv.len(), 6);
 	/// let ls = bv << 2usize;
-	/// assert_eq!("[0111]", &format!("{}", ls));
+	/// assert_eq!("[011100]", &format!("{}", ls));
 	/// assert_eq!(0b0111_0000, ls.as_slice()[0]);
-	/// assert_eq!(ls.len(), 4);
+	/// assert_eq!(ls.len(), 6);
 	/// ```
 	fn shl(mut self, shamt: usize) -> Self::Output {
 		self <<= shamt;
@@ -2851,14 +4274,14 @@ In order to preserve the effects in memory that this operator traditionally
 expects, the bits that are emptied by this operation are zeroed rather than left
 to their old value.
 
-The length of the vector is decreased by the shift amount.
-
-If the shift amount is greater than the length, the vector calls `clear()` and
-zeroes its memory. This is *not* an error.
+The length of the vector does not change. Bits shifted past the front are
+discarded, and the bits at the back are cleared. A shift by the length of the
+vector or more clears every bit. Whole elements are moved at a time where the
+vector’s layout permits, as `BitSlice` does.
 **/
 impl<C, T> ShlAssign<usize> for BitVec<C, T>
 where C: Cursor, T: BitStore {
-	/// Shifts a `BitVec` to the left in place, shortening it.
+	/// Shifts a `BitVec` to the left in place, keeping its length.
 	///
 	/// # Examples
 	///
@@ -2870,26 +4293,15 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(0b0011_1000, bv.as_slice()[0]);
 	/// assert_eq!(bv.len(), 6);
 	/// bv <<= 2;
-	/// assert_eq!("[0111]", &format!("{}", bv));
+	/// assert_eq!("[011100]", &format!("{}", bv));
 	/// assert_eq!(0b0000_1110, bv.as_slice()[0]);
-	/// assert_eq!(bv.len(), 4);
+	/// assert_eq!(bv.len(), 6);
+	/// bv <<= 10;
+	/// assert!(bv.not_any());
+	/// assert_eq!(bv.len(), 6);
 	/// ```
 	fn shl_assign(&mut self, shamt: usize) {
-		let len = self.len();
-		if shamt >= len {
-			self.set_all(false);
-			self.clear();
-			return;
-		}
-		for idx in shamt .. len {
-			let val = self[idx];
-			self.set(idx.saturating_sub(shamt), val);
-		}
-		let trunc = len.saturating_sub(shamt);
-		for idx in trunc .. len {
-			self.set(idx, false);
-		}
-		self.truncate(trunc);
+		self.as_mut_bitslice().shl_assign(shamt);
 	}
 }
 
@@ -2917,17 +4329,17 @@ In order to preserve the effects in memory that this operator traditionally
 expects, the bits that are emptied by this operation are zeroed rather than left
 to their old value.
 
-The length of the vector is increased by the shift amount.
-
-If the new length of the vector would overflow, a panic occurs. This *is* an
-error.
+The length of the vector does not change. Bits shifted past the back are
+discarded, and the bits at the front are cleared. A shift by the length of the
+vector or more clears every bit. Whole elements are moved at a time where the
+vector’s layout permits, as `BitSlice` does.
 **/
 impl<C, T> Shr<usize> for BitVec<C, T>
 where C: Cursor, T: BitStore {
 	type Output = Self;
 
-	/// Shifts a `BitVec` to the right, lengthening it and filling the front
-	/// with 0.
+	/// Shifts a `BitVec` to the right, keeping its length and filling the
+	/// front with 0.
 	///
 	/// # Examples
 	///
@@ -2939,9 +4351,9 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(0b0001_1100, bv.as_slice()[0]);
 	/// assert_eq!(bv.len(), 6);
 	/// let rs = bv >> 2usize;
-	/// assert_eq!("[00000111]", &format!("{}", rs));
-	/// assert_eq!(0b0000_0111, rs.as_slice()[0]);
-	/// assert_eq!(rs.len(), 8);
+	/// assert_eq!("[000001]", &format!("{}", rs));
+	/// assert_eq!(0b0000_0100, rs.as_slice()[0]);
+	/// assert_eq!(rs.len(), 6);
 	/// ```
 	fn shr(mut self, shamt: usize) -> Self::Output {
 		self >>= shamt;
@@ -2973,15 +4385,15 @@ In order to preserve the effects in memory that this operator traditionally
 expects, the bits that are emptied by this operation are zeroed rather than left
 to their old value.
 
-The length of the vector is increased by the shift amount.
-
-If the new length of the vector would overflow, a panic occurs. This *is* an
-error.
+The length of the vector does not change. Bits shifted past the back are
+discarded, and the bits at the front are cleared. A shift by the length of the
+vector or more clears every bit. Whole elements are moved at a time where the
+vector’s layout permits, as `BitSlice` does.
 **/
 impl<C, T> ShrAssign<usize> for BitVec<C, T>
 where C: Cursor, T: BitStore {
-	/// Shifts a `BitVec` to the right in place, lengthening it and filling the
-	/// front with 0.
+	/// Shifts a `BitVec` to the right in place, keeping its length and filling
+	/// the front with 0.
 	///
 	/// # Examples
 	///
@@ -2993,22 +4405,12 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(0b0011_1000, bv.as_slice()[0]);
 	/// assert_eq!(bv.len(), 6);
 	/// bv >>= 2;
-	/// assert_eq!("[00000111]", &format!("{}", bv));
-	/// assert_eq!(0b1110_0000, bv.as_slice()[0]);
-	/// assert_eq!(bv.len(), 8);
+	/// assert_eq!("[000001]", &format!("{}", bv));
+	/// assert_eq!(0b0010_0000, bv.as_slice()[0]);
+	/// assert_eq!(bv.len(), 6);
 	/// ```
 	fn shr_assign(&mut self, shamt: usize) {
-		let old_len = self.len();
-		for _ in 0 .. shamt {
-			self.push(false);
-		}
-		for idx in (0 .. old_len).rev() {
-			let val = self[idx];
-			self.set(idx.saturating_add(shamt), val);
-		}
-		for idx in 0 .. shamt {
-			self.set(idx, false);
-		}
+		self.as_mut_bitslice().shr_assign(shamt);
 	}
 }
 
@@ -3124,8 +4526,10 @@ where C: Cursor, T: BitStore {
 		subtrahend = -subtrahend;
 		let (llen, rlen) = (self.len(), subtrahend.len());
 		//  If the subtrahend is longer than the minuend, 0-extend the minuend.
+		//  Shifts keep the length of the vector, so it is lengthened first.
 		if rlen > llen {
 			let diff = rlen - llen;
+			self.resize(rlen, false);
 			*self >>= diff;
 		}
 		else {
@@ -3134,6 +4538,7 @@ where C: Cursor, T: BitStore {
 			if llen > rlen {
 				let diff = llen - rlen;
 				let sign = subtrahend[0];
+				subtrahend.resize(llen, false);
 				subtrahend >>= diff;
 				subtrahend[.. diff].set_all(sign);
 			}
@@ -3143,7 +4548,95 @@ where C: Cursor, T: BitStore {
 		//  If the subtraction emitted a carry, remove it.
 		if self.len() > old {
 			*self <<= 1;
+			self.pop();
+		}
+	}
+}
+
+/** Flattens a sequence of bit slices into a single `BitVec`.
+
+The standard library’s `concat` and `join` methods on slices only produce
+element vectors and strings. This trait provides the same operations for
+sequences of anything that borrows as a `BitSlice` – `&BitSlice`, `BitBox`, or
+`BitVec` – counting the total length first so that the result is allocated
+once.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the produced `BitVec`.
+- `T: BitStore`: The storage type of the produced `BitVec`.
+**/
+pub trait BitConcat<C, T>
+where C: Cursor, T: BitStore {
+	/// Concatenates the bit slices in order.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `BitVec` containing each slice in `self`, in order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![1, 0];
+	/// let b = bitvec![0, 1, 1];
+	/// let parts: &[&BitSlice] = &[&a, &b, &a[.. 1]];
+	/// assert_eq!(parts.concat(), bitvec![1, 0, 0, 1, 1, 1]);
+	/// ```
+	fn concat(&self) -> BitVec<C, T>;
+
+	/// Concatenates the bit slices in order, placing a separator between each
+	/// pair of adjacent slices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `sep`: The separator to place between each slice.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` containing each slice in `self`, in order, with `sep`
+	/// between each of them.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let sep = bitvec![0, 0];
+	/// let parts = [bitvec![1; 3], bitvec![1], bitvec![]];
+	/// assert_eq!(parts.join(&sep), bitvec![1, 1, 1, 0, 0, 1, 0, 0]);
+	/// ```
+	fn join(&self, sep: &BitSlice<C, T>) -> BitVec<C, T>;
+}
+
+impl<C, T, S> BitConcat<C, T> for [S]
+where C: Cursor, T: BitStore, S: Borrow<BitSlice<C, T>> {
+	fn concat(&self) -> BitVec<C, T> {
+		let len = self.iter().map(|s| s.borrow().len()).sum();
+		let mut out = BitVec::with_capacity(len);
+		for slice in self {
+			out.extend(slice.borrow());
+		}
+		out
+	}
+
+	fn join(&self, sep: &BitSlice<C, T>) -> BitVec<C, T> {
+		let len = self.iter().map(|s| s.borrow().len()).sum::<usize>()
+			+ sep.len() * self.len().saturating_sub(1);
+		let mut out = BitVec::with_capacity(len);
+		for (n, slice) in self.iter().enumerate() {
+			if n != 0 {
+				out.extend(sep);
+			}
+			out.extend(slice.borrow());
 		}
+		out
 	}
 }
 
@@ -3192,18 +4685,19 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// - `I: Iterator<Item=bool>`: A provider of bits.
 	unsafe fn fill<I: Iterator<Item=bool>>(&mut self, stream: &mut I) -> bool {
-		let bv = self.bitvec.as_mut();
-		let drain_from = bv.len();
+		let drain_from = self.bitvec.as_ref().len();
 		let drain_upto = self.tail_start;
 
 		for n in drain_from .. drain_upto {
 			if let Some(bit) = stream.next() {
-				bv.push(bit);
+				self.put(bit);
 			}
 			else {
-				for (to, from) in (n .. n + self.tail_len).zip(drain_upto ..) {
-					bv.swap(from, to);
-				}
+				let bv = self.bitvec.as_mut();
+				let full_len = drain_upto + self.tail_len;
+				bv.set_len(full_len);
+				bv[n .. full_len] <<= drain_upto - n;
+				bv.set_len(n);
 				self.tail_start = n;
 				return false;
 			}
@@ -3211,6 +4705,28 @@ where C: Cursor, T: 'a + BitStore {
 		true
 	}
 
+	/// Writes a bit into the drained span, just past the end of the vector.
+	///
+	/// `BitVec::push` writes a fresh zero element into the buffer when the
+	/// vector ends at an element edge. Inside a drain, that element still holds
+	/// drained bits that have not been yielded, or the tail, so this only ever
+	/// writes the one bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bit`: The bit to write.
+	///
+	/// # Safety
+	///
+	/// The vector must end before the start of the tail span.
+	unsafe fn put(&mut self, bit: bool) {
+		let bv = self.bitvec.as_mut();
+		let len = bv.len();
+		bv.set_len(len + 1);
+		bv.set(len, bit);
+	}
+
 	/// Moves the tail span farther back in the vector.
 	///
 	/// # Parameters
@@ -3219,15 +4735,18 @@ where C: Cursor, T: 'a + BitStore {
 	/// - `by`: The amount by which to move the tail span.
 	unsafe fn move_tail(&mut self, by: usize) {
 		let bv = self.bitvec.as_mut();
-		bv.reserve(by);
 		let new_tail = self.tail_start + by;
 		let old_len = bv.len();
-		let new_len = self.tail_start + self.tail_len + by;
+		let full_len = self.tail_start + self.tail_len;
+		let new_len = full_len + by;
+		//  The vector’s length stops at the drain, so it must be extended over
+		//  the tail before reserving. Otherwise, a reallocation would copy
+		//  only the elements before the drain, and lose the tail.
+		bv.set_len(full_len);
+		bv.reserve(by);
 
 		bv.set_len(new_len);
-		for n in (0 .. self.tail_len).rev() {
-			bv.swap(self.tail_start + n, new_tail + n);
-		}
+		bv[self.tail_start .. new_len] >>= by;
 		bv.set_len(old_len);
 
 		self.tail_start = new_tail;
@@ -3287,9 +4806,9 @@ where C: Cursor, T: 'a + BitStore {
 		let end_len = start + tail_len;
 		//  Inflate the vector to include the remnant span,
 		bv.set_len(full_len);
-		//  Swap the remnant span down into the drained span,
-		for (from, to) in (tail .. full_len).zip(start .. end_len) {
-			bv.swap(from, to);
+		//  Shift the remnant span, if any, down into the drained span,
+		if tail_len > 0 {
+			bv[start .. full_len] <<= tail - start;
 		}
 		//  And deflate the vector to fit.
 		bv.set_len(end_len);
@@ -3513,13 +5032,15 @@ where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
 
 	fn next(&mut self) -> Option<Self::Item> {
 		//  If the drain produced a bit, then try to pull a bit from the
-		//  replacement. If the replacement produced a bit, push it into the
+		//  replacement. If the replacement produced a bit, write it into the
 		//  `BitVec` that the drain is managing. This works because the `Drain`
 		//  type truncates the `BitVec` to the front of the region being
 		//  drained, then tracks the remainder of the memory.
 		self.drain.next().map(|bit| {
+			//  The vector cannot have passed the bit that the drain just
+			//  yielded, so it is still short of the tail.
 			if let Some(new_bit) = self.splice.next() {
-				unsafe { self.drain.bitvec.as_mut() }.push(new_bit);
+				unsafe { self.drain.put(new_bit) };
 			}
 			bit
 		})
@@ -0,0 +1,135 @@
+/*! `BitVec::append` and `BitVec::split_off` against a `Vec<bool>` model.
+
+`split_off` copies the elements under the split-off bits as they stand, so the
+new vector begins at the same place in its first element as the split point
+did; a split at an element edge starts it at the front of an element. `append`
+takes the buffer of an empty receiver outright, copies whole elements when the
+two vectors share a layout, and falls back to copying bit by bit when they do
+not. These tests cover each path, from vectors at several head offsets and
+onto receivers whose last element is only partly filled.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+#[macro_use]
+mod model;
+
+use bitvec::prelude::*;
+
+use model::{
+	build,
+	check,
+};
+
+/// The number of elements that `len` bits occupy when they begin `head` bits
+/// into their first element.
+fn elements(head: usize, len: usize, width: usize) -> usize {
+	if len == 0 { 0 } else { (head + len + width - 1) / width }
+}
+
+fn split_each<C, T>()
+where C: Cursor, T: BitStore {
+	let w = T::BITS as usize;
+	let len = 3 * w + 2;
+	for &head in &[0, 1, w / 2, w - 1] {
+		for at in 0 ..= len {
+			let (mut bits, mut model) = build::<C, T>(head, len);
+			let mut rest = bits.split_off(at);
+			let mut rest_model = model.split_off(at);
+			let what = format!("split {} at {}, head {}", len, at, head);
+			check(&bits, &model, &what);
+			check(&rest, &rest_model, &what);
+			//  A split at an element edge starts the new vector at the front
+			//  of an element; a split within one keeps its place there.
+			let rest_head = if at == 0 { head } else { (head + at) % w };
+			assert_eq!(
+				rest.as_slice().len(),
+				elements(rest_head, rest.len(), w),
+				"{}",
+				what,
+			);
+			//  Both halves remain usable as growable vectors.
+			bits.push(true);
+			model.push(true);
+			rest.push(false);
+			rest_model.push(false);
+			check(&bits, &model, &what);
+			check(&rest, &rest_model, &what);
+		}
+	}
+}
+
+/// Appends between two vectors of the same layout.
+fn append_each<C, T>()
+where C: Cursor, T: BitStore {
+	let w = T::BITS as usize;
+	let heads = [0, 1, w / 2, w - 1];
+	let lens = [0, 1, w - 1, w, 2 * w + 3];
+	for &my_head in &heads {
+		for &my_len in &lens {
+			for &their_head in &heads {
+				for &their_len in &lens {
+					let (mut mine, mut model) = build::<C, T>(my_head, my_len);
+					let (mut theirs, mut their_model) =
+						build::<C, T>(their_head, their_len);
+					mine.append(&mut theirs);
+					model.append(&mut their_model);
+					let what = format!(
+						"append {}@{} onto {}@{}",
+						their_len,
+						their_head,
+						my_len,
+						my_head,
+					);
+					check(&mine, &model, &what);
+					assert!(theirs.is_empty(), "{}", what);
+					mine.push(true);
+					model.push(true);
+					check(&mine, &model, &what);
+				}
+			}
+		}
+	}
+}
+
+each_layout!(split_off => split_each);
+each_layout!(append => append_each);
+
+/// Appending a vector of another cursor or element type copies it bit by bit.
+#[test]
+fn append_other_layout() {
+	for &my_len in &[0, 1, 7, 8, 13] {
+		for &their_len in &[0, 1, 9, 21] {
+			let (mut mine, mut model) = build::<BigEndian, u8>(3, my_len);
+			let (mut cursor, cursor_model) =
+				build::<LittleEndian, u8>(5, their_len);
+			let (mut store, store_model) =
+				build::<BigEndian, u16>(11, their_len);
+
+			mine.append(&mut cursor);
+			model.extend(cursor_model);
+			check(&mine, &model, "other cursor");
+			assert!(cursor.is_empty());
+
+			mine.append(&mut store);
+			model.extend(store_model);
+			check(&mine, &model, "other element");
+			assert!(store.is_empty());
+		}
+	}
+}
+
+/// An empty receiver takes the buffer of the other vector, which keeps its
+/// place in its first element.
+#[test]
+fn append_onto_empty() {
+	let (mut theirs, model) = build::<LittleEndian, u16>(9, 30);
+	let elts = theirs.as_slice().len();
+	let mut mine = BitVec::<LittleEndian, u16>::new();
+	mine.append(&mut theirs);
+	check(&mine, &model, "empty receiver");
+	assert_eq!(mine.as_slice().len(), elts);
+	assert!(theirs.is_empty());
+	theirs.push(true);
+	assert_eq!(theirs, bitvec![LittleEndian, u16; 1]);
+}
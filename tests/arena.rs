@@ -0,0 +1,54 @@
+/*! `BitArena` slices that are held across later allocations.
+
+Every slice that an arena hands out must stay valid while the arena carves more
+slices from the same buffer, and after it moves on to a new one. These tests
+keep mutable slices alive across such allocations and write through all of
+them afterwards. They use only the public API, so that `cargo miri test` can
+check the arena’s pointer handling.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use bitvec::prelude::*;
+
+/// Two slices that share a storage element stay writable across allocations.
+#[test]
+fn hold_two_across_allocations() {
+	let arena = BitArena::<LittleEndian, u8>::new();
+	let a = arena.alloc_mut(5);
+	let b = arena.alloc_mut(6);
+	//  Fill the rest of the first buffer, and then force a second one.
+	let rest = arena.capacity() - arena.allocated();
+	let c = arena.alloc_mut(rest);
+	let d = arena.alloc_mut(arena.capacity() + 3);
+
+	a.set_all(true);
+	b.set(0, true);
+	b.set(5, true);
+	c.set_all(true);
+	d.set(0, true);
+
+	assert_eq!(a, &bitvec![LittleEndian, u8; 1; 5]);
+	assert_eq!(b, &bitvec![LittleEndian, u8; 1, 0, 0, 0, 0, 1]);
+	assert!(c.all());
+	assert_eq!(d.count_ones(), 1);
+	assert!(d[0]);
+}
+
+/// Many small slices, frozen as they are filled, keep their contents while the
+/// arena grows underneath them.
+#[test]
+fn freeze_many() {
+	let arena = BitArena::<BigEndian, u16>::with_capacity(10);
+	let held = (0u16 .. 200)
+		.map(|n| {
+			let bits = arena.alloc_mut(11);
+			bits.store(n);
+			&*bits
+		})
+		.collect::<Vec<_>>();
+	for (n, bits) in held.iter().enumerate() {
+		assert_eq!(bits.load::<u16>(), n as u16);
+	}
+	assert_eq!(arena.allocated(), 2200);
+}
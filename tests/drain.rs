@@ -0,0 +1,97 @@
+/*! `BitVec::drain` against a `Vec<bool>` model.
+
+Dropping a `Drain` closes the gap left by the drained range by shifting the
+bits after it down a whole element at a time. These tests drain every range,
+including empty ones and ranges whose ends lie on either side of an element
+edge, from vectors at several head offsets, and drop the `Drain` after
+consuming none, some, or all of it.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+#[macro_use]
+mod model;
+
+use bitvec::prelude::*;
+
+use model::build;
+
+/// How much of the `Drain` to consume before dropping it.
+#[derive(Clone, Copy, Debug)]
+enum Consume {
+	Nothing,
+	Front(usize),
+	Back(usize),
+	All,
+}
+
+/// Drains `from .. upto`, consumes the `Drain` as directed, and drops it.
+fn run<C, T>(head: usize, len: usize, from: usize, upto: usize, how: Consume)
+where C: Cursor, T: BitStore {
+	let (mut bits, mut model) = build::<C, T>(head, len);
+	let expected = model.drain(from .. upto).collect::<Vec<_>>();
+	let mut drain = bits.drain(from .. upto);
+	assert_eq!(drain.len(), expected.len());
+	match how {
+		Consume::Nothing => {},
+		Consume::Front(n) => {
+			let got = drain.by_ref().take(n).collect::<Vec<_>>();
+			assert_eq!(got, &expected[.. got.len()]);
+		},
+		Consume::Back(n) => {
+			let got = drain.by_ref().rev().take(n).collect::<Vec<_>>();
+			let exp = expected.iter().rev().take(n).cloned();
+			assert_eq!(got, exp.collect::<Vec<_>>());
+		},
+		Consume::All => {
+			assert_eq!(drain.by_ref().collect::<Vec<_>>(), expected);
+		},
+	}
+	drop(drain);
+	assert_eq!(
+		bits.iter().collect::<Vec<_>>(),
+		model,
+		"head {}, drained {}..{} of {}, consumed {:?}",
+		head,
+		from,
+		upto,
+		len,
+		how,
+	);
+}
+
+fn each<C, T>()
+where C: Cursor, T: BitStore {
+	let w = T::BITS as usize;
+	let len = 3 * w + 3;
+	let consume = [
+		Consume::Nothing,
+		Consume::Front(2),
+		Consume::Back(2),
+		Consume::All,
+	];
+	for &head in &[0, 1, w / 2, w - 1] {
+		for from in 0 ..= len {
+			for upto in from ..= len {
+				for &how in &consume {
+					run::<C, T>(head, len, from, upto, how);
+				}
+			}
+		}
+	}
+}
+
+each_layout!(each);
+
+/// An empty drain, at either end or in the middle, leaves the vector alone.
+#[test]
+fn empty_range() {
+	for &at in &[0, 5, 8, 13] {
+		let mut bv = bitvec![LittleEndian, u8;
+			1, 0, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1,
+		];
+		let before = bv.clone();
+		assert_eq!(bv.drain(at .. at).count(), 0);
+		assert_eq!(bv, before);
+	}
+}
@@ -0,0 +1,53 @@
+/*! `BitVec::force_align` against a `Vec<bool>` model.
+
+Aligning a vector moves its bits down to the front edge of its first element,
+one whole element at a time, and clears the bits that it vacates. These tests
+align vectors from every head offset and of lengths that end within, at, and
+beyond element edges, and compare the storage to that of a vector built from
+the same bits at bit zero.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+#[macro_use]
+mod model;
+
+use bitvec::prelude::*;
+
+use model::build;
+
+fn each<C, T>()
+where C: Cursor, T: BitStore {
+	let w = T::BITS as usize;
+	for head in 0 .. w {
+		for &len in &[0, 1, w - head, w - 1, w, w + 1, 3 * w + 2, 4 * w] {
+			let (mut bits, mut model) = build::<C, T>(head, len);
+			bits.force_align();
+			let what = format!("align {} bits from head {}", len, head);
+			assert_eq!(bits.iter().collect::<Vec<_>>(), model, "{}", what);
+			//  The storage matches that of a vector built at bit zero, dead
+			//  bits included.
+			let fresh = model.iter().cloned().collect::<BitVec<C, T>>();
+			assert_eq!(bits.as_slice(), fresh.as_slice(), "{}", what);
+			//  Aligning again has no effect.
+			bits.force_align();
+			assert_eq!(bits.as_slice(), fresh.as_slice(), "{}", what);
+			//  The vector still grows from its new tail.
+			bits.push(true);
+			model.push(true);
+			assert_eq!(bits.iter().collect::<Vec<_>>(), model, "{}", what);
+		}
+	}
+}
+
+each_layout!(each);
+
+/// A vector that already begins at bit zero is left exactly as it was.
+#[test]
+fn already_aligned() {
+	let mut bv = bitvec![LittleEndian, u16; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1];
+	let before = bv.as_slice().to_vec();
+	bv.force_align();
+	assert_eq!(bv.as_slice(), &before[..]);
+	assert_eq!(bv, bitvec![LittleEndian, u16; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1]);
+}
@@ -0,0 +1,97 @@
+/*! The `u32` word layout shared with compute shaders.
+
+`BitSlice::to_gpu_words` and `BitVec::from_gpu_words` promise that bit `n` of a
+sequence lives in word `n / 32`, at bit `n % 32` counting from the least
+significant bit, whatever the cursor and storage type of the sequence. These
+tests pin that layout, so that shaders written against it keep working.
+!*/
+
+#[cfg(feature = "alloc")]
+extern crate bitvec;
+
+#[cfg(feature = "alloc")]
+use bitvec::prelude::*;
+
+/// Builds `len` bits in which bit `n` is set when `n` is a multiple of 3 or 7.
+#[cfg(feature = "alloc")]
+fn pattern<C, T>(len: usize) -> BitVec<C, T>
+where C: Cursor, T: BitStore {
+	(0 .. len).map(|n| n % 3 == 0 || n % 7 == 0).collect()
+}
+
+/// The words that `pattern(len)` must export as, computed from the layout rule.
+#[cfg(feature = "alloc")]
+fn expected(len: usize) -> Vec<u32> {
+	let mut out = vec![0u32; (len + 31) / 32];
+	for n in (0 .. len).filter(|n| n % 3 == 0 || n % 7 == 0) {
+		out[n / 32] |= 1 << (n % 32);
+	}
+	out
+}
+
+#[cfg(feature = "alloc")]
+fn check<C, T>()
+where C: Cursor, T: BitStore {
+	for &len in &[0, 1, 31, 32, 33, 63, 64, 65, 100, 200] {
+		let bv = pattern::<C, T>(len);
+		assert_eq!(bv.to_gpu_words(), expected(len), "len {}", len);
+		assert_eq!(BitVec::<C, T>::from_gpu_words(&expected(len), len), bv);
+
+		//  Misaligned views export the bits they show, not their storage.
+		for start in 1 .. 10 {
+			if start > len {
+				break;
+			}
+			let view = &bv[start ..];
+			let words = view.to_gpu_words();
+			assert_eq!(words.len(), (view.len() + 31) / 32);
+			assert_eq!(BitVec::<C, T>::from_gpu_words(&words, view.len()), view);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn layout() {
+	check::<BigEndian, u8>();
+	check::<BigEndian, u16>();
+	check::<BigEndian, u32>();
+	check::<BigEndian, u64>();
+	check::<LittleEndian, u8>();
+	check::<LittleEndian, u16>();
+	check::<LittleEndian, u32>();
+	check::<LittleEndian, u64>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fixed_words() {
+	let bv = bitvec![BigEndian, u8; 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+	assert_eq!(bv.to_gpu_words(), [0x0000_8003]);
+
+	let src = [0x0102_0304_0506_0708u64];
+	assert_eq!(
+		src.as_bitslice::<LittleEndian>().to_gpu_words(),
+		[0x0506_0708, 0x0102_0304],
+	);
+	assert_eq!(
+		src.as_bitslice::<BigEndian>().to_gpu_words(),
+		[0x20C0_4080, 0x10E0_60A0],
+	);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn import_ignores_dead_bits() {
+	let bv = BitVec::<LittleEndian, u16>::from_gpu_words(&[!0, !0], 40);
+	assert_eq!(bv.len(), 40);
+	assert!(bv.all());
+	assert_eq!(bv.as_slice(), [!0, !0, 0x00FF]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn import_too_short() {
+	BitVec::<BigEndian, u8>::from_gpu_words(&[0], 33);
+}
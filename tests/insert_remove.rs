@@ -0,0 +1,69 @@
+/*! `BitVec::insert` and `BitVec::remove` against a `Vec<bool>` model.
+
+Both operations move the bits after the index one place, a whole element at a
+time, carrying one bit between neighboring elements. These tests start the
+vector at every head offset within its first element, and insert or remove at
+every index, so that the moves cross element boundaries, begin exactly on
+them, and run over tails of several elements.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+#[macro_use]
+mod model;
+
+use bitvec::prelude::*;
+
+use model::{
+	build,
+	check,
+};
+
+fn each<C, T>()
+where C: Cursor, T: BitStore {
+	let w = T::BITS as usize;
+	for head in 0 .. w {
+		for &len in &[1, w - 1, w, 3 * w + 2] {
+			for at in 0 ..= len {
+				for &value in &[false, true] {
+					let (mut bits, mut model) = build::<C, T>(head, len);
+					bits.insert(at, value);
+					model.insert(at, value);
+					let what = format!(
+						"insert {} at {} of {}, head {}",
+						value,
+						at,
+						len,
+						head,
+					);
+					check(&bits, &model, &what);
+				}
+			}
+			for at in 0 .. len {
+				let (mut bits, mut model) = build::<C, T>(head, len);
+				assert_eq!(bits.remove(at), model.remove(at));
+				let what = format!("remove at {} of {}, head {}", at, len, head);
+				check(&bits, &model, &what);
+			}
+		}
+	}
+}
+
+each_layout!(each);
+
+/// Repeated edits at an element boundary carry bits across it each time.
+#[test]
+fn boundary_churn() {
+	let (mut bits, mut model) = build::<LittleEndian, u8>(5, 40);
+	for round in 0 .. 64 {
+		//  Index 3 is the first bit of the second element.
+		let value = round % 3 != 0;
+		bits.insert(3, value);
+		model.insert(3, value);
+		assert_eq!(bits.remove(19), model.remove(19));
+		if round % 4 == 0 {
+			assert_eq!(bits.remove(3), model.remove(3));
+		}
+	}
+	check(&bits, &model, "churn");
+}
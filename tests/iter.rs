@@ -0,0 +1,109 @@
+/*! `BitSlice::iter` against a `Vec<bool>` model.
+
+The iterator keeps a copy of the element under each of its ends, and reloads
+it only when that end crosses into another element or `nth` skips ahead. These
+tests interleave `next`, `next_back`, and `nth` over slices that begin partway
+into their first element and end partway into their last, so that both ends
+cross element boundaries, meet within one element, and skip over several.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+#[macro_use]
+mod model;
+
+use bitvec::prelude::*;
+
+use model::build;
+
+/// Drives an iterator and its model with the same pseudo-random stream of
+/// calls, checking every result and the remaining bits after each one.
+fn walk<C, T>(head: usize, len: usize, seed: u32)
+where C: Cursor + Clone, T: BitStore {
+	let w = T::BITS as usize;
+	let (bits, model) = build::<C, T>(head, len);
+	let mut iter = bits.iter();
+	let mut expect = model.iter().cloned();
+	let mut state = seed;
+	for step in 0 .. 4 * len + 4 {
+		state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+		let pick = (state >> 16) as usize;
+		let what = format!("step {} of seed {}, head {}", step, seed, head);
+		match pick % 4 {
+			0 => assert_eq!(iter.next(), expect.next(), "{}", what),
+			1 => assert_eq!(iter.next_back(), expect.next_back(), "{}", what),
+			2 => {
+				let n = (pick >> 2) % (w + 2);
+				assert_eq!(iter.nth(n), expect.nth(n), "{} nth {}", what, n);
+			},
+			_ => {
+				//  A clone carries the cached elements with it.
+				let fork = iter.clone();
+				let rest = expect.clone();
+				assert_eq!(
+					fork.rev().collect::<Vec<_>>(),
+					rest.rev().collect::<Vec<_>>(),
+					"{}",
+					what,
+				);
+			},
+		}
+		assert_eq!(iter.len(), expect.len(), "{}", what);
+		assert_eq!(
+			iter.as_bitslice().iter().collect::<Vec<_>>(),
+			expect.clone().collect::<Vec<_>>(),
+			"{}",
+			what,
+		);
+	}
+	assert_eq!(iter.next(), expect.next());
+	assert_eq!(iter.next_back(), expect.next_back());
+}
+
+fn each<C, T>()
+where C: Cursor + Clone, T: BitStore {
+	let w = T::BITS as usize;
+	for head in 0 .. w {
+		for &len in &[1, w - 1, w + 1, 3 * w + 2] {
+			for seed in 0 .. 8 {
+				walk::<C, T>(head, len, seed);
+			}
+		}
+	}
+}
+
+each_layout!(each);
+
+/// The two ends meet inside one element that both of them have cached.
+#[test]
+fn ends_meet_in_one_element() {
+	let (bits, model) = build::<LittleEndian, u8>(2, 13);
+	//  The slice covers bits 2 .. 8 of one element and 0 .. 7 of the next.
+	let mut iter = bits.iter();
+	let mut expect = model.iter().cloned();
+	for _ in 0 .. 6 {
+		assert_eq!(iter.next(), expect.next());
+	}
+	for _ in 0 .. 3 {
+		assert_eq!(iter.next_back(), expect.next_back());
+	}
+	assert_eq!(iter.next(), expect.next());
+	assert_eq!(iter.nth(1), expect.nth(1));
+	assert_eq!(iter.next_back(), expect.next_back());
+	assert_eq!(iter.next(), expect.next());
+	assert_eq!(iter.next_back(), expect.next_back());
+	assert!(iter.next().is_none());
+	assert!(expect.next().is_none());
+}
+
+/// `nth` past the end empties the iterator from both ends.
+#[test]
+fn nth_past_end() {
+	let (bits, _) = build::<BigEndian, u16>(5, 40);
+	let mut iter = bits.iter();
+	assert!(iter.next_back().is_some());
+	assert!(iter.nth(39).is_none());
+	assert_eq!(iter.len(), 0);
+	assert!(iter.next().is_none());
+	assert!(iter.next_back().is_none());
+}
@@ -0,0 +1,52 @@
+/*! Matrix operations on degenerate shapes.
+
+A matrix with no rows has no storage, but every column index below `cols` is
+still valid. Column operations must not index into the storage until they have
+a row to read.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use bitvec::prelude::*;
+
+#[test]
+fn zero_rows() {
+	let mut mat: BitMatrix = BitMatrix::new(0, 3);
+	assert!(mat.as_slice().is_empty());
+	for col in 0 .. 3 {
+		assert_eq!(mat.column(col).len(), 0);
+		assert_eq!(mat.column(col).next(), None);
+		assert_eq!(mat.column(col).next_back(), None);
+	}
+	mat.column_or(0, 2);
+	mat.column_and(2, 1);
+	mat.column_xor(1, 1);
+	assert_eq!(mat, BitMatrix::new(0, 3));
+
+	let t = mat.transpose();
+	assert_eq!((t.rows(), t.cols()), (3, 0));
+	assert_eq!(t.transpose(), mat);
+}
+
+#[test]
+fn zero_columns() {
+	let mat: BitMatrix<LittleEndian, u16> = BitMatrix::new(4, 0);
+	let t = mat.transpose();
+	assert_eq!((t.rows(), t.cols()), (0, 4));
+	assert_eq!(t.column(3).count(), 0);
+	assert_eq!(t.transpose(), mat);
+}
+
+#[test]
+fn last_column() {
+	let mut mat: BitMatrix<BigEndian, u8> = BitMatrix::new(3, 9);
+	mat.set(0, 8, true);
+	mat.set(2, 8, true);
+	assert_eq!(mat.column(8).collect::<Vec<_>>(), [true, false, true]);
+	assert_eq!(mat.column(8).rev().collect::<Vec<_>>(), [true, false, true]);
+	mat.column_xor(0, 8);
+	mat.column_and(0, 8);
+	mat.column_or(1, 0);
+	assert_eq!(mat.column(1).collect::<Vec<_>>(), [true, false, true]);
+	assert_eq!(mat.transpose().row(8), &bitvec![1, 0, 1]);
+}
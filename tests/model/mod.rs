@@ -0,0 +1,478 @@
+/*! Model-based action interpreter.
+
+This module drives a `BitVec` and a `Vec<bool>` through the same sequence of
+operations, and checks after every step that the two agree. The sequence is
+decoded from a byte string, so the same interpreter serves the deterministic
+test in `tests/model_actions.rs` and the `cargo fuzz` targets in `fuzz/`.
+
+Most operations act on a subrange of the vector, rather than on the whole, so
+that they run against every possible head offset and partial edge element.
+
+The module also holds the fixtures of the edge-case tests, which build vectors
+at a chosen head offset, compare them to their models, and run a generic test
+function under several storage layouts with `each_layout!`.
+
+Each file that includes this module uses only part of it.
+!*/
+
+#![allow(dead_code)]
+
+use bitvec::prelude::*;
+
+use std::cmp;
+
+/// Defines one test for each storage layout that the edge-case tests cover,
+/// each of which calls the generic function `$func::<C, T>`.
+///
+/// The layouts are `BigEndian` over `u8` and `u32`, `LittleEndian` over `u16`,
+/// and `Morton` over `u8`. The `=>` form places the tests in a module named
+/// `$name`, so that one file can run several functions under every layout.
+#[allow(unused_macros)]
+macro_rules! each_layout {
+	( $func:ident ) => {
+		#[test]
+		fn big_endian_u8() {
+			$func::<bitvec::cursor::BigEndian, u8>();
+		}
+
+		#[test]
+		fn little_endian_u16() {
+			$func::<bitvec::cursor::LittleEndian, u16>();
+		}
+
+		#[test]
+		fn big_endian_u32() {
+			$func::<bitvec::cursor::BigEndian, u32>();
+		}
+
+		#[test]
+		fn morton_u8() {
+			$func::<bitvec::cursor::Morton, u8>();
+		}
+	};
+
+	( $name:ident => $func:ident ) => {
+		mod $name {
+			use super::*;
+
+			each_layout!($func);
+		}
+	};
+}
+
+/// A cursor over the bytes from which actions are decoded.
+///
+/// Once the bytes are exhausted, every read produces zero.
+pub struct Source<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> Source<'a> {
+	pub fn new(data: &'a [u8]) -> Self {
+		Self { data }
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.data.is_empty()
+	}
+
+	pub fn byte(&mut self) -> u8 {
+		match self.data.split_first() {
+			Some((&b, rest)) => {
+				self.data = rest;
+				b
+			},
+			None => 0,
+		}
+	}
+
+	pub fn bit(&mut self) -> bool {
+		self.byte() & 1 == 1
+	}
+
+	/// Reads a number in `0 .. max`, or zero when `max` is zero.
+	pub fn below(&mut self, max: usize) -> usize {
+		if max == 0 {
+			return 0;
+		}
+		let raw = (self.byte() as usize) << 8 | self.byte() as usize;
+		raw % max
+	}
+
+	/// Reads a range within `0 .. len`.
+	pub fn range(&mut self, len: usize) -> (usize, usize) {
+		let a = self.below(len + 1);
+		let b = self.below(len + 1);
+		(cmp::min(a, b), cmp::max(a, b))
+	}
+
+	/// Reads a run of up to 64 bits.
+	pub fn bits(&mut self) -> Vec<bool> {
+		let len = self.below(65);
+		(0 .. len).map(|_| self.bit()).collect()
+	}
+}
+
+/// A `BitVec` under test, and the `Vec<bool>` model of its contents.
+pub struct Machine<C, T>
+where C: Cursor, T: BitStore {
+	pub bits: BitVec<C, T>,
+	pub model: Vec<bool>,
+	/// The operation most recently applied, for failure messages.
+	last: &'static str,
+}
+
+impl<C, T> Machine<C, T>
+where C: Cursor, T: BitStore {
+	pub fn new() -> Self {
+		Self {
+			bits: BitVec::new(),
+			model: Vec::new(),
+			last: "new",
+		}
+	}
+
+	/// Runs every action in `src`, checking the machine after each one.
+	pub fn run(&mut self, src: &mut Source) {
+		while !src.is_empty() {
+			self.step(src);
+			self.check();
+		}
+	}
+
+	/// Decodes one action from `src`, and applies it to both sides.
+	pub fn step(&mut self, src: &mut Source) {
+		let len = self.model.len();
+		match src.byte() % 27 {
+			0 => {
+				self.last = "push";
+				let bit = src.bit();
+				self.bits.push(bit);
+				self.model.push(bit);
+			},
+			1 => {
+				self.last = "pop";
+				assert_eq!(self.bits.pop(), self.model.pop(), "pop");
+			},
+			2 => {
+				self.last = "insert";
+				let (at, bit) = (src.below(len + 1), src.bit());
+				self.bits.insert(at, bit);
+				self.model.insert(at, bit);
+			},
+			3 => {
+				self.last = "remove";
+				if len > 0 {
+					let at = src.below(len);
+					assert_eq!(self.bits.remove(at), self.model.remove(at));
+				}
+			},
+			4 => {
+				self.last = "swap_remove";
+				if len > 0 {
+					let at = src.below(len);
+					assert_eq!(
+						self.bits.swap_remove(at),
+						self.model.swap_remove(at),
+					);
+				}
+			},
+			5 => {
+				self.last = "set";
+				if len > 0 {
+					let (at, bit) = (src.below(len), src.bit());
+					self.bits.set(at, bit);
+					self.model[at] = bit;
+				}
+			},
+			6 => {
+				self.last = "set_all";
+				let ((from, upto), bit) = (src.range(len), src.bit());
+				self.bits[from .. upto].set_all(bit);
+				for slot in &mut self.model[from .. upto] {
+					*slot = bit;
+				}
+			},
+			7 => {
+				self.last = "not";
+				let (from, upto) = src.range(len);
+				let _ = !&mut self.bits[from .. upto];
+				for slot in &mut self.model[from .. upto] {
+					*slot = !*slot;
+				}
+			},
+			8 => {
+				self.last = "iter_mut";
+				let (from, upto) = src.range(len);
+				let mask = src.bit();
+				for mut bit in self.bits[from .. upto].iter_mut() {
+					*bit ^= mask;
+				}
+				for slot in &mut self.model[from .. upto] {
+					*slot ^= mask;
+				}
+			},
+			9 => {
+				self.last = "swap";
+				if len > 0 {
+					let (a, b) = (src.below(len), src.below(len));
+					self.bits.swap(a, b);
+					self.model.swap(a, b);
+				}
+			},
+			10 => {
+				self.last = "reverse";
+				let (from, upto) = src.range(len);
+				self.bits[from .. upto].reverse();
+				self.model[from .. upto].reverse();
+			},
+			11 => {
+				self.last = "rotate_left";
+				let (from, upto) = src.range(len);
+				let by = src.below(upto - from + 1);
+				self.bits[from .. upto].rotate_left(by);
+				self.model[from .. upto].rotate_left(by);
+			},
+			12 => {
+				self.last = "rotate_right";
+				let (from, upto) = src.range(len);
+				let by = src.below(upto - from + 1);
+				self.bits[from .. upto].rotate_right(by);
+				self.model[from .. upto].rotate_right(by);
+			},
+			13 => {
+				self.last = "shl";
+				let (from, upto) = src.range(len);
+				let by = src.below(upto - from + 2);
+				self.bits[from .. upto] <<= by;
+				shift_left(&mut self.model[from .. upto], by);
+			},
+			14 => {
+				self.last = "shr";
+				let (from, upto) = src.range(len);
+				let by = src.below(upto - from + 2);
+				self.bits[from .. upto] >>= by;
+				shift_right(&mut self.model[from .. upto], by);
+			},
+			15 => {
+				self.last = "add_assign";
+				let (from, upto) = src.range(len);
+				let addend = src.bits();
+				self.bits[from .. upto] += addend.iter().cloned();
+				add_assign(&mut self.model[from .. upto], &addend);
+			},
+			16 => {
+				self.last = "bitxor_assign";
+				let (from, upto) = src.range(len);
+				let other = src.bits();
+				self.bits[from .. upto] ^= other.iter().cloned();
+				for (slot, bit) in self.model[from .. upto].iter_mut().zip(&other) {
+					*slot ^= *bit;
+				}
+			},
+			17 => {
+				self.last = "truncate";
+				let to = src.below(len + 1);
+				self.bits.truncate(to);
+				self.model.truncate(to);
+			},
+			18 => {
+				self.last = "split_off/append";
+				let at = src.below(len + 1);
+				let mut tail = self.bits.split_off(at);
+				let model_tail = self.model.split_off(at);
+				assert!(tail.iter().eq(model_tail.iter().cloned()), "split_off");
+				self.check();
+				self.bits.append(&mut tail);
+				self.model.extend(model_tail);
+			},
+			19 => {
+				self.last = "drain";
+				let (from, upto) = src.range(len);
+				let drained = self.bits.drain(from .. upto).collect::<Vec<_>>();
+				let model = self.model.drain(from .. upto).collect::<Vec<_>>();
+				assert_eq!(drained, model, "drain");
+			},
+			20 => {
+				self.last = "extend";
+				let (from, upto) = src.range(len);
+				let copy = self.bits[from .. upto].to_owned();
+				if src.bit() {
+					self.bits.extend_from_bitslice(&copy);
+				}
+				else {
+					self.bits.extend(copy.iter());
+				}
+				let copy = self.model[from .. upto].to_vec();
+				self.model.extend(copy);
+			},
+			21 => {
+				self.last = "resize";
+				let (to, bit) = (src.below(len + 65), src.bit());
+				self.bits.resize(to, bit);
+				self.model.resize(to, bit);
+			},
+			22 => {
+				self.last = "retain";
+				let modulus = src.below(4) + 1;
+				self.bits.retain(|n, bit| bit || n % modulus != 0);
+				let mut n = 0;
+				self.model.retain(|&bit| {
+					let keep = bit || n % modulus != 0;
+					n += 1;
+					keep
+				});
+			},
+			23 => {
+				self.last = "swap_ranges";
+				let (from, upto) = src.range(len);
+				let width = src.below((upto - from) / 2 + 1);
+				let other = upto - width;
+				self.bits.swap_ranges(from .. from + width, other .. upto);
+				for n in 0 .. width {
+					self.model.swap(from + n, other + n);
+				}
+			},
+			24 => {
+				self.last = "for_each_mut";
+				let (from, upto) = src.range(len);
+				let seed = src.bit();
+				let mut parity = seed;
+				self.bits[from .. upto].for_each_mut(|_, bit| {
+					parity ^= bit;
+					parity
+				});
+				let mut parity = seed;
+				for slot in &mut self.model[from .. upto] {
+					parity ^= *slot;
+					*slot = parity;
+				}
+			},
+			25 => {
+				self.last = "set_indices";
+				let (from, upto) = src.range(len);
+				let (count, bit) = (src.below(65), src.bit());
+				let indices = (0 .. count)
+					.map(|_| src.below(upto - from))
+					.collect::<Vec<_>>();
+				if from < upto {
+					self.bits[from .. upto].set_indices(indices.iter().cloned(), bit);
+					for n in indices {
+						self.model[from + n] = bit;
+					}
+				}
+			},
+			_ => {
+				self.last = "force_align";
+				self.bits.force_align();
+			},
+		}
+	}
+
+	/// Asserts that the `BitVec` and its model agree, through every read path.
+	pub fn check(&self) {
+		let last = self.last;
+		assert_eq!(self.bits.len(), self.model.len(), "len after {}", last);
+		assert!(
+			self.bits.iter().eq(self.model.iter().cloned()),
+			"contents after {}:\n{:?}\n{:?}",
+			last,
+			self.bits,
+			self.model,
+		);
+		for (n, bit) in self.model.iter().enumerate() {
+			assert_eq!(self.bits[n], *bit, "index {} after {}", n, last);
+		}
+		let ones = self.model.iter().filter(|b| **b).count();
+		assert_eq!(self.bits.count_ones(), ones, "count_ones after {}", last);
+		assert_eq!(
+			self.bits.count_zeros(),
+			self.model.len() - ones,
+			"count_zeros after {}",
+			last,
+		);
+		assert_eq!(self.bits.any(), ones > 0, "any after {}", last);
+		assert_eq!(
+			self.bits.all(),
+			ones == self.model.len(),
+			"all after {}",
+			last,
+		);
+		assert_eq!(
+			self.bits.first_one(),
+			self.model.iter().position(|b| *b),
+			"first_one after {}",
+			last,
+		);
+		assert_eq!(
+			self.bits.last_zero(),
+			self.model.iter().rposition(|b| !*b),
+			"last_zero after {}",
+			last,
+		);
+	}
+}
+
+/// Models `<<=` on a `BitSlice`: bits move towards the front, and the back is
+/// zero-filled.
+pub fn shift_left(model: &mut [bool], by: usize) {
+	let len = model.len();
+	let by = cmp::min(by, len);
+	for n in 0 .. len - by {
+		model[n] = model[n + by];
+	}
+	for slot in &mut model[len - by ..] {
+		*slot = false;
+	}
+}
+
+/// Models `>>=` on a `BitSlice`: bits move towards the back, and the front is
+/// zero-filled.
+pub fn shift_right(model: &mut [bool], by: usize) {
+	let len = model.len();
+	let by = cmp::min(by, len);
+	for n in (by .. len).rev() {
+		model[n] = model[n - by];
+	}
+	for slot in &mut model[.. by] {
+		*slot = false;
+	}
+}
+
+/// Models `+=` on a `BitSlice`: big-endian wrapping addition, with the addend
+/// aligned at the back of the slice.
+pub fn add_assign(model: &mut [bool], addend: &[bool]) {
+	let mut carry = false;
+	let mut addend = addend.iter().rev();
+	for slot in model.iter_mut().rev() {
+		let b = addend.next().cloned().unwrap_or(false);
+		let sum = *slot as u8 + b as u8 + carry as u8;
+		*slot = sum & 1 == 1;
+		carry = sum > 1;
+	}
+}
+
+/// Builds a vector of `len` bits that begins `head` bits into its first
+/// element, and the model of its contents.
+pub fn build<C, T>(head: usize, len: usize) -> (BitVec<C, T>, Vec<bool>)
+where C: Cursor, T: BitStore {
+	let pattern = |n: usize| n % 3 == 0 || n % 7 == 1;
+	let mut bv = (0 .. head + len).map(pattern).collect::<BitVec<C, T>>();
+	let bits = bv.split_off(head);
+	let model = (head .. head + len).map(pattern).collect::<Vec<_>>();
+	assert_eq!(bits.iter().collect::<Vec<_>>(), model);
+	(bits, model)
+}
+
+/// Checks that a vector holds the bits of its model, read from either end.
+pub fn check<C, T>(bits: &BitVec<C, T>, model: &[bool], what: &str)
+where C: Cursor, T: BitStore {
+	assert_eq!(bits.len(), model.len(), "{}", what);
+	assert_eq!(bits.iter().collect::<Vec<_>>(), model, "{}", what);
+	assert_eq!(
+		bits.iter().rev().collect::<Vec<_>>(),
+		model.iter().rev().cloned().collect::<Vec<_>>(),
+		"{}",
+		what,
+	);
+}
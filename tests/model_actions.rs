@@ -0,0 +1,76 @@
+/*! Runs the model-based action interpreter over pseudo-random action streams.
+
+The `fuzz/` targets drive the same interpreter from `cargo fuzz`. This test
+replays a fixed set of streams, so that every `cargo test` run exercises each
+action at a spread of head offsets and lengths.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+mod model;
+
+use bitvec::prelude::*;
+
+use model::{
+	Machine,
+	Source,
+};
+
+/// Produces a deterministic byte stream from a seed, by xorshift.
+fn stream(mut seed: u64, len: usize) -> Vec<u8> {
+	(0 .. len).map(|_| {
+		seed ^= seed << 13;
+		seed ^= seed >> 7;
+		seed ^= seed << 17;
+		(seed >> 32) as u8
+	}).collect()
+}
+
+fn replay<C, T>()
+where C: Cursor, T: BitStore {
+	for seed in 1 .. 65 {
+		let data = stream(seed, 2048);
+		Machine::<C, T>::new().run(&mut Source::new(&data));
+	}
+}
+
+#[test]
+fn big_endian_u8() {
+	replay::<BigEndian, u8>();
+}
+
+#[test]
+fn little_endian_u16() {
+	replay::<LittleEndian, u16>();
+}
+
+#[test]
+fn big_endian_u32() {
+	replay::<BigEndian, u32>();
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn little_endian_u64() {
+	replay::<LittleEndian, u64>();
+}
+
+#[test]
+fn morton_u8() {
+	replay::<bitvec::cursor::Morton, u8>();
+}
+
+#[test]
+fn morton_u32() {
+	replay::<bitvec::cursor::Morton, u32>();
+}
+
+#[test]
+fn byte_swapped_u32() {
+	replay::<bitvec::cursor::ByteSwapped, u32>();
+}
+
+#[test]
+fn nibble_swapped_u16() {
+	replay::<bitvec::cursor::NibbleSwapped, u16>();
+}
@@ -0,0 +1,74 @@
+/*! Shifts by whole elements on slices with partial edge elements.
+
+Shifting by a multiple of the element width once copied whole storage elements
+even when the slice did not fill its first or last element. That moved bits
+that lie outside the slice, and could panic. Such slices must shift bit by bit.
+
+This is a regression.
+!*/
+
+use bitvec::prelude::*;
+
+fn each<C, T>()
+where C: Cursor, T: BitStore {
+	let w = T::BITS as usize;
+	let total = 4 * w;
+	for from in 0 .. w {
+		for upto in total - w + 1 ..= total {
+			if from == 0 && upto == total {
+				continue;
+			}
+			for &shamt in &[w, 2 * w] {
+				for &left in &[true, false] {
+					let mut data = [T::bits(false); 4];
+					let bits = BitSlice::<C, T>::from_slice_mut(&mut data);
+					for n in 0 .. total {
+						bits.set(n, n % 3 == 0);
+					}
+					let model = bits.iter().collect::<Vec<_>>();
+					let len = upto - from;
+					let slice = &mut bits[from .. upto];
+					if left {
+						*slice <<= shamt;
+					}
+					else {
+						*slice >>= shamt;
+					}
+					for (n, bit) in bits.iter().enumerate() {
+						let expected = if n < from || n >= upto {
+							model[n]
+						}
+						else {
+							let at = n - from;
+							let src = if left {
+								at.checked_add(shamt).filter(|&s| s < len)
+							}
+							else {
+								at.checked_sub(shamt)
+							};
+							src.map_or(false, |s| model[from + s])
+						};
+						assert_eq!(
+							bit,
+							expected,
+							"{}..{} shifted {} by {}, bit {}",
+							from,
+							upto,
+							if left { "left" } else { "right" },
+							shamt,
+							n,
+						);
+					}
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn partial_edges() {
+	each::<BigEndian, u8>();
+	each::<LittleEndian, u8>();
+	each::<BigEndian, u16>();
+	each::<LittleEndian, u32>();
+}